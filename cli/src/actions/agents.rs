@@ -15,13 +15,90 @@
  * -----------------------------------------------------------------------------
  */
 
+use std::collections::HashMap;
+
 use crate::error::CliError;
-use crate::http::submit_batches;
+use crate::format::{print_item, print_list, CsvRecord, OutputFormat};
+use crate::http::{fetch_all_pages, submit_batches};
 use crate::transaction::{pike_batch_builder, PIKE_NAMESPACE};
 use grid_sdk::{
-    protocol::pike::payload::{Action, CreateAgentAction, PikePayloadBuilder, UpdateAgentAction},
+    protocol::pike::payload::{
+        Action, CreateAgentAction, DeleteAgentAction, PikePayloadBuilder, RotateAgentKeyAction,
+        UpdateAgentAction,
+    },
     protos::IntoProto,
 };
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GridAgentSlice {
+    pub public_key: String,
+    pub org_id: String,
+    pub active: bool,
+    pub roles: Vec<String>,
+    pub metadata: HashMap<String, String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub predecessor: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_id: Option<String>,
+}
+
+impl CsvRecord for GridAgentSlice {
+    const CSV_HEADERS: &'static [&'static str] = &[
+        "public_key",
+        "org_id",
+        "active",
+        "roles",
+        "metadata",
+        "predecessor",
+        "service_id",
+    ];
+}
+
+pub fn display_agent(agent: &GridAgentSlice) {
+    println!(
+        "Public Key: {:?}\n Organization ID: {:?}\n Active: {:?}\n Roles: {:?}\n Metadata:",
+        agent.public_key, agent.org_id, agent.active, agent.roles,
+    );
+    agent
+        .metadata
+        .iter()
+        .for_each(|(key, value)| println!("\t{:?}: {:?}", key, value));
+    if let Some(predecessor) = &agent.predecessor {
+        println!(" Predecessor: {:?}", predecessor);
+    }
+}
+
+pub fn do_list_agents(
+    url: &str,
+    service_id: Option<String>,
+    format: OutputFormat,
+) -> Result<(), CliError> {
+    let mut path = "/agent".to_string();
+    if let Some(service_id) = service_id {
+        path = format!("{}?service_id={}", path, service_id);
+    }
+    let agents = fetch_all_pages::<GridAgentSlice>(url, &path)?;
+    print_list(format, &agents, display_agent)
+}
+
+pub fn do_show_agent(
+    url: &str,
+    public_key: &str,
+    service_id: Option<String>,
+    format: OutputFormat,
+) -> Result<(), CliError> {
+    let client = Client::new();
+    let mut final_url = format!("{}/agent/{}", url, public_key);
+    if let Some(service_id) = service_id {
+        final_url = format!("{}?service_id={}", final_url, service_id);
+    }
+    let agent = client.get(&final_url).send()?.json::<GridAgentSlice>()?;
+    print_item(format, &agent, display_agent)
+}
 
 pub fn do_create_agent(
     url: &str,
@@ -70,3 +147,51 @@ pub fn do_update_agent(
 
     submit_batches(url, wait, &batch_list, service_id.as_deref())
 }
+
+pub fn do_delete_agent(
+    url: &str,
+    key: Option<String>,
+    wait: u64,
+    delete_agent: DeleteAgentAction,
+    service_id: Option<String>,
+) -> Result<(), CliError> {
+    let payload = PikePayloadBuilder::new()
+        .with_action(Action::DeleteAgent)
+        .with_delete_agent(delete_agent)
+        .build()
+        .map_err(|err| CliError::UserError(format!("{}", err)))?;
+
+    let batch_list = pike_batch_builder(key)
+        .add_transaction(
+            &payload.into_proto()?,
+            &[PIKE_NAMESPACE.to_string()],
+            &[PIKE_NAMESPACE.to_string()],
+        )?
+        .create_batch_list();
+
+    submit_batches(url, wait, &batch_list, service_id.as_deref())
+}
+
+pub fn do_rotate_agent_key(
+    url: &str,
+    key: Option<String>,
+    wait: u64,
+    rotate_agent_key: RotateAgentKeyAction,
+    service_id: Option<String>,
+) -> Result<(), CliError> {
+    let payload = PikePayloadBuilder::new()
+        .with_action(Action::RotateAgentKey)
+        .with_rotate_agent_key(rotate_agent_key)
+        .build()
+        .map_err(|err| CliError::UserError(format!("{}", err)))?;
+
+    let batch_list = pike_batch_builder(key)
+        .add_transaction(
+            &payload.into_proto()?,
+            &[PIKE_NAMESPACE.to_string()],
+            &[PIKE_NAMESPACE.to_string()],
+        )?
+        .create_batch_list();
+
+    submit_batches(url, wait, &batch_list, service_id.as_deref())
+}
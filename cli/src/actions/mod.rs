@@ -19,7 +19,10 @@
 pub mod admin;
 pub mod agents;
 pub mod database;
+pub mod delegations;
 pub mod keygen;
 pub mod organizations;
 pub mod products;
+pub mod records;
+pub mod roles;
 pub mod schemas;
@@ -15,15 +15,126 @@
  * -----------------------------------------------------------------------------
  */
 
+use std::collections::HashMap;
+
 use crate::error::CliError;
-use crate::http::submit_batches;
+use crate::format::{print_item, print_list, CsvRecord, OutputFormat};
+use crate::http::{fetch_all_pages, submit_batches, submit_batches_with_status, BatchStatus};
 use crate::transaction::{pike_batch_builder, PIKE_NAMESPACE};
+use crate::yaml_parser::{
+    parse_value_as_boolean, parse_value_as_key_value_entries, parse_value_as_sequence,
+    parse_value_as_string, parse_value_as_vec_string,
+};
 use grid_sdk::{
-    protocol::pike::payload::{
-        Action, CreateOrganizationAction, PikePayloadBuilder, UpdateOrganizationAction,
+    protocol::pike::{
+        payload::{
+            Action, CreateAgentActionBuilder, CreateOrganizationAction,
+            CreateOrganizationActionBuilder, CreateRoleActionBuilder, DeleteOrganizationAction,
+            PikePayload, PikePayloadBuilder, UpdateOrganizationAction,
+        },
+        state::{AlternateId, AlternateIdBuilder, Location, LocationBuilder},
     },
     protos::IntoProto,
 };
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use serde_yaml::{Mapping, Value};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GridOrganizationSlice {
+    pub org_id: String,
+    pub name: String,
+    pub address: String,
+    pub metadata: Vec<HashMap<String, String>>,
+    #[serde(default)]
+    pub alternate_ids: Vec<GridAlternateIdSlice>,
+    #[serde(default)]
+    pub locations: Vec<HashMap<String, String>>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GridAlternateIdSlice {
+    pub id_type: String,
+    pub id: String,
+}
+
+impl CsvRecord for GridOrganizationSlice {
+    const CSV_HEADERS: &'static [&'static str] = &[
+        "org_id",
+        "name",
+        "address",
+        "metadata",
+        "alternate_ids",
+        "locations",
+        "service_id",
+    ];
+}
+
+pub fn display_organization(organization: &GridOrganizationSlice) {
+    println!(
+        "Organization ID: {:?}\n Name: {:?}\n Address: {:?}\n Metadata:",
+        organization.org_id, organization.name, organization.address,
+    );
+    organization
+        .metadata
+        .iter()
+        .flat_map(|entry| entry.iter())
+        .for_each(|(key, value)| println!("\t{:?}: {:?}", key, value));
+    if !organization.alternate_ids.is_empty() {
+        println!(" Alternate IDs:");
+        organization
+            .alternate_ids
+            .iter()
+            .for_each(|alternate_id| println!("\t{}:{}", alternate_id.id_type, alternate_id.id));
+    }
+    if !organization.locations.is_empty() {
+        println!(" Locations:");
+        organization.locations.iter().for_each(|location| {
+            println!(
+                "\t{:?}: {:?}",
+                location.get("name").map(String::as_str).unwrap_or_default(),
+                location
+                    .get("country")
+                    .map(String::as_str)
+                    .unwrap_or_default(),
+            )
+        });
+    }
+}
+
+pub fn do_list_organizations(
+    url: &str,
+    service_id: Option<String>,
+    format: OutputFormat,
+) -> Result<(), CliError> {
+    let mut path = "/organization".to_string();
+    if let Some(service_id) = service_id {
+        path = format!("{}?service_id={}", path, service_id);
+    }
+    let organizations = fetch_all_pages::<GridOrganizationSlice>(url, &path)?;
+    print_list(format, &organizations, display_organization)
+}
+
+pub fn do_show_organization(
+    url: &str,
+    org_id: &str,
+    service_id: Option<String>,
+    format: OutputFormat,
+) -> Result<(), CliError> {
+    let client = Client::new();
+    let mut final_url = format!("{}/organization/{}", url, org_id);
+    if let Some(service_id) = service_id {
+        final_url = format!("{}?service_id={}", final_url, service_id);
+    }
+    let organization = client
+        .get(&final_url)
+        .send()?
+        .json::<GridOrganizationSlice>()?;
+    print_item(format, &organization, display_organization)
+}
 
 pub fn do_create_organization(
     url: &str,
@@ -72,3 +183,493 @@ pub fn do_update_organization(
 
     submit_batches(url, wait, &batch_list, service_id.as_deref())
 }
+
+pub fn do_delete_organization(
+    url: &str,
+    key: Option<String>,
+    wait: u64,
+    delete_org: DeleteOrganizationAction,
+    service_id: Option<String>,
+) -> Result<(), CliError> {
+    let payload = PikePayloadBuilder::new()
+        .with_action(Action::DeleteOrganization)
+        .with_delete_organization(delete_org)
+        .build()
+        .map_err(|err| CliError::UserError(format!("{}", err)))?;
+
+    let batch_list = pike_batch_builder(key)
+        .add_transaction(
+            &payload.into_proto()?,
+            &[PIKE_NAMESPACE.to_string()],
+            &[PIKE_NAMESPACE.to_string()],
+        )?
+        .create_batch_list();
+
+    submit_batches(url, wait, &batch_list, service_id.as_deref())
+}
+
+/// A transaction of an organization import, along with the description
+/// printed next to its result and the name of the key that signs it; `None`
+/// signs with the key the import was run with
+#[derive(Debug)]
+struct ImportTransaction {
+    description: String,
+    payload: PikePayload,
+    signing_key: Option<String>,
+}
+
+/**
+ * Create organizations, along with their roles and agents, from a yaml file
+ *
+ * All of the transactions are submitted in a single batch, so either every
+ * organization, role and agent in the file is created or none are. The key
+ * that signs an organization's transactions becomes its admin agent, so it
+ * must not already be an agent. Each organization names that key with
+ * `signing_key`; at most one organization may leave it out and use the key
+ * the import is run with, which also signs the batch.
+ *
+ * url - Url for the REST API
+ * key - Signing key of the agent
+ * wait - Time in seconds to wait for commit
+ * path - Path to the yaml file that contains the organization descriptions
+ */
+pub fn do_import_organizations(
+    url: &str,
+    key: Option<String>,
+    wait: u64,
+    path: &str,
+    service_id: Option<String>,
+) -> Result<(), CliError> {
+    let file = std::fs::File::open(path)?;
+    let organizations_yaml: Vec<Mapping> = serde_yaml::from_reader(file)?;
+    let transactions = parse_organizations_yaml(&organizations_yaml)?;
+
+    let payloads = transactions
+        .iter()
+        .map(|transaction| transaction.payload.clone().into_proto())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let signed_payloads = payloads
+        .iter()
+        .zip(transactions.iter())
+        .map(|(payload, transaction)| {
+            (
+                payload,
+                transaction.signing_key.clone().or_else(|| key.clone()),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let batch_list = pike_batch_builder(key)
+        .add_signed_atomic_batch(
+            &signed_payloads,
+            &[PIKE_NAMESPACE.to_string()],
+            &[PIKE_NAMESPACE.to_string()],
+        )?
+        .create_batch_list();
+
+    let transaction_ids = batch_list
+        .get_batches()
+        .iter()
+        .flat_map(|batch| batch.get_transactions())
+        .map(|transaction| transaction.get_header_signature().to_string())
+        .collect::<Vec<String>>();
+
+    let statuses = submit_batches_with_status(url, wait, &batch_list, service_id.as_deref())?;
+    let status = statuses.first();
+
+    for (transaction, transaction_id) in transactions.iter().zip(transaction_ids.iter()) {
+        println!(
+            "{}: {}",
+            transaction.description,
+            import_result(status, transaction_id)
+        );
+    }
+
+    match status {
+        Some(status) if status.status == "INVALID" => Err(CliError::UserError(
+            "Import failed; no organizations, roles, or agents were created".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/**
+ * Describe the outcome of one transaction of an import batch
+ *
+ * status - Status of the batch, if it was checked
+ * transaction_id - Header signature of the transaction
+ */
+fn import_result(status: Option<&BatchStatus>, transaction_id: &str) -> String {
+    let status = match status {
+        Some(status) => status,
+        None => return "SUBMITTED".to_string(),
+    };
+
+    if status.status != "INVALID" {
+        return status.status.clone();
+    }
+
+    // Only the transaction that failed is listed; the others were rejected
+    // along with it because they share its batch
+    match status
+        .invalid_transactions
+        .iter()
+        .find(|invalid| invalid.get("id").map(String::as_str) == Some(transaction_id))
+    {
+        Some(invalid) => format!(
+            "INVALID ({})",
+            invalid
+                .get("message")
+                .map(String::as_str)
+                .unwrap_or_default()
+        ),
+        None => "NOT APPLIED".to_string(),
+    }
+}
+
+/**
+ * Build the transactions of an import, in the order they must be applied:
+ * the organization is followed by its roles and then its agents.
+ *
+ * organizations_yaml - Yaml objects describing organizations; no two may be
+ * signed by the same key, since a key can only be an agent of one organization
+ */
+fn parse_organizations_yaml(
+    organizations_yaml: &[Mapping],
+) -> Result<Vec<ImportTransaction>, CliError> {
+    if organizations_yaml.is_empty() {
+        return Err(CliError::InvalidYamlError(
+            "An import must describe at least one organization".to_string(),
+        ));
+    }
+
+    let mut transactions = Vec::new();
+    let mut signed_orgs: Vec<(Option<String>, String)> = Vec::new();
+
+    for organization_yaml in organizations_yaml {
+        let org_id = parse_value_as_string(organization_yaml, "org_id")?.ok_or_else(|| {
+            CliError::InvalidYamlError("Missing `org_id` field for Organization.".to_string())
+        })?;
+
+        let name = parse_value_as_string(organization_yaml, "name")?.ok_or_else(|| {
+            CliError::InvalidYamlError("Missing `name` field for Organization.".to_string())
+        })?;
+
+        let signing_key = parse_value_as_string(organization_yaml, "signing_key")?;
+        if let Some((_, other_org_id)) = signed_orgs.iter().find(|(key, _)| *key == signing_key) {
+            return Err(CliError::InvalidYamlError(format!(
+                "Organizations {} and {} are signed by the same key; the signing key becomes \
+                 the organization's admin agent and a key can only be an agent of one \
+                 organization, so give each organization its own `signing_key`",
+                other_org_id, org_id
+            )));
+        }
+        signed_orgs.push((signing_key.clone(), org_id.clone()));
+
+        let alternate_ids = parse_value_as_sequence(organization_yaml, "alternate_ids")?
+            .unwrap_or_default()
+            .iter()
+            .map(|value| parse_alternate_id_yaml(as_mapping(value, "alternate ID")?))
+            .collect::<Result<Vec<AlternateId>, CliError>>()?;
+
+        let locations = parse_value_as_sequence(organization_yaml, "locations")?
+            .unwrap_or_default()
+            .iter()
+            .map(|value| parse_location_yaml(as_mapping(value, "location")?))
+            .collect::<Result<Vec<Location>, CliError>>()?;
+
+        let create_org = CreateOrganizationActionBuilder::new()
+            .with_org_id(org_id.clone())
+            .with_name(name)
+            .with_address(parse_value_as_string(organization_yaml, "address")?.unwrap_or_default())
+            .with_metadata(
+                parse_value_as_key_value_entries(organization_yaml, "metadata")?
+                    .unwrap_or_default(),
+            )
+            .with_alternate_ids(alternate_ids)
+            .with_locations(locations)
+            .build()
+            .map_err(|err| CliError::InvalidYamlError(format!("{}", err)))?;
+
+        transactions.push(ImportTransaction {
+            description: format!("Create organization {}", org_id),
+            payload: PikePayloadBuilder::new()
+                .with_action(Action::CreateOrganization)
+                .with_create_organization(create_org)
+                .build()
+                .map_err(|err| CliError::PayloadError(format!("{}", err)))?,
+            signing_key: signing_key.clone(),
+        });
+
+        for value in parse_value_as_sequence(organization_yaml, "roles")?.unwrap_or_default() {
+            let role_yaml = as_mapping(&value, "role")?;
+
+            let role_name = parse_value_as_string(role_yaml, "name")?.ok_or_else(|| {
+                CliError::InvalidYamlError("Missing `name` field for Role.".to_string())
+            })?;
+
+            let create_role = CreateRoleActionBuilder::new()
+                .with_org_id(org_id.clone())
+                .with_name(role_name.clone())
+                .with_description(
+                    parse_value_as_string(role_yaml, "description")?.unwrap_or_default(),
+                )
+                .with_permissions(
+                    parse_value_as_vec_string(role_yaml, "permissions")?.unwrap_or_default(),
+                )
+                .with_inherit_from(
+                    parse_value_as_vec_string(role_yaml, "inherit_from")?.unwrap_or_default(),
+                )
+                .build()
+                .map_err(|err| CliError::InvalidYamlError(format!("{}", err)))?;
+
+            transactions.push(ImportTransaction {
+                description: format!("Create role {} for {}", role_name, org_id),
+                payload: PikePayloadBuilder::new()
+                    .with_action(Action::CreateRole)
+                    .with_create_role(create_role)
+                    .build()
+                    .map_err(|err| CliError::PayloadError(format!("{}", err)))?,
+                signing_key: signing_key.clone(),
+            });
+        }
+
+        for value in parse_value_as_sequence(organization_yaml, "agents")?.unwrap_or_default() {
+            let agent_yaml = as_mapping(&value, "agent")?;
+
+            let public_key = parse_value_as_string(agent_yaml, "public_key")?.ok_or_else(|| {
+                CliError::InvalidYamlError("Missing `public_key` field for Agent.".to_string())
+            })?;
+
+            let create_agent = CreateAgentActionBuilder::new()
+                .with_org_id(org_id.clone())
+                .with_public_key(public_key.clone())
+                .with_active(parse_value_as_boolean(agent_yaml, "active")?.unwrap_or(true))
+                .with_roles(parse_value_as_vec_string(agent_yaml, "roles")?.unwrap_or_default())
+                .with_metadata(
+                    parse_value_as_key_value_entries(agent_yaml, "metadata")?.unwrap_or_default(),
+                )
+                .build()
+                .map_err(|err| CliError::InvalidYamlError(format!("{}", err)))?;
+
+            transactions.push(ImportTransaction {
+                description: format!("Create agent {} for {}", public_key, org_id),
+                payload: PikePayloadBuilder::new()
+                    .with_action(Action::CreateAgent)
+                    .with_create_agent(create_agent)
+                    .build()
+                    .map_err(|err| CliError::PayloadError(format!("{}", err)))?,
+                signing_key: signing_key.clone(),
+            });
+        }
+    }
+
+    Ok(transactions)
+}
+
+fn parse_alternate_id_yaml(alternate_id_yaml: &Mapping) -> Result<AlternateId, CliError> {
+    let id_type = parse_value_as_string(alternate_id_yaml, "id_type")?.ok_or_else(|| {
+        CliError::InvalidYamlError("Missing `id_type` field for alternate ID.".to_string())
+    })?;
+
+    let id = parse_value_as_string(alternate_id_yaml, "id")?.ok_or_else(|| {
+        CliError::InvalidYamlError("Missing `id` field for alternate ID.".to_string())
+    })?;
+
+    AlternateIdBuilder::new()
+        .with_id_type(id_type)
+        .with_id(id)
+        .build()
+        .map_err(|err| CliError::InvalidYamlError(format!("{}", err)))
+}
+
+fn parse_location_yaml(location_yaml: &Mapping) -> Result<Location, CliError> {
+    let name = parse_value_as_string(location_yaml, "name")?.ok_or_else(|| {
+        CliError::InvalidYamlError("Missing `name` field for Location.".to_string())
+    })?;
+
+    let country = parse_value_as_string(location_yaml, "country")?.ok_or_else(|| {
+        CliError::InvalidYamlError("Missing `country` field for Location.".to_string())
+    })?;
+
+    LocationBuilder::new()
+        .with_name(name)
+        .with_street_address(
+            parse_value_as_string(location_yaml, "street_address")?.unwrap_or_default(),
+        )
+        .with_locality(parse_value_as_string(location_yaml, "locality")?.unwrap_or_default())
+        .with_region(parse_value_as_string(location_yaml, "region")?.unwrap_or_default())
+        .with_postal_code(parse_value_as_string(location_yaml, "postal_code")?.unwrap_or_default())
+        .with_country(country)
+        .with_gln(parse_value_as_string(location_yaml, "gln")?.unwrap_or_default())
+        .build()
+        .map_err(|err| CliError::InvalidYamlError(format!("{}", err)))
+}
+
+fn as_mapping<'a>(value: &'a Value, name: &str) -> Result<&'a Mapping, CliError> {
+    value
+        .as_mapping()
+        .ok_or_else(|| CliError::InvalidYamlError(format!("Failed to parse {}.", name)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    static EXAMPLE_ORGANIZATION_YAML: &str = r##"- org_id: "acme"
+  name: "Acme Corporation"
+  address: "1 Main Street"
+  metadata:
+    gs1_company_prefixes: "0614141"
+  alternate_ids:
+    - id_type: "gln"
+      id: "1234567890128"
+  roles:
+    - name: "shipper"
+      description: "Ships products"
+      permissions:
+        - "can_create_product"
+  agents:
+    - public_key: "agent_key"
+      roles:
+        - "shipper"
+    - public_key: "inactive_key"
+      active: false"##;
+
+    /*
+     * Verifies parse_organizations_yaml creates the organization first, then
+     * its roles, then its agents, carrying over the fields of each
+     */
+    #[test]
+    fn test_parse_organizations_yaml() {
+        let organizations_yaml: Vec<Mapping> =
+            serde_yaml::from_str(EXAMPLE_ORGANIZATION_YAML).expect("Error reading yaml");
+
+        let transactions =
+            parse_organizations_yaml(&organizations_yaml).expect("Error parsing yaml");
+
+        let actions = transactions
+            .iter()
+            .map(|transaction| transaction.payload.action().clone())
+            .collect::<Vec<Action>>();
+        assert_eq!(
+            actions,
+            vec![
+                Action::CreateOrganization,
+                Action::CreateRole,
+                Action::CreateAgent,
+                Action::CreateAgent,
+            ]
+        );
+
+        let create_org = transactions[0].payload.create_organization();
+        assert_eq!(create_org.org_id(), "acme");
+        assert_eq!(create_org.address(), "1 Main Street");
+        assert_eq!(create_org.metadata()[0].key(), "gs1_company_prefixes");
+        assert_eq!(create_org.metadata()[0].value(), "0614141");
+        assert_eq!(create_org.alternate_ids()[0].id_type(), "gln");
+
+        let create_role = transactions[1].payload.create_role();
+        assert_eq!(create_role.org_id(), "acme");
+        assert_eq!(
+            create_role.permissions(),
+            &["can_create_product".to_string()][..]
+        );
+
+        let create_agent = transactions[2].payload.create_agent();
+        assert_eq!(create_agent.org_id(), "acme");
+        assert!(*create_agent.active());
+        assert_eq!(create_agent.roles(), &["shipper".to_string()][..]);
+        assert!(!*transactions[3].payload.create_agent().active());
+    }
+
+    /*
+     * Verifies an import is rejected before submission when a required field
+     * is missing or metadata is not a mapping of strings
+     */
+    #[test]
+    fn test_parse_organizations_yaml_invalid() {
+        let missing_name: Vec<Mapping> =
+            serde_yaml::from_str("- org_id: \"acme\"").expect("Error reading yaml");
+        assert!(parse_organizations_yaml(&missing_name).is_err());
+
+        let missing_key: Vec<Mapping> = serde_yaml::from_str(
+            "- org_id: \"acme\"\n  name: \"Acme\"\n  agents:\n    - roles: []",
+        )
+        .expect("Error reading yaml");
+        assert!(parse_organizations_yaml(&missing_key).is_err());
+
+        let numeric_metadata: Vec<Mapping> = serde_yaml::from_str(
+            "- org_id: \"acme\"\n  name: \"Acme\"\n  metadata:\n    prefix: 614141",
+        )
+        .expect("Error reading yaml");
+        assert!(parse_organizations_yaml(&numeric_metadata).is_err());
+    }
+
+    /*
+     * Verifies an import may describe several organizations, each of whose
+     * transactions are signed with the key it names, or with the import's key
+     * when it names none
+     */
+    #[test]
+    fn test_parse_organizations_yaml_multiple_organizations() {
+        let organizations_yaml: Vec<Mapping> = serde_yaml::from_str(&format!(
+            "{}\n- org_id: \"globex\"\n  name: \"Globex\"\n  signing_key: \"globex_admin\"\n  \
+             agents:\n    - public_key: \"globex_agent\"",
+            EXAMPLE_ORGANIZATION_YAML
+        ))
+        .expect("Error reading yaml");
+
+        let transactions =
+            parse_organizations_yaml(&organizations_yaml).expect("Error parsing yaml");
+
+        assert_eq!(transactions.len(), 6);
+        assert!(transactions[..4]
+            .iter()
+            .all(|transaction| transaction.signing_key.is_none()));
+
+        assert_eq!(
+            transactions[4].payload.action(),
+            &Action::CreateOrganization
+        );
+        assert_eq!(
+            transactions[4].payload.create_organization().org_id(),
+            "globex"
+        );
+        assert_eq!(transactions[5].payload.create_agent().org_id(), "globex");
+        assert!(transactions[4..]
+            .iter()
+            .all(|transaction| transaction.signing_key.as_deref() == Some("globex_admin")));
+    }
+
+    /*
+     * Verifies an import is rejected before submission when it describes no
+     * organizations, or two organizations signed by the same key, since a key
+     * can only become the admin agent of one
+     */
+    #[test]
+    fn test_parse_organizations_yaml_shared_signing_key() {
+        let default_key: Vec<Mapping> = serde_yaml::from_str(&format!(
+            "{}\n- org_id: \"globex\"\n  name: \"Globex\"",
+            EXAMPLE_ORGANIZATION_YAML
+        ))
+        .expect("Error reading yaml");
+        match parse_organizations_yaml(&default_key) {
+            Err(CliError::InvalidYamlError(err)) => {
+                assert!(err.starts_with("Organizations acme and globex are signed by the same key"))
+            }
+            res => panic!("Expected an InvalidYamlError, got {:?}", res),
+        }
+
+        let named_key: Vec<Mapping> = serde_yaml::from_str(
+            "- org_id: \"acme\"\n  name: \"Acme\"\n  signing_key: \"admin\"\n\
+             - org_id: \"globex\"\n  name: \"Globex\"\n  signing_key: \"admin\"",
+        )
+        .expect("Error reading yaml");
+        assert!(parse_organizations_yaml(&named_key).is_err());
+
+        let no_organizations: Vec<Mapping> = Vec::new();
+        assert!(parse_organizations_yaml(&no_organizations).is_err());
+    }
+}
@@ -12,43 +12,70 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::http::submit_batches;
+use crate::http::{fetch_all_pages, submit_batches};
 use crate::transaction::{
-    product_batch_builder, GRID_PRODUCT_NAMESPACE, GRID_SCHEMA_NAMESPACE, PIKE_NAMESPACE,
+    product_batch_builder, BLOCK_INFO_NAMESPACE, GRID_PRODUCT_NAMESPACE, GRID_SCHEMA_NAMESPACE,
+    PIKE_NAMESPACE,
 };
 use grid_sdk::protocol::product::payload::{
     Action, ProductCreateAction, ProductCreateActionBuilder, ProductDeleteActionBuilder,
-    ProductPayload, ProductPayloadBuilder, ProductUpdateAction, ProductUpdateActionBuilder,
+    ProductPayload, ProductPayloadBuilder, ProductTransferActionBuilder, ProductUpdateAction,
+    ProductUpdateActionBuilder,
 };
 use grid_sdk::protocol::product::state::ProductType;
-use grid_sdk::protocol::schema::state::PropertyValue;
+use grid_sdk::protocol::schema::state::{DataType, PropertyValue, PropertyValueBuilder};
 use grid_sdk::protos::IntoProto;
 use reqwest::Client;
 
 use crate::error::CliError;
-use serde::Deserialize;
+use crate::format::{print_item, print_list, CsvRecord, OutputFormat};
+use serde::{Deserialize, Serialize};
 
 use crate::yaml_parser::{
-    parse_value_as_product_type, parse_value_as_repeated_property_values, parse_value_as_sequence,
-    parse_value_as_string,
+    parse_value_as_data_type, parse_value_as_product_type, parse_value_as_repeated_property_values,
+    parse_value_as_sequence, parse_value_as_string,
 };
 
 use sawtooth_sdk::messages::batch::BatchList;
 use serde_yaml::Mapping;
 use std::time::{SystemTime, SystemTimeError, UNIX_EPOCH};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct GridProduct {
     pub product_id: String,
+    pub product_address: String,
     pub product_namespace: String,
     pub owner: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_owner: Option<String>,
     pub properties: Vec<GridPropertyValue>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_id: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GridProductVersion {
+    pub start_commit_num: i64,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_commit_id: Option<String>,
+    #[serde(default)]
+    pub end_commit_num: Option<i64>,
+    #[serde(default)]
+    pub end_commit_id: Option<String>,
+    #[serde(flatten)]
+    pub product: GridProduct,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct GridPropertyValue {
     pub name: String,
     pub data_type: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_id: Option<String>,
     pub bytes_value: Option<Vec<u8>>,
     pub boolean_value: Option<bool>,
     pub number_value: Option<i64>,
@@ -58,12 +85,41 @@ pub struct GridPropertyValue {
     pub lat_long_value: Option<LatLong>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct LatLong {
     latitude: i64,
     longitude: i64,
 }
 
+impl CsvRecord for GridProduct {
+    const CSV_HEADERS: &'static [&'static str] = &[
+        "product_id",
+        "product_address",
+        "product_namespace",
+        "owner",
+        "pending_owner",
+        "properties",
+        "service_id",
+    ];
+}
+
+// The product fields are flattened into the version
+impl CsvRecord for GridProductVersion {
+    const CSV_HEADERS: &'static [&'static str] = &[
+        "start_commit_num",
+        "start_commit_id",
+        "end_commit_num",
+        "end_commit_id",
+        "product_id",
+        "product_address",
+        "product_namespace",
+        "owner",
+        "pending_owner",
+        "properties",
+        "service_id",
+    ];
+}
+
 /**
  * Print the fields for a given product
  *
@@ -71,12 +127,39 @@ pub struct LatLong {
  */
 pub fn display_product(product: &GridProduct) {
     println!(
-        "Product Id: {:?}\n Product Type: {:?}\n Owner: {:?}\n Properties:",
+        "Product Id: {:?}\n Product Type: {:?}\n Owner: {:?}",
         product.product_id, product.product_namespace, product.owner,
     );
+    if let Some(pending_owner) = &product.pending_owner {
+        println!(" Pending Owner: {:?}", pending_owner);
+    }
+    println!(" Properties:");
     display_product_property_definitions(&product.properties);
 }
 
+/**
+ * Print a version of a product, along with the commits that introduced and retired it
+ *
+ * version - Product version to be printed
+ */
+pub fn display_product_version(version: &GridProductVersion) {
+    let end_commit = match version.end_commit_num {
+        Some(end_commit_num) => format!(
+            "{} ({})",
+            end_commit_num,
+            version.end_commit_id.as_deref().unwrap_or("unknown")
+        ),
+        None => "current".to_string(),
+    };
+    println!(
+        "Start Commit: {} ({})\nEnd Commit: {}",
+        version.start_commit_num,
+        version.start_commit_id.as_deref().unwrap_or("unknown"),
+        end_commit,
+    );
+    display_product(&version.product);
+}
+
 /**
  * Iterate through all fields of a Property Value and print the given value
  *
@@ -105,15 +188,17 @@ pub fn display_product_property_definitions(properties: &[GridPropertyValue]) {
  *
  * url - Url for the REST API
  */
-pub fn do_list_products(url: &str, service_id: Option<String>) -> Result<(), CliError> {
-    let client = Client::new();
-    let mut final_url = format!("{}/product", url);
+pub fn do_list_products(
+    url: &str,
+    service_id: Option<String>,
+    format: OutputFormat,
+) -> Result<(), CliError> {
+    let mut path = "/product".to_string();
     if let Some(service_id) = service_id {
-        final_url = format!("{}?service_id={}", final_url, service_id);
+        path = format!("{}?service_id={}", path, service_id);
     }
-    let products = client.get(&final_url).send()?.json::<Vec<GridProduct>>()?;
-    products.iter().for_each(|product| display_product(product));
-    Ok(())
+    let products = fetch_all_pages::<GridProduct>(url, &path)?;
+    print_list(format, &products, display_product)
 }
 
 /**
@@ -121,20 +206,68 @@ pub fn do_list_products(url: &str, service_id: Option<String>) -> Result<(), Cli
  *
  * url - Url for the REST API
  * product_id - e.g. GTIN
+ * product_type - e.g. GS1, GLN or SSCC; the REST API defaults to GS1
  */
 pub fn do_show_products(
     url: &str,
     product_id: &str,
+    product_type: Option<&str>,
     service_id: Option<String>,
+    format: OutputFormat,
 ) -> Result<(), CliError> {
     let client = Client::new();
-    let mut final_url = format!("{}/product/{}", url, product_id);
-    if let Some(service_id) = service_id {
-        final_url = format!("{}?service_id={}", final_url, service_id);
-    }
+    let final_url = format!(
+        "{}/product/{}{}",
+        url,
+        product_id,
+        product_query(product_type, service_id)
+    );
     let product = client.get(&final_url).send()?.json::<GridProduct>()?;
-    display_product(&product);
-    Ok(())
+    print_item(format, &product, display_product)
+}
+
+/**
+ * Print every version of a product, oldest first
+ *
+ * url - Url for the REST API
+ * product_id - e.g. GTIN
+ * product_type - e.g. GS1, GLN or SSCC; the REST API defaults to GS1
+ */
+pub fn do_show_product_history(
+    url: &str,
+    product_id: &str,
+    product_type: Option<&str>,
+    service_id: Option<String>,
+    format: OutputFormat,
+) -> Result<(), CliError> {
+    let path = format!(
+        "/product/{}/history{}",
+        product_id,
+        product_query(product_type, service_id)
+    );
+    let versions = fetch_all_pages::<GridProductVersion>(url, &path)?;
+    print_list(format, &versions, display_product_version)
+}
+
+/**
+ * Build the query string used to look up a single product. The same ID may name different
+ * products of different types, e.g. a GTIN-13 and a GLN with the same digits.
+ *
+ * product_type - e.g. GS1, GLN or SSCC
+ * service_id - ID of the service the product belongs to
+ */
+fn product_query(product_type: Option<&str>, service_id: Option<String>) -> String {
+    let params = product_type
+        .map(|product_type| format!("namespace={}", product_type.to_uppercase()))
+        .into_iter()
+        .chain(service_id.map(|service_id| format!("service_id={}", service_id)))
+        .collect::<Vec<String>>();
+
+    if params.is_empty() {
+        String::new()
+    } else {
+        format!("?{}", params.join("&"))
+    }
 }
 
 /**
@@ -177,6 +310,167 @@ pub fn do_update_products(
     submit_batches(url, wait, &batch_list, service_id.as_deref())
 }
 
+/**
+ * Set or remove individual properties of an existing product, leaving its
+ * other properties as they are
+ *
+ * url - Url for the REST API
+ * key - Signing key of the agent
+ * wait - Time in seconds to wait for commit
+ * product_id - e.g. GTIN
+ * product_type - e.g. GS1, GLN or SSCC
+ * set - Properties to set, as name=value or name:type=value
+ * unset - Names of the properties to remove
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn do_patch_product(
+    url: &str,
+    key: Option<String>,
+    wait: u64,
+    product_id: &str,
+    product_type: &str,
+    set: &[&str],
+    unset: &[&str],
+    service_id: Option<String>,
+) -> Result<(), CliError> {
+    let parsed_product_type = parse_value_as_product_type(product_type)?;
+    let assignments = set
+        .iter()
+        .map(|arg| parse_property_assignment(arg))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Properties given without a type take the type they already have on the
+    // product, so the current product is only fetched when it is needed
+    let current_properties = if assignments
+        .iter()
+        .any(|(_, data_type, _)| data_type.is_none())
+    {
+        let client = Client::new();
+        let mut final_url = format!("{}/product/{}", url, product_id);
+        if let Some(service_id) = &service_id {
+            final_url = format!("{}?service_id={}", final_url, service_id);
+        }
+        client
+            .get(&final_url)
+            .send()?
+            .json::<GridProduct>()?
+            .properties
+    } else {
+        vec![]
+    };
+
+    let set_properties = assignments
+        .into_iter()
+        .map(|(name, data_type, value)| {
+            let data_type = match data_type {
+                Some(data_type) => data_type,
+                None => current_properties
+                    .iter()
+                    .find(|property| property.name == name)
+                    .ok_or_else(|| {
+                        CliError::UserError(format!(
+                            "Product {} has no property {}, give its type as {}:<type>=<value>",
+                            product_id, name, name
+                        ))
+                    })
+                    .and_then(|property| parse_value_as_data_type(&property.data_type))?,
+            };
+            make_property_value(&name, data_type, &value)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let remove_properties = unset.iter().map(|name| name.to_string()).collect();
+
+    let payloads = vec![generate_patch_product_payload(
+        parsed_product_type,
+        product_id,
+        set_properties,
+        remove_properties,
+    )?];
+    let batch_list = build_batches_from_payloads(payloads, key)?;
+    submit_batches(url, wait, &batch_list, service_id.as_deref())
+}
+
+/**
+ * Split a --set argument into the property name, its type if one was given,
+ * and the value
+ *
+ * arg - Property assignment of the form name=value or name:type=value
+ */
+fn parse_property_assignment(arg: &str) -> Result<(String, Option<DataType>, String), CliError> {
+    let mut parts = arg.splitn(2, '=');
+    let target = parts.next().unwrap_or_default();
+    let value = parts.next().ok_or_else(|| {
+        CliError::UserError(format!(
+            "Invalid property {}, expected name=value or name:type=value",
+            arg
+        ))
+    })?;
+
+    let mut target_parts = target.splitn(2, ':');
+    let name = target_parts.next().unwrap_or_default();
+    if name.is_empty() {
+        return Err(CliError::UserError(format!(
+            "Invalid property {}, property name cannot be empty",
+            arg
+        )));
+    }
+    let data_type = match target_parts.next() {
+        Some(data_type) => Some(parse_value_as_data_type(data_type)?),
+        None => None,
+    };
+
+    Ok((name.to_string(), data_type, value.to_string()))
+}
+
+/**
+ * Build a property value from a command line string
+ *
+ * name - Name of the property
+ * data_type - Type the value is parsed as
+ * value - Value as given on the command line
+ */
+fn make_property_value(
+    name: &str,
+    data_type: DataType,
+    value: &str,
+) -> Result<PropertyValue, CliError> {
+    let invalid_value = || {
+        CliError::UserError(format!(
+            "Invalid value {} for property {} of type {:?}",
+            value, name, data_type
+        ))
+    };
+
+    let property_value = PropertyValueBuilder::new()
+        .with_name(name.to_string())
+        .with_data_type(data_type.clone());
+
+    let property_value = match data_type {
+        DataType::String => property_value.with_string_value(value.to_string()),
+        DataType::Number => {
+            property_value.with_number_value(value.parse().map_err(|_| invalid_value())?)
+        }
+        DataType::Boolean => {
+            property_value.with_boolean_value(value.parse().map_err(|_| invalid_value())?)
+        }
+        DataType::Enum => {
+            property_value.with_enum_value(value.parse().map_err(|_| invalid_value())?)
+        }
+        _ => {
+            return Err(CliError::UserError(format!(
+                "Property {} is of type {:?}, which cannot be set with --set; \
+                 update the product from a yaml file instead",
+                name, data_type
+            )))
+        }
+    };
+
+    property_value.build().map_err(|err| {
+        CliError::PayloadError(format!("Failed to build property value {}: {}", name, err))
+    })
+}
+
 /**
  * Delete an existing product
  *
@@ -202,6 +496,38 @@ pub fn do_delete_products(
     submit_batches(url, wait, &batch_list, service_id.as_deref())
 }
 
+/**
+ * Transfer a product to another organization. Submitted by an admin of the
+ * owning organization this offers the product, unless the receiving
+ * organization consented in advance; submitted by an admin of the receiving
+ * organization it accepts the offer.
+ *
+ * url - Url for the REST API
+ * key - Signing key of the agent
+ * wait - Time in seconds to wait for commit
+ * product_id - e.g. GTIN
+ * product_type - e.g. GS1, GLN or SSCC
+ * new_owner - Identifier of the organization receiving the product
+ */
+pub fn do_transfer_product(
+    url: &str,
+    key: Option<String>,
+    wait: u64,
+    product_id: &str,
+    product_type: &str,
+    new_owner: &str,
+    service_id: Option<String>,
+) -> Result<(), CliError> {
+    let parsed_product_type = parse_value_as_product_type(product_type)?;
+    let payloads = vec![generate_transfer_product_payload(
+        parsed_product_type,
+        product_id,
+        new_owner,
+    )?];
+    let batch_list = build_batches_from_payloads(payloads, key)?;
+    submit_batches(url, wait, &batch_list, service_id.as_deref())
+}
+
 /**
  * Build a batch from our Product Payloads. The CLI is responsible for batch creation.
  *
@@ -220,6 +546,7 @@ pub fn build_batches_from_payloads(
                 PIKE_NAMESPACE.to_string(),
                 GRID_SCHEMA_NAMESPACE.to_string(),
                 GRID_PRODUCT_NAMESPACE.to_string(),
+                BLOCK_INFO_NAMESPACE.to_string(),
             ],
             &[GRID_PRODUCT_NAMESPACE.to_string()],
         )?;
@@ -270,7 +597,15 @@ fn parse_product_yaml(path: &str, action: Action) -> Result<Vec<ProductPayload>,
 
                 let property_values = parse_value_as_repeated_property_values(&properties)?;
 
-                generate_create_product_payload(product_type, &product_id, &owner, &property_values)
+                let schema = parse_value_as_string(product_yaml, "schema")?;
+
+                generate_create_product_payload(
+                    product_type,
+                    &product_id,
+                    &owner,
+                    &property_values,
+                    schema.as_deref(),
+                )
             })
             .collect::<Result<Vec<ProductPayload>, _>>(),
         Action::ProductUpdate(_) => products_yaml
@@ -303,32 +638,39 @@ fn parse_product_yaml(path: &str, action: Action) -> Result<Vec<ProductPayload>,
                 generate_update_product_payload(product_type, &product_id, &property_values)
             })
             .collect::<Result<Vec<ProductPayload>, _>>(),
-        Action::ProductDelete(_) => Err(CliError::UserError("To delete a product pass the arguments to the command line directly rather than using a Yaml file.".to_string()))
+        Action::ProductDelete(_) => Err(CliError::UserError("To delete a product pass the arguments to the command line directly rather than using a Yaml file.".to_string())),
+        Action::ProductTransfer(_) => Err(CliError::UserError("To transfer a product pass the arguments to the command line directly rather than using a Yaml file.".to_string()))
     }
 }
 
 /**
  * Generate the payload needed to create a new product
  *
- * product_type - e.g. GS1
+ * product_type - e.g. GS1, GLN or SSCC
  * product_id - e.g. GTIN
  * owner - Identifier of the organization responsible for maintaining the product
  * properties - One or more property values
+ * schema - Optional name of the schema the properties are validated against
  */
 fn generate_create_product_payload(
     product_type: ProductType,
     product_id: &str,
     owner: &str,
     properties: &[PropertyValue],
+    schema: Option<&str>,
 ) -> Result<ProductPayload, CliError> {
     let product_payload = ProductPayloadBuilder::new();
 
-    let product_create_action_builder = ProductCreateActionBuilder::new()
+    let mut product_create_action_builder = ProductCreateActionBuilder::new()
         .with_product_id(product_id.to_string())
         .with_product_type(product_type)
         .with_owner(owner.to_string())
         .with_properties(properties.to_vec());
 
+    if let Some(schema) = schema {
+        product_create_action_builder = product_create_action_builder.with_schema(schema.into());
+    }
+
     let product_create_action = product_create_action_builder.build().map_err(|err| {
         CliError::PayloadError(format!("Failed to build product create payload: {}", err))
     })?;
@@ -353,7 +695,7 @@ fn generate_create_product_payload(
 /**
  * Generate the payload needed to update an existing product
  *
- * product_type - e.g. GS1
+ * product_type - e.g. GS1, GLN or SSCC
  * product_id - e.g. GTIN
  * properties - One or more property values
  */
@@ -392,10 +734,48 @@ fn generate_update_product_payload(
         })
 }
 
+/**
+ * Generate the payload needed to set or remove individual properties of an
+ * existing product
+ *
+ * product_type - e.g. GS1, GLN or SSCC
+ * product_id - e.g. GTIN
+ * set_properties - Property values to add or replace
+ * remove_properties - Names of the properties to remove
+ */
+fn generate_patch_product_payload(
+    product_type: ProductType,
+    product_id: &str,
+    set_properties: Vec<PropertyValue>,
+    remove_properties: Vec<String>,
+) -> Result<ProductPayload, CliError> {
+    let product_update_action = ProductUpdateActionBuilder::new()
+        .with_product_id(product_id.to_string())
+        .with_product_type(product_type)
+        .with_set_properties(set_properties)
+        .with_remove_properties(remove_properties)
+        .build()
+        .map_err(|err| {
+            CliError::PayloadError(format!("Failed to build product update payload: {}", err))
+        })?;
+
+    let timestamp = get_unix_utc_timestamp().map_err(|err| {
+        CliError::PayloadError(format!("Failed to build product update payload: {}", err))
+    })?;
+
+    ProductPayloadBuilder::new()
+        .with_action(Action::ProductUpdate(product_update_action))
+        .with_timestamp(timestamp)
+        .build()
+        .map_err(|err| {
+            CliError::PayloadError(format!("Failed to build product update payload: {}", err))
+        })
+}
+
 /**
  * Generate the payload needed to delete an existing product
  *
- * product_type - e.g. GS1
+ * product_type - e.g. GS1, GLN or SSCC
  * product_id - e.g. GTIN
  */
 fn generate_delete_product_payload(
@@ -431,7 +811,41 @@ fn generate_delete_product_payload(
         })
 }
 
-fn get_unix_utc_timestamp() -> Result<u64, SystemTimeError> {
+/**
+ * Generate the payload needed to transfer a product to another organization
+ *
+ * product_type - e.g. GS1, GLN or SSCC
+ * product_id - e.g. GTIN
+ * new_owner - Identifier of the organization receiving the product
+ */
+fn generate_transfer_product_payload(
+    product_type: ProductType,
+    product_id: &str,
+    new_owner: &str,
+) -> Result<ProductPayload, CliError> {
+    let product_transfer_action = ProductTransferActionBuilder::new()
+        .with_product_id(product_id.to_string())
+        .with_product_type(product_type)
+        .with_new_owner(new_owner.to_string())
+        .build()
+        .map_err(|err| {
+            CliError::PayloadError(format!("Failed to build product transfer payload: {}", err))
+        })?;
+
+    let timestamp = get_unix_utc_timestamp().map_err(|err| {
+        CliError::PayloadError(format!("Failed to build product transfer payload: {}", err))
+    })?;
+
+    ProductPayloadBuilder::new()
+        .with_action(Action::ProductTransfer(product_transfer_action))
+        .with_timestamp(timestamp)
+        .build()
+        .map_err(|err| {
+            CliError::PayloadError(format!("Failed to build product transfer payload: {}", err))
+        })
+}
+
+pub fn get_unix_utc_timestamp() -> Result<u64, SystemTimeError> {
     match SystemTime::now().duration_since(UNIX_EPOCH) {
         Ok(duration) => Ok(duration.as_secs()),
         Err(err) => Err(err),
@@ -500,6 +914,86 @@ mod test {
         })
     }
 
+    /*
+     * Verifies generate_transfer_product_payload builds a ProductPayload with a
+     * ProductTransferAction naming the receiving organization
+     */
+    #[test]
+    fn test_generate_transfer_product_payload() {
+        let payload =
+            generate_transfer_product_payload(ProductType::GS1, "723382885088", "cargill")
+                .expect("Error building payload");
+
+        match payload.action() {
+            Action::ProductTransfer(action) => {
+                assert_eq!(action.product_id(), "723382885088");
+                assert_eq!(*action.product_type(), ProductType::GS1);
+                assert_eq!(action.new_owner(), "cargill");
+            }
+            action => panic!("Expected a product transfer action, got {:?}", action),
+        }
+    }
+
+    /*
+     * Verifies --set arguments are split into name, optional type and value, and that
+     * values which are not valid for their type are rejected
+     */
+    #[test]
+    fn test_parse_property_assignment() {
+        assert_eq!(
+            parse_property_assignment("color=red").expect("Error parsing assignment"),
+            ("color".to_string(), None, "red".to_string())
+        );
+        assert_eq!(
+            parse_property_assignment("note:string=a=b").expect("Error parsing assignment"),
+            (
+                "note".to_string(),
+                Some(DataType::String),
+                "a=b".to_string()
+            )
+        );
+
+        let (name, data_type, value) =
+            parse_property_assignment("length:number=8").expect("Error parsing assignment");
+        assert_eq!(
+            make_property_value(&name, data_type.unwrap(), &value)
+                .expect("Error building property value"),
+            make_number_property_value("length", 8)
+        );
+
+        assert!(parse_property_assignment("color").is_err());
+        assert!(parse_property_assignment(":string=red").is_err());
+        assert!(make_property_value("length", DataType::Number, "eight").is_err());
+        assert!(make_property_value("location", DataType::LatLong, "1,2").is_err());
+    }
+
+    /*
+     * Verifies generate_patch_product_payload builds a ProductUpdateAction carrying
+     * only the properties being set and removed
+     */
+    #[test]
+    fn test_generate_patch_product_payload() {
+        let payload = generate_patch_product_payload(
+            ProductType::GS1,
+            "723382885088",
+            vec![make_number_property_value("length", 8)],
+            vec!["depth".to_string()],
+        )
+        .expect("Error building payload");
+
+        match payload.action() {
+            Action::ProductUpdate(action) => {
+                assert!(action.properties().is_empty());
+                assert_eq!(
+                    action.set_properties(),
+                    &[make_number_property_value("length", 8)][..]
+                );
+                assert_eq!(action.remove_properties(), &["depth".to_string()][..]);
+            }
+            action => panic!("Expected a product update action, got {:?}", action),
+        }
+    }
+
     fn write_yaml_file(file_path: &str) {
         let mut file = File::create(file_path).expect("Error creating test product yaml file.");
 
@@ -518,6 +1012,7 @@ mod test {
             "723382885088",
             "314156",
             &create_property_values(),
+            None,
         )
         .unwrap()
     }
@@ -0,0 +1,604 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::actions::products::get_unix_utc_timestamp;
+use crate::http::{fetch_all_pages, submit_batches};
+use crate::transaction::{
+    track_and_trace_batch_builder, GRID_SCHEMA_NAMESPACE, PIKE_NAMESPACE, TRACK_AND_TRACE_NAMESPACE,
+};
+use crate::yaml_parser::{
+    parse_value_as_repeated_property_values, parse_value_as_sequence, parse_value_as_string,
+};
+use grid_sdk::protocol::track_and_trace::payload::{
+    Action, AnswerProposalAction, CreateProposalAction, CreateRecordAction,
+    CreateRecordActionBuilder, FinalizeRecordActionBuilder, RevokeReporterAction,
+    TrackAndTracePayload, TrackAndTracePayloadBuilder, UpdatePropertiesAction,
+    UpdatePropertiesActionBuilder,
+};
+use grid_sdk::protos::IntoProto;
+use reqwest::Client;
+
+use crate::error::CliError;
+use crate::format::{print_item, print_list, CsvRecord, OutputFormat};
+use serde::{Deserialize, Serialize};
+
+use sawtooth_sdk::messages::batch::BatchList;
+use serde_yaml::Mapping;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GridRecordSlice {
+    pub record_id: String,
+    pub schema: String,
+    pub owner: String,
+    pub custodian: String,
+    pub properties: Vec<GridPropertySlice>,
+    pub r#final: bool,
+    pub proposals: Vec<GridProposalSlice>,
+    pub owner_updates: Vec<GridAssociatedAgentSlice>,
+    pub custodian_updates: Vec<GridAssociatedAgentSlice>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GridAssociatedAgentSlice {
+    pub agent_id: String,
+    pub timestamp: u64,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GridPropertySlice {
+    pub name: String,
+    pub record_id: String,
+    pub data_type: String,
+    pub reporters: Vec<String>,
+    pub updates: Vec<GridPropertyValueSlice>,
+    pub value: Option<GridPropertyValueSlice>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GridPropertyValueSlice {
+    pub timestamp: u64,
+    pub value: GridValue,
+    pub reporter: GridReporterSlice,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GridReporterSlice {
+    pub public_key: String,
+    pub metadata: serde_json::Value,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum GridValue {
+    String(String),
+    Bool(bool),
+    Struct(Vec<GridStructPropertyValue>),
+    LatLong(GridLatLong),
+    Number(i64),
+    Enum(i32),
+    Bytes(String),
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GridStructPropertyValue {
+    pub name: String,
+    pub data_type: String,
+    pub value: GridValue,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GridLatLong {
+    pub latitude: i64,
+    pub longitude: i64,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GridProposalSlice {
+    pub receiving_agent: String,
+    pub issuing_agent: String,
+    pub role: String,
+    pub properties: Vec<String>,
+    pub status: String,
+    pub terms: String,
+    pub timestamp: u64,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_id: Option<String>,
+}
+
+impl CsvRecord for GridRecordSlice {
+    const CSV_HEADERS: &'static [&'static str] = &[
+        "record_id",
+        "schema",
+        "owner",
+        "custodian",
+        "properties",
+        "final",
+        "proposals",
+        "owner_updates",
+        "custodian_updates",
+        "service_id",
+    ];
+}
+
+/**
+ * Print the fields for a given record
+ *
+ * record - Record to be printed
+ */
+pub fn display_record(record: &GridRecordSlice) {
+    println!(
+        "Record Id: {:?}\n Schema: {:?}\n Owner: {:?}\n Custodian: {:?}\n Final: {:?}\n Properties:",
+        record.record_id, record.schema, record.owner, record.custodian, record.r#final,
+    );
+    display_record_properties(&record.properties);
+    println!(" Proposals:");
+    display_record_proposals(&record.proposals);
+}
+
+/**
+ * Print the name, data type, reporters and current value of each property
+ *
+ * properties - Record properties to be printed
+ */
+pub fn display_record_properties(properties: &[GridPropertySlice]) {
+    properties.iter().for_each(|property| {
+        println!(
+            "\tProperty Name: {:?}\n\t Data Type: {:?}\n\t Reporters: {:?}\n\t Value: {:?}",
+            property.name,
+            property.data_type,
+            property.reporters,
+            property.value.as_ref().map(|value| &value.value),
+        );
+    })
+}
+
+/**
+ * Print the fields of each proposal on a record
+ *
+ * proposals - Record proposals to be printed
+ */
+pub fn display_record_proposals(proposals: &[GridProposalSlice]) {
+    proposals.iter().for_each(|proposal| {
+        println!(
+            "\tIssuing Agent: {:?}\n\t Receiving Agent: {:?}\n\t Role: {:?}\n\t Properties: {:?}
+        Status: {:?}\n\t Terms: {:?}\n\t Timestamp: {:?}",
+            proposal.issuing_agent,
+            proposal.receiving_agent,
+            proposal.role,
+            proposal.properties,
+            proposal.status,
+            proposal.terms,
+            proposal.timestamp,
+        );
+    })
+}
+
+/**
+ * Print all records in state
+ *
+ * url - Url for the REST API
+ */
+pub fn do_list_records(
+    url: &str,
+    service_id: Option<String>,
+    format: OutputFormat,
+) -> Result<(), CliError> {
+    let mut path = "/record".to_string();
+    if let Some(service_id) = service_id {
+        path = format!("{}?service_id={}", path, service_id);
+    }
+    let records = fetch_all_pages::<GridRecordSlice>(url, &path)?;
+    print_list(format, &records, display_record)
+}
+
+/**
+ * Print a single record in state
+ *
+ * url - Url for the REST API
+ * record_id - Natural key of the record
+ */
+pub fn do_show_record(
+    url: &str,
+    record_id: &str,
+    service_id: Option<String>,
+    format: OutputFormat,
+) -> Result<(), CliError> {
+    let client = Client::new();
+    let mut final_url = format!("{}/record/{}", url, record_id);
+    if let Some(service_id) = service_id {
+        final_url = format!("{}?service_id={}", final_url, service_id);
+    }
+    let record = client.get(&final_url).send()?.json::<GridRecordSlice>()?;
+    print_item(format, &record, display_record)
+}
+
+/**
+ * Create new records
+ *
+ * url - Url for the REST API
+ * key - Signing key of the agent
+ * wait - Time in seconds to wait for commit
+ * path - Path to the yaml file that contains the record descriptions
+ */
+pub fn do_create_records(
+    url: &str,
+    key: Option<String>,
+    wait: u64,
+    path: &str,
+    service_id: Option<String>,
+) -> Result<(), CliError> {
+    let payloads = parse_record_yaml(path, Action::CreateRecord(CreateRecordAction::default()))?;
+    let batch_list = build_batches_from_payloads(payloads, key)?;
+    submit_batches(url, wait, &batch_list, service_id.as_deref())
+}
+
+/**
+ * Update the properties of existing records
+ *
+ * url - Url for the REST API
+ * key - Signing key of the agent
+ * wait - Time in seconds to wait for commit
+ * path - Path to the yaml file that contains the property updates
+ */
+pub fn do_update_record_properties(
+    url: &str,
+    key: Option<String>,
+    wait: u64,
+    path: &str,
+    service_id: Option<String>,
+) -> Result<(), CliError> {
+    let payloads = parse_record_yaml(
+        path,
+        Action::UpdateProperties(UpdatePropertiesAction::default()),
+    )?;
+    let batch_list = build_batches_from_payloads(payloads, key)?;
+    submit_batches(url, wait, &batch_list, service_id.as_deref())
+}
+
+/**
+ * Finalize an existing record
+ *
+ * url - Url for the REST API
+ * key - Signing key of the agent
+ * wait - Time in seconds to wait for commit
+ * record_id - Natural key of the record
+ */
+pub fn do_finalize_record(
+    url: &str,
+    key: Option<String>,
+    wait: u64,
+    record_id: &str,
+    service_id: Option<String>,
+) -> Result<(), CliError> {
+    let finalize_record = FinalizeRecordActionBuilder::new()
+        .with_record_id(record_id.to_string())
+        .build()
+        .map_err(|err| {
+            CliError::PayloadError(format!("Failed to build finalize record payload: {}", err))
+        })?;
+
+    let payloads = vec![generate_payload(Action::FinalizeRecord(finalize_record))?];
+    let batch_list = build_batches_from_payloads(payloads, key)?;
+    submit_batches(url, wait, &batch_list, service_id.as_deref())
+}
+
+/**
+ * Propose transferring a role on a record to another agent
+ *
+ * url - Url for the REST API
+ * key - Signing key of the agent
+ * wait - Time in seconds to wait for commit
+ * create_proposal - Proposal to submit
+ */
+pub fn do_create_proposal(
+    url: &str,
+    key: Option<String>,
+    wait: u64,
+    create_proposal: CreateProposalAction,
+    service_id: Option<String>,
+) -> Result<(), CliError> {
+    let payloads = vec![generate_payload(Action::CreateProposal(create_proposal))?];
+    let batch_list = build_batches_from_payloads(payloads, key)?;
+    submit_batches(url, wait, &batch_list, service_id.as_deref())
+}
+
+/**
+ * Accept, reject or cancel an open proposal
+ *
+ * url - Url for the REST API
+ * key - Signing key of the agent
+ * wait - Time in seconds to wait for commit
+ * answer_proposal - Answer to submit
+ */
+pub fn do_answer_proposal(
+    url: &str,
+    key: Option<String>,
+    wait: u64,
+    answer_proposal: AnswerProposalAction,
+    service_id: Option<String>,
+) -> Result<(), CliError> {
+    let payloads = vec![generate_payload(Action::AnswerProposal(answer_proposal))?];
+    let batch_list = build_batches_from_payloads(payloads, key)?;
+    submit_batches(url, wait, &batch_list, service_id.as_deref())
+}
+
+/**
+ * Revoke a reporter's authorization on properties of a record
+ *
+ * url - Url for the REST API
+ * key - Signing key of the agent
+ * wait - Time in seconds to wait for commit
+ * revoke_reporter - Revocation to submit
+ */
+pub fn do_revoke_reporter(
+    url: &str,
+    key: Option<String>,
+    wait: u64,
+    revoke_reporter: RevokeReporterAction,
+    service_id: Option<String>,
+) -> Result<(), CliError> {
+    let payloads = vec![generate_payload(Action::RevokeReporter(revoke_reporter))?];
+    let batch_list = build_batches_from_payloads(payloads, key)?;
+    submit_batches(url, wait, &batch_list, service_id.as_deref())
+}
+
+/**
+ * Build a batch from our Track and Trace Payloads. The CLI is responsible for batch creation.
+ *
+ * payloads - Track and Trace payloads
+ * key - Signing key of the agent
+ */
+pub fn build_batches_from_payloads(
+    payloads: Vec<TrackAndTracePayload>,
+    key: Option<String>,
+) -> Result<BatchList, CliError> {
+    let mut batch_list_builder = track_and_trace_batch_builder(key);
+    for payload in payloads {
+        batch_list_builder = batch_list_builder.add_transaction(
+            &payload.into_proto()?,
+            &[
+                PIKE_NAMESPACE.to_string(),
+                GRID_SCHEMA_NAMESPACE.to_string(),
+                TRACK_AND_TRACE_NAMESPACE.to_string(),
+            ],
+            &[TRACK_AND_TRACE_NAMESPACE.to_string()],
+        )?;
+    }
+
+    Ok(batch_list_builder.create_batch_list())
+}
+
+/**
+ * Iterate through a list of records in a yaml file to build our payloads.
+ *
+ * path: Path to the yaml file
+ * action: Determines the type of track and trace payload to generate
+ */
+fn parse_record_yaml(path: &str, action: Action) -> Result<Vec<TrackAndTracePayload>, CliError> {
+    let file = std::fs::File::open(path)?;
+    let records_yaml: Vec<Mapping> = serde_yaml::from_reader(file)?;
+
+    records_yaml
+        .iter()
+        .map(|record_yaml| {
+            let record_id = parse_value_as_string(record_yaml, "record_id")?.ok_or_else(|| {
+                CliError::InvalidYamlError("Missing `record_id` field for Record.".to_string())
+            })?;
+
+            let properties =
+                parse_value_as_sequence(record_yaml, "properties")?.ok_or_else(|| {
+                    CliError::InvalidYamlError("Record is missing `properties` field.".to_string())
+                })?;
+
+            let property_values = parse_value_as_repeated_property_values(&properties)?;
+
+            match action {
+                Action::CreateRecord(_) => {
+                    let schema =
+                        parse_value_as_string(record_yaml, "schema")?.ok_or_else(|| {
+                            CliError::InvalidYamlError(
+                                "Missing `schema` field for Record.".to_string(),
+                            )
+                        })?;
+
+                    let create_record = CreateRecordActionBuilder::new()
+                        .with_record_id(record_id)
+                        .with_schema(schema)
+                        .with_properties(property_values)
+                        .build()
+                        .map_err(|err| {
+                            CliError::PayloadError(format!(
+                                "Failed to build create record payload: {}",
+                                err
+                            ))
+                        })?;
+
+                    generate_payload(Action::CreateRecord(create_record))
+                }
+                Action::UpdateProperties(_) => {
+                    let update_properties = UpdatePropertiesActionBuilder::new()
+                        .with_record_id(record_id)
+                        .with_properties(property_values)
+                        .build()
+                        .map_err(|err| {
+                            CliError::PayloadError(format!(
+                                "Failed to build update properties payload: {}",
+                                err
+                            ))
+                        })?;
+
+                    generate_payload(Action::UpdateProperties(update_properties))
+                }
+                _ => Err(CliError::UserError(
+                    "Only record creation and property updates can be read from a Yaml file."
+                        .to_string(),
+                )),
+            }
+        })
+        .collect::<Result<Vec<TrackAndTracePayload>, _>>()
+}
+
+/**
+ * Wrap a Track and Trace action in a timestamped payload
+ *
+ * action - The action the payload should carry
+ */
+fn generate_payload(action: Action) -> Result<TrackAndTracePayload, CliError> {
+    let timestamp = get_unix_utc_timestamp().map_err(|err| {
+        CliError::PayloadError(format!("Failed to build track and trace payload: {}", err))
+    })?;
+
+    TrackAndTracePayloadBuilder::new()
+        .with_action(action)
+        .with_timestamp(timestamp)
+        .build()
+        .map_err(|err| {
+            CliError::PayloadError(format!("Failed to build track and trace payload: {}", err))
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use grid_sdk::protocol::schema::state::{DataType, PropertyValue, PropertyValueBuilder};
+    use std::fs::{remove_file, File};
+    use std::io::Write;
+    use std::{env, panic, thread};
+
+    static EXAMPLE_RECORD_YAML: &[u8; 199] = br##"- record_id: "lightbulb-001"
+  schema: "Lightbulb"
+  properties:
+    - name: "size"
+      data_type: "NUMBER"
+      number_value: 8
+    - name: "bulb_type"
+      data_type: "ENUM"
+      enum_value: 2"##;
+
+    /*
+     * Verifies parse_record_yaml returns valid TrackAndTracePayloads with CreateRecordAction set
+     * from a yaml containing a Record definition
+     */
+    #[test]
+    fn test_valid_yaml_create_record() {
+        run_test(|test_yaml_file_path| {
+            write_yaml_file(test_yaml_file_path);
+
+            let payload = parse_record_yaml(
+                test_yaml_file_path,
+                Action::CreateRecord(CreateRecordAction::default()),
+            )
+            .expect("Error parsing yaml");
+
+            match payload[0].action() {
+                Action::CreateRecord(create_record) => {
+                    assert_eq!(create_record.record_id(), "lightbulb-001");
+                    assert_eq!(create_record.schema(), "Lightbulb");
+                    assert_eq!(create_record.properties(), &create_property_values()[..]);
+                }
+                action => panic!("Unexpected action: {:?}", action),
+            }
+        })
+    }
+
+    /*
+     * Verifies parse_record_yaml returns valid TrackAndTracePayloads with UpdatePropertiesAction
+     * set from a yaml containing a Record definition
+     */
+    #[test]
+    fn test_valid_yaml_update_record_properties() {
+        run_test(|test_yaml_file_path| {
+            write_yaml_file(test_yaml_file_path);
+
+            let payload = parse_record_yaml(
+                test_yaml_file_path,
+                Action::UpdateProperties(UpdatePropertiesAction::default()),
+            )
+            .expect("Error parsing yaml");
+
+            match payload[0].action() {
+                Action::UpdateProperties(update_properties) => {
+                    assert_eq!(update_properties.record_id(), "lightbulb-001");
+                    assert_eq!(
+                        update_properties.properties(),
+                        &create_property_values()[..]
+                    );
+                }
+                action => panic!("Unexpected action: {:?}", action),
+            }
+        })
+    }
+
+    fn write_yaml_file(file_path: &str) {
+        let mut file = File::create(file_path).expect("Error creating test record yaml file.");
+
+        file.write_all(EXAMPLE_RECORD_YAML)
+            .expect("Error writting example record yaml.");
+    }
+
+    fn create_property_values() -> Vec<PropertyValue> {
+        vec![
+            PropertyValueBuilder::new()
+                .with_name("size".to_string())
+                .with_data_type(DataType::Number)
+                .with_number_value(8)
+                .build()
+                .unwrap(),
+            PropertyValueBuilder::new()
+                .with_name("bulb_type".to_string())
+                .with_data_type(DataType::Enum)
+                .with_enum_value(2)
+                .build()
+                .unwrap(),
+        ]
+    }
+
+    fn run_test<T>(test: T) -> ()
+    where
+        T: FnOnce(&str) -> () + panic::UnwindSafe,
+    {
+        let test_yaml_file = temp_yaml_file_path();
+
+        let test_path = test_yaml_file.clone();
+        let result = panic::catch_unwind(move || test(&test_path));
+
+        remove_file(test_yaml_file).unwrap();
+
+        assert!(result.is_ok())
+    }
+
+    fn temp_yaml_file_path() -> String {
+        let mut temp_dir = env::temp_dir();
+
+        let thread_id = thread::current().id();
+        temp_dir.push(format!("test_parse_record-{:?}.yaml", thread_id));
+        temp_dir.to_str().unwrap().to_string()
+    }
+}
@@ -12,48 +12,76 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::http::submit_batches;
-use crate::transaction::{schema_batch_builder, GRID_SCHEMA_NAMESPACE, PIKE_NAMESPACE};
+use crate::http::{fetch_all_pages, submit_batches};
+use crate::transaction::{
+    schema_batch_builder, BLOCK_INFO_NAMESPACE, GRID_SCHEMA_NAMESPACE, PIKE_NAMESPACE,
+};
 use crate::yaml_parser::{
     parse_value_as_boolean, parse_value_as_data_type, parse_value_as_i32, parse_value_as_sequence,
     parse_value_as_string, parse_value_as_vec_string,
 };
 use grid_sdk::protocol::schema::payload::{
-    Action, SchemaCreateAction, SchemaCreateBuilder, SchemaPayload, SchemaPayloadBuilder,
-    SchemaUpdateAction, SchemaUpdateBuilder,
+    Action, EnumOptionsUpdate, EnumOptionsUpdateBuilder, SchemaAddEditorBuilder,
+    SchemaCreateAction, SchemaCreateBuilder, SchemaPayload, SchemaPayloadBuilder,
+    SchemaRemoveEditorBuilder, SchemaTransferBuilder, SchemaUpdateAction, SchemaUpdateBuilder,
 };
 use grid_sdk::protocol::schema::state::{DataType, PropertyDefinition, PropertyDefinitionBuilder};
 use grid_sdk::protos::IntoProto;
 use reqwest::Client;
 
 use crate::error::CliError;
-use serde::Deserialize;
+use crate::format::{print_item, print_list, CsvRecord, OutputFormat};
+use serde::{Deserialize, Serialize};
 use serde_yaml::{Mapping, Value};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct GridSchemaSlice {
     pub name: String,
     pub description: String,
     pub owner: String,
+    #[serde(default)]
+    pub version: i64,
+    #[serde(default)]
+    pub editors: Vec<String>,
     pub properties: Vec<GridPropertyDefinitionSlice>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_id: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct GridPropertyDefinitionSlice {
     pub name: String,
     pub schema_name: String,
     pub data_type: String,
     pub required: bool,
     pub description: String,
+    #[serde(default)]
+    pub deprecated: bool,
     pub number_exponent: i64,
     pub enum_options: Vec<String>,
     pub struct_properties: Vec<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_id: Option<String>,
+}
+
+impl CsvRecord for GridSchemaSlice {
+    const CSV_HEADERS: &'static [&'static str] = &[
+        "name",
+        "description",
+        "owner",
+        "version",
+        "editors",
+        "properties",
+        "service_id",
+    ];
 }
 
 pub fn display_schema(schema: &GridSchemaSlice) {
     println!(
-        "Name: {:?}\n Description: {:?}\n Owner: {:?}\n Properties:",
-        schema.name, schema.description, schema.owner,
+        "Name: {:?}\n Description: {:?}\n Owner: {:?}\n Version: {:?}\n Editors: {:?}\n Properties:",
+        schema.name, schema.description, schema.owner, schema.version, schema.editors,
     );
     display_schema_property_definitions(&schema.properties);
 }
@@ -61,11 +89,12 @@ pub fn display_schema(schema: &GridSchemaSlice) {
 pub fn display_schema_property_definitions(properties: &[GridPropertyDefinitionSlice]) {
     properties.iter().for_each(|def| {
         println!(
-            "\tName: {:?}\n\t Data Type: {:?}\n\t Required: {:?}\n\t Description: {:?}
+            "\tName: {:?}\n\t Data Type: {:?}\n\t Required: {:?}\n\t Deprecated: {:?}\n\t Description: {:?}
         Number Exponent: {:?}\n\t Enum Options: {:?}\n\t Struct Properties: {:?}",
             def.name,
             def.data_type,
             def.required,
+            def.deprecated,
             def.description,
             def.number_exponent,
             def.enum_options,
@@ -74,29 +103,32 @@ pub fn display_schema_property_definitions(properties: &[GridPropertyDefinitionS
     });
 }
 
-pub fn do_list_schemas(url: &str, service_id: Option<String>) -> Result<(), CliError> {
-    let client = Client::new();
-    let mut final_url = format!("{}/schema", url);
+pub fn do_list_schemas(
+    url: &str,
+    service_id: Option<String>,
+    format: OutputFormat,
+) -> Result<(), CliError> {
+    let mut path = "/schema".to_string();
     if let Some(service_id) = service_id {
-        final_url = format!("{}?service_id={}", final_url, service_id);
+        path = format!("{}?service_id={}", path, service_id);
     }
-    let schemas = client
-        .get(&final_url)
-        .send()?
-        .json::<Vec<GridSchemaSlice>>()?;
-    schemas.iter().for_each(|schema| display_schema(schema));
-    Ok(())
+    let schemas = fetch_all_pages::<GridSchemaSlice>(url, &path)?;
+    print_list(format, &schemas, display_schema)
 }
 
-pub fn do_show_schema(url: &str, name: &str, service_id: Option<String>) -> Result<(), CliError> {
+pub fn do_show_schema(
+    url: &str,
+    name: &str,
+    service_id: Option<String>,
+    format: OutputFormat,
+) -> Result<(), CliError> {
     let client = Client::new();
     let mut final_url = format!("{}/schema/{}", url, name);
     if let Some(service_id) = service_id {
         final_url = format!("{}?service_id={}", final_url, service_id);
     }
     let schema = client.get(&final_url).send()?.json::<GridSchemaSlice>()?;
-    display_schema(&schema);
-    Ok(())
+    print_item(format, &schema, display_schema)
 }
 
 pub fn do_create_schemas(
@@ -114,6 +146,7 @@ pub fn do_create_schemas(
             &[
                 PIKE_NAMESPACE.to_string(),
                 GRID_SCHEMA_NAMESPACE.to_string(),
+                BLOCK_INFO_NAMESPACE.to_string(),
             ],
             &[GRID_SCHEMA_NAMESPACE.to_string()],
         )?;
@@ -139,6 +172,7 @@ pub fn do_update_schemas(
             &[
                 PIKE_NAMESPACE.to_string(),
                 GRID_SCHEMA_NAMESPACE.to_string(),
+                BLOCK_INFO_NAMESPACE.to_string(),
             ],
             &[GRID_SCHEMA_NAMESPACE.to_string()],
         )?;
@@ -149,6 +183,98 @@ pub fn do_update_schemas(
     submit_batches(url, wait, &batch_list, service_id.as_deref())
 }
 
+pub fn do_add_schema_editor(
+    url: &str,
+    key: Option<String>,
+    wait: u64,
+    name: &str,
+    editor: &str,
+    service_id: Option<String>,
+) -> Result<(), CliError> {
+    let action = SchemaAddEditorBuilder::new()
+        .with_schema_name(name.to_string())
+        .with_editor(editor.to_string())
+        .build()
+        .map_err(|err| {
+            CliError::PayloadError(format!("Failed to build schema payload: {}", err))
+        })?;
+
+    submit_schema_payload(url, key, wait, Action::SchemaAddEditor(action), service_id)
+}
+
+pub fn do_remove_schema_editor(
+    url: &str,
+    key: Option<String>,
+    wait: u64,
+    name: &str,
+    editor: &str,
+    service_id: Option<String>,
+) -> Result<(), CliError> {
+    let action = SchemaRemoveEditorBuilder::new()
+        .with_schema_name(name.to_string())
+        .with_editor(editor.to_string())
+        .build()
+        .map_err(|err| {
+            CliError::PayloadError(format!("Failed to build schema payload: {}", err))
+        })?;
+
+    submit_schema_payload(
+        url,
+        key,
+        wait,
+        Action::SchemaRemoveEditor(action),
+        service_id,
+    )
+}
+
+pub fn do_transfer_schema(
+    url: &str,
+    key: Option<String>,
+    wait: u64,
+    name: &str,
+    new_owner: &str,
+    service_id: Option<String>,
+) -> Result<(), CliError> {
+    let action = SchemaTransferBuilder::new()
+        .with_schema_name(name.to_string())
+        .with_new_owner(new_owner.to_string())
+        .build()
+        .map_err(|err| {
+            CliError::PayloadError(format!("Failed to build schema payload: {}", err))
+        })?;
+
+    submit_schema_payload(url, key, wait, Action::SchemaTransfer(action), service_id)
+}
+
+fn submit_schema_payload(
+    url: &str,
+    key: Option<String>,
+    wait: u64,
+    action: Action,
+    service_id: Option<String>,
+) -> Result<(), CliError> {
+    let payload = SchemaPayloadBuilder::new()
+        .with_action(action)
+        .build()
+        .map_err(|err| {
+            CliError::PayloadError(format!("Failed to build schema payload: {}", err))
+        })?;
+
+    let batch_list = schema_batch_builder(key)
+        .add_transaction(
+            &payload.into_proto()?,
+            &[
+                PIKE_NAMESPACE.to_string(),
+                GRID_SCHEMA_NAMESPACE.to_string(),
+                BLOCK_INFO_NAMESPACE.to_string(),
+            ],
+            &[GRID_SCHEMA_NAMESPACE.to_string()],
+        )?
+        .create_batch_list();
+
+    submit_batches(url, wait, &batch_list, service_id.as_deref())
+}
+
 fn parse_yaml(path: &str, action: Action) -> Result<Vec<SchemaPayload>, CliError> {
     let file = std::fs::File::open(path)?;
     let schemas_yaml: Vec<Mapping> = serde_yaml::from_reader(file)?;
@@ -177,23 +303,44 @@ fn parse_yaml(path: &str, action: Action) -> Result<Vec<SchemaPayload>, CliError
             })
             .collect::<Result<Vec<SchemaPayload>, _>>(),
 
+        // An update may add properties and change existing ones; the contract
+        // rejects updates that do neither
         Action::SchemaUpdate(_) => schemas_yaml
             .iter()
             .map(|schema_yaml| {
-                let properties =
-                    parse_value_as_sequence(schema_yaml, "properties")?.ok_or_else(|| {
-                        CliError::InvalidYamlError(
-                            "Schema definition is missing `properties` field.".to_string(),
-                        )
-                    })?;
-                let property_definitions = parse_properties(&properties)?;
                 let schema_name = parse_value_as_string(schema_yaml, "name")?.ok_or_else(|| {
                     CliError::InvalidYamlError("Missing `name` field for schema.".to_string())
                 })?;
-
-                generate_update_schema_payload(&schema_name, &property_definitions)
+                let property_definitions = match parse_value_as_sequence(schema_yaml, "properties")?
+                {
+                    Some(properties) => parse_properties(&properties)?,
+                    None => vec![],
+                };
+                let add_enum_options =
+                    match parse_value_as_sequence(schema_yaml, "add_enum_options")? {
+                        Some(updates) => parse_enum_options_updates(&updates)?,
+                        None => vec![],
+                    };
+
+                let schema_update_action_builder = SchemaUpdateBuilder::new()
+                    .with_schema_name(schema_name)
+                    .with_properties(property_definitions)
+                    .with_deprecate_properties(
+                        parse_value_as_vec_string(schema_yaml, "deprecate_properties")?
+                            .unwrap_or_default(),
+                    )
+                    .with_optional_properties(
+                        parse_value_as_vec_string(schema_yaml, "optional_properties")?
+                            .unwrap_or_default(),
+                    )
+                    .with_add_enum_options(add_enum_options);
+
+                generate_update_schema_payload(schema_update_action_builder)
             })
             .collect::<Result<Vec<SchemaPayload>, _>>(),
+        _ => Err(CliError::UserError(
+            "Schemas can only be created or updated from a yaml file".to_string(),
+        )),
     }
 }
 
@@ -224,15 +371,10 @@ fn generate_create_schema_payload(
 }
 
 fn generate_update_schema_payload(
-    name: &str,
-    properties: &[PropertyDefinition],
+    schema_update_action_builder: SchemaUpdateBuilder,
 ) -> Result<SchemaPayload, CliError> {
     let mut schema_paylod = SchemaPayloadBuilder::new();
 
-    let schema_update_action_builder = SchemaUpdateBuilder::new()
-        .with_schema_name(name.to_string())
-        .with_properties(properties.to_vec());
-
     let schema_update_action = schema_update_action_builder.build().map_err(|err| {
         CliError::PayloadError(format!("Failed to build schema payload: {}", err))
     })?;
@@ -257,6 +399,39 @@ fn parse_properties(properties: &[Value]) -> Result<Vec<PropertyDefinition>, Cli
         .collect()
 }
 
+fn parse_enum_options_updates(updates: &[Value]) -> Result<Vec<EnumOptionsUpdate>, CliError> {
+    updates
+        .iter()
+        .map(|value| {
+            let update = value.as_mapping().ok_or_else(|| {
+                CliError::InvalidYamlError(
+                    "Failed to parse schema enum options update.".to_string(),
+                )
+            })?;
+
+            EnumOptionsUpdateBuilder::new()
+                .with_property_name(parse_value_as_string(update, "property_name")?.ok_or_else(
+                    || {
+                        CliError::InvalidYamlError(
+                            "Missing `property_name` field for enum options update.".to_string(),
+                        )
+                    },
+                )?)
+                .with_enum_options(
+                    parse_value_as_vec_string(update, "enum_options")?.ok_or_else(|| {
+                        CliError::InvalidYamlError(
+                            "Missing `enum_options` field for enum options update.".to_string(),
+                        )
+                    })?,
+                )
+                .build()
+                .map_err(|err| {
+                    CliError::PayloadError(format!("Failed to build enum options update: {}", err))
+                })
+        })
+        .collect()
+}
+
 fn parse_property_definition(property: &Mapping) -> Result<PropertyDefinition, CliError> {
     let data_type = parse_value_as_data_type(
         &parse_value_as_string(property, "data_type")?.ok_or_else(|| {
@@ -282,6 +457,11 @@ fn parse_property_definition(property: &Mapping) -> Result<PropertyDefinition, C
         None => property_definition,
     };
 
+    property_definition = match parse_value_as_boolean(property, "deprecated")? {
+        Some(deprecated) => property_definition.with_deprecated(deprecated),
+        None => property_definition,
+    };
+
     property_definition = match data_type {
         DataType::Number => property_definition.with_number_exponent(
             parse_value_as_i32(property, "number_exponent")?.ok_or_else(|| {
@@ -380,6 +560,13 @@ mod test {
         data_type: lat_long
         description: "Location where manufacturer is headquarted.""##;
 
+    static LIGHTBULB_CHANGES_YAML_EXAMPLE: &[u8] = br##"- name: "Lightbulb"
+  deprecate_properties: ["energy_rating"]
+  optional_properties: ["size"]
+  add_enum_options:
+    - property_name: "bulb_type"
+      enum_options: ["halogen"]"##;
+
     ///
     /// Verifies parse_yaml returns a valid SchemaPayload with SchemaCreateAction set from a yaml
     /// containing a single schema definition
@@ -476,6 +663,41 @@ mod test {
         })
     }
 
+    ///
+    /// Verifies parse_yaml reads the changes to existing properties of a schema update,
+    /// which may be given without adding any properties
+    ///
+    #[test]
+    fn test_valid_yaml_update_existing_properties() {
+        run_test(|test_yaml_file_path| {
+            let mut file =
+                File::create(test_yaml_file_path).expect("Error creating test schema yaml file.");
+            file.write_all(LIGHTBULB_CHANGES_YAML_EXAMPLE)
+                .expect("Error writting example schema.");
+
+            let payload = parse_yaml(
+                test_yaml_file_path,
+                Action::SchemaUpdate(SchemaUpdateAction::default()),
+            )
+            .expect("Error parsing yaml");
+
+            let expected = generate_update_schema_payload(
+                SchemaUpdateBuilder::new()
+                    .with_schema_name("Lightbulb".to_string())
+                    .with_deprecate_properties(vec!["energy_rating".to_string()])
+                    .with_optional_properties(vec!["size".to_string()])
+                    .with_add_enum_options(vec![EnumOptionsUpdateBuilder::new()
+                        .with_property_name("bulb_type".to_string())
+                        .with_enum_options(vec!["halogen".to_string()])
+                        .build()
+                        .unwrap()]),
+            )
+            .unwrap();
+
+            assert_eq!(expected, payload[0]);
+        })
+    }
+
     fn make_create_schema_payload_1() -> SchemaPayload {
         generate_create_schema_payload(
             "Lightbulb",
@@ -495,12 +717,21 @@ mod test {
     }
 
     fn make_update_schema_payload_1() -> SchemaPayload {
-        generate_update_schema_payload("Lightbulb", &create_lightbulb_property_definitions())
-            .unwrap()
+        generate_update_schema_payload(
+            SchemaUpdateBuilder::new()
+                .with_schema_name("Lightbulb".to_string())
+                .with_properties(create_lightbulb_property_definitions()),
+        )
+        .unwrap()
     }
 
     fn make_update_schema_payload_2() -> SchemaPayload {
-        generate_update_schema_payload("Phone", &create_phone_property_definitions()).unwrap()
+        generate_update_schema_payload(
+            SchemaUpdateBuilder::new()
+                .with_schema_name("Phone".to_string())
+                .with_properties(create_phone_property_definitions()),
+        )
+        .unwrap()
     }
 
     fn create_lightbulb_property_definitions() -> Vec<PropertyDefinition> {
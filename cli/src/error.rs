@@ -26,6 +26,7 @@ use std::io;
 pub enum CliError {
     LoggingInitializationError(Box<flexi_logger::FlexiLoggerError>),
     InvalidYamlError(String),
+    SerializationError(String),
     PayloadError(String),
     UserError(String),
     SigningError(signing::Error),
@@ -42,6 +43,7 @@ impl StdError for CliError {
         match self {
             CliError::LoggingInitializationError(err) => Some(err),
             CliError::InvalidYamlError(_) => None,
+            CliError::SerializationError(_) => None,
             CliError::PayloadError(_) => None,
             CliError::UserError(_) => None,
             CliError::DatabaseError(_) => None,
@@ -60,6 +62,7 @@ impl std::fmt::Display for CliError {
         match *self {
             CliError::UserError(ref err) => write!(f, "Error: {}", err),
             CliError::InvalidYamlError(ref err) => write!(f, "InvalidYamlError: {}", err),
+            CliError::SerializationError(ref err) => write!(f, "SerializationError: {}", err),
             CliError::PayloadError(ref err) => write!(f, "PayloadError: {}", err),
             CliError::IoError(ref err) => write!(f, "IoError: {}", err),
             CliError::DatabaseError(ref err) => write!(f, "DatabaseError: {}", err),
@@ -98,6 +101,18 @@ impl From<serde_yaml::Error> for CliError {
     }
 }
 
+impl From<serde_json::Error> for CliError {
+    fn from(err: serde_json::Error) -> Self {
+        CliError::SerializationError(err.to_string())
+    }
+}
+
+impl From<csv::Error> for CliError {
+    fn from(err: csv::Error) -> Self {
+        CliError::SerializationError(err.to_string())
+    }
+}
+
 impl From<protobuf::ProtobufError> for CliError {
     fn from(err: protobuf::ProtobufError) -> Self {
         CliError::ProtobufError(err)
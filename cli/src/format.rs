@@ -0,0 +1,203 @@
+/*
+ * Copyright 2019 Cargill Incorporated
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * -----------------------------------------------------------------------------
+ */
+
+use std::io;
+use std::str::FromStr;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::error::CliError;
+
+/// The output formats supported by the list and show commands
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OutputFormat {
+    Human,
+    Json,
+    Yaml,
+    Csv,
+}
+
+impl Default for OutputFormat {
+    fn default() -> Self {
+        OutputFormat::Human
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = CliError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "human" => Ok(OutputFormat::Human),
+            "json" => Ok(OutputFormat::Json),
+            "yaml" => Ok(OutputFormat::Yaml),
+            "csv" => Ok(OutputFormat::Csv),
+            _ => Err(CliError::UserError(format!("Invalid output format: {}", s))),
+        }
+    }
+}
+
+/// A type that can be written as csv, with one column per serialized field
+pub trait CsvRecord: Serialize {
+    /// Column names, in the order the fields are declared on the type. The headers are fixed by
+    /// the type rather than read from the data, so an empty list or an unset optional field does
+    /// not change the columns.
+    const CSV_HEADERS: &'static [&'static str];
+}
+
+/**
+ * Print a list of items in the given format
+ *
+ * format - Format the items should be printed in
+ * items - Items to be printed
+ * display_human - Function used to print a single item in the human readable format
+ */
+pub fn print_list<T, F>(format: OutputFormat, items: &[T], display_human: F) -> Result<(), CliError>
+where
+    T: CsvRecord,
+    F: Fn(&T),
+{
+    match format {
+        OutputFormat::Human => items.iter().for_each(display_human),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(items)?),
+        OutputFormat::Yaml => println!("{}", serde_yaml::to_string(items)?.trim_end()),
+        OutputFormat::Csv => write_csv(io::stdout(), items)?,
+    }
+    Ok(())
+}
+
+/**
+ * Print a single item in the given format
+ *
+ * format - Format the item should be printed in
+ * item - Item to be printed
+ * display_human - Function used to print the item in the human readable format
+ */
+pub fn print_item<T, F>(format: OutputFormat, item: &T, display_human: F) -> Result<(), CliError>
+where
+    T: CsvRecord,
+    F: Fn(&T),
+{
+    match format {
+        OutputFormat::Human => display_human(item),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(item)?),
+        OutputFormat::Yaml => println!("{}", serde_yaml::to_string(item)?.trim_end()),
+        OutputFormat::Csv => write_csv(io::stdout(), std::slice::from_ref(item))?,
+    }
+    Ok(())
+}
+
+/**
+ * Write the items as csv, with one row per item and one column per top level field. Nested
+ * fields, such as lists of properties, are written as JSON strings.
+ *
+ * writer - Destination of the csv output
+ * items - Items to be written
+ */
+fn write_csv<W: io::Write, T: CsvRecord>(writer: W, items: &[T]) -> Result<(), CliError> {
+    let mut csv_writer = csv::Writer::from_writer(writer);
+    csv_writer.write_record(T::CSV_HEADERS)?;
+    for item in items {
+        let row = match serde_json::to_value(item)? {
+            Value::Object(map) => map,
+            _ => {
+                return Err(CliError::UserError(
+                    "Only structured values can be written as csv".to_string(),
+                ))
+            }
+        };
+        csv_writer.write_record(T::CSV_HEADERS.iter().map(|header| match row.get(*header) {
+            None | Some(Value::Null) => String::new(),
+            Some(Value::String(value)) => value.to_string(),
+            Some(value) => value.to_string(),
+        }))?;
+    }
+    csv_writer.flush()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct TestSlice {
+        name: String,
+        required: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        service_id: Option<String>,
+        enum_options: Vec<String>,
+    }
+
+    impl CsvRecord for TestSlice {
+        const CSV_HEADERS: &'static [&'static str] =
+            &["name", "required", "service_id", "enum_options"];
+    }
+
+    #[test]
+    fn test_parse_output_format() {
+        assert_eq!(
+            OutputFormat::from_str("human").unwrap(),
+            OutputFormat::Human
+        );
+        assert_eq!(OutputFormat::from_str("JSON").unwrap(), OutputFormat::Json);
+        assert_eq!(OutputFormat::from_str("yaml").unwrap(), OutputFormat::Yaml);
+        assert_eq!(OutputFormat::from_str("csv").unwrap(), OutputFormat::Csv);
+        assert!(OutputFormat::from_str("xml").is_err());
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let items = vec![
+            TestSlice {
+                name: "color".to_string(),
+                required: true,
+                service_id: None,
+                enum_options: vec!["red".to_string(), "blue".to_string()],
+            },
+            TestSlice {
+                name: "size, in cm".to_string(),
+                required: false,
+                service_id: Some("01234-ABCDE::grid".to_string()),
+                enum_options: vec![],
+            },
+        ];
+
+        let mut output = Vec::new();
+        write_csv(&mut output, &items).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "name,required,service_id,enum_options\n\
+             color,true,,\"[\"\"red\"\",\"\"blue\"\"]\"\n\
+             \"size, in cm\",false,01234-ABCDE::grid,[]\n"
+        );
+    }
+
+    #[test]
+    fn test_write_csv_empty() {
+        let mut output = Vec::new();
+        write_csv::<_, TestSlice>(&mut output, &[]).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "name,required,service_id,enum_options\n"
+        );
+    }
+}
@@ -19,16 +19,30 @@ use crate::CliError;
 use protobuf::Message;
 use reqwest::Client;
 use sawtooth_sdk::messages::batch::BatchList;
-use serde::Deserialize;
+use serde::{de::DeserializeOwned, Deserialize};
 use std::collections::HashMap;
 use std::time::Instant;
 
 pub fn submit_batches(
     url: &str,
-    mut wait: u64,
+    wait: u64,
     batch_list: &BatchList,
     service_id: Option<&str>,
 ) -> Result<(), CliError> {
+    submit_batches_with_status(url, wait, batch_list, service_id).map(|_| ())
+}
+
+/**
+ * Submit batches and wait up to `wait` seconds for them to leave the PENDING
+ * state, returning the last statuses reported. No statuses are returned when
+ * `wait` is zero.
+ */
+pub fn submit_batches_with_status(
+    url: &str,
+    mut wait: u64,
+    batch_list: &BatchList,
+    service_id: Option<&str>,
+) -> Result<Vec<BatchStatus>, CliError> {
     let bytes = batch_list.write_to_bytes()?;
 
     let client = Client::new();
@@ -46,6 +60,8 @@ pub fn submit_batches(
 
     debug!("Response: {:#?}", batch_link);
 
+    let mut statuses = Vec::new();
+
     while wait > 0 {
         let time = Instant::now();
 
@@ -56,18 +72,54 @@ pub fn submit_batches(
 
         debug!("Batch Status: {:#?}", batch_status);
 
-        if batch_status.data.iter().all(|x| x.status != "PENDING") {
+        let done = batch_status.data.iter().all(|x| x.status != "PENDING");
+        statuses = batch_status.data;
+        if done {
             break;
         }
 
         wait -= time.elapsed().as_secs()
     }
 
-    Ok(())
+    Ok(statuses)
+}
+
+/**
+ * Fetch every item of a paged list endpoint, following the `next` links until the last page
+ *
+ * url - Url for the REST API
+ * path - Path, including any query parameters, of the list endpoint
+ */
+pub fn fetch_all_pages<T: DeserializeOwned>(url: &str, path: &str) -> Result<Vec<T>, CliError> {
+    let client = Client::new();
+
+    let mut items = Vec::new();
+    let mut next = Some(path.to_string());
+
+    while let Some(path) = next {
+        let final_url = format!("{}{}", url, path);
+        debug!("url {}", final_url);
+        let mut page = client.get(&final_url).send()?.json::<ListResponse<T>>()?;
+        items.append(&mut page.data);
+        next = page.paging.next;
+    }
+
+    Ok(items)
 }
 
 // Server Responses
 
+#[derive(Deserialize, Debug)]
+struct ListResponse<T> {
+    pub data: Vec<T>,
+    pub paging: Paging,
+}
+
+#[derive(Deserialize, Debug)]
+struct Paging {
+    pub next: Option<String>,
+}
+
 #[derive(Deserialize, Debug)]
 pub struct BatchStatusLink {
     pub link: String,
@@ -80,7 +132,7 @@ struct BatchStatusResponse {
 }
 
 #[derive(Deserialize, Debug)]
-struct BatchStatus {
+pub struct BatchStatus {
     pub id: String,
     pub invalid_transactions: Vec<HashMap<String, String>>,
     pub status: String,
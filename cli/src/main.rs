@@ -22,6 +22,7 @@ extern crate diesel;
 
 mod actions;
 mod error;
+mod format;
 mod http;
 mod key;
 #[cfg(feature = "sawtooth")]
@@ -37,16 +38,25 @@ use clap::ArgMatches;
 use flexi_logger::{DeferredNow, LogSpecBuilder, Logger};
 use grid_sdk::protocol::pike::{
     payload::{
-        CreateAgentActionBuilder, CreateOrganizationActionBuilder, UpdateAgentActionBuilder,
-        UpdateOrganizationActionBuilder,
+        CreateAgentActionBuilder, CreateDelegationActionBuilder, CreateOrganizationActionBuilder,
+        CreateRoleActionBuilder, DeleteAgentActionBuilder, DeleteDelegationActionBuilder,
+        DeleteOrganizationActionBuilder, DeleteRoleActionBuilder, RotateAgentKeyActionBuilder,
+        UpdateAgentActionBuilder, UpdateOrganizationActionBuilder, UpdateRoleActionBuilder,
     },
-    state::{KeyValueEntry, KeyValueEntryBuilder},
+    state::{AlternateId, AlternateIdBuilder, KeyValueEntry, KeyValueEntryBuilder},
+};
+use grid_sdk::protocol::track_and_trace::payload::{
+    AnswerProposalActionBuilder, CreateProposalActionBuilder, Response, RevokeReporterActionBuilder,
 };
 use log::Record;
 
 use crate::error::CliError;
+use crate::format::OutputFormat;
+use crate::yaml_parser::parse_value_as_role;
 
-use actions::{agents, database, keygen, organizations as orgs, products, schemas};
+use actions::{
+    agents, database, delegations, keygen, organizations as orgs, products, records, roles, schemas,
+};
 
 #[cfg(feature = "admin-keygen")]
 use actions::admin;
@@ -78,11 +88,13 @@ fn run() -> Result<(), CliError> {
         (@arg wait: --wait +takes_value "How long to wait for transaction to be committed")
         (@arg key: -k +takes_value "base name for private key file")
         (@arg verbose: -v +multiple +global "Log verbosely")
+        (@arg format: --format +takes_value +global possible_values(&["human", "json", "yaml", "csv"])
+            "Output format for list and show commands")
         (@arg quiet: -q --quiet +global conflicts_with[verbose] "Do not display output")
         (@arg service_id: --("service-id") +takes_value "The ID of the service the payload should be \
             sent to; required if running on Splinter. Format <circuit-id>::<service-id>")
         (@subcommand agent =>
-            (about: "Update or create agent")
+            (about: "Create, update, delete, or view agents")
             (@setting SubcommandRequiredElseHelp)
             (@subcommand create =>
                 (about: "Create an agent")
@@ -108,9 +120,27 @@ fn run() -> Result<(), CliError> {
                 (@arg metadata: --metadata +takes_value +multiple +use_delimiter
                     "Key-value pairs (format: <key>=<value>) in a comma-separated list")
             )
+            (@subcommand delete =>
+                (about: "Delete an agent")
+                (@arg org_id: +takes_value +required "organization ID")
+                (@arg public_key: +takes_value +required "public key")
+            )
+            (@subcommand rotate_key =>
+                (about: "Move an agent's roles and metadata to a new public key")
+                (@arg org_id: +takes_value +required "organization ID")
+                (@arg public_key: +takes_value +required "current public key")
+                (@arg new_public_key: +takes_value +required "new public key")
+            )
+            (@subcommand list =>
+                (about: "List currently defined agents")
+            )
+            (@subcommand show =>
+                (about: "Show agent specified by public key argument")
+                (@arg public_key: +takes_value +required "Public key of agent")
+            )
         )
         (@subcommand organization =>
-            (about: "Update or create organization")
+            (about: "Create, update, delete, or view organizations")
             (@setting SubcommandRequiredElseHelp)
             (@subcommand create =>
                 (about: "Create an organization")
@@ -119,6 +149,11 @@ fn run() -> Result<(), CliError> {
                 (@arg address: +takes_value "Physical address for organization")
                 (@arg metadata: --metadata +takes_value +multiple +use_delimiter
                     "Key-value pairs (format: <key>=<value>) in a comma-separated list")
+                (@arg gs1_company_prefixes: --("gs1-company-prefixes") +takes_value +multiple
+                    +use_delimiter "GS1 company prefixes owned by the organization \
+                    in a comma-separated list")
+                (@arg alternate_ids: --("alternate-ids") +takes_value +multiple +use_delimiter
+                    "Alternate IDs (format: <id_type>:<id>) in a comma-separated list")
             )
             (@subcommand update =>
                 (about: "Update an organization")
@@ -127,6 +162,86 @@ fn run() -> Result<(), CliError> {
                 (@arg address: +takes_value "Physical address for organization")
                 (@arg metadata: --metadata +takes_value +multiple +use_delimiter
                     "Key-value pairs (format: <key>=<value>) in a comma-separated list")
+                (@arg gs1_company_prefixes: --("gs1-company-prefixes") +takes_value +multiple
+                    +use_delimiter "GS1 company prefixes owned by the organization \
+                    in a comma-separated list")
+                (@arg alternate_ids: --("alternate-ids") +takes_value +multiple +use_delimiter
+                    "Alternate IDs (format: <id_type>:<id>) in a comma-separated list")
+                (@arg clear_alternate_ids: --("clear-alternate-ids") conflicts_with[alternate_ids]
+                    "Release all of the organization's alternate IDs")
+                (@arg agents: --agents +takes_value +multiple +use_delimiter
+                    "Public keys of every agent of an organization created before its agents \
+                    were tracked, in a comma-separated list")
+            )
+            (@subcommand delete =>
+                (about: "Delete an organization; all other agents must be deleted first")
+                (@arg org_id: +required +takes_value "Unique ID for organization")
+            )
+            (@subcommand import =>
+                (about: "Create organizations with their roles and agents from a yaml file, \
+                    in a single batch that is committed or rejected as a whole; the key that \
+                    signs an organization's transactions becomes its admin and must not \
+                    already be an agent")
+                (@arg path: +takes_value +required
+                    "Path to yaml file containing a list of organizations; each may name the \
+                    key that signs it with `signing_key`, defaulting to --key for at most one")
+            )
+            (@subcommand list =>
+                (about: "List currently defined organizations")
+            )
+            (@subcommand show =>
+                (about: "Show organization specified by ID argument")
+                (@arg org_id: +takes_value +required "ID of organization")
+            )
+        )
+        (@subcommand role =>
+            (about: "Create, update, or delete roles")
+            (@setting SubcommandRequiredElseHelp)
+            (@subcommand create =>
+                (about: "Create a role for an organization")
+                (@arg org_id: +required +takes_value "ID of the organization that owns the role")
+                (@arg name: +required +takes_value "Name of the role")
+                (@arg description: --description +takes_value "Description of the role")
+                (@arg permission: --permission +takes_value +use_delimiter +multiple
+                    "Permissions granted by the role")
+                (@arg inherit_from: --("inherit-from") +takes_value +use_delimiter +multiple
+                    "Roles of the same organization whose permissions are inherited")
+            )
+            (@subcommand update =>
+                (about: "Replace the description, permissions, and inherited roles of a role")
+                (@arg org_id: +required +takes_value "ID of the organization that owns the role")
+                (@arg name: +required +takes_value "Name of the role")
+                (@arg description: --description +takes_value "Description of the role")
+                (@arg permission: --permission +takes_value +use_delimiter +multiple
+                    "Permissions granted by the role")
+                (@arg inherit_from: --("inherit-from") +takes_value +use_delimiter +multiple
+                    "Roles of the same organization whose permissions are inherited")
+            )
+            (@subcommand delete =>
+                (about: "Delete a role")
+                (@arg org_id: +required +takes_value "ID of the organization that owns the role")
+                (@arg name: +required +takes_value "Name of the role")
+            )
+        )
+        (@subcommand delegation =>
+            (about: "Delegate permissions to, or revoke them from, another organization")
+            (@setting SubcommandRequiredElseHelp)
+            (@subcommand create =>
+                (about: "Allow the agents of another organization to use permissions for an \
+                    organization")
+                (@arg org_id: +required +takes_value "ID of the organization delegating permissions")
+                (@arg delegate_org_id: +required +takes_value
+                    "ID of the organization the permissions are delegated to")
+                (@arg permission: --permission +required +takes_value +use_delimiter +multiple
+                    "Permissions delegated")
+                (@arg expiry: --expiry +takes_value
+                    "Unix UTC timestamp after which the delegation no longer applies")
+            )
+            (@subcommand delete =>
+                (about: "Revoke the permissions delegated to another organization")
+                (@arg org_id: +required +takes_value "ID of the organization delegating permissions")
+                (@arg delegate_org_id: +required +takes_value
+                    "ID of the organization the permissions are delegated to")
             )
         )
         (@subcommand schema =>
@@ -147,6 +262,23 @@ fn run() -> Result<(), CliError> {
                 (about: "Show schema specified by name argument")
                 (@arg name: +takes_value +required "Name of schema")
             )
+            (@subcommand add_editor =>
+                (name: "add-editor")
+                (about: "Allow another organization's agents to update a schema")
+                (@arg name: +takes_value +required "Name of schema")
+                (@arg org_id: +takes_value +required "ID of the editor organization")
+            )
+            (@subcommand remove_editor =>
+                (name: "remove-editor")
+                (about: "Revoke an organization's permission to update a schema")
+                (@arg name: +takes_value +required "Name of schema")
+                (@arg org_id: +takes_value +required "ID of the editor organization")
+            )
+            (@subcommand transfer =>
+                (about: "Transfer ownership of a schema to another organization")
+                (@arg name: +takes_value +required "Name of schema")
+                (@arg new_owner: +takes_value +required "ID of the organization receiving the schema")
+            )
         )
         (@subcommand database =>
             (about: "Manage Grid Daemon database")
@@ -164,20 +296,37 @@ fn run() -> Result<(), CliError> {
            (@arg key_dir: -d --key_dir +takes_value "Specify the directory for the key files")
         )
         (@subcommand product =>
-            (about: "Create, update, or delete products")
+            (about: "Create, update, delete, or transfer products")
             (@setting SubcommandRequiredElseHelp)
             (@subcommand create =>
                 (about: "Create products from a yaml file")
                 (@arg path: +takes_value +required "Path to yaml file containing a list of products")
             )
             (@subcommand update =>
-                (about: "Update products from a yaml file")
-                (@arg path: +takes_value +required "Path to yaml file containing a list of products")
+                (about: "Update products from a yaml file, or set and remove individual \
+                    properties of a single product")
+                (@arg path: +takes_value required_unless[product_id] conflicts_with[product_id]
+                    "Path to yaml file containing a list of products")
+                (@arg product_id: --product_id +takes_value requires[product_type]
+                    "Unique ID of the product to change")
+                (@arg product_type: --product_type +takes_value
+                    "Type of product (GS1, GLN or SSCC)")
+                (@arg set: --set +takes_value +multiple number_of_values(1) use_delimiter(false)
+                    requires[product_id] "Property to set, as name=value or name:type=value")
+                (@arg unset: --unset +takes_value +multiple number_of_values(1)
+                    requires[product_id] "Name of a property to remove")
             )
             (@subcommand delete =>
                 (about: "Delete a product")
                 (@arg product_id: +required +takes_value "Unique ID for a product")
-                (@arg product_type: +required +takes_value "Type of product (e.g. GS1")
+                (@arg product_type: +required +takes_value "Type of product (GS1, GLN or SSCC)")
+            )
+            (@subcommand transfer =>
+                (about: "Offer a product to another organization, or accept an offered product")
+                (@arg product_id: +required +takes_value "Unique ID for a product")
+                (@arg product_type: +required +takes_value "Type of product (GS1, GLN or SSCC)")
+                (@arg new_owner: +required +takes_value
+                    "ID of the organization receiving the product")
             )
             (@subcommand list =>
                 (about: "List currently defined products")
@@ -185,6 +334,92 @@ fn run() -> Result<(), CliError> {
             (@subcommand show =>
                 (about: "Show product specified by ID argument")
                 (@arg product_id: +takes_value +required "ID of product")
+                (@arg product_type: --("product-type") +takes_value
+                    "Type of product (GS1, GLN or SSCC); defaults to GS1")
+            )
+            (@subcommand history =>
+                (about: "Show every version of the product specified by ID argument")
+                (@arg product_id: +takes_value +required "ID of product")
+                (@arg product_type: --("product-type") +takes_value
+                    "Type of product (GS1, GLN or SSCC); defaults to GS1")
+            )
+        )
+        (@subcommand proposal =>
+            (about: "Create or answer proposals to transfer a role on a record")
+            (@setting SubcommandRequiredElseHelp)
+            (@subcommand create =>
+                (about: "Create a proposal")
+                (@arg record_id: +takes_value +required "Unique ID for a record")
+                (@arg receiving_agent: +takes_value +required
+                    "Public key of the agent receiving the proposal")
+                (@arg role: --role +takes_value +required
+                    possible_values(&["owner", "custodian", "reporter"])
+                    "Role being proposed")
+                (@arg property: --property +takes_value +use_delimiter +multiple
+                    "Properties the receiving agent may report on; required for reporter proposals")
+                (@arg terms: --terms +takes_value "Human-readable terms of transfer")
+            )
+            (@subcommand accept =>
+                (about: "Accept a proposal")
+                (@arg record_id: +takes_value +required "Unique ID for a record")
+                (@arg receiving_agent: +takes_value +required
+                    "Public key of the agent receiving the proposal")
+                (@arg role: --role +takes_value +required
+                    possible_values(&["owner", "custodian", "reporter"])
+                    "Role being proposed")
+            )
+            (@subcommand reject =>
+                (about: "Reject a proposal")
+                (@arg record_id: +takes_value +required "Unique ID for a record")
+                (@arg receiving_agent: +takes_value +required
+                    "Public key of the agent receiving the proposal")
+                (@arg role: --role +takes_value +required
+                    possible_values(&["owner", "custodian", "reporter"])
+                    "Role being proposed")
+            )
+            (@subcommand cancel =>
+                (about: "Cancel a proposal")
+                (@arg record_id: +takes_value +required "Unique ID for a record")
+                (@arg receiving_agent: +takes_value +required
+                    "Public key of the agent receiving the proposal")
+                (@arg role: --role +takes_value +required
+                    possible_values(&["owner", "custodian", "reporter"])
+                    "Role being proposed")
+            )
+        )
+        (@subcommand reporter =>
+            (about: "Manage reporters of record properties")
+            (@setting SubcommandRequiredElseHelp)
+            (@subcommand revoke =>
+                (about: "Revoke a reporter's authorization")
+                (@arg record_id: +takes_value +required "Unique ID for a record")
+                (@arg reporter_id: +takes_value +required "Public key of the reporter")
+                (@arg property: --property +takes_value +use_delimiter +multiple +required
+                    "Properties for which the reporter's authorization is revoked")
+            )
+        )
+        (@subcommand record =>
+            (about: "Create, update, or finalize Track and Trace records")
+            (@setting SubcommandRequiredElseHelp)
+            (@subcommand create =>
+                (about: "Create records from a yaml file")
+                (@arg path: +takes_value +required "Path to yaml file containing a list of records")
+            )
+            (@subcommand finalize =>
+                (about: "Finalize a record")
+                (@arg record_id: +takes_value +required "Unique ID for a record")
+            )
+            (@subcommand update_properties =>
+                (about: "Update record properties from a yaml file")
+                (@arg path: +takes_value +required
+                    "Path to yaml file containing a list of property updates")
+            )
+            (@subcommand list =>
+                (about: "List currently defined records")
+            )
+            (@subcommand show =>
+                (about: "Show record specified by ID argument")
+                (@arg record_id: +takes_value +required "ID of record")
             )
         )
     );
@@ -331,6 +566,32 @@ fn run() -> Result<(), CliError> {
 
                 agents::do_update_agent(&url, key, wait, update_agent, service_id)?
             }
+            ("delete", Some(m)) => {
+                let delete_agent = DeleteAgentActionBuilder::new()
+                    .with_org_id(m.value_of("org_id").unwrap().into())
+                    .with_public_key(m.value_of("public_key").unwrap().into())
+                    .build()
+                    .map_err(|err| CliError::UserError(format!("{}", err)))?;
+
+                agents::do_delete_agent(&url, key, wait, delete_agent, service_id)?
+            }
+            ("rotate_key", Some(m)) => {
+                let rotate_agent_key = RotateAgentKeyActionBuilder::new()
+                    .with_org_id(m.value_of("org_id").unwrap().into())
+                    .with_public_key(m.value_of("public_key").unwrap().into())
+                    .with_new_public_key(m.value_of("new_public_key").unwrap().into())
+                    .build()
+                    .map_err(|err| CliError::UserError(format!("{}", err)))?;
+
+                agents::do_rotate_agent_key(&url, key, wait, rotate_agent_key, service_id)?
+            }
+            ("list", Some(m)) => agents::do_list_agents(&url, service_id, output_format(m)?)?,
+            ("show", Some(m)) => agents::do_show_agent(
+                &url,
+                m.value_of("public_key").unwrap(),
+                service_id,
+                output_format(m)?,
+            )?,
             _ => return Err(CliError::UserError("Subcommand not recognized".into())),
         },
         ("organization", Some(m)) => match m.subcommand() {
@@ -339,7 +600,8 @@ fn run() -> Result<(), CliError> {
                     .with_org_id(m.value_of("org_id").unwrap().into())
                     .with_name(m.value_of("name").unwrap().into())
                     .with_address(m.value_of("address").unwrap().into())
-                    .with_metadata(parse_metadata(&m)?)
+                    .with_metadata(parse_org_metadata(&m)?)
+                    .with_alternate_ids(parse_alternate_ids(&m)?)
                     .build()
                     .map_err(|err| CliError::UserError(format!("{}", err)))?;
 
@@ -350,12 +612,131 @@ fn run() -> Result<(), CliError> {
                     .with_org_id(m.value_of("org_id").unwrap().into())
                     .with_name(m.value_of("name").unwrap().into())
                     .with_address(m.value_of("address").unwrap().into())
-                    .with_metadata(parse_metadata(&m)?)
+                    .with_metadata(parse_org_metadata(&m)?)
+                    .with_alternate_ids(parse_alternate_ids(&m)?)
+                    .with_clear_alternate_ids(m.is_present("clear_alternate_ids"))
+                    .with_agents(
+                        m.values_of("agents")
+                            .unwrap_or_default()
+                            .map(String::from)
+                            .collect::<Vec<String>>(),
+                    )
                     .build()
                     .map_err(|err| CliError::UserError(format!("{}", err)))?;
 
                 orgs::do_update_organization(&url, key, wait, update_org, service_id)?
             }
+            ("delete", Some(m)) => {
+                let delete_org = DeleteOrganizationActionBuilder::new()
+                    .with_org_id(m.value_of("org_id").unwrap().into())
+                    .build()
+                    .map_err(|err| CliError::UserError(format!("{}", err)))?;
+
+                orgs::do_delete_organization(&url, key, wait, delete_org, service_id)?
+            }
+            ("import", Some(m)) => orgs::do_import_organizations(
+                &url,
+                key,
+                wait,
+                m.value_of("path").unwrap(),
+                service_id,
+            )?,
+            ("list", Some(m)) => orgs::do_list_organizations(&url, service_id, output_format(m)?)?,
+            ("show", Some(m)) => orgs::do_show_organization(
+                &url,
+                m.value_of("org_id").unwrap(),
+                service_id,
+                output_format(m)?,
+            )?,
+            _ => return Err(CliError::UserError("Subcommand not recognized".into())),
+        },
+        ("role", Some(m)) => match m.subcommand() {
+            ("create", Some(m)) => {
+                let create_role = CreateRoleActionBuilder::new()
+                    .with_org_id(m.value_of("org_id").unwrap().into())
+                    .with_name(m.value_of("name").unwrap().into())
+                    .with_description(m.value_of("description").unwrap_or_default().into())
+                    .with_permissions(
+                        m.values_of("permission")
+                            .unwrap_or_default()
+                            .map(String::from)
+                            .collect::<Vec<String>>(),
+                    )
+                    .with_inherit_from(
+                        m.values_of("inherit_from")
+                            .unwrap_or_default()
+                            .map(String::from)
+                            .collect::<Vec<String>>(),
+                    )
+                    .build()
+                    .map_err(|err| CliError::UserError(format!("{}", err)))?;
+
+                roles::do_create_role(&url, key, wait, create_role, service_id)?
+            }
+            ("update", Some(m)) => {
+                let update_role = UpdateRoleActionBuilder::new()
+                    .with_org_id(m.value_of("org_id").unwrap().into())
+                    .with_name(m.value_of("name").unwrap().into())
+                    .with_description(m.value_of("description").unwrap_or_default().into())
+                    .with_permissions(
+                        m.values_of("permission")
+                            .unwrap_or_default()
+                            .map(String::from)
+                            .collect::<Vec<String>>(),
+                    )
+                    .with_inherit_from(
+                        m.values_of("inherit_from")
+                            .unwrap_or_default()
+                            .map(String::from)
+                            .collect::<Vec<String>>(),
+                    )
+                    .build()
+                    .map_err(|err| CliError::UserError(format!("{}", err)))?;
+
+                roles::do_update_role(&url, key, wait, update_role, service_id)?
+            }
+            ("delete", Some(m)) => {
+                let delete_role = DeleteRoleActionBuilder::new()
+                    .with_org_id(m.value_of("org_id").unwrap().into())
+                    .with_name(m.value_of("name").unwrap().into())
+                    .build()
+                    .map_err(|err| CliError::UserError(format!("{}", err)))?;
+
+                roles::do_delete_role(&url, key, wait, delete_role, service_id)?
+            }
+            _ => return Err(CliError::UserError("Subcommand not recognized".into())),
+        },
+        ("delegation", Some(m)) => match m.subcommand() {
+            ("create", Some(m)) => {
+                let mut builder = CreateDelegationActionBuilder::new()
+                    .with_org_id(m.value_of("org_id").unwrap().into())
+                    .with_delegate_org_id(m.value_of("delegate_org_id").unwrap().into())
+                    .with_permissions(
+                        m.values_of("permission")
+                            .unwrap_or_default()
+                            .map(String::from)
+                            .collect::<Vec<String>>(),
+                    );
+                if let Some(expiry) = m.value_of("expiry") {
+                    builder = builder.with_expiry(expiry.parse::<u64>().map_err(|err| {
+                        CliError::UserError(format!("Invalid expiry {}: {}", expiry, err))
+                    })?);
+                }
+                let create_delegation = builder
+                    .build()
+                    .map_err(|err| CliError::UserError(format!("{}", err)))?;
+
+                delegations::do_create_delegation(&url, key, wait, create_delegation, service_id)?
+            }
+            ("delete", Some(m)) => {
+                let delete_delegation = DeleteDelegationActionBuilder::new()
+                    .with_org_id(m.value_of("org_id").unwrap().into())
+                    .with_delegate_org_id(m.value_of("delegate_org_id").unwrap().into())
+                    .build()
+                    .map_err(|err| CliError::UserError(format!("{}", err)))?;
+
+                delegations::do_delete_delegation(&url, key, wait, delete_delegation, service_id)?
+            }
             _ => return Err(CliError::UserError("Subcommand not recognized".into())),
         },
         ("schema", Some(m)) => match m.subcommand() {
@@ -373,10 +754,37 @@ fn run() -> Result<(), CliError> {
                 m.value_of("path").unwrap(),
                 service_id,
             )?,
-            ("list", Some(_)) => schemas::do_list_schemas(&url, service_id)?,
-            ("show", Some(m)) => {
-                schemas::do_show_schema(&url, m.value_of("name").unwrap(), service_id)?
-            }
+            ("list", Some(m)) => schemas::do_list_schemas(&url, service_id, output_format(m)?)?,
+            ("show", Some(m)) => schemas::do_show_schema(
+                &url,
+                m.value_of("name").unwrap(),
+                service_id,
+                output_format(m)?,
+            )?,
+            ("add-editor", Some(m)) => schemas::do_add_schema_editor(
+                &url,
+                key,
+                wait,
+                m.value_of("name").unwrap(),
+                m.value_of("org_id").unwrap(),
+                service_id,
+            )?,
+            ("remove-editor", Some(m)) => schemas::do_remove_schema_editor(
+                &url,
+                key,
+                wait,
+                m.value_of("name").unwrap(),
+                m.value_of("org_id").unwrap(),
+                service_id,
+            )?,
+            ("transfer", Some(m)) => schemas::do_transfer_schema(
+                &url,
+                key,
+                wait,
+                m.value_of("name").unwrap(),
+                m.value_of("new_owner").unwrap(),
+                service_id,
+            )?,
             _ => return Err(CliError::UserError("Subcommand not recognized".into())),
         },
         ("database", Some(m)) => match m.subcommand() {
@@ -399,24 +807,146 @@ fn run() -> Result<(), CliError> {
                 m.value_of("path").unwrap(),
                 service_id,
             )?,
-            ("update", Some(m)) => products::do_update_products(
+            ("update", Some(m)) => match m.value_of("product_id") {
+                Some(product_id) => products::do_patch_product(
+                    &url,
+                    key,
+                    wait,
+                    product_id,
+                    m.value_of("product_type").unwrap(),
+                    &m.values_of("set")
+                        .map(|values| values.collect::<Vec<_>>())
+                        .unwrap_or_default(),
+                    &m.values_of("unset")
+                        .map(|values| values.collect::<Vec<_>>())
+                        .unwrap_or_default(),
+                    service_id,
+                )?,
+                None => products::do_update_products(
+                    &url,
+                    key,
+                    wait,
+                    m.value_of("path").unwrap(),
+                    service_id,
+                )?,
+            },
+            ("delete", Some(m)) => products::do_delete_products(
                 &url,
                 key,
                 wait,
-                m.value_of("path").unwrap(),
+                m.value_of("product_id").unwrap(),
+                m.value_of("product_type").unwrap(),
                 service_id,
             )?,
-            ("delete", Some(m)) => products::do_delete_products(
+            ("transfer", Some(m)) => products::do_transfer_product(
                 &url,
                 key,
                 wait,
                 m.value_of("product_id").unwrap(),
                 m.value_of("product_type").unwrap(),
+                m.value_of("new_owner").unwrap(),
+                service_id,
+            )?,
+            ("list", Some(m)) => products::do_list_products(&url, service_id, output_format(m)?)?,
+            ("show", Some(m)) => products::do_show_products(
+                &url,
+                m.value_of("product_id").unwrap(),
+                m.value_of("product_type"),
+                service_id,
+                output_format(m)?,
+            )?,
+            ("history", Some(m)) => products::do_show_product_history(
+                &url,
+                m.value_of("product_id").unwrap(),
+                m.value_of("product_type"),
+                service_id,
+                output_format(m)?,
+            )?,
+            _ => return Err(CliError::UserError("Subcommand not recognized".into())),
+        },
+        ("record", Some(m)) => match m.subcommand() {
+            ("create", Some(m)) => records::do_create_records(
+                &url,
+                key,
+                wait,
+                m.value_of("path").unwrap(),
+                service_id,
+            )?,
+            ("finalize", Some(m)) => records::do_finalize_record(
+                &url,
+                key,
+                wait,
+                m.value_of("record_id").unwrap(),
+                service_id,
+            )?,
+            ("update_properties", Some(m)) => records::do_update_record_properties(
+                &url,
+                key,
+                wait,
+                m.value_of("path").unwrap(),
+                service_id,
+            )?,
+            ("list", Some(m)) => records::do_list_records(&url, service_id, output_format(m)?)?,
+            ("show", Some(m)) => records::do_show_record(
+                &url,
+                m.value_of("record_id").unwrap(),
                 service_id,
+                output_format(m)?,
             )?,
-            ("list", Some(_)) => products::do_list_products(&url, service_id)?,
-            ("show", Some(m)) => {
-                products::do_show_products(&url, m.value_of("product_id").unwrap(), service_id)?
+            _ => return Err(CliError::UserError("Subcommand not recognized".into())),
+        },
+        ("proposal", Some(m)) => match m.subcommand() {
+            ("create", Some(m)) => {
+                let create_proposal = CreateProposalActionBuilder::new()
+                    .with_record_id(m.value_of("record_id").unwrap().into())
+                    .with_receiving_agent(m.value_of("receiving_agent").unwrap().into())
+                    .with_role(parse_value_as_role(m.value_of("role").unwrap())?)
+                    .with_properties(
+                        m.values_of("property")
+                            .unwrap_or_default()
+                            .map(String::from)
+                            .collect::<Vec<String>>(),
+                    )
+                    .with_terms(m.value_of("terms").unwrap_or("").into())
+                    .build()
+                    .map_err(|err| CliError::UserError(format!("{}", err)))?;
+
+                records::do_create_proposal(&url, key, wait, create_proposal, service_id)?
+            }
+            (response, Some(m)) => {
+                let response = match response {
+                    "accept" => Response::Accept,
+                    "reject" => Response::Reject,
+                    "cancel" => Response::Cancel,
+                    _ => return Err(CliError::UserError("Subcommand not recognized".into())),
+                };
+                let answer_proposal = AnswerProposalActionBuilder::new()
+                    .with_record_id(m.value_of("record_id").unwrap().into())
+                    .with_receiving_agent(m.value_of("receiving_agent").unwrap().into())
+                    .with_role(parse_value_as_role(m.value_of("role").unwrap())?)
+                    .with_response(response)
+                    .build()
+                    .map_err(|err| CliError::UserError(format!("{}", err)))?;
+
+                records::do_answer_proposal(&url, key, wait, answer_proposal, service_id)?
+            }
+            _ => return Err(CliError::UserError("Subcommand not recognized".into())),
+        },
+        ("reporter", Some(m)) => match m.subcommand() {
+            ("revoke", Some(m)) => {
+                let revoke_reporter = RevokeReporterActionBuilder::new()
+                    .with_record_id(m.value_of("record_id").unwrap().into())
+                    .with_reporter_id(m.value_of("reporter_id").unwrap().into())
+                    .with_properties(
+                        m.values_of("property")
+                            .unwrap_or_default()
+                            .map(String::from)
+                            .collect::<Vec<String>>(),
+                    )
+                    .build()
+                    .map_err(|err| CliError::UserError(format!("{}", err)))?;
+
+                records::do_revoke_reporter(&url, key, wait, revoke_reporter, service_id)?
             }
             _ => return Err(CliError::UserError("Subcommand not recognized".into())),
         },
@@ -456,6 +986,62 @@ fn parse_metadata(matches: &ArgMatches) -> Result<Vec<KeyValueEntry>, CliError>
     Ok(key_value_entries)
 }
 
+/// Parses an organization's metadata, adding the `gs1_company_prefixes` entry from its own
+/// argument since the list cannot be given through the comma-separated `--metadata` argument.
+fn parse_org_metadata(matches: &ArgMatches) -> Result<Vec<KeyValueEntry>, CliError> {
+    let mut key_value_entries = parse_metadata(matches)?;
+
+    if let Some(prefixes) = matches.values_of("gs1_company_prefixes") {
+        if key_value_entries
+            .iter()
+            .any(|entry| entry.key() == "gs1_company_prefixes")
+        {
+            return Err(CliError::UserError(
+                "gs1_company_prefixes must be set with --gs1-company-prefixes, not --metadata"
+                    .into(),
+            ));
+        }
+
+        key_value_entries.push(
+            KeyValueEntryBuilder::new()
+                .with_key("gs1_company_prefixes".into())
+                .with_value(prefixes.collect::<Vec<_>>().join(","))
+                .build()
+                .map_err(|err| CliError::UserError(format!("Metadata malformed: {}", err)))?,
+        );
+    }
+
+    Ok(key_value_entries)
+}
+
+fn parse_alternate_ids(matches: &ArgMatches) -> Result<Vec<AlternateId>, CliError> {
+    matches
+        .values_of("alternate_ids")
+        .unwrap_or_default()
+        .map(|alternate_id| {
+            let mut parts = alternate_id.splitn(2, ':');
+            match (parts.next(), parts.next()) {
+                (Some(id_type), Some(id)) => AlternateIdBuilder::new()
+                    .with_id_type(id_type.to_string())
+                    .with_id(id.to_string())
+                    .build()
+                    .map_err(|err| CliError::UserError(format!("Alternate ID malformed: {}", err))),
+                _ => Err(CliError::UserError(format!(
+                    "Alternate ID malformed: {}",
+                    alternate_id
+                ))),
+            }
+        })
+        .collect()
+}
+
+fn output_format(matches: &ArgMatches) -> Result<OutputFormat, CliError> {
+    matches
+        .value_of("format")
+        .map(str::parse)
+        .unwrap_or_else(|| Ok(OutputFormat::default()))
+}
+
 fn main() {
     if let Err(e) = run() {
         error!("{}", e);
@@ -48,6 +48,15 @@ pub const GRID_PRODUCT_NAMESPACE: &str = "621dee02";
 const GRID_PRODUCT_FAMILY_NAME: &str = "grid_product";
 const GRID_PRODUCT_FAMILY_VERSION: &str = "1.0";
 
+pub const TRACK_AND_TRACE_NAMESPACE: &str = "a43b46";
+const TRACK_AND_TRACE_FAMILY_NAME: &str = "grid_track_and_trace";
+const TRACK_AND_TRACE_FAMILY_VERSION: &str = "1.0";
+
+/// Namespace of the Sawtooth BlockInfo transaction family. Product and schema
+/// transactions read the timestamp of the latest block from it to check the
+/// expiry of delegated permissions.
+pub const BLOCK_INFO_NAMESPACE: &str = "00b10c";
+
 const SABRE_FAMILY_NAME: &str = "sabre";
 const SABRE_FAMILY_VERSION: &str = "0.5";
 const SABRE_NAMESPACE_REGISTRY_PREFIX: &str = "00ec00";
@@ -66,6 +75,14 @@ pub fn product_batch_builder(key: Option<String>) -> BatchBuilder {
     BatchBuilder::new(GRID_PRODUCT_FAMILY_NAME, GRID_PRODUCT_FAMILY_VERSION, key)
 }
 
+pub fn track_and_trace_batch_builder(key: Option<String>) -> BatchBuilder {
+    BatchBuilder::new(
+        TRACK_AND_TRACE_FAMILY_NAME,
+        TRACK_AND_TRACE_FAMILY_VERSION,
+        key,
+    )
+}
+
 #[derive(Clone)]
 pub struct BatchBuilder {
     family_name: String,
@@ -90,6 +107,91 @@ impl BatchBuilder {
         inputs: &[String],
         outputs: &[String],
     ) -> Result<Self, CliError> {
+        self.add_atomic_batch(&[payload], inputs, outputs)
+    }
+
+    /// Adds a single batch holding one transaction per payload, in order.
+    ///
+    /// The transactions of a batch are committed together or not at all, so
+    /// a later payload may depend on the state written by an earlier one.
+    /// Every transaction declares the same inputs and outputs.
+    pub fn add_atomic_batch<T: protobuf::Message>(
+        &mut self,
+        payloads: &[&T],
+        inputs: &[String],
+        outputs: &[String],
+    ) -> Result<Self, CliError> {
+        let signed_payloads = payloads
+            .iter()
+            .map(|payload| (*payload, self.key_name.clone()))
+            .collect::<Vec<_>>();
+        self.add_signed_atomic_batch(&signed_payloads, inputs, outputs)
+    }
+
+    /// Adds a single batch holding one transaction per payload, in order, as
+    /// add_atomic_batch does, but signs each transaction with the key named
+    /// alongside its payload. The batch is signed with the builder's key.
+    pub fn add_signed_atomic_batch<T: protobuf::Message>(
+        &mut self,
+        payloads: &[(&T, Option<String>)],
+        inputs: &[String],
+        outputs: &[String],
+    ) -> Result<Self, CliError> {
+        let context = signing::create_context("secp256k1")?;
+        let factory = signing::CryptoFactory::new(&*context);
+
+        let batcher_private_key = key::load_signing_key(self.key_name.clone())?;
+        let batcher_public_key = context.get_public_key(&batcher_private_key)?.as_hex();
+        let batcher = factory.new_signer(&batcher_private_key);
+
+        let transactions = payloads
+            .iter()
+            .map(|(payload, key_name)| {
+                let private_key = key::load_signing_key(key_name.clone())?;
+                let public_key = context.get_public_key(&private_key)?.as_hex();
+                let signer = factory.new_signer(&private_key);
+                self.create_transaction(
+                    *payload,
+                    inputs,
+                    outputs,
+                    &signer,
+                    &public_key,
+                    &batcher_public_key,
+                )
+            })
+            .collect::<Result<Vec<Transaction>, CliError>>()?;
+
+        let mut batch = Batch::new();
+        let mut batch_header = BatchHeader::new();
+
+        batch_header.set_transaction_ids(protobuf::RepeatedField::from_vec(
+            transactions
+                .iter()
+                .map(|txn| txn.header_signature.clone())
+                .collect(),
+        ));
+        batch_header.set_signer_public_key(batcher_public_key);
+        batch.set_transactions(protobuf::RepeatedField::from_vec(transactions));
+
+        let batch_header_bytes = batch_header.write_to_bytes()?;
+        batch.set_header(batch_header_bytes.clone());
+
+        batch.set_header_signature(batcher.sign(&batch_header_bytes)?);
+
+        self.batches.push(batch);
+
+        Ok(self.clone())
+    }
+
+    fn create_transaction<T: protobuf::Message>(
+        &self,
+        payload: &T,
+        inputs: &[String],
+        outputs: &[String],
+        signer: &signing::Signer,
+        public_key: &str,
+        batcher_public_key: &str,
+    ) -> Result<Transaction, CliError> {
         // create execute contract action for sabre payload
         let sabre_payload = ExecuteContractActionBuilder::new()
             .with_name(self.family_name.to_string())
@@ -142,20 +244,14 @@ impl BatchBuilder {
         }
         output_addresses.append(&mut outputs.to_vec());
 
-        let private_key = key::load_signing_key(self.key_name.clone())?;
-        let context = signing::create_context("secp256k1")?;
-        let public_key = context.get_public_key(&private_key)?.as_hex();
-        let factory = signing::CryptoFactory::new(&*context);
-        let signer = factory.new_signer(&private_key);
-
         let mut txn = Transaction::new();
         let mut txn_header = TransactionHeader::new();
 
         txn_header.set_family_name(SABRE_FAMILY_NAME.into());
         txn_header.set_family_version(SABRE_FAMILY_VERSION.into());
         txn_header.set_nonce(create_nonce());
-        txn_header.set_signer_public_key(public_key.clone());
-        txn_header.set_batcher_public_key(public_key.clone());
+        txn_header.set_signer_public_key(public_key.to_string());
+        txn_header.set_batcher_public_key(batcher_public_key.to_string());
 
         txn_header.set_inputs(protobuf::RepeatedField::from_vec(input_addresses));
         txn_header.set_outputs(protobuf::RepeatedField::from_vec(output_addresses));
@@ -174,23 +270,7 @@ impl BatchBuilder {
         let b: &[u8] = &txn_header_bytes;
         txn.set_header_signature(signer.sign(b)?);
 
-        let mut batch = Batch::new();
-        let mut batch_header = BatchHeader::new();
-
-        batch_header.set_transaction_ids(protobuf::RepeatedField::from_vec(vec![txn
-            .header_signature
-            .clone()]));
-        batch_header.set_signer_public_key(public_key);
-        batch.set_transactions(protobuf::RepeatedField::from_vec(vec![txn]));
-
-        let batch_header_bytes = batch_header.write_to_bytes()?;
-        batch.set_header(batch_header_bytes.clone());
-
-        batch.set_header_signature(signer.sign(&batch_header_bytes)?);
-
-        self.batches.push(batch);
-
-        Ok(self.clone())
+        Ok(txn)
     }
 
     pub fn create_batch_list(&mut self) -> BatchList {
@@ -13,9 +13,11 @@
 // limitations under the License.
 
 use crate::error::CliError;
+use grid_sdk::protocol::pike::state::{KeyValueEntry, KeyValueEntryBuilder};
 use grid_sdk::protocol::product::state::ProductType;
 use grid_sdk::protocol::schema::state::{DataType, PropertyValue, PropertyValueBuilder};
 use grid_sdk::protocol::schema::state::{LatLong, LatLongBuilder};
+use grid_sdk::protocol::track_and_trace::state::Role;
 use serde_yaml::{Mapping, Sequence, Value};
 
 /**
@@ -259,6 +261,44 @@ pub fn parse_value_as_vec_string(
     }
 }
 
+/**
+ * Given a yaml object, parse it as a list of Pike key-value entries
+ *
+ * property - Yaml object we wish to parse in as a mapping of string keys to string values
+ */
+pub fn parse_value_as_key_value_entries(
+    property: &Mapping,
+    key: &str,
+) -> Result<Option<Vec<KeyValueEntry>>, CliError> {
+    match property.get(&Value::String(key.to_string())) {
+        Some(value) => match value.as_mapping() {
+            Some(mapping) => Ok(Some(
+                mapping
+                    .iter()
+                    .map(|(entry_key, entry_value)| {
+                        match (entry_key.as_str(), entry_value.as_str()) {
+                            (Some(entry_key), Some(entry_value)) => KeyValueEntryBuilder::new()
+                                .with_key(entry_key.to_string())
+                                .with_value(entry_value.to_string())
+                                .build()
+                                .map_err(|err| CliError::InvalidYamlError(format!("{}", err))),
+                            _ => Err(CliError::InvalidYamlError(format!(
+                                "Keys and values in {} must be yaml strings.",
+                                key
+                            ))),
+                        }
+                    })
+                    .collect::<Result<Vec<KeyValueEntry>, CliError>>()?,
+            )),
+            None => Err(CliError::InvalidYamlError(format!(
+                "Value of {} has an invalid format. Expected is a yaml mapping.",
+                key
+            ))),
+        },
+        None => Ok(None),
+    }
+}
+
 /**
  * Given a yaml object, parse it as a Product ProductType
  *
@@ -267,6 +307,8 @@ pub fn parse_value_as_vec_string(
 pub fn parse_value_as_product_type(product_type: &str) -> Result<ProductType, CliError> {
     match product_type.to_uppercase().as_ref() {
         "GS1" => Ok(ProductType::GS1),
+        "GLN" => Ok(ProductType::GLN),
+        "SSCC" => Ok(ProductType::SSCC),
         _ => Err(CliError::InvalidYamlError(format!(
             "Invalid product_type for value: {}",
             product_type
@@ -274,6 +316,23 @@ pub fn parse_value_as_product_type(product_type: &str) -> Result<ProductType, Cl
     }
 }
 
+/**
+ * Given a string, parse it as a Track and Trace proposal Role
+ *
+ * role - String we wish to parse in as a Track and Trace Role
+ */
+pub fn parse_value_as_role(role: &str) -> Result<Role, CliError> {
+    match role.to_lowercase().as_ref() {
+        "owner" => Ok(Role::Owner),
+        "custodian" => Ok(Role::Custodian),
+        "reporter" => Ok(Role::Reporter),
+        _ => Err(CliError::UserError(format!(
+            "Invalid role for proposal: {}",
+            role
+        ))),
+    }
+}
+
 /**
  * Given a yaml key/val, parse the val as a list of Property Value objects
  *
@@ -660,6 +719,29 @@ mod test {
             parse_value_as_product_type("GS1").unwrap(),
             ProductType::GS1
         );
+        assert_eq!(
+            parse_value_as_product_type("gln").unwrap(),
+            ProductType::GLN
+        );
+        assert_eq!(
+            parse_value_as_product_type("SSCC").unwrap(),
+            ProductType::SSCC
+        );
+        assert!(parse_value_as_product_type("GTIN").is_err());
+    }
+
+    /*
+     * Verifies parse_value_as_role can parse Values as Role for valid inputs
+     * and returns an error for invalid inputs
+     */
+    #[test]
+    fn test_parse_value_as_role() {
+        assert_eq!(parse_value_as_role("owner").unwrap(), Role::Owner);
+        assert_eq!(parse_value_as_role("Custodian").unwrap(), Role::Custodian);
+        assert_eq!(parse_value_as_role("REPORTER").unwrap(), Role::Reporter);
+
+        // Check the method returns an error for an invalid input
+        assert!(parse_value_as_role("not_a_valid_role").is_err());
     }
 
     ///
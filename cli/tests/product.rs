@@ -133,7 +133,7 @@ mod integration {
             .arg(&ORG_ID)
             .arg(&ORG_NAME)
             .arg(&ORG_ADDRESS)
-            .args(&["--metadata", "gs1_company_prefixes=314"]);
+            .args(&["--gs1-company-prefixes", "0762111,0881334"]);
         cmd_org_create.assert().success();
 
         //run `grid agent create`
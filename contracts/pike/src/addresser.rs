@@ -17,6 +17,9 @@
 pub enum Resource {
     AGENT,
     ORG,
+    ROLE,
+    DELEGATION,
+    ALTERNATE_ID_INDEX,
 }
 
 /// Convert resource part to byte value in hex
@@ -24,6 +27,9 @@ pub fn resource_to_byte(part: Resource) -> String {
     match part {
         Resource::AGENT => String::from("00"),
         Resource::ORG => String::from("01"),
+        Resource::ROLE => String::from("02"),
+        Resource::DELEGATION => String::from("03"),
+        Resource::ALTERNATE_ID_INDEX => String::from("04"),
     }
 }
 
@@ -32,6 +38,9 @@ pub fn byte_to_resource(bytes: &str) -> Result<Resource, ResourceError> {
     match bytes {
         "00" => Ok(Resource::AGENT),
         "01" => Ok(Resource::ORG),
+        "02" => Ok(Resource::ROLE),
+        "03" => Ok(Resource::DELEGATION),
+        "04" => Ok(Resource::ALTERNATE_ID_INDEX),
         _ => Err(ResourceError::UnknownResource(format!(
             "No resource found matching byte pattern {}",
             bytes
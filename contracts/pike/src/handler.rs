@@ -32,11 +32,24 @@ cfg_if! {
 }
 
 use addresser::{resource_to_byte, Resource};
+use grid_sdk::protocol::pike::addressing::{
+    compute_alternate_id_index_address, compute_delegation_address, compute_role_address,
+};
+use grid_sdk::protocol::pike::state::{
+    AlternateId as NativeAlternateId, Location as NativeLocation,
+};
+use grid_sdk::protocol::pike::validation::{validate_alternate_ids, validate_locations};
 use grid_sdk::protos::pike_payload::{
-    CreateAgentAction, CreateOrganizationAction, PikePayload, PikePayload_Action as Action,
-    UpdateAgentAction, UpdateOrganizationAction,
+    CreateAgentAction, CreateDelegationAction, CreateOrganizationAction, CreateRoleAction,
+    DeleteAgentAction, DeleteDelegationAction, DeleteOrganizationAction, DeleteRoleAction,
+    PikePayload, PikePayload_Action as Action, RotateAgentKeyAction, UpdateAgentAction,
+    UpdateOrganizationAction, UpdateRoleAction,
 };
-use grid_sdk::protos::pike_state::{Agent, AgentList, Organization, OrganizationList};
+use grid_sdk::protos::pike_state::{
+    Agent, AgentList, AlternateId, AlternateIdIndexEntry, AlternateIdIndexEntryList, Delegation,
+    DelegationList, Location, Organization, OrganizationList, Role, RoleList,
+};
+use grid_sdk::protos::FromProto;
 
 pub struct PikeTransactionHandler {
     family_name: String,
@@ -132,6 +145,48 @@ impl<'a> PikeState<'a> {
         Ok(())
     }
 
+    pub fn remove_agent(&mut self, public_key: &str) -> Result<(), ApplyError> {
+        let address = compute_address(public_key, Resource::AGENT);
+        let d = self.context.get_state_entry(&address)?;
+        let mut agent_list: AgentList = match d {
+            Some(packed) => match protobuf::parse_from_bytes(packed.as_slice()) {
+                Ok(agents) => agents,
+                Err(err) => {
+                    return Err(ApplyError::InternalError(format!(
+                        "Cannot deserialize agent list: {}",
+                        err,
+                    )))
+                }
+            },
+            None => return Ok(()),
+        };
+
+        agent_list
+            .agents
+            .retain(|agent| agent.public_key != public_key);
+
+        // drop the entry entirely unless another agent shares the address
+        if agent_list.agents.is_empty() {
+            self.context
+                .delete_state_entries(&[address])
+                .map_err(|err| ApplyError::InternalError(format!("{}", err)))?;
+            return Ok(());
+        }
+
+        let serialized = match protobuf::Message::write_to_bytes(&agent_list) {
+            Ok(serialized) => serialized,
+            Err(_) => {
+                return Err(ApplyError::InternalError(String::from(
+                    "Cannot serialize agent list",
+                )))
+            }
+        };
+        self.context
+            .set_state_entry(address, serialized)
+            .map_err(|err| ApplyError::InternalError(format!("{}", err)))?;
+        Ok(())
+    }
+
     pub fn get_organization(&mut self, id: &str) -> Result<Option<Organization>, ApplyError> {
         let address = compute_address(id, Resource::ORG);
         let d = self.context.get_state_entry(&address)?;
@@ -208,117 +263,525 @@ impl<'a> PikeState<'a> {
             .map_err(|err| ApplyError::InternalError(format!("{}", err)))?;
         Ok(())
     }
-}
 
-impl PikeTransactionHandler {
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> PikeTransactionHandler {
-        PikeTransactionHandler {
-            family_name: "pike".to_string(),
-            family_versions: vec!["0.1".to_string()],
-            namespaces: vec![NAMESPACE.to_string()],
+    pub fn remove_organization(&mut self, id: &str) -> Result<(), ApplyError> {
+        let address = compute_address(id, Resource::ORG);
+        let d = self.context.get_state_entry(&address)?;
+        let mut organization_list: OrganizationList = match d {
+            Some(packed) => match protobuf::parse_from_bytes(packed.as_slice()) {
+                Ok(orgs) => orgs,
+                Err(err) => {
+                    return Err(ApplyError::InternalError(format!(
+                        "Cannot deserialize organization list: {}",
+                        err,
+                    )))
+                }
+            },
+            None => return Ok(()),
+        };
+
+        organization_list
+            .organizations
+            .retain(|organization| organization.org_id != id);
+
+        if organization_list.organizations.is_empty() {
+            self.context
+                .delete_state_entries(&[address])
+                .map_err(|err| ApplyError::InternalError(format!("{}", err)))?;
+            return Ok(());
         }
-    }
-}
 
-impl TransactionHandler for PikeTransactionHandler {
-    fn family_name(&self) -> String {
-        self.family_name.clone()
+        let serialized = match protobuf::Message::write_to_bytes(&organization_list) {
+            Ok(serialized) => serialized,
+            Err(_) => {
+                return Err(ApplyError::InternalError(String::from(
+                    "Cannot serialize organization list",
+                )))
+            }
+        };
+        self.context
+            .set_state_entry(address, serialized)
+            .map_err(|err| ApplyError::InternalError(format!("{}", err)))?;
+        Ok(())
     }
 
-    fn family_versions(&self) -> Vec<String> {
-        self.family_versions.clone()
+    pub fn get_role(&mut self, org_id: &str, name: &str) -> Result<Option<Role>, ApplyError> {
+        let address = compute_role_address(org_id, name);
+        let d = self.context.get_state_entry(&address)?;
+        match d {
+            Some(packed) => {
+                let roles: RoleList = match protobuf::parse_from_bytes(packed.as_slice()) {
+                    Ok(roles) => roles,
+                    Err(err) => {
+                        return Err(ApplyError::InternalError(format!(
+                            "Cannot deserialize role list: {:?}",
+                            err,
+                        )))
+                    }
+                };
+
+                for role in roles.get_roles() {
+                    if role.org_id == org_id && role.name == name {
+                        return Ok(Some(role.clone()));
+                    }
+                }
+                Ok(None)
+            }
+            None => Ok(None),
+        }
     }
 
-    fn namespaces(&self) -> Vec<String> {
-        self.namespaces.clone()
+    pub fn set_role(&mut self, new_role: Role) -> Result<(), ApplyError> {
+        let address = compute_role_address(new_role.get_org_id(), new_role.get_name());
+        let d = self.context.get_state_entry(&address)?;
+        let mut role_list = match d {
+            Some(packed) => match protobuf::parse_from_bytes(packed.as_slice()) {
+                Ok(roles) => roles,
+                Err(err) => {
+                    return Err(ApplyError::InternalError(format!(
+                        "Cannot deserialize role list: {}",
+                        err,
+                    )))
+                }
+            },
+            None => RoleList::new(),
+        };
+
+        // replace the old role if it exists and keep the roles sorted
+        role_list
+            .roles
+            .retain(|role| role.org_id != new_role.org_id || role.name != new_role.name);
+        role_list.roles.push(new_role);
+        role_list
+            .roles
+            .sort_by_key(|r| (r.org_id.clone(), r.name.clone()));
+        let serialized = match protobuf::Message::write_to_bytes(&role_list) {
+            Ok(serialized) => serialized,
+            Err(_) => {
+                return Err(ApplyError::InternalError(String::from(
+                    "Cannot serialize role list",
+                )))
+            }
+        };
+        self.context
+            .set_state_entry(address, serialized)
+            .map_err(|err| ApplyError::InternalError(format!("{}", err)))?;
+        Ok(())
     }
 
-    fn apply(
-        &self,
-        request: &TpProcessRequest,
-        context: &mut dyn TransactionContext,
-    ) -> Result<(), ApplyError> {
-        let payload = protobuf::parse_from_bytes::<PikePayload>(request.get_payload())
-            .map_err(|_| ApplyError::InternalError("Failed to parse payload".into()))?;
+    pub fn remove_role(&mut self, org_id: &str, name: &str) -> Result<(), ApplyError> {
+        let address = compute_role_address(org_id, name);
+        let d = self.context.get_state_entry(&address)?;
+        let mut role_list: RoleList = match d {
+            Some(packed) => match protobuf::parse_from_bytes(packed.as_slice()) {
+                Ok(roles) => roles,
+                Err(err) => {
+                    return Err(ApplyError::InternalError(format!(
+                        "Cannot deserialize role list: {}",
+                        err,
+                    )))
+                }
+            },
+            None => return Ok(()),
+        };
 
-        let signer = request.get_header().get_signer_public_key();
-        let mut state = PikeState::new(context);
+        role_list
+            .roles
+            .retain(|role| role.org_id != org_id || role.name != name);
 
-        info!("Pike Payload {:?}", payload.get_action(),);
+        if role_list.roles.is_empty() {
+            self.context
+                .delete_state_entries(&[address])
+                .map_err(|err| ApplyError::InternalError(format!("{}", err)))?;
+            return Ok(());
+        }
 
-        match payload.action {
-            Action::CREATE_AGENT => create_agent(payload.get_create_agent(), signer, &mut state),
-            Action::UPDATE_AGENT => update_agent(payload.get_update_agent(), signer, &mut state),
-            Action::CREATE_ORGANIZATION => {
-                create_org(payload.get_create_organization(), signer, &mut state)
+        let serialized = match protobuf::Message::write_to_bytes(&role_list) {
+            Ok(serialized) => serialized,
+            Err(_) => {
+                return Err(ApplyError::InternalError(String::from(
+                    "Cannot serialize role list",
+                )))
             }
-            Action::UPDATE_ORGANIZATION => {
-                update_org(payload.get_update_organization(), signer, &mut state)
+        };
+        self.context
+            .set_state_entry(address, serialized)
+            .map_err(|err| ApplyError::InternalError(format!("{}", err)))?;
+        Ok(())
+    }
+
+    pub fn get_delegation(
+        &mut self,
+        org_id: &str,
+        delegate_org_id: &str,
+    ) -> Result<Option<Delegation>, ApplyError> {
+        let address = compute_delegation_address(org_id, delegate_org_id);
+        let d = self.context.get_state_entry(&address)?;
+        match d {
+            Some(packed) => {
+                let delegations: DelegationList =
+                    match protobuf::parse_from_bytes(packed.as_slice()) {
+                        Ok(delegations) => delegations,
+                        Err(err) => {
+                            return Err(ApplyError::InternalError(format!(
+                                "Cannot deserialize delegation list: {:?}",
+                                err,
+                            )))
+                        }
+                    };
+
+                for delegation in delegations.get_delegations() {
+                    if delegation.org_id == org_id && delegation.delegate_org_id == delegate_org_id
+                    {
+                        return Ok(Some(delegation.clone()));
+                    }
+                }
+                Ok(None)
             }
-            _ => Err(ApplyError::InvalidTransaction("Invalid action".into())),
+            None => Ok(None),
         }
     }
-}
 
-fn create_agent(
-    payload: &CreateAgentAction,
-    signer: &str,
-    state: &mut PikeState,
-) -> Result<(), ApplyError> {
-    if payload.get_public_key().is_empty() {
-        return Err(ApplyError::InvalidTransaction("Public key required".into()));
-    }
+    pub fn set_delegation(&mut self, new_delegation: Delegation) -> Result<(), ApplyError> {
+        let address = compute_delegation_address(
+            new_delegation.get_org_id(),
+            new_delegation.get_delegate_org_id(),
+        );
+        let d = self.context.get_state_entry(&address)?;
+        let mut delegation_list = match d {
+            Some(packed) => match protobuf::parse_from_bytes(packed.as_slice()) {
+                Ok(delegations) => delegations,
+                Err(err) => {
+                    return Err(ApplyError::InternalError(format!(
+                        "Cannot deserialize delegation list: {}",
+                        err,
+                    )))
+                }
+            },
+            None => DelegationList::new(),
+        };
 
-    if payload.get_org_id().is_empty() {
-        return Err(ApplyError::InvalidTransaction(
-            "Organization ID required".into(),
-        ));
+        // replace the old delegation if it exists and keep the delegations sorted
+        delegation_list.delegations.retain(|delegation| {
+            delegation.org_id != new_delegation.org_id
+                || delegation.delegate_org_id != new_delegation.delegate_org_id
+        });
+        delegation_list.delegations.push(new_delegation);
+        delegation_list
+            .delegations
+            .sort_by_key(|d| (d.org_id.clone(), d.delegate_org_id.clone()));
+        let serialized = match protobuf::Message::write_to_bytes(&delegation_list) {
+            Ok(serialized) => serialized,
+            Err(_) => {
+                return Err(ApplyError::InternalError(String::from(
+                    "Cannot serialize delegation list",
+                )))
+            }
+        };
+        self.context
+            .set_state_entry(address, serialized)
+            .map_err(|err| ApplyError::InternalError(format!("{}", err)))?;
+        Ok(())
     }
 
-    // verify the signer of the transaction is authorized to create agent
-    is_admin(signer, payload.get_org_id(), state)?;
-
-    // Check if agent already exists
-    match state.get_agent(payload.get_public_key()) {
-        Ok(None) => (),
-        Ok(Some(_)) => {
-            return Err(ApplyError::InvalidTransaction(format!(
-                "Agent already exists: {}",
-                payload.get_public_key(),
-            )))
-        }
-        Err(err) => {
-            return Err(ApplyError::InvalidTransaction(format!(
-                "Failed to retrieve state: {}",
-                err,
-            )))
-        }
-    };
+    pub fn remove_delegation(
+        &mut self,
+        org_id: &str,
+        delegate_org_id: &str,
+    ) -> Result<(), ApplyError> {
+        let address = compute_delegation_address(org_id, delegate_org_id);
+        let d = self.context.get_state_entry(&address)?;
+        let mut delegation_list: DelegationList = match d {
+            Some(packed) => match protobuf::parse_from_bytes(packed.as_slice()) {
+                Ok(delegations) => delegations,
+                Err(err) => {
+                    return Err(ApplyError::InternalError(format!(
+                        "Cannot deserialize delegation list: {}",
+                        err,
+                    )))
+                }
+            },
+            None => return Ok(()),
+        };
 
-    let mut agent = Agent::new();
-    agent.set_public_key(payload.get_public_key().to_string());
-    agent.set_org_id(payload.get_org_id().to_string());
-    agent.set_active(payload.get_active());
-    agent.set_roles(protobuf::RepeatedField::from_vec(
-        payload.get_roles().to_vec(),
-    ));
-    agent.set_metadata(protobuf::RepeatedField::from_vec(
-        payload.get_metadata().to_vec(),
-    ));
+        delegation_list.delegations.retain(|delegation| {
+            delegation.org_id != org_id || delegation.delegate_org_id != delegate_org_id
+        });
 
-    state
-        .set_agent(payload.get_public_key(), agent)
-        .map_err(|e| ApplyError::InternalError(format!("Failed to create agent: {:?}", e)))
-}
+        if delegation_list.delegations.is_empty() {
+            self.context
+                .delete_state_entries(&[address])
+                .map_err(|err| ApplyError::InternalError(format!("{}", err)))?;
+            return Ok(());
+        }
 
-fn update_agent(
-    payload: &UpdateAgentAction,
-    signer: &str,
-    state: &mut PikeState,
-) -> Result<(), ApplyError> {
-    if payload.get_public_key().is_empty() {
-        return Err(ApplyError::InvalidTransaction("Public key required".into()));
+        let serialized = match protobuf::Message::write_to_bytes(&delegation_list) {
+            Ok(serialized) => serialized,
+            Err(_) => {
+                return Err(ApplyError::InternalError(String::from(
+                    "Cannot serialize delegation list",
+                )))
+            }
+        };
+        self.context
+            .set_state_entry(address, serialized)
+            .map_err(|err| ApplyError::InternalError(format!("{}", err)))?;
+        Ok(())
+    }
+
+    /// Returns the ID of the organization holding the given alternate ID, if any
+    pub fn get_alternate_id_holder(
+        &mut self,
+        id_type: &str,
+        id: &str,
+    ) -> Result<Option<String>, ApplyError> {
+        let address = compute_alternate_id_index_address(id_type, id);
+        let d = self.context.get_state_entry(&address)?;
+        match d {
+            Some(packed) => {
+                let entries: AlternateIdIndexEntryList =
+                    match protobuf::parse_from_bytes(packed.as_slice()) {
+                        Ok(entries) => entries,
+                        Err(err) => {
+                            return Err(ApplyError::InternalError(format!(
+                                "Cannot deserialize alternate ID index entry list: {:?}",
+                                err,
+                            )))
+                        }
+                    };
+
+                for entry in entries.get_entries() {
+                    if entry.id_type == id_type && entry.id == id {
+                        return Ok(Some(entry.org_id.clone()));
+                    }
+                }
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn set_alternate_id_holder(
+        &mut self,
+        id_type: &str,
+        id: &str,
+        org_id: &str,
+    ) -> Result<(), ApplyError> {
+        let address = compute_alternate_id_index_address(id_type, id);
+        let d = self.context.get_state_entry(&address)?;
+        let mut entry_list = match d {
+            Some(packed) => match protobuf::parse_from_bytes(packed.as_slice()) {
+                Ok(entries) => entries,
+                Err(err) => {
+                    return Err(ApplyError::InternalError(format!(
+                        "Cannot deserialize alternate ID index entry list: {}",
+                        err,
+                    )))
+                }
+            },
+            None => AlternateIdIndexEntryList::new(),
+        };
+
+        let mut new_entry = AlternateIdIndexEntry::new();
+        new_entry.set_id_type(id_type.to_string());
+        new_entry.set_id(id.to_string());
+        new_entry.set_org_id(org_id.to_string());
+
+        // replace the old entry if it exists and keep the entries sorted
+        entry_list
+            .entries
+            .retain(|entry| entry.id_type != id_type || entry.id != id);
+        entry_list.entries.push(new_entry);
+        entry_list
+            .entries
+            .sort_by_key(|e| (e.id_type.clone(), e.id.clone()));
+        let serialized = match protobuf::Message::write_to_bytes(&entry_list) {
+            Ok(serialized) => serialized,
+            Err(_) => {
+                return Err(ApplyError::InternalError(String::from(
+                    "Cannot serialize alternate ID index entry list",
+                )))
+            }
+        };
+        self.context
+            .set_state_entry(address, serialized)
+            .map_err(|err| ApplyError::InternalError(format!("{}", err)))?;
+        Ok(())
+    }
+
+    pub fn remove_alternate_id_holder(
+        &mut self,
+        id_type: &str,
+        id: &str,
+    ) -> Result<(), ApplyError> {
+        let address = compute_alternate_id_index_address(id_type, id);
+        let d = self.context.get_state_entry(&address)?;
+        let mut entry_list: AlternateIdIndexEntryList = match d {
+            Some(packed) => match protobuf::parse_from_bytes(packed.as_slice()) {
+                Ok(entries) => entries,
+                Err(err) => {
+                    return Err(ApplyError::InternalError(format!(
+                        "Cannot deserialize alternate ID index entry list: {}",
+                        err,
+                    )))
+                }
+            },
+            None => return Ok(()),
+        };
+
+        entry_list
+            .entries
+            .retain(|entry| entry.id_type != id_type || entry.id != id);
+
+        if entry_list.entries.is_empty() {
+            self.context
+                .delete_state_entries(&[address])
+                .map_err(|err| ApplyError::InternalError(format!("{}", err)))?;
+            return Ok(());
+        }
+
+        let serialized = match protobuf::Message::write_to_bytes(&entry_list) {
+            Ok(serialized) => serialized,
+            Err(_) => {
+                return Err(ApplyError::InternalError(String::from(
+                    "Cannot serialize alternate ID index entry list",
+                )))
+            }
+        };
+        self.context
+            .set_state_entry(address, serialized)
+            .map_err(|err| ApplyError::InternalError(format!("{}", err)))?;
+        Ok(())
+    }
+}
+
+impl PikeTransactionHandler {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> PikeTransactionHandler {
+        PikeTransactionHandler {
+            family_name: "pike".to_string(),
+            family_versions: vec!["0.1".to_string()],
+            namespaces: vec![NAMESPACE.to_string()],
+        }
+    }
+}
+
+impl TransactionHandler for PikeTransactionHandler {
+    fn family_name(&self) -> String {
+        self.family_name.clone()
+    }
+
+    fn family_versions(&self) -> Vec<String> {
+        self.family_versions.clone()
+    }
+
+    fn namespaces(&self) -> Vec<String> {
+        self.namespaces.clone()
+    }
+
+    fn apply(
+        &self,
+        request: &TpProcessRequest,
+        context: &mut dyn TransactionContext,
+    ) -> Result<(), ApplyError> {
+        let payload = protobuf::parse_from_bytes::<PikePayload>(request.get_payload())
+            .map_err(|_| ApplyError::InternalError("Failed to parse payload".into()))?;
+
+        let signer = request.get_header().get_signer_public_key();
+        let mut state = PikeState::new(context);
+
+        info!("Pike Payload {:?}", payload.get_action(),);
+
+        match payload.action {
+            Action::CREATE_AGENT => create_agent(payload.get_create_agent(), signer, &mut state),
+            Action::UPDATE_AGENT => update_agent(payload.get_update_agent(), signer, &mut state),
+            Action::CREATE_ORGANIZATION => {
+                create_org(payload.get_create_organization(), signer, &mut state)
+            }
+            Action::UPDATE_ORGANIZATION => {
+                update_org(payload.get_update_organization(), signer, &mut state)
+            }
+            Action::DELETE_AGENT => delete_agent(payload.get_delete_agent(), signer, &mut state),
+            Action::DELETE_ORGANIZATION => {
+                delete_org(payload.get_delete_organization(), signer, &mut state)
+            }
+            Action::CREATE_ROLE => create_role(payload.get_create_role(), signer, &mut state),
+            Action::UPDATE_ROLE => update_role(payload.get_update_role(), signer, &mut state),
+            Action::DELETE_ROLE => delete_role(payload.get_delete_role(), signer, &mut state),
+            Action::CREATE_DELEGATION => {
+                create_delegation(payload.get_create_delegation(), signer, &mut state)
+            }
+            Action::DELETE_DELEGATION => {
+                delete_delegation(payload.get_delete_delegation(), signer, &mut state)
+            }
+            Action::ROTATE_AGENT_KEY => {
+                rotate_agent_key(payload.get_rotate_agent_key(), signer, &mut state)
+            }
+            _ => Err(ApplyError::InvalidTransaction("Invalid action".into())),
+        }
+    }
+}
+
+fn create_agent(
+    payload: &CreateAgentAction,
+    signer: &str,
+    state: &mut PikeState,
+) -> Result<(), ApplyError> {
+    if payload.get_public_key().is_empty() {
+        return Err(ApplyError::InvalidTransaction("Public key required".into()));
+    }
+
+    if payload.get_org_id().is_empty() {
+        return Err(ApplyError::InvalidTransaction(
+            "Organization ID required".into(),
+        ));
+    }
+
+    // verify the signer of the transaction is authorized to create agent
+    is_admin(signer, payload.get_org_id(), state)?;
+
+    // Check if agent already exists
+    match state.get_agent(payload.get_public_key()) {
+        Ok(None) => (),
+        Ok(Some(_)) => {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent already exists: {}",
+                payload.get_public_key(),
+            )))
+        }
+        Err(err) => {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Failed to retrieve state: {}",
+                err,
+            )))
+        }
+    };
+
+    let mut agent = Agent::new();
+    agent.set_public_key(payload.get_public_key().to_string());
+    agent.set_org_id(payload.get_org_id().to_string());
+    agent.set_active(payload.get_active());
+    agent.set_roles(protobuf::RepeatedField::from_vec(
+        payload.get_roles().to_vec(),
+    ));
+    agent.set_metadata(protobuf::RepeatedField::from_vec(
+        payload.get_metadata().to_vec(),
+    ));
+
+    state
+        .set_agent(payload.get_public_key(), agent)
+        .map_err(|e| ApplyError::InternalError(format!("Failed to create agent: {:?}", e)))?;
+
+    add_org_agent(payload.get_org_id(), payload.get_public_key(), state)
+}
+
+fn update_agent(
+    payload: &UpdateAgentAction,
+    signer: &str,
+    state: &mut PikeState,
+) -> Result<(), ApplyError> {
+    if payload.get_public_key().is_empty() {
+        return Err(ApplyError::InvalidTransaction("Public key required".into()));
     }
 
     if payload.get_org_id().is_empty() {
@@ -416,6 +879,11 @@ fn create_org(
             )))
         }
     };
+
+    check_alternate_ids(payload.get_alternate_ids())?;
+    check_locations(payload.get_locations())?;
+    claim_alternate_ids(payload.get_id(), payload.get_alternate_ids(), state)?;
+
     let mut organization = Organization::new();
     organization.set_org_id(payload.get_id().to_string());
     organization.set_name(payload.get_name().to_string());
@@ -423,6 +891,14 @@ fn create_org(
     organization.set_metadata(protobuf::RepeatedField::from_vec(
         payload.get_metadata().to_vec(),
     ));
+    organization.set_alternate_ids(protobuf::RepeatedField::from_vec(
+        payload.get_alternate_ids().to_vec(),
+    ));
+    organization.set_locations(protobuf::RepeatedField::from_vec(
+        payload.get_locations().to_vec(),
+    ));
+    organization.set_agents(protobuf::RepeatedField::from_vec(vec![signer.to_string()]));
+    organization.set_agents_tracked(true);
     state.set_organization(payload.get_id(), organization)?;
 
     state.get_agent(signer).map_err(|e| {
@@ -500,66 +976,1338 @@ fn update_org(
             payload.get_metadata().to_vec(),
         ));
     }
-    state.set_organization(payload.get_id(), organization)
-}
+    if payload.get_clear_alternate_ids() {
+        if !payload.get_alternate_ids().is_empty() {
+            return Err(ApplyError::InvalidTransaction(
+                "Alternate IDs cannot be both cleared and set".into(),
+            ));
+        }
 
-pub fn is_admin(signer: &str, org_id: &str, state: &mut PikeState) -> Result<(), ApplyError> {
-    let admin = match state.get_agent(signer) {
-        Ok(None) => {
-            return Err(ApplyError::InvalidTransaction(format!(
-                "Signer is not an agent: {}",
-                signer,
-            )))
+        for old_id in organization.get_alternate_ids() {
+            state.remove_alternate_id_holder(old_id.get_id_type(), old_id.get_id())?;
         }
-        Ok(Some(admin)) => admin,
-        Err(err) => {
-            return Err(ApplyError::InvalidTransaction(format!(
-                "Failed to retrieve state: {}",
-                err,
-            )))
+        organization.clear_alternate_ids();
+    } else if !payload.get_alternate_ids().is_empty() {
+        check_alternate_ids(payload.get_alternate_ids())?;
+
+        // release the IDs the organization no longer lists before claiming the new ones
+        for old_id in organization.get_alternate_ids() {
+            if !payload.get_alternate_ids().contains(old_id) {
+                state.remove_alternate_id_holder(old_id.get_id_type(), old_id.get_id())?;
+            }
         }
-    };
+        claim_alternate_ids(payload.get_id(), payload.get_alternate_ids(), state)?;
 
-    if admin.get_org_id() != org_id {
-        return Err(ApplyError::InvalidTransaction(format!(
-            "Signer is not associated with the organization: {}",
-            signer,
-        )));
+        organization.set_alternate_ids(protobuf::RepeatedField::from_vec(
+            payload.get_alternate_ids().to_vec(),
+        ));
     }
-    if !admin.roles.contains(&"admin".to_string()) {
+    if !payload.get_locations().is_empty() {
+        check_locations(payload.get_locations())?;
+        organization.set_locations(protobuf::RepeatedField::from_vec(
+            payload.get_locations().to_vec(),
+        ));
+    }
+    if !payload.get_agents().is_empty() {
+        check_backfilled_agents(&organization, payload.get_agents(), signer, state)?;
+        organization.set_agents(protobuf::RepeatedField::from_vec(
+            payload.get_agents().to_vec(),
+        ));
+        organization.set_agents_tracked(true);
+    }
+    state.set_organization(payload.get_id(), organization)
+}
+
+/// Checks a list of agents given to start tracking the agents of an organization created before
+/// they were tracked. The list cannot be checked for completeness, so it is taken on the word of
+/// the organization's admin, but every key in it must be an agent of the organization.
+fn check_backfilled_agents(
+    organization: &Organization,
+    agents: &[String],
+    signer: &str,
+    state: &mut PikeState,
+) -> Result<(), ApplyError> {
+    let org_id = organization.get_org_id();
+    if organization.get_agents_tracked() {
         return Err(ApplyError::InvalidTransaction(format!(
-            "Signer is not an admin: {}",
-            signer,
+            "Agents of the organization are already tracked: {}",
+            org_id,
         )));
-    };
+    }
 
-    if !admin.active {
+    if !agents.iter().any(|public_key| public_key == signer) {
         return Err(ApplyError::InvalidTransaction(format!(
-            "Admin is not currently an active agent: {}",
+            "Agents of the organization must include the signer: {}",
             signer,
         )));
     }
-    Ok(())
-}
 
-#[cfg(target_arch = "wasm32")]
-// Sabre apply must return a bool
-fn apply(
-    request: &TpProcessRequest,
-    context: &mut dyn TransactionContext,
-) -> Result<bool, ApplyError> {
-    let handler = PikeTransactionHandler::new();
-    match handler.apply(request, context) {
-        Ok(_) => Ok(true),
-        Err(err) => {
-            info!("{} received {}", handler.family_name(), err);
-            Err(err)
+    for (i, public_key) in agents.iter().enumerate() {
+        if agents[..i].contains(public_key) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Duplicate agent: {}",
+                public_key,
+            )));
+        }
+
+        match state.get_agent(public_key)? {
+            Some(ref agent) if agent.get_org_id() == org_id => (),
+            _ => {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Agent is not associated with the organization: {}",
+                    public_key,
+                )))
+            }
         }
     }
+
+    Ok(())
 }
 
-#[cfg(target_arch = "wasm32")]
-#[no_mangle]
+fn delete_agent(
+    payload: &DeleteAgentAction,
+    signer: &str,
+    state: &mut PikeState,
+) -> Result<(), ApplyError> {
+    if payload.get_public_key().is_empty() {
+        return Err(ApplyError::InvalidTransaction("Public key required".into()));
+    }
+
+    if payload.get_org_id().is_empty() {
+        return Err(ApplyError::InvalidTransaction(
+            "Organization ID required".into(),
+        ));
+    }
+
+    // verify the signer of the transaction is authorized to delete agent
+    is_admin(signer, payload.get_org_id(), state)?;
+
+    if signer == payload.get_public_key() {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Admin may not delete themselves: {}",
+            signer,
+        )));
+    }
+
+    // make sure the agent exists and belongs to the admin's organization
+    match state.get_agent(payload.get_public_key()) {
+        Ok(None) => {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent does not exist: {}",
+                payload.get_public_key(),
+            )))
+        }
+        Ok(Some(agent)) => {
+            if agent.get_org_id() != payload.get_org_id() {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Agent is not associated with the organization: {}",
+                    payload.get_public_key(),
+                )));
+            }
+        }
+        Err(err) => {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Failed to retrieve state: {}",
+                err,
+            )))
+        }
+    };
+
+    state
+        .remove_agent(payload.get_public_key())
+        .map_err(|e| ApplyError::InternalError(format!("Failed to delete agent: {:?}", e)))?;
+
+    remove_org_agent(payload.get_org_id(), payload.get_public_key(), state)
+}
+
+fn rotate_agent_key(
+    payload: &RotateAgentKeyAction,
+    signer: &str,
+    state: &mut PikeState,
+) -> Result<(), ApplyError> {
+    if payload.get_public_key().is_empty() || payload.get_new_public_key().is_empty() {
+        return Err(ApplyError::InvalidTransaction(
+            "Current and new public keys required".into(),
+        ));
+    }
+
+    if payload.get_org_id().is_empty() {
+        return Err(ApplyError::InvalidTransaction(
+            "Organization ID required".into(),
+        ));
+    }
+
+    // verify the signer of the transaction is authorized to rotate the key
+    is_admin(signer, payload.get_org_id(), state)?;
+
+    let mut old_agent = match state.get_agent(payload.get_public_key())? {
+        Some(agent) => agent,
+        None => {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent does not exist: {}",
+                payload.get_public_key(),
+            )))
+        }
+    };
+
+    if old_agent.get_org_id() != payload.get_org_id() {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Agent is not associated with the organization: {}",
+            payload.get_public_key(),
+        )));
+    }
+
+    if !old_agent.get_active() {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Agent is not active: {}",
+            payload.get_public_key(),
+        )));
+    }
+
+    if state.get_agent(payload.get_new_public_key())?.is_some() {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Agent already exists: {}",
+            payload.get_new_public_key(),
+        )));
+    }
+
+    let mut new_agent = Agent::new();
+    new_agent.set_public_key(payload.get_new_public_key().to_string());
+    new_agent.set_org_id(payload.get_org_id().to_string());
+    new_agent.set_active(true);
+    new_agent.set_roles(old_agent.take_roles());
+    new_agent.set_metadata(old_agent.take_metadata());
+    new_agent.set_predecessor(payload.get_public_key().to_string());
+
+    // The old agent is kept, inactive and without roles, so that the key cannot
+    // be registered again and the rotation chain can be followed back through it.
+    old_agent.set_active(false);
+
+    state
+        .set_agent(payload.get_new_public_key(), new_agent)
+        .map_err(|e| ApplyError::InternalError(format!("Failed to create agent: {:?}", e)))?;
+    state
+        .set_agent(payload.get_public_key(), old_agent)
+        .map_err(|e| ApplyError::InternalError(format!("Failed to update agent: {:?}", e)))?;
+
+    add_org_agent(payload.get_org_id(), payload.get_new_public_key(), state)?;
+    remove_org_agent(payload.get_org_id(), payload.get_public_key(), state)?;
+    add_org_retired_agent(payload.get_org_id(), payload.get_public_key(), state)
+}
+
+fn delete_org(
+    payload: &DeleteOrganizationAction,
+    signer: &str,
+    state: &mut PikeState,
+) -> Result<(), ApplyError> {
+    if payload.get_id().is_empty() {
+        return Err(ApplyError::InvalidTransaction(
+            "Unique organization ID required".into(),
+        ));
+    }
+
+    // verify the signer of the transaction is authorized to delete organization
+    is_admin(signer, payload.get_id(), state)?;
+
+    let organization = match state.get_organization(payload.get_id()) {
+        Ok(None) => {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Organization does not exist: {}",
+                payload.get_id(),
+            )))
+        }
+        Ok(Some(org)) => org,
+        Err(err) => {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Failed to retrieve state: {}",
+                err,
+            )))
+        }
+    };
+
+    // Until its agents are backfilled, the list of an organization created
+    // before agents were tracked cannot show whether other agents remain.
+    if !organization.get_agents_tracked() {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Organization predates agent tracking and cannot be deleted: {}",
+            payload.get_id(),
+        )));
+    }
+
+    // The signing admin is removed along with the organization; any other
+    // agent must be deleted first.
+    if organization
+        .get_agents()
+        .iter()
+        .any(|public_key| public_key != signer)
+    {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Organization still has agents: {}",
+            payload.get_id(),
+        )));
+    }
+
+    state
+        .remove_agent(signer)
+        .map_err(|e| ApplyError::InternalError(format!("Failed to delete agent: {:?}", e)))?;
+
+    // Agents whose keys were rotated away are kept until now so that rotation
+    // chains can be followed back through them.
+    for public_key in organization.get_retired_agents() {
+        state
+            .remove_agent(public_key)
+            .map_err(|e| ApplyError::InternalError(format!("Failed to delete agent: {:?}", e)))?;
+    }
+
+    for alternate_id in organization.get_alternate_ids() {
+        state.remove_alternate_id_holder(alternate_id.get_id_type(), alternate_id.get_id())?;
+    }
+
+    // Roles are removed so that an organization created later with the same
+    // ID does not inherit them.
+    for role_name in organization.get_roles() {
+        state.remove_role(payload.get_id(), role_name)?;
+    }
+
+    // Delegations in either direction are revoked, so that neither the
+    // organization's resources nor its agents keep them after it is gone.
+    for delegate_org_id in organization.get_delegate_org_ids() {
+        state.remove_delegation(payload.get_id(), delegate_org_id)?;
+        remove_org_delegation(payload.get_id(), delegate_org_id, state)?;
+    }
+    for delegator_org_id in organization.get_delegator_org_ids() {
+        state.remove_delegation(delegator_org_id, payload.get_id())?;
+        remove_org_delegation(delegator_org_id, payload.get_id(), state)?;
+    }
+
+    state.remove_organization(payload.get_id())
+}
+
+fn create_role(
+    payload: &CreateRoleAction,
+    signer: &str,
+    state: &mut PikeState,
+) -> Result<(), ApplyError> {
+    if payload.get_org_id().is_empty() {
+        return Err(ApplyError::InvalidTransaction(
+            "Organization ID required".into(),
+        ));
+    }
+
+    if payload.get_name().is_empty() {
+        return Err(ApplyError::InvalidTransaction("Role name required".into()));
+    }
+
+    // verify the signer of the transaction is authorized to create roles
+    is_admin(signer, payload.get_org_id(), state)?;
+
+    if state
+        .get_role(payload.get_org_id(), payload.get_name())?
+        .is_some()
+    {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Role already exists: {}",
+            payload.get_name(),
+        )));
+    }
+
+    check_inherited_roles(
+        payload.get_org_id(),
+        payload.get_name(),
+        payload.get_inherit_from(),
+        state,
+    )?;
+
+    let mut role = Role::new();
+    role.set_org_id(payload.get_org_id().to_string());
+    role.set_name(payload.get_name().to_string());
+    role.set_description(payload.get_description().to_string());
+    role.set_permissions(protobuf::RepeatedField::from_vec(
+        payload.get_permissions().to_vec(),
+    ));
+    role.set_inherit_from(protobuf::RepeatedField::from_vec(
+        payload.get_inherit_from().to_vec(),
+    ));
+
+    state.set_role(role)?;
+
+    add_org_role(payload.get_org_id(), payload.get_name(), state)
+}
+
+fn update_role(
+    payload: &UpdateRoleAction,
+    signer: &str,
+    state: &mut PikeState,
+) -> Result<(), ApplyError> {
+    if payload.get_org_id().is_empty() {
+        return Err(ApplyError::InvalidTransaction(
+            "Organization ID required".into(),
+        ));
+    }
+
+    if payload.get_name().is_empty() {
+        return Err(ApplyError::InvalidTransaction("Role name required".into()));
+    }
+
+    // verify the signer of the transaction is authorized to update roles
+    is_admin(signer, payload.get_org_id(), state)?;
+
+    let mut role = match state.get_role(payload.get_org_id(), payload.get_name())? {
+        Some(role) => role,
+        None => {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Role does not exist: {}",
+                payload.get_name(),
+            )))
+        }
+    };
+
+    check_inherited_roles(
+        payload.get_org_id(),
+        payload.get_name(),
+        payload.get_inherit_from(),
+        state,
+    )?;
+
+    role.set_description(payload.get_description().to_string());
+    role.set_permissions(protobuf::RepeatedField::from_vec(
+        payload.get_permissions().to_vec(),
+    ));
+    role.set_inherit_from(protobuf::RepeatedField::from_vec(
+        payload.get_inherit_from().to_vec(),
+    ));
+
+    state.set_role(role)
+}
+
+fn delete_role(
+    payload: &DeleteRoleAction,
+    signer: &str,
+    state: &mut PikeState,
+) -> Result<(), ApplyError> {
+    if payload.get_org_id().is_empty() {
+        return Err(ApplyError::InvalidTransaction(
+            "Organization ID required".into(),
+        ));
+    }
+
+    if payload.get_name().is_empty() {
+        return Err(ApplyError::InvalidTransaction("Role name required".into()));
+    }
+
+    // verify the signer of the transaction is authorized to delete roles
+    is_admin(signer, payload.get_org_id(), state)?;
+
+    if state
+        .get_role(payload.get_org_id(), payload.get_name())?
+        .is_none()
+    {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Role does not exist: {}",
+            payload.get_name(),
+        )));
+    }
+
+    // A role cannot be deleted while another role of the organization inherits
+    // from it
+    let role_names = match state.get_organization(payload.get_org_id())? {
+        Some(organization) => organization.get_roles().to_vec(),
+        None => Vec::new(),
+    };
+    for role_name in role_names {
+        if let Some(role) = state.get_role(payload.get_org_id(), &role_name)? {
+            if role
+                .get_inherit_from()
+                .iter()
+                .any(|inherited| inherited == payload.get_name())
+            {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Role {} is inherited by role: {}",
+                    payload.get_name(),
+                    role_name,
+                )));
+            }
+        }
+    }
+
+    state.remove_role(payload.get_org_id(), payload.get_name())?;
+
+    remove_org_role(payload.get_org_id(), payload.get_name(), state)
+}
+
+/// Verifies that every inherited role exists in the organization and that
+/// inheriting from them does not lead back to the role itself.
+fn check_inherited_roles(
+    org_id: &str,
+    name: &str,
+    inherit_from: &[String],
+    state: &mut PikeState,
+) -> Result<(), ApplyError> {
+    for inherited in inherit_from {
+        if state.get_role(org_id, inherited)?.is_none() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Inherited role does not exist: {}",
+                inherited,
+            )));
+        }
+    }
+
+    let mut visited = Vec::new();
+    let mut pending = inherit_from.to_vec();
+    while let Some(role_name) = pending.pop() {
+        if role_name == name {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Role cannot inherit from itself: {}",
+                name,
+            )));
+        }
+        if visited.contains(&role_name) {
+            continue;
+        }
+        if let Some(role) = state.get_role(org_id, &role_name)? {
+            pending.extend(role.get_inherit_from().iter().cloned());
+        }
+        visited.push(role_name);
+    }
+    Ok(())
+}
+
+fn create_delegation(
+    payload: &CreateDelegationAction,
+    signer: &str,
+    state: &mut PikeState,
+) -> Result<(), ApplyError> {
+    if payload.get_org_id().is_empty() {
+        return Err(ApplyError::InvalidTransaction(
+            "Organization ID required".into(),
+        ));
+    }
+
+    if payload.get_delegate_org_id().is_empty() {
+        return Err(ApplyError::InvalidTransaction(
+            "Delegate organization ID required".into(),
+        ));
+    }
+
+    if payload.get_org_id() == payload.get_delegate_org_id() {
+        return Err(ApplyError::InvalidTransaction(
+            "An organization cannot delegate permissions to itself".into(),
+        ));
+    }
+
+    if payload.get_permissions().is_empty() {
+        return Err(ApplyError::InvalidTransaction(
+            "Delegation must grant at least one permission".into(),
+        ));
+    }
+
+    // verify the signer of the transaction is authorized to delegate permissions
+    is_admin(signer, payload.get_org_id(), state)?;
+
+    if state
+        .get_organization(payload.get_delegate_org_id())?
+        .is_none()
+    {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Delegate organization does not exist: {}",
+            payload.get_delegate_org_id(),
+        )));
+    }
+
+    if state
+        .get_delegation(payload.get_org_id(), payload.get_delegate_org_id())?
+        .is_some()
+    {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Delegation to {} already exists",
+            payload.get_delegate_org_id(),
+        )));
+    }
+
+    let mut delegation = Delegation::new();
+    delegation.set_org_id(payload.get_org_id().to_string());
+    delegation.set_delegate_org_id(payload.get_delegate_org_id().to_string());
+    delegation.set_permissions(protobuf::RepeatedField::from_vec(
+        payload.get_permissions().to_vec(),
+    ));
+    delegation.set_expiry(payload.get_expiry());
+
+    state.set_delegation(delegation)?;
+
+    add_org_delegation(payload.get_org_id(), payload.get_delegate_org_id(), state)
+}
+
+fn delete_delegation(
+    payload: &DeleteDelegationAction,
+    signer: &str,
+    state: &mut PikeState,
+) -> Result<(), ApplyError> {
+    if payload.get_org_id().is_empty() {
+        return Err(ApplyError::InvalidTransaction(
+            "Organization ID required".into(),
+        ));
+    }
+
+    if payload.get_delegate_org_id().is_empty() {
+        return Err(ApplyError::InvalidTransaction(
+            "Delegate organization ID required".into(),
+        ));
+    }
+
+    // verify the signer of the transaction is authorized to revoke delegations
+    is_admin(signer, payload.get_org_id(), state)?;
+
+    if state
+        .get_delegation(payload.get_org_id(), payload.get_delegate_org_id())?
+        .is_none()
+    {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Delegation to {} does not exist",
+            payload.get_delegate_org_id(),
+        )));
+    }
+
+    state.remove_delegation(payload.get_org_id(), payload.get_delegate_org_id())?;
+
+    remove_org_delegation(payload.get_org_id(), payload.get_delegate_org_id(), state)
+}
+
+fn check_alternate_ids(alternate_ids: &[AlternateId]) -> Result<(), ApplyError> {
+    let alternate_ids = alternate_ids
+        .iter()
+        .cloned()
+        .map(NativeAlternateId::from_proto)
+        .collect::<Result<Vec<NativeAlternateId>, _>>()
+        .map_err(|err| ApplyError::InvalidTransaction(format!("{}", err)))?;
+    validate_alternate_ids(&alternate_ids)
+        .map_err(|err| ApplyError::InvalidTransaction(format!("{}", err)))
+}
+
+fn check_locations(locations: &[Location]) -> Result<(), ApplyError> {
+    let locations = locations
+        .iter()
+        .cloned()
+        .map(NativeLocation::from_proto)
+        .collect::<Result<Vec<NativeLocation>, _>>()
+        .map_err(|err| ApplyError::InvalidTransaction(format!("{}", err)))?;
+    validate_locations(&locations).map_err(|err| ApplyError::InvalidTransaction(format!("{}", err)))
+}
+
+/// Records the organization as the holder of each of the alternate IDs. An
+/// ID already held by a different organization cannot be claimed.
+fn claim_alternate_ids(
+    org_id: &str,
+    alternate_ids: &[AlternateId],
+    state: &mut PikeState,
+) -> Result<(), ApplyError> {
+    for alternate_id in alternate_ids {
+        match state.get_alternate_id_holder(alternate_id.get_id_type(), alternate_id.get_id())? {
+            Some(ref holder) if holder != org_id => {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Alternate ID {}:{} is already held by organization {}",
+                    alternate_id.get_id_type(),
+                    alternate_id.get_id(),
+                    holder,
+                )))
+            }
+            _ => state.set_alternate_id_holder(
+                alternate_id.get_id_type(),
+                alternate_id.get_id(),
+                org_id,
+            )?,
+        }
+    }
+    Ok(())
+}
+
+fn add_org_agent(org_id: &str, public_key: &str, state: &mut PikeState) -> Result<(), ApplyError> {
+    let mut organization = match state.get_organization(org_id)? {
+        Some(org) => org,
+        None => {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Organization does not exist: {}",
+                org_id,
+            )))
+        }
+    };
+
+    // The agents of an organization created before agents were tracked are
+    // only listed once they are backfilled; adding to the list before then
+    // would make it look complete when it is not.
+    if organization.get_agents_tracked()
+        && !organization
+            .get_agents()
+            .iter()
+            .any(|key| key == public_key)
+    {
+        organization.mut_agents().push(public_key.to_string());
+        state.set_organization(org_id, organization)?;
+    }
+    Ok(())
+}
+
+fn remove_org_agent(
+    org_id: &str,
+    public_key: &str,
+    state: &mut PikeState,
+) -> Result<(), ApplyError> {
+    if let Some(mut organization) = state.get_organization(org_id)? {
+        organization.mut_agents().retain(|key| key != public_key);
+        organization
+            .mut_retired_agents()
+            .retain(|key| key != public_key);
+        state.set_organization(org_id, organization)?;
+    }
+    Ok(())
+}
+
+/// Records an agent whose key was rotated away, so that it is removed along
+/// with the organization.
+fn add_org_retired_agent(
+    org_id: &str,
+    public_key: &str,
+    state: &mut PikeState,
+) -> Result<(), ApplyError> {
+    if let Some(mut organization) = state.get_organization(org_id)? {
+        organization
+            .mut_retired_agents()
+            .push(public_key.to_string());
+        state.set_organization(org_id, organization)?;
+    }
+    Ok(())
+}
+
+fn add_org_role(org_id: &str, name: &str, state: &mut PikeState) -> Result<(), ApplyError> {
+    let mut organization = match state.get_organization(org_id)? {
+        Some(org) => org,
+        None => {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Organization does not exist: {}",
+                org_id,
+            )))
+        }
+    };
+
+    if !organization.get_roles().iter().any(|role| role == name) {
+        organization.mut_roles().push(name.to_string());
+        state.set_organization(org_id, organization)?;
+    }
+    Ok(())
+}
+
+fn remove_org_role(org_id: &str, name: &str, state: &mut PikeState) -> Result<(), ApplyError> {
+    if let Some(mut organization) = state.get_organization(org_id)? {
+        organization.mut_roles().retain(|role| role != name);
+        state.set_organization(org_id, organization)?;
+    }
+    Ok(())
+}
+
+/// Records a delegation on both the delegating and the delegate organization.
+fn add_org_delegation(
+    org_id: &str,
+    delegate_org_id: &str,
+    state: &mut PikeState,
+) -> Result<(), ApplyError> {
+    if let Some(mut organization) = state.get_organization(org_id)? {
+        if !organization
+            .get_delegate_org_ids()
+            .iter()
+            .any(|id| id == delegate_org_id)
+        {
+            organization
+                .mut_delegate_org_ids()
+                .push(delegate_org_id.to_string());
+            state.set_organization(org_id, organization)?;
+        }
+    }
+    if let Some(mut delegate) = state.get_organization(delegate_org_id)? {
+        if !delegate
+            .get_delegator_org_ids()
+            .iter()
+            .any(|id| id == org_id)
+        {
+            delegate.mut_delegator_org_ids().push(org_id.to_string());
+            state.set_organization(delegate_org_id, delegate)?;
+        }
+    }
+    Ok(())
+}
+
+/// Removes a delegation from the records of the delegating and the delegate
+/// organization.
+fn remove_org_delegation(
+    org_id: &str,
+    delegate_org_id: &str,
+    state: &mut PikeState,
+) -> Result<(), ApplyError> {
+    if let Some(mut organization) = state.get_organization(org_id)? {
+        organization
+            .mut_delegate_org_ids()
+            .retain(|id| id != delegate_org_id);
+        state.set_organization(org_id, organization)?;
+    }
+    if let Some(mut delegate) = state.get_organization(delegate_org_id)? {
+        delegate.mut_delegator_org_ids().retain(|id| id != org_id);
+        state.set_organization(delegate_org_id, delegate)?;
+    }
+    Ok(())
+}
+
+pub fn is_admin(signer: &str, org_id: &str, state: &mut PikeState) -> Result<(), ApplyError> {
+    let admin = match state.get_agent(signer) {
+        Ok(None) => {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Signer is not an agent: {}",
+                signer,
+            )))
+        }
+        Ok(Some(admin)) => admin,
+        Err(err) => {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Failed to retrieve state: {}",
+                err,
+            )))
+        }
+    };
+
+    if admin.get_org_id() != org_id {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Signer is not associated with the organization: {}",
+            signer,
+        )));
+    }
+    if !admin.roles.contains(&"admin".to_string()) {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Signer is not an admin: {}",
+            signer,
+        )));
+    };
+
+    if !admin.active {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Admin is not currently an active agent: {}",
+            signer,
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+// Sabre apply must return a bool
+fn apply(
+    request: &TpProcessRequest,
+    context: &mut dyn TransactionContext,
+) -> Result<bool, ApplyError> {
+    let handler = PikeTransactionHandler::new();
+    match handler.apply(request, context) {
+        Ok(_) => Ok(true),
+        Err(err) => {
+            info!("{} received {}", handler.family_name(), err);
+            Err(err)
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+#[no_mangle]
 pub unsafe fn entrypoint(payload: WasmPtr, signer: WasmPtr, signature: WasmPtr) -> i32 {
     execute_entrypoint(payload, signer, signature, apply)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    use sawtooth_sdk::processor::handler::{ContextError, TransactionContext};
+
+    const ORG_ID: &str = "test_org";
+    const ADMIN_KEY: &str = "admin_public_key";
+    const AGENT_KEY: &str = "agent_public_key";
+    const OTHER_ORG_ID: &str = "other_test_org";
+    const OTHER_ADMIN_KEY: &str = "other_admin_public_key";
+
+    #[derive(Default, Debug)]
+    /// A MockTransactionContext that can be used to test PikeState
+    struct MockTransactionContext {
+        state: RefCell<HashMap<String, Vec<u8>>>,
+    }
+
+    impl TransactionContext for MockTransactionContext {
+        fn get_state_entries(
+            &self,
+            addresses: &[String],
+        ) -> Result<Vec<(String, Vec<u8>)>, ContextError> {
+            let mut results = Vec::new();
+            for addr in addresses {
+                if let Some(data) = self.state.borrow().get(addr) {
+                    results.push((addr.to_string(), data.clone()));
+                }
+            }
+            Ok(results)
+        }
+
+        fn set_state_entries(&self, entries: Vec<(String, Vec<u8>)>) -> Result<(), ContextError> {
+            for (addr, data) in entries {
+                self.state.borrow_mut().insert(addr, data);
+            }
+            Ok(())
+        }
+
+        fn delete_state_entries(&self, addresses: &[String]) -> Result<Vec<String>, ContextError> {
+            let mut deleted = Vec::new();
+            for addr in addresses {
+                if self.state.borrow_mut().remove(addr).is_some() {
+                    deleted.push(addr.to_string());
+                }
+            }
+            Ok(deleted)
+        }
+
+        /// this is not needed for these tests
+        fn add_receipt_data(&self, _data: &[u8]) -> Result<(), ContextError> {
+            unimplemented!()
+        }
+
+        /// this is not needed for these tests
+        fn add_event(
+            &self,
+            _event_type: String,
+            _attributes: Vec<(String, String)>,
+            _data: &[u8],
+        ) -> Result<(), ContextError> {
+            unimplemented!()
+        }
+    }
+
+    #[test]
+    /// Test that an admin can delete another agent of their organization, and that the agent is
+    /// removed from the organization's list of agents.
+    fn test_delete_agent() {
+        let mut transaction_context = MockTransactionContext::default();
+        let mut state = PikeState::new(&mut transaction_context);
+        setup_org(&mut state, ORG_ID, &[ADMIN_KEY, AGENT_KEY]);
+
+        delete_agent(&delete_agent_action(AGENT_KEY), ADMIN_KEY, &mut state)
+            .expect("Failed to delete agent");
+
+        assert!(state.get_agent(AGENT_KEY).unwrap().is_none());
+        let org = state.get_organization(ORG_ID).unwrap().unwrap();
+        assert_eq!(org.get_agents(), &[ADMIN_KEY.to_string()]);
+    }
+
+    #[test]
+    /// Test that an admin cannot delete themselves.
+    fn test_delete_agent_self() {
+        let mut transaction_context = MockTransactionContext::default();
+        let mut state = PikeState::new(&mut transaction_context);
+        setup_org(&mut state, ORG_ID, &[ADMIN_KEY, AGENT_KEY]);
+
+        match delete_agent(&delete_agent_action(ADMIN_KEY), ADMIN_KEY, &mut state) {
+            Ok(()) => panic!("Admin should not be able to delete themselves"),
+            Err(ApplyError::InvalidTransaction(err)) => assert_eq!(
+                err,
+                format!("Admin may not delete themselves: {}", ADMIN_KEY)
+            ),
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+        assert!(state.get_agent(ADMIN_KEY).unwrap().is_some());
+    }
+
+    #[test]
+    /// Test that an agent without the admin role cannot delete an agent.
+    fn test_delete_agent_not_admin() {
+        let mut transaction_context = MockTransactionContext::default();
+        let mut state = PikeState::new(&mut transaction_context);
+        setup_org(&mut state, ORG_ID, &[ADMIN_KEY, AGENT_KEY]);
+
+        match delete_agent(&delete_agent_action(ADMIN_KEY), AGENT_KEY, &mut state) {
+            Ok(()) => panic!("Non-admin should not be able to delete an agent"),
+            Err(ApplyError::InvalidTransaction(err)) => {
+                assert_eq!(err, format!("Signer is not an admin: {}", AGENT_KEY))
+            }
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+        assert!(state.get_agent(ADMIN_KEY).unwrap().is_some());
+    }
+
+    #[test]
+    /// Test that an admin can delete their organization once they are its only agent, and that
+    /// the admin is removed along with it.
+    fn test_delete_org() {
+        let mut transaction_context = MockTransactionContext::default();
+        let mut state = PikeState::new(&mut transaction_context);
+        setup_org(&mut state, ORG_ID, &[ADMIN_KEY]);
+
+        delete_org(&delete_org_action(), ADMIN_KEY, &mut state).expect("Failed to delete org");
+
+        assert!(state.get_organization(ORG_ID).unwrap().is_none());
+        assert!(state.get_agent(ADMIN_KEY).unwrap().is_none());
+    }
+
+    #[test]
+    /// Test that an organization cannot be deleted while it has agents other than the signer.
+    fn test_delete_org_with_agents() {
+        let mut transaction_context = MockTransactionContext::default();
+        let mut state = PikeState::new(&mut transaction_context);
+        setup_org(&mut state, ORG_ID, &[ADMIN_KEY, AGENT_KEY]);
+
+        match delete_org(&delete_org_action(), ADMIN_KEY, &mut state) {
+            Ok(()) => panic!("Org with agents should not be deleted"),
+            Err(ApplyError::InvalidTransaction(err)) => {
+                assert_eq!(err, format!("Organization still has agents: {}", ORG_ID))
+            }
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+        assert!(state.get_organization(ORG_ID).unwrap().is_some());
+        assert!(state.get_agent(AGENT_KEY).unwrap().is_some());
+    }
+
+    #[test]
+    /// Test that an agent without the admin role cannot delete their organization.
+    fn test_delete_org_not_admin() {
+        let mut transaction_context = MockTransactionContext::default();
+        let mut state = PikeState::new(&mut transaction_context);
+        setup_org(&mut state, ORG_ID, &[ADMIN_KEY, AGENT_KEY]);
+
+        match delete_org(&delete_org_action(), AGENT_KEY, &mut state) {
+            Ok(()) => panic!("Non-admin should not be able to delete the org"),
+            Err(ApplyError::InvalidTransaction(err)) => {
+                assert_eq!(err, format!("Signer is not an admin: {}", AGENT_KEY))
+            }
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+        assert!(state.get_organization(ORG_ID).unwrap().is_some());
+    }
+
+    #[test]
+    /// Test that an organization created before agents were tracked cannot be deleted, since its
+    /// empty list of agents does not show whether other agents remain.
+    fn test_delete_org_untracked_agents() {
+        let mut transaction_context = MockTransactionContext::default();
+        let mut state = PikeState::new(&mut transaction_context);
+        setup_org(&mut state, ORG_ID, &[ADMIN_KEY, AGENT_KEY]);
+        untrack_org_agents(&mut state, ORG_ID);
+
+        match delete_org(&delete_org_action(), ADMIN_KEY, &mut state) {
+            Ok(()) => panic!("Org without tracked agents should not be deleted"),
+            Err(ApplyError::InvalidTransaction(err)) => assert_eq!(
+                err,
+                format!(
+                    "Organization predates agent tracking and cannot be deleted: {}",
+                    ORG_ID
+                )
+            ),
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+        assert!(state.get_agent(AGENT_KEY).unwrap().is_some());
+    }
+
+    #[test]
+    /// Test that an admin can backfill the agents of an organization created before agents were
+    /// tracked, after which the organization can be deleted once its other agents are.
+    fn test_update_org_backfill_agents() {
+        let mut transaction_context = MockTransactionContext::default();
+        let mut state = PikeState::new(&mut transaction_context);
+        setup_org(&mut state, ORG_ID, &[ADMIN_KEY, AGENT_KEY]);
+        setup_org(&mut state, OTHER_ORG_ID, &[OTHER_ADMIN_KEY]);
+        untrack_org_agents(&mut state, ORG_ID);
+
+        let mut action = UpdateOrganizationAction::new();
+        action.set_id(ORG_ID.to_string());
+
+        action.set_agents(vec![AGENT_KEY.to_string()].into());
+        match update_org(&action, ADMIN_KEY, &mut state) {
+            Ok(()) => panic!("Backfilled agents without the signer should be invalid"),
+            Err(ApplyError::InvalidTransaction(err)) => assert_eq!(
+                err,
+                format!(
+                    "Agents of the organization must include the signer: {}",
+                    ADMIN_KEY
+                )
+            ),
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+
+        action.set_agents(vec![ADMIN_KEY.to_string(), OTHER_ADMIN_KEY.to_string()].into());
+        match update_org(&action, ADMIN_KEY, &mut state) {
+            Ok(()) => panic!("Backfilled agents of another org should be invalid"),
+            Err(ApplyError::InvalidTransaction(err)) => assert_eq!(
+                err,
+                format!(
+                    "Agent is not associated with the organization: {}",
+                    OTHER_ADMIN_KEY
+                )
+            ),
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+
+        action.set_agents(vec![ADMIN_KEY.to_string(), AGENT_KEY.to_string()].into());
+        update_org(&action, ADMIN_KEY, &mut state).expect("Failed to backfill agents");
+        let org = state.get_organization(ORG_ID).unwrap().unwrap();
+        assert!(org.get_agents_tracked());
+        assert_eq!(
+            org.get_agents(),
+            &[ADMIN_KEY.to_string(), AGENT_KEY.to_string()]
+        );
+
+        match update_org(&action, ADMIN_KEY, &mut state) {
+            Ok(()) => panic!("Agents should only be backfilled once"),
+            Err(ApplyError::InvalidTransaction(err)) => assert_eq!(
+                err,
+                format!("Agents of the organization are already tracked: {}", ORG_ID)
+            ),
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+
+        delete_agent(&delete_agent_action(AGENT_KEY), ADMIN_KEY, &mut state)
+            .expect("Failed to delete agent");
+        delete_org(&delete_org_action(), ADMIN_KEY, &mut state).expect("Failed to delete org");
+        assert!(state.get_organization(ORG_ID).unwrap().is_none());
+    }
+
+    #[test]
+    /// Test that an agent whose key was rotated away is kept until its organization is deleted,
+    /// and then removed along with it.
+    fn test_delete_org_removes_retired_agents() {
+        let mut transaction_context = MockTransactionContext::default();
+        let mut state = PikeState::new(&mut transaction_context);
+        setup_org(&mut state, ORG_ID, &[ADMIN_KEY, AGENT_KEY]);
+
+        let mut action = RotateAgentKeyAction::new();
+        action.set_org_id(ORG_ID.to_string());
+        action.set_public_key(AGENT_KEY.to_string());
+        action.set_new_public_key("rotated_public_key".to_string());
+        rotate_agent_key(&action, ADMIN_KEY, &mut state).expect("Failed to rotate agent key");
+
+        let org = state.get_organization(ORG_ID).unwrap().unwrap();
+        assert_eq!(
+            org.get_agents(),
+            &[ADMIN_KEY.to_string(), "rotated_public_key".to_string()]
+        );
+        assert_eq!(org.get_retired_agents(), &[AGENT_KEY.to_string()]);
+        assert!(!state.get_agent(AGENT_KEY).unwrap().unwrap().get_active());
+
+        delete_agent(
+            &delete_agent_action("rotated_public_key"),
+            ADMIN_KEY,
+            &mut state,
+        )
+        .expect("Failed to delete agent");
+        delete_org(&delete_org_action(), ADMIN_KEY, &mut state).expect("Failed to delete org");
+
+        assert!(state.get_agent(AGENT_KEY).unwrap().is_none());
+        assert!(state.get_organization(ORG_ID).unwrap().is_none());
+    }
+
+    #[test]
+    /// Test that deleting an organization removes its roles, so that an organization created
+    /// later with the same ID does not inherit them.
+    fn test_delete_org_removes_roles() {
+        let mut transaction_context = MockTransactionContext::default();
+        let mut state = PikeState::new(&mut transaction_context);
+        setup_org(&mut state, ORG_ID, &[ADMIN_KEY]);
+
+        let mut action = CreateRoleAction::new();
+        action.set_org_id(ORG_ID.to_string());
+        action.set_name("product_owner".to_string());
+        action.set_permissions(vec!["can_create_product".to_string()].into());
+        create_role(&action, ADMIN_KEY, &mut state).expect("Failed to create role");
+        let org = state.get_organization(ORG_ID).unwrap().unwrap();
+        assert_eq!(org.get_roles(), &["product_owner".to_string()]);
+
+        delete_org(&delete_org_action(), ADMIN_KEY, &mut state).expect("Failed to delete org");
+
+        assert!(state.get_role(ORG_ID, "product_owner").unwrap().is_none());
+    }
+
+    #[test]
+    /// Test that a role cannot be deleted while another role inherits from it, and can be
+    /// deleted once nothing inherits from it.
+    fn test_delete_role_inherited() {
+        let mut transaction_context = MockTransactionContext::default();
+        let mut state = PikeState::new(&mut transaction_context);
+        setup_org(&mut state, ORG_ID, &[ADMIN_KEY]);
+
+        let mut action = CreateRoleAction::new();
+        action.set_org_id(ORG_ID.to_string());
+        action.set_name("product_owner".to_string());
+        action.set_permissions(vec!["can_create_product".to_string()].into());
+        create_role(&action, ADMIN_KEY, &mut state).expect("Failed to create role");
+
+        let mut action = CreateRoleAction::new();
+        action.set_org_id(ORG_ID.to_string());
+        action.set_name("product_admin".to_string());
+        action.set_permissions(vec!["can_delete_product".to_string()].into());
+        action.set_inherit_from(vec!["product_owner".to_string()].into());
+        create_role(&action, ADMIN_KEY, &mut state).expect("Failed to create role");
+
+        let mut action = DeleteRoleAction::new();
+        action.set_org_id(ORG_ID.to_string());
+        action.set_name("product_owner".to_string());
+        match delete_role(&action, ADMIN_KEY, &mut state) {
+            Ok(()) => panic!("Role is inherited, InvalidTransaction should be returned"),
+            Err(ApplyError::InvalidTransaction(err)) => assert_eq!(
+                err,
+                "Role product_owner is inherited by role: product_admin"
+            ),
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+        assert!(state.get_role(ORG_ID, "product_owner").unwrap().is_some());
+
+        let mut admin_action = DeleteRoleAction::new();
+        admin_action.set_org_id(ORG_ID.to_string());
+        admin_action.set_name("product_admin".to_string());
+        delete_role(&admin_action, ADMIN_KEY, &mut state).expect("Failed to delete role");
+        delete_role(&action, ADMIN_KEY, &mut state).expect("Failed to delete role");
+        assert!(state.get_role(ORG_ID, "product_owner").unwrap().is_none());
+    }
+
+    #[test]
+    /// Test that deleting an organization revokes the delegations it granted and the delegations
+    /// granted to it, and removes them from the other organization's records.
+    fn test_delete_org_removes_delegations() {
+        let mut transaction_context = MockTransactionContext::default();
+        let mut state = PikeState::new(&mut transaction_context);
+        setup_org(&mut state, ORG_ID, &[ADMIN_KEY]);
+        setup_org(&mut state, OTHER_ORG_ID, &[OTHER_ADMIN_KEY]);
+
+        create_delegation(
+            &create_delegation_action(ORG_ID, OTHER_ORG_ID),
+            ADMIN_KEY,
+            &mut state,
+        )
+        .expect("Failed to create delegation");
+        create_delegation(
+            &create_delegation_action(OTHER_ORG_ID, ORG_ID),
+            OTHER_ADMIN_KEY,
+            &mut state,
+        )
+        .expect("Failed to create delegation");
+        let other_org = state.get_organization(OTHER_ORG_ID).unwrap().unwrap();
+        assert_eq!(other_org.get_delegate_org_ids(), &[ORG_ID.to_string()]);
+        assert_eq!(other_org.get_delegator_org_ids(), &[ORG_ID.to_string()]);
+
+        delete_org(&delete_org_action(), ADMIN_KEY, &mut state).expect("Failed to delete org");
+
+        assert!(state
+            .get_delegation(ORG_ID, OTHER_ORG_ID)
+            .unwrap()
+            .is_none());
+        assert!(state
+            .get_delegation(OTHER_ORG_ID, ORG_ID)
+            .unwrap()
+            .is_none());
+        let other_org = state.get_organization(OTHER_ORG_ID).unwrap().unwrap();
+        assert!(other_org.get_delegate_org_ids().is_empty());
+        assert!(other_org.get_delegator_org_ids().is_empty());
+    }
+
+    #[test]
+    /// Test that an organization can release all of its alternate IDs, after which another
+    /// organization can claim them.
+    fn test_update_org_clear_alternate_ids() {
+        let mut transaction_context = MockTransactionContext::default();
+        let mut state = PikeState::new(&mut transaction_context);
+        setup_org(&mut state, ORG_ID, &[ADMIN_KEY]);
+
+        let mut alternate_id = AlternateId::new();
+        alternate_id.set_id_type("gln".to_string());
+        alternate_id.set_id("0614141000005".to_string());
+
+        let mut action = UpdateOrganizationAction::new();
+        action.set_id(ORG_ID.to_string());
+        action.set_alternate_ids(vec![alternate_id.clone()].into());
+        update_org(&action, ADMIN_KEY, &mut state).expect("Failed to set alternate IDs");
+        assert_eq!(
+            state
+                .get_alternate_id_holder("gln", "0614141000005")
+                .unwrap(),
+            Some(ORG_ID.to_string())
+        );
+
+        action.set_clear_alternate_ids(true);
+        match update_org(&action, ADMIN_KEY, &mut state) {
+            Ok(()) => panic!("Alternate IDs should not be both cleared and set"),
+            Err(ApplyError::InvalidTransaction(err)) => {
+                assert_eq!(err, "Alternate IDs cannot be both cleared and set")
+            }
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+
+        action.clear_alternate_ids();
+        update_org(&action, ADMIN_KEY, &mut state).expect("Failed to clear alternate IDs");
+        let org = state.get_organization(ORG_ID).unwrap().unwrap();
+        assert!(org.get_alternate_ids().is_empty());
+        assert!(state
+            .get_alternate_id_holder("gln", "0614141000005")
+            .unwrap()
+            .is_none());
+
+        setup_org(&mut state, OTHER_ORG_ID, &[OTHER_ADMIN_KEY]);
+        let mut action = UpdateOrganizationAction::new();
+        action.set_id(OTHER_ORG_ID.to_string());
+        action.set_alternate_ids(vec![alternate_id].into());
+        update_org(&action, OTHER_ADMIN_KEY, &mut state)
+            .expect("Failed to claim released alternate ID");
+    }
+
+    /// Adds an organization and its agents to state. The first agent is given the admin role.
+    fn setup_org(state: &mut PikeState, org_id: &str, agent_keys: &[&str]) {
+        let mut org = Organization::new();
+        org.set_org_id(org_id.to_string());
+        org.set_name("test_org_name".to_string());
+        org.set_agents(
+            agent_keys
+                .iter()
+                .map(|key| key.to_string())
+                .collect::<Vec<_>>()
+                .into(),
+        );
+        org.set_agents_tracked(true);
+        state.set_organization(org_id, org).unwrap();
+
+        for (i, key) in agent_keys.iter().enumerate() {
+            let mut agent = Agent::new();
+            agent.set_public_key(key.to_string());
+            agent.set_org_id(org_id.to_string());
+            agent.set_active(true);
+            if i == 0 {
+                agent.set_roles(vec!["admin".to_string()].into());
+            }
+            state.set_agent(key, agent).unwrap();
+        }
+    }
+
+    /// Makes an organization look like one created before its agents were tracked
+    fn untrack_org_agents(state: &mut PikeState, org_id: &str) {
+        let mut org = state.get_organization(org_id).unwrap().unwrap();
+        org.clear_agents();
+        org.set_agents_tracked(false);
+        state.set_organization(org_id, org).unwrap();
+    }
+
+    fn delete_agent_action(public_key: &str) -> DeleteAgentAction {
+        let mut action = DeleteAgentAction::new();
+        action.set_org_id(ORG_ID.to_string());
+        action.set_public_key(public_key.to_string());
+        action
+    }
+
+    fn delete_org_action() -> DeleteOrganizationAction {
+        let mut action = DeleteOrganizationAction::new();
+        action.set_id(ORG_ID.to_string());
+        action
+    }
+
+    fn create_delegation_action(org_id: &str, delegate_org_id: &str) -> CreateDelegationAction {
+        let mut action = CreateDelegationAction::new();
+        action.set_org_id(org_id.to_string());
+        action.set_delegate_org_id(delegate_org_id.to_string());
+        action.set_permissions(vec!["can_update_product".to_string()].into());
+        action
+    }
+}
@@ -15,9 +15,16 @@
 use crypto::digest::Digest;
 use crypto::sha2::Sha512;
 
+use grid_sdk::protocol::product::state::ProductType;
+
+use crate::validation::normalize_gtin;
+
 const GRID_ADDRESS_LEN: usize = 70;
-const GS1_NAMESPACE: &str = "01"; // Indicates GS1 standard
+const GS1_NAMESPACE: &str = "01"; // Indicates GS1 standard, products identified by GTIN
+const GLN_NAMESPACE: &str = "02"; // Indicates a GS1 Global Location Number
+const SSCC_NAMESPACE: &str = "03"; // Indicates a GS1 Serial Shipping Container Code
 const PRODUCT_NAMESPACE: &str = "02"; // Indicates product under GS1 standard
+const GRID_SCHEMA_NAMESPACE: &str = "01"; // Indicates a Grid Schema
 const GRID_NAMESPACE: &str = "621dee"; // Grid prefix
 pub const PIKE_NAMESPACE: &str = "cad11d";
 pub const PIKE_AGENT_NAMESPACE: &str = "00";
@@ -35,12 +42,34 @@ pub fn hash(to_hash: &str, num: usize) -> String {
     hash.to_string()
 }
 
-pub fn make_product_address(product_id: &str) -> String {
-    let grid_product_gs1_prefix = get_product_prefix() + PRODUCT_NAMESPACE + GS1_NAMESPACE;
+/// Returns the form of a product ID that identifies the product within its type. A GTIN may be
+/// written as a GTIN-8, -12, -13 or -14 by padding it with leading zeros, so GTINs are compared
+/// in their GTIN-14 form.
+pub fn product_key(product_type: &ProductType, product_id: &str) -> String {
+    match product_type {
+        ProductType::GS1 => normalize_gtin(product_id),
+        ProductType::GLN | ProductType::SSCC => product_id.to_string(),
+    }
+}
+
+/// Computes the address a product is stored at. Each product type has its own
+/// namespace, so the same digits registered as e.g. a GTIN-13 and a GLN do not collide.
+pub fn make_product_address(product_type: &ProductType, product_id: &str) -> String {
+    let type_namespace = match product_type {
+        ProductType::GS1 => GS1_NAMESPACE,
+        ProductType::GLN => GLN_NAMESPACE,
+        ProductType::SSCC => SSCC_NAMESPACE,
+    };
+    let grid_product_gs1_prefix = get_product_prefix() + PRODUCT_NAMESPACE + type_namespace;
     let grid_product_gs1_prefix_len = grid_product_gs1_prefix.chars().count();
     let hash_len = GRID_ADDRESS_LEN - grid_product_gs1_prefix_len;
 
-    grid_product_gs1_prefix + &hash(product_id, hash_len)
+    grid_product_gs1_prefix + &hash(&product_key(product_type, product_id), hash_len)
+}
+
+/// Computes the address a Grid Schema is stored at based on its name
+pub fn make_schema_address(name: &str) -> String {
+    get_product_prefix() + GRID_SCHEMA_NAMESPACE + &hash(name, 62)
 }
 
 /// Computes the address a Pike Agent is stored at based on its public_key
@@ -28,17 +28,21 @@ cfg_if! {
 }
 
 use grid_sdk::permissions::PermissionChecker;
+use grid_sdk::protocol::pike::state::Organization;
 use grid_sdk::protocol::product::payload::{
-    Action, ProductCreateAction, ProductDeleteAction, ProductPayload, ProductUpdateAction,
+    Action, ProductCreateAction, ProductDeleteAction, ProductPayload, ProductTransferAction,
+    ProductUpdateAction,
 };
-use grid_sdk::protocol::product::state::{ProductBuilder, ProductType};
+use grid_sdk::protocol::product::state::{Product, ProductBuilder, ProductType};
+use grid_sdk::protocol::schema::state::PropertyValue;
+use grid_sdk::protocol::schema::validation::validate_properties;
 
 use grid_sdk::protos::FromBytes;
 
 use crate::addressing::*;
 use crate::payload::validate_payload;
 use crate::state::ProductState;
-use crate::validation::validate_gtin;
+use crate::validation::{gs1_company_prefix_digits, validate_product_id};
 
 #[cfg(target_arch = "wasm32")]
 // Sabre apply must return a bool
@@ -101,8 +105,8 @@ impl ProductTransactionHandler {
             }
         };
 
-        // Check signing agent's permission
-        check_permission(perm_checker, signer, "can_create_product")?;
+        // Check signing agent's permission to create products for the owning organization
+        check_permission(perm_checker, signer, "can_create_product", owner)?;
 
         // Check that the agent has an organization associated with it
         if agent.org_id().is_empty() {
@@ -113,24 +117,15 @@ impl ProductTransactionHandler {
         }
 
         // Check if product exists in state
-        if state.get_product(product_id)?.is_some() {
+        if state.get_product(product_type, product_id)?.is_some() {
             return Err(ApplyError::InvalidTransaction(format!(
                 "Product already exists: {}",
                 product_id,
             )));
         }
 
-        // Check if the product type is a GS1 product
-        if product_type != &ProductType::GS1 {
-            return Err(ApplyError::InvalidTransaction(
-                "Invalid product type enum for product".to_string(),
-            ));
-        }
-
-        // Check if product product_id is a valid gtin
-        if let Err(e) = validate_gtin(product_id) {
-            return Err(ApplyError::InvalidTransaction(e.to_string()));
-        }
+        // Check that the product_id is a valid GS1 key for the product type
+        validate_product_id(product_type, product_id)?;
 
         // Check that the organization ID exists in state
         let org = match state.get_organization(payload.owner())? {
@@ -143,37 +138,23 @@ impl ProductTransactionHandler {
             }
         };
 
-        /* Check if the agents organization contain GS1 Company Prefix key in its metadata
-        (gs1_company_prefixes), and the prefix must match the company prefix in the product_id */
-        let gs1_company_prefix_vec = org.metadata().to_vec();
-        let gs1_company_prefix_kv = match gs1_company_prefix_vec
-            .iter()
-            .find(|kv| kv.key() == "gs1_company_prefixes")
-        {
-            Some(gs1_company_prefix_kv) => gs1_company_prefix_kv,
-            None => {
-                return Err(ApplyError::InvalidTransaction(format!(
-                    "The agents organization does not have the gs1_company_prefixes key in its metadata: {:?}",
-                    org.metadata()
-                )));
-            }
+        // Check that the organization owns the GS1 company prefix of the product_id
+        check_gs1_company_prefix(&org, product_type, product_id)?;
+
+        // Check the properties against the product's schema, if it names one
+        let schema_version = if !payload.schema().is_empty() {
+            check_schema_properties(state, payload.schema(), properties)?
+        } else {
+            0
         };
-        // If the 'gs1_company_prefixes' key is found
-        if gs1_company_prefix_kv.key().is_empty() {
-            // If the gtin identifer does not contain the organizations gs1 prefix
-            if !product_id.contains(gs1_company_prefix_kv.value()) {
-                return Err(ApplyError::InvalidTransaction(format!(
-                    "The agents organization does not own the GS1 company prefix in the GTIN product_id: {:?}",
-                    org.metadata()
-                )));
-            }
-        }
 
         let new_product = ProductBuilder::new()
             .with_product_id(product_id.to_string())
             .with_owner(owner.to_string())
             .with_product_type(product_type.clone())
             .with_properties(properties.to_vec())
+            .with_schema(payload.schema().to_string())
+            .with_schema_version(schema_version)
             .build()
             .map_err(|err| {
                 ApplyError::InvalidTransaction(format!("Cannot build product: {}", err))
@@ -193,7 +174,6 @@ impl ProductTransactionHandler {
     ) -> Result<(), ApplyError> {
         let product_id = payload.product_id();
         let product_type = payload.product_type();
-        let properties = payload.properties();
 
         // Check that the agent submitting the transactions exists in state
         let agent = match state.get_agent(signer)? {
@@ -207,7 +187,7 @@ impl ProductTransactionHandler {
         };
 
         // Check signing agent's permission
-        check_permission(perm_checker, signer, "can_update_product")?;
+        check_permission(perm_checker, signer, "can_update_product", agent.org_id())?;
 
         // Check that the agent has an organization associated with it
         if agent.org_id().is_empty() {
@@ -217,15 +197,8 @@ impl ProductTransactionHandler {
             )));
         }
 
-        // Check if the product type is a GS1 product
-        if product_type != &ProductType::GS1 {
-            return Err(ApplyError::InvalidTransaction(
-                "Invalid product type enum for product".to_string(),
-            ));
-        }
-
         // Check if product exists
-        let product = match state.get_product(product_id) {
+        let product = match state.get_product(product_type, product_id) {
             Ok(Some(product)) => Ok(product),
             Ok(None) => Err(ApplyError::InvalidTransaction(format!(
                 "No product exists: {}",
@@ -234,24 +207,67 @@ impl ProductTransactionHandler {
             Err(err) => Err(err),
         }?;
 
-        // Check if the agent updating the product is part of the organization associated with the product
-        if product.owner() != agent.org_id() {
-            return Err(ApplyError::InvalidTransaction(
-                "Invalid organization for the agent submitting this transaction".to_string(),
-            ));
-        }
+        // Check if the agent updating the product is part of the organization associated with
+        // the product, or has been delegated the permission by it
+        check_owner_or_delegate(
+            perm_checker,
+            signer,
+            "can_update_product",
+            agent.org_id(),
+            product.owner(),
+        )?;
+
+        // Check that the product_id is a valid GS1 key for the product type
+        validate_product_id(product_type, product_id)?;
+
+        // The owning organization must still hold the product's GS1 company prefix
+        let org = match state.get_organization(product.owner())? {
+            Some(org) => org,
+            None => {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "The product's organization does not exist: {}",
+                    product.owner(),
+                )));
+            }
+        };
+        check_gs1_company_prefix(&org, product_type, product_id)?;
 
-        // Check if product product_id is a valid gtin
-        if let Err(e) = validate_gtin(product_id) {
-            return Err(ApplyError::InvalidTransaction(e.to_string()));
+        // Either replace all of the product's properties or patch the current ones
+        let patching =
+            !payload.set_properties().is_empty() || !payload.remove_properties().is_empty();
+        if patching && !payload.properties().is_empty() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Cannot both replace and patch the properties of product: {}",
+                product_id
+            )));
         }
+        let properties = if patching {
+            patch_properties(
+                product.properties(),
+                payload.set_properties(),
+                payload.remove_properties(),
+            )?
+        } else {
+            payload.properties().to_vec()
+        };
+
+        // The updated properties must conform to the current version of the
+        // product's schema
+        let schema_version = if !product.schema().is_empty() {
+            check_schema_properties(state, product.schema(), &properties)?
+        } else {
+            0
+        };
 
         // Handle updating the product
         let updated_product = ProductBuilder::new()
-            .with_product_id(product_id.to_string())
+            .with_product_id(product.product_id().to_string())
             .with_owner(product.owner().to_string())
             .with_product_type(product_type.clone())
-            .with_properties(properties.to_vec())
+            .with_properties(properties)
+            .with_schema(product.schema().to_string())
+            .with_schema_version(schema_version)
+            .with_pending_owner(product.pending_owner().to_string())
             .build()
             .map_err(|err| {
                 ApplyError::InvalidTransaction(format!("Cannot build product: {}", err))
@@ -284,17 +300,63 @@ impl ProductTransactionHandler {
         };
 
         // Check signing agent's permission
-        check_permission(perm_checker, signer, "can_delete_product")?;
+        check_permission(perm_checker, signer, "can_delete_product", agent.org_id())?;
 
-        // Check if the product type is a GS1 product
-        if product_type != &ProductType::GS1 {
-            return Err(ApplyError::InvalidTransaction(
-                "Invalid product type enum for product".to_string(),
-            ));
-        }
+        // Check if product exists in state
+        let product = match state.get_product(product_type, product_id) {
+            Ok(Some(product)) => Ok(product),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No product exists: {}",
+                product_id
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        // Check that the product_id is a valid GS1 key for the product type
+        validate_product_id(product_type, product_id)?;
+
+        // Check that the owner of the products organization is the same as the agent trying to
+        // delete the product, or has delegated the permission to the agent's organization
+        check_owner_or_delegate(
+            perm_checker,
+            signer,
+            "can_delete_product",
+            agent.org_id(),
+            product.owner(),
+        )?;
+
+        // Delete the product
+        state.remove_product(product_type, product_id)?;
+        Ok(())
+    }
+
+    fn transfer_product(
+        &self,
+        payload: &ProductTransferAction,
+        state: &mut ProductState,
+        signer: &str,
+        perm_checker: &PermissionChecker,
+    ) -> Result<(), ApplyError> {
+        let product_id = payload.product_id();
+        let product_type = payload.product_type();
+        let new_owner = payload.new_owner();
+
+        // Check that the agent submitting the transactions exists in state
+        let agent = match state.get_agent(signer)? {
+            Some(agent) => agent,
+            None => {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "The signing Agent does not exist: {}",
+                    signer
+                )));
+            }
+        };
+
+        // Transfers are offered and accepted by organization admins
+        check_permission(perm_checker, signer, "admin", agent.org_id())?;
 
         // Check if product exists in state
-        let product = match state.get_product(product_id) {
+        let product = match state.get_product(product_type, product_id) {
             Ok(Some(product)) => Ok(product),
             Ok(None) => Err(ApplyError::InvalidTransaction(format!(
                 "No product exists: {}",
@@ -303,20 +365,45 @@ impl ProductTransactionHandler {
             Err(err) => Err(err),
         }?;
 
-        // Check if product product_id is a valid gtin
-        if let Err(e) = validate_gtin(product_id) {
-            return Err(ApplyError::InvalidTransaction(e.to_string()));
-        }
+        let new_org = match state.get_organization(new_owner)? {
+            Some(org) => org,
+            None => {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "The receiving organization does not exist: {}",
+                    new_owner
+                )));
+            }
+        };
 
-        // Check that the owner of the products organization is the same as the agent trying to delete the product
-        if product.owner() != agent.org_id() {
+        let pending_owner = if agent.org_id() == product.owner() {
+            if new_owner == product.owner() {
+                // The owner withdraws a pending offer
+                None
+            } else if accepts_transfers_from(&new_org, product.owner()) {
+                // The receiving organization consented to the transfer in advance
+                return complete_transfer(state, product, &new_org);
+            } else {
+                Some(new_owner)
+            }
+        } else if agent.org_id() == new_owner && product.pending_owner() == new_owner {
+            // The receiving organization accepts the pending offer
+            return complete_transfer(state, product, &new_org);
+        } else {
             return Err(ApplyError::InvalidTransaction(
                 "Invalid organization for the agent submitting this transaction".to_string(),
             ));
-        }
+        };
+
+        let offered_product = product
+            .into_builder()
+            .with_pending_owner(pending_owner.unwrap_or_default().to_string())
+            .build()
+            .map_err(|err| {
+                ApplyError::InvalidTransaction(format!("Cannot build product: {}", err))
+            })?;
+
+        state.set_product(product_id, offered_product)?;
 
-        // Delete the product
-        state.remove_product(product_id)?;
         Ok(())
     }
 }
@@ -365,6 +452,9 @@ impl TransactionHandler for ProductTransactionHandler {
             Action::ProductDelete(delete_product_payload) => {
                 self.delete_product(delete_product_payload, &mut state, signer, &perm_checker)?
             }
+            Action::ProductTransfer(transfer_product_payload) => {
+                self.transfer_product(transfer_product_payload, &mut state, signer, &perm_checker)?
+            }
         }
         Ok(())
     }
@@ -374,8 +464,9 @@ fn check_permission(
     perm_checker: &PermissionChecker,
     signer: &str,
     permission: &str,
+    org_id: &str,
 ) -> Result<(), ApplyError> {
-    match perm_checker.has_permission(signer, permission) {
+    match perm_checker.has_permission_for_org(signer, permission, org_id) {
         Ok(true) => Ok(()),
         Ok(false) => Err(ApplyError::InvalidTransaction(format!(
             "The signer does not have the {} permission: {}.",
@@ -385,6 +476,155 @@ fn check_permission(
     }
 }
 
+/// Checks that an agent may act on a resource owned by `owner`. Agents of the
+/// owning organization are allowed once their own permission has been checked;
+/// agents of other organizations need the permission delegated by the owner.
+fn check_owner_or_delegate(
+    perm_checker: &PermissionChecker,
+    signer: &str,
+    permission: &str,
+    agent_org_id: &str,
+    owner: &str,
+) -> Result<(), ApplyError> {
+    if agent_org_id == owner {
+        return Ok(());
+    }
+
+    match perm_checker.has_delegated_permission(signer, permission, owner) {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(ApplyError::InvalidTransaction(
+            "Invalid organization for the agent submitting this transaction".to_string(),
+        )),
+        Err(e) => Err(ApplyError::InvalidTransaction(format!("{}", e))),
+    }
+}
+
+/// Checks that one of the organization's GS1 company prefixes, listed comma
+/// separated in its `gs1_company_prefixes` metadata, prefixes the product_id
+fn check_gs1_company_prefix(
+    org: &Organization,
+    product_type: &ProductType,
+    product_id: &str,
+) -> Result<(), ApplyError> {
+    let gs1_company_prefixes = match org
+        .metadata()
+        .iter()
+        .find(|kv| kv.key() == "gs1_company_prefixes")
+    {
+        Some(kv) => kv.value(),
+        None => {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "The agents organization does not have the gs1_company_prefixes key in its metadata: {:?}",
+                org.metadata()
+            )));
+        }
+    };
+
+    let digits = gs1_company_prefix_digits(product_type, product_id);
+    if gs1_company_prefixes
+        .split(',')
+        .map(str::trim)
+        .filter(|prefix| !prefix.is_empty())
+        .any(|prefix| digits.starts_with(prefix))
+    {
+        Ok(())
+    } else {
+        Err(ApplyError::InvalidTransaction(format!(
+            "The agents organization does not own the GS1 company prefix in the product_id: {}",
+            product_id
+        )))
+    }
+}
+
+/// Applies a patch to a product's properties: the properties named in `remove`
+/// are dropped, and each property in `set` replaces the property of the same
+/// name or, if there is none, is appended.
+fn patch_properties(
+    current: &[PropertyValue],
+    set: &[PropertyValue],
+    remove: &[String],
+) -> Result<Vec<PropertyValue>, ApplyError> {
+    if let Some(name) = remove.iter().find(|name| {
+        !current
+            .iter()
+            .any(|property| property.name() == name.as_str())
+    }) {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Cannot remove property {}, it is not set on the product",
+            name
+        )));
+    }
+
+    let mut properties: Vec<PropertyValue> = current
+        .iter()
+        .filter(|property| !remove.iter().any(|name| name == property.name()))
+        .cloned()
+        .collect();
+
+    for property in set {
+        match properties.iter_mut().find(|p| p.name() == property.name()) {
+            Some(existing) => *existing = property.clone(),
+            None => properties.push(property.clone()),
+        }
+    }
+
+    Ok(properties)
+}
+
+/// Checks whether the organization has pre-registered its consent to receive
+/// products from `from_org`, by listing it in the comma separated
+/// `accepts_product_transfers_from` metadata
+fn accepts_transfers_from(org: &Organization, from_org: &str) -> bool {
+    org.metadata()
+        .iter()
+        .filter(|kv| kv.key() == "accepts_product_transfers_from")
+        .flat_map(|kv| kv.value().split(','))
+        .any(|org_id| org_id.trim() == from_org)
+}
+
+/// Hands the product to `new_org`. The receiving organization must hold the
+/// product's GS1 company prefix, or it would be unable to update the product.
+fn complete_transfer(
+    state: &ProductState,
+    product: Product,
+    new_org: &Organization,
+) -> Result<(), ApplyError> {
+    check_gs1_company_prefix(new_org, product.product_type(), product.product_id())?;
+
+    let product_id = product.product_id().to_string();
+    let transferred_product = product
+        .into_builder()
+        .with_owner(new_org.org_id().to_string())
+        .with_pending_owner(String::new())
+        .build()
+        .map_err(|err| ApplyError::InvalidTransaction(format!("Cannot build product: {}", err)))?;
+
+    state.set_product(&product_id, transferred_product)
+}
+
+/// Validates the properties against the named schema, returning the version of
+/// the schema they were validated against
+fn check_schema_properties(
+    state: &ProductState,
+    schema_name: &str,
+    properties: &[PropertyValue],
+) -> Result<u32, ApplyError> {
+    let schema = match state.get_schema(schema_name)? {
+        Some(schema) => schema,
+        None => {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Schema does not exist: {}",
+                schema_name
+            )));
+        }
+    };
+
+    validate_properties(schema.properties(), properties)
+        .map_err(|err| ApplyError::InvalidTransaction(err.to_string()))?;
+
+    Ok(*schema.version())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -392,19 +632,25 @@ mod tests {
     use std::cell::RefCell;
     use std::collections::HashMap;
 
+    use grid_sdk::protocol::pike::addressing::compute_delegation_address;
     use grid_sdk::protocol::pike::state::{
-        AgentBuilder, AgentListBuilder, KeyValueEntryBuilder, OrganizationBuilder,
-        OrganizationListBuilder,
+        AgentBuilder, AgentListBuilder, DelegationBuilder, DelegationListBuilder,
+        KeyValueEntryBuilder, OrganizationBuilder, OrganizationListBuilder,
     };
     use grid_sdk::protocol::product::payload::{
         ProductCreateAction, ProductCreateActionBuilder, ProductDeleteAction,
-        ProductDeleteActionBuilder, ProductUpdateAction, ProductUpdateActionBuilder,
+        ProductDeleteActionBuilder, ProductTransferActionBuilder, ProductUpdateAction,
+        ProductUpdateActionBuilder,
     };
     use grid_sdk::protocol::product::state::{
         Product, ProductBuilder, ProductListBuilder, ProductType,
     };
-    use grid_sdk::protocol::schema::state::{DataType, PropertyValue, PropertyValueBuilder};
-    use grid_sdk::protos::IntoBytes;
+    use grid_sdk::protocol::schema::state::{
+        DataType, PropertyDefinitionBuilder, PropertyValue, PropertyValueBuilder, Schema,
+        SchemaBuilder, SchemaListBuilder,
+    };
+    use grid_sdk::protos::product_payload::ProductUpdateAction as ProductUpdateActionProto;
+    use grid_sdk::protos::{IntoBytes, IntoNative, IntoProto};
 
     use sawtooth_sdk::processor::handler::{ContextError, TransactionContext};
 
@@ -412,6 +658,11 @@ mod tests {
     const PUBLIC_KEY: &str = "test_public_key";
     const PRODUCT_ID: &str = "688955434684";
     const PRODUCT_2_ID: &str = "9781981855728";
+    const GLN_PRODUCT_ID: &str = "0614141000005";
+    const GS1_COMPANY_PREFIXES: &str = "0688955,978198,0614141";
+    const SCHEMA_NAME: &str = "test_product_schema";
+    const NEW_ORG_ID: &str = "new_test_org";
+    const NEW_ORG_PUBLIC_KEY: &str = "new_test_org_public_key";
 
     #[derive(Default, Debug)]
     /// A MockTransactionContext that can be used to test ProductState
@@ -485,6 +736,64 @@ mod tests {
             self.set_state_entry(agent_address, agent_bytes).unwrap();
         }
 
+        fn add_delegate_agent(&self, public_key: &str, org_id: &str) {
+            let agent = AgentBuilder::new()
+                .with_org_id(org_id.to_string())
+                .with_public_key(public_key.to_string())
+                .with_active(true)
+                .with_roles(vec![
+                    "can_update_product".to_string(),
+                    "can_delete_product".to_string(),
+                ])
+                .build()
+                .unwrap();
+
+            let agent_list = AgentListBuilder::new()
+                .with_agents(vec![agent])
+                .build()
+                .unwrap();
+            let agent_bytes = agent_list.into_bytes().unwrap();
+            let agent_address = compute_agent_address(public_key);
+            self.set_state_entry(agent_address, agent_bytes).unwrap();
+        }
+
+        fn add_delegation(&self, delegate_org_id: &str, permissions: &[&str]) {
+            let delegation = DelegationBuilder::new()
+                .with_org_id(AGENT_ORG_ID.to_string())
+                .with_delegate_org_id(delegate_org_id.to_string())
+                .with_permissions(permissions.iter().map(|p| p.to_string()).collect())
+                .build()
+                .unwrap();
+
+            let delegation_list = DelegationListBuilder::new()
+                .with_delegations(vec![delegation])
+                .build()
+                .unwrap();
+            self.set_state_entry(
+                compute_delegation_address(AGENT_ORG_ID, delegate_org_id),
+                delegation_list.into_bytes().unwrap(),
+            )
+            .unwrap();
+        }
+
+        fn add_admin(&self, public_key: &str, org_id: &str) {
+            let agent = AgentBuilder::new()
+                .with_org_id(org_id.to_string())
+                .with_public_key(public_key.to_string())
+                .with_active(true)
+                .with_roles(vec!["admin".to_string()])
+                .build()
+                .unwrap();
+
+            let agent_list = AgentListBuilder::new()
+                .with_agents(vec![agent])
+                .build()
+                .unwrap();
+            let agent_bytes = agent_list.into_bytes().unwrap();
+            let agent_address = compute_agent_address(public_key);
+            self.set_state_entry(agent_address, agent_bytes).unwrap();
+        }
+
         fn add_agent_without_roles(&self, public_key: &str) {
             let builder = AgentBuilder::new();
             let agent = builder
@@ -503,21 +812,33 @@ mod tests {
         }
 
         fn add_org(&self, org_id: &str) {
-            // Products can only be created when there is a gs1 prefix
-            // within the product organization's metadata
-            let builder = KeyValueEntryBuilder::new();
-            let key_value = builder
-                .with_key("gs1_company_prefixes".to_string())
-                .with_value("test_value".to_string())
-                .build()
-                .unwrap();
+            // Products can only be created when the product organization's metadata
+            // holds the gs1 company prefixes of PRODUCT_ID, PRODUCT_2_ID and GLN_PRODUCT_ID
+            self.add_org_with_gs1_prefixes(org_id, GS1_COMPANY_PREFIXES);
+        }
+
+        fn add_org_with_gs1_prefixes(&self, org_id: &str, prefixes: &str) {
+            self.add_org_with_metadata(org_id, &[("gs1_company_prefixes", prefixes)]);
+        }
+
+        fn add_org_with_metadata(&self, org_id: &str, metadata: &[(&str, &str)]) {
+            let metadata = metadata
+                .iter()
+                .map(|(key, value)| {
+                    KeyValueEntryBuilder::new()
+                        .with_key(key.to_string())
+                        .with_value(value.to_string())
+                        .build()
+                        .unwrap()
+                })
+                .collect();
 
             let builder = OrganizationBuilder::new();
             let org = builder
                 .with_org_id(org_id.to_string())
                 .with_name("test_org_name".to_string())
                 .with_address("test_org_address".to_string())
-                .with_metadata(vec![key_value.clone()])
+                .with_metadata(metadata)
                 .build()
                 .unwrap();
 
@@ -556,11 +877,43 @@ mod tests {
                 .build()
                 .unwrap();
             let product_bytes = product_list.into_bytes().unwrap();
-            let product_address = make_product_address(prod_id);
+            let product_address = make_product_address(&ProductType::GS1, prod_id);
             self.set_state_entry(product_address, product_bytes)
                 .unwrap();
         }
 
+        fn add_schema(&self) {
+            self.set_schema(make_schema());
+        }
+
+        fn set_schema(&self, schema: Schema) {
+            let schema_list = SchemaListBuilder::new()
+                .with_schemas(vec![schema])
+                .build()
+                .unwrap();
+            let schema_bytes = schema_list.into_bytes().unwrap();
+            self.set_state_entry(make_schema_address(SCHEMA_NAME), schema_bytes)
+                .unwrap();
+        }
+
+        fn add_product_with_schema(&self) {
+            let product = make_product()
+                .into_builder()
+                .with_schema(SCHEMA_NAME.to_string())
+                .build()
+                .unwrap();
+            let product_list = ProductListBuilder::new()
+                .with_products(vec![product])
+                .build()
+                .unwrap();
+            let product_bytes = product_list.into_bytes().unwrap();
+            self.set_state_entry(
+                make_product_address(&ProductType::GS1, PRODUCT_ID),
+                product_bytes,
+            )
+            .unwrap();
+        }
+
         fn add_products(&self, product_ids: &[&str]) {
             let product_list = ProductListBuilder::new()
                 .with_products(make_products(product_ids))
@@ -568,8 +921,8 @@ mod tests {
                 .unwrap();
             let product_list_bytes = product_list.into_bytes().unwrap();
             let product_list_bytes_copy = product_list_bytes.clone();
-            let product_1_address = make_product_address(PRODUCT_ID);
-            let product_2_address = make_product_address(PRODUCT_2_ID);
+            let product_1_address = make_product_address(&ProductType::GS1, PRODUCT_ID);
+            let product_2_address = make_product_address(&ProductType::GS1, PRODUCT_2_ID);
             self.set_state_entries(vec![
                 (product_1_address, product_list_bytes),
                 (product_2_address, product_list_bytes_copy),
@@ -600,7 +953,7 @@ mod tests {
             .is_ok());
 
         let product = state
-            .get_product(PRODUCT_ID)
+            .get_product(&ProductType::GS1, PRODUCT_ID)
             .expect("Failed to fetch product")
             .expect("No product found");
 
@@ -608,14 +961,61 @@ mod tests {
     }
 
     #[test]
-    /// Test that ProductCreationAction is invalid if the signer is not an Agent.
-    fn test_create_product_agent_does_not_exist() {
+    /// Test that a product keyed by a GLN is validated as a GLN and stored in the GLN namespace
+    fn test_create_product_handler_valid_gln() {
         let transaction_context = MockTransactionContext::default();
+        transaction_context.add_agent(PUBLIC_KEY);
+        transaction_context.add_org(AGENT_ORG_ID);
         let perm_checker = PermissionChecker::new(&transaction_context);
         let mut state = ProductState::new(&transaction_context);
 
         let transaction_handler = ProductTransactionHandler::new();
-        let product_create_action = make_product_create_action();
+        let product_create_action = ProductCreateActionBuilder::new()
+            .with_product_id(GLN_PRODUCT_ID.to_string())
+            .with_owner(AGENT_ORG_ID.to_string())
+            .with_product_type(ProductType::GLN)
+            .with_properties(make_properties())
+            .build()
+            .expect("Failed to build ProductCreateAction");
+
+        assert!(transaction_handler
+            .create_product(
+                &product_create_action,
+                &mut state,
+                PUBLIC_KEY,
+                &perm_checker
+            )
+            .is_ok());
+
+        let product = state
+            .get_product(&ProductType::GLN, GLN_PRODUCT_ID)
+            .expect("Failed to fetch product")
+            .expect("No product found");
+        assert_eq!(product.product_type(), &ProductType::GLN);
+
+        assert!(state
+            .get_product(&ProductType::GS1, GLN_PRODUCT_ID)
+            .expect("Failed to fetch product")
+            .is_none());
+    }
+
+    #[test]
+    /// Test that ProductCreationAction is invalid if the product_id is not a valid key for its type
+    fn test_create_product_invalid_sscc() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_agent(PUBLIC_KEY);
+        transaction_context.add_org(AGENT_ORG_ID);
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let transaction_handler = ProductTransactionHandler::new();
+        let product_create_action = ProductCreateActionBuilder::new()
+            .with_product_id(PRODUCT_ID.to_string())
+            .with_owner(AGENT_ORG_ID.to_string())
+            .with_product_type(ProductType::SSCC)
+            .with_properties(make_properties())
+            .build()
+            .expect("Failed to build ProductCreateAction");
 
         match transaction_handler.create_product(
             &product_create_action,
@@ -623,19 +1023,23 @@ mod tests {
             PUBLIC_KEY,
             &perm_checker,
         ) {
-            Ok(()) => panic!("Agent should not exist, InvalidTransaction should be returned"),
-            Err(ApplyError::InvalidTransaction(err)) => {
-                assert!(err.contains(&format!("The signing Agent does not exist: {}", PUBLIC_KEY)));
+            Ok(()) => panic!("Product id is not an SSCC, InvalidTransaction should be returned"),
+            Err(ApplyError::InvalidTransaction(ref err)) => {
+                assert!(err.contains(&format!(
+                    "Invalid length for SSCC identifier: {}",
+                    PRODUCT_ID
+                )));
             }
-            Err(err) => panic!("Should have gotten invalid error but go {}", err),
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
         }
     }
 
     #[test]
-    /// Test that ProductCreationAction is invalid if the agent does not have can_create_product role
-    fn test_create_product_agent_without_roles() {
+    /// Test that ProductCreationAction is invalid if the org does not own the GTIN's GS1 company prefix
+    fn test_create_product_gs1_prefix_not_owned() {
         let transaction_context = MockTransactionContext::default();
-        transaction_context.add_agent_without_roles(PUBLIC_KEY);
+        transaction_context.add_agent(PUBLIC_KEY);
+        transaction_context.add_org_with_gs1_prefixes(AGENT_ORG_ID, "0123456,978198");
         let perm_checker = PermissionChecker::new(&transaction_context);
         let mut state = ProductState::new(&transaction_context);
 
@@ -649,15 +1053,160 @@ mod tests {
             &perm_checker,
         ) {
             Ok(()) => panic!(
-                "Agent should not have can_create_product role, InvalidTransaction should be returned"
+                "Org does not own the GS1 company prefix, InvalidTransaction should be returned"
             ),
-            Err(ApplyError::InvalidTransaction(err)) => {
+            Err(ApplyError::InvalidTransaction(ref err)) => {
                 assert!(err.contains(&format!(
-                    "The signer does not have the can_create_product permission: {}",
-                    PUBLIC_KEY
+                    "The agents organization does not own the GS1 company prefix in the product_id: {}",
+                    PRODUCT_ID
                 )));
             }
-            Err(err) => panic!("Should have gotten invalid error but go {}", err),
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+    }
+
+    #[test]
+    /// Test that a GS1 company prefix found inside the GTIN, but not leading it, is not accepted
+    fn test_create_product_gs1_prefix_not_leading() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_agent(PUBLIC_KEY);
+        transaction_context.add_org_with_gs1_prefixes(AGENT_ORG_ID, "8955434");
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let transaction_handler = ProductTransactionHandler::new();
+        let product_create_action = make_product_create_action();
+
+        match transaction_handler.create_product(
+            &product_create_action,
+            &mut state,
+            PUBLIC_KEY,
+            &perm_checker,
+        ) {
+            Ok(()) => panic!(
+                "GS1 company prefix does not lead the GTIN, InvalidTransaction should be returned"
+            ),
+            Err(ApplyError::InvalidTransaction(ref err)) => {
+                assert!(err.contains(&format!(
+                    "The agents organization does not own the GS1 company prefix in the product_id: {}",
+                    PRODUCT_ID
+                )));
+            }
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+    }
+
+    #[test]
+    /// Test that a GTIN matching any of the org's comma separated GS1 company prefixes is accepted
+    fn test_create_product_second_gs1_prefix() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_agent(PUBLIC_KEY);
+        transaction_context.add_org_with_gs1_prefixes(AGENT_ORG_ID, "0123456, 0688955");
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let transaction_handler = ProductTransactionHandler::new();
+        let product_create_action = make_product_create_action();
+
+        assert!(transaction_handler
+            .create_product(
+                &product_create_action,
+                &mut state,
+                PUBLIC_KEY,
+                &perm_checker
+            )
+            .is_ok());
+    }
+
+    #[test]
+    /// Test that ProductCreationAction is invalid if the signer is not an Agent.
+    fn test_create_product_agent_does_not_exist() {
+        let transaction_context = MockTransactionContext::default();
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let transaction_handler = ProductTransactionHandler::new();
+        let product_create_action = make_product_create_action();
+
+        match transaction_handler.create_product(
+            &product_create_action,
+            &mut state,
+            PUBLIC_KEY,
+            &perm_checker,
+        ) {
+            Ok(()) => panic!("Agent should not exist, InvalidTransaction should be returned"),
+            Err(ApplyError::InvalidTransaction(err)) => {
+                assert!(err.contains(&format!("The signing Agent does not exist: {}", PUBLIC_KEY)));
+            }
+            Err(err) => panic!("Should have gotten invalid error but go {}", err),
+        }
+    }
+
+    #[test]
+    /// Test that ProductCreationAction is invalid if the agent does not have can_create_product role
+    fn test_create_product_agent_without_roles() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_agent_without_roles(PUBLIC_KEY);
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let transaction_handler = ProductTransactionHandler::new();
+        let product_create_action = make_product_create_action();
+
+        match transaction_handler.create_product(
+            &product_create_action,
+            &mut state,
+            PUBLIC_KEY,
+            &perm_checker,
+        ) {
+            Ok(()) => panic!(
+                "Agent should not have can_create_product role, InvalidTransaction should be returned"
+            ),
+            Err(ApplyError::InvalidTransaction(err)) => {
+                assert!(err.contains(&format!(
+                    "The signer does not have the can_create_product permission: {}",
+                    PUBLIC_KEY
+                )));
+            }
+            Err(err) => panic!("Should have gotten invalid error but go {}", err),
+        }
+    }
+
+    #[test]
+    /// Test that ProductCreationAction is invalid if the product would be owned by an
+    /// organization other than the agent's
+    fn test_create_product_for_other_org() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_agent(PUBLIC_KEY);
+        transaction_context.add_org("other_org");
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let transaction_handler = ProductTransactionHandler::new();
+        let product_create_action = ProductCreateActionBuilder::new()
+            .with_product_id(PRODUCT_ID.to_string())
+            .with_owner("other_org".to_string())
+            .with_product_type(ProductType::GS1)
+            .with_properties(make_properties())
+            .build()
+            .unwrap();
+
+        match transaction_handler.create_product(
+            &product_create_action,
+            &mut state,
+            PUBLIC_KEY,
+            &perm_checker,
+        ) {
+            Ok(()) => {
+                panic!("Agent does not belong to other_org, InvalidTransaction should be returned")
+            }
+            Err(ApplyError::InvalidTransaction(err)) => {
+                assert!(err.contains(&format!(
+                    "The signer does not have the can_create_product permission: {}",
+                    PUBLIC_KEY
+                )));
+            }
+            Err(err) => panic!("Should have gotten invalid error but go {}", err),
         }
     }
 
@@ -731,17 +1280,388 @@ mod tests {
         let transaction_handler = ProductTransactionHandler::new();
         let product_create_action = make_product_create_action();
 
-        match transaction_handler.create_product(
-            &product_create_action,
+        match transaction_handler.create_product(
+            &product_create_action,
+            &mut state,
+            PUBLIC_KEY,
+            &perm_checker,
+        ) {
+            Ok(()) => panic!("Product should not exist, InvalidTransaction should be returned"),
+            Err(ApplyError::InvalidTransaction(err)) => {
+                assert!(err.contains(&format!("Product already exists: {}", PRODUCT_ID)));
+            }
+            Err(err) => panic!("Should have gotten invalid error but go {}", err),
+        }
+    }
+
+    #[test]
+    /// Test that ProductCreationAction is invalid if the same GTIN already exists in
+    /// another of its zero-padded forms.
+    fn test_create_product_already_exist_padded_gtin() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_agent(PUBLIC_KEY);
+        transaction_context.add_org(AGENT_ORG_ID);
+        transaction_context.add_product(PRODUCT_ID);
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let transaction_handler = ProductTransactionHandler::new();
+        let padded_product_id = format!("00{}", PRODUCT_ID);
+        let product_create_action = ProductCreateActionBuilder::new()
+            .with_product_id(padded_product_id.clone())
+            .with_owner(AGENT_ORG_ID.to_string())
+            .with_product_type(ProductType::GS1)
+            .with_properties(make_properties())
+            .build()
+            .expect("Failed to build ProductCreateAction");
+
+        match transaction_handler.create_product(
+            &product_create_action,
+            &mut state,
+            PUBLIC_KEY,
+            &perm_checker,
+        ) {
+            Ok(()) => panic!("Product should already exist, InvalidTransaction should be returned"),
+            Err(ApplyError::InvalidTransaction(err)) => {
+                assert!(err.contains(&format!("Product already exists: {}", padded_product_id)));
+            }
+            Err(err) => panic!("Should have gotten invalid error but go {}", err),
+        }
+    }
+
+    #[test]
+    /// Test that a product whose properties conform to its schema is created with the schema set
+    fn test_create_product_with_schema_valid() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_agent(PUBLIC_KEY);
+        transaction_context.add_org(AGENT_ORG_ID);
+        transaction_context.add_schema();
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let transaction_handler = ProductTransactionHandler::new();
+        let product_create_action = make_product_create_action_with_schema(make_properties());
+
+        transaction_handler
+            .create_product(
+                &product_create_action,
+                &mut state,
+                PUBLIC_KEY,
+                &perm_checker,
+            )
+            .expect("Product with valid schema properties should be created");
+
+        let product = state
+            .get_product(&ProductType::GS1, PRODUCT_ID)
+            .expect("Failed to fetch product")
+            .expect("No product found");
+
+        assert_eq!(product.schema(), SCHEMA_NAME);
+        assert_eq!(*product.schema_version(), 1);
+    }
+
+    #[test]
+    /// Test that ProductCreationAction is invalid if the referenced schema does not exist
+    fn test_create_product_schema_does_not_exist() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_agent(PUBLIC_KEY);
+        transaction_context.add_org(AGENT_ORG_ID);
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let transaction_handler = ProductTransactionHandler::new();
+        let product_create_action = make_product_create_action_with_schema(make_properties());
+
+        match transaction_handler.create_product(
+            &product_create_action,
+            &mut state,
+            PUBLIC_KEY,
+            &perm_checker,
+        ) {
+            Ok(()) => panic!("Schema should not exist, InvalidTransaction should be returned"),
+            Err(ApplyError::InvalidTransaction(err)) => {
+                assert!(err.contains(&format!("Schema does not exist: {}", SCHEMA_NAME)));
+            }
+            Err(err) => panic!("Should have gotten invalid error but go {}", err),
+        }
+    }
+
+    #[test]
+    /// Test that ProductCreationAction is invalid if a property has the wrong type for the schema
+    fn test_create_product_invalid_schema_property() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_agent(PUBLIC_KEY);
+        transaction_context.add_org(AGENT_ORG_ID);
+        transaction_context.add_schema();
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let transaction_handler = ProductTransactionHandler::new();
+        let price = PropertyValueBuilder::new()
+            .with_name("price".into())
+            .with_data_type(DataType::String)
+            .with_string_value("three".into())
+            .build()
+            .unwrap();
+        let mut properties = make_properties();
+        properties[1] = price;
+        let product_create_action = make_product_create_action_with_schema(properties);
+
+        match transaction_handler.create_product(
+            &product_create_action,
+            &mut state,
+            PUBLIC_KEY,
+            &perm_checker,
+        ) {
+            Ok(()) => panic!("Price has the wrong type, InvalidTransaction should be returned"),
+            Err(ApplyError::InvalidTransaction(err)) => {
+                assert!(err.contains("Value provided for price is the wrong type"));
+            }
+            Err(err) => panic!("Should have gotten invalid error but go {}", err),
+        }
+    }
+
+    #[test]
+    /// Test that ProductUpdateAction is invalid if it drops a property required by the schema
+    fn test_update_product_missing_schema_property() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_agent(PUBLIC_KEY);
+        transaction_context.add_org(AGENT_ORG_ID);
+        transaction_context.add_schema();
+        transaction_context.add_product_with_schema();
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let transaction_handler = ProductTransactionHandler::new();
+        let product_update_action = ProductUpdateActionBuilder::new()
+            .with_product_id(PRODUCT_ID.to_string())
+            .with_product_type(ProductType::GS1)
+            .with_properties(vec![make_updated_properties()[1].clone()])
+            .build()
+            .expect("Failed to build ProductUpdateAction");
+
+        match transaction_handler.update_product(
+            &product_update_action,
+            &mut state,
+            PUBLIC_KEY,
+            &perm_checker,
+        ) {
+            Ok(()) => panic!("Description is required, InvalidTransaction should be returned"),
+            Err(ApplyError::InvalidTransaction(err)) => {
+                assert!(err.contains("Required property description not provided"));
+            }
+            Err(err) => panic!("Should have gotten invalid error but go {}", err),
+        }
+    }
+
+    #[test]
+    /// Test that a patch replaces the named properties, appends new ones and removes the
+    /// unset ones, leaving the other properties as they were
+    fn test_update_product_patch_valid() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_agent(PUBLIC_KEY);
+        transaction_context.add_org(AGENT_ORG_ID);
+        transaction_context.add_product(PRODUCT_ID);
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let color = PropertyValueBuilder::new()
+            .with_name("color".into())
+            .with_data_type(DataType::String)
+            .with_string_value("red".into())
+            .build()
+            .unwrap();
+
+        let transaction_handler = ProductTransactionHandler::new();
+        let product_update_action = ProductUpdateActionBuilder::new()
+            .with_product_id(PRODUCT_ID.to_string())
+            .with_product_type(ProductType::GS1)
+            .with_set_properties(vec![make_updated_properties()[1].clone(), color.clone()])
+            .with_remove_properties(vec!["description".to_string()])
+            .build()
+            .expect("Failed to build ProductUpdateAction");
+
+        assert!(transaction_handler
+            .update_product(
+                &product_update_action,
+                &mut state,
+                PUBLIC_KEY,
+                &perm_checker
+            )
+            .is_ok());
+
+        let product = state
+            .get_product(&ProductType::GS1, PRODUCT_ID)
+            .expect("Failed to fetch product")
+            .expect("No product found");
+
+        assert_eq!(
+            product.properties(),
+            &[make_updated_properties()[1].clone(), color][..]
+        );
+    }
+
+    #[test]
+    /// Test that a patch cannot remove a property the product does not have
+    fn test_update_product_patch_remove_unknown_property() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_agent(PUBLIC_KEY);
+        transaction_context.add_org(AGENT_ORG_ID);
+        transaction_context.add_product(PRODUCT_ID);
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let transaction_handler = ProductTransactionHandler::new();
+        let product_update_action = ProductUpdateActionBuilder::new()
+            .with_product_id(PRODUCT_ID.to_string())
+            .with_product_type(ProductType::GS1)
+            .with_remove_properties(vec!["color".to_string()])
+            .build()
+            .expect("Failed to build ProductUpdateAction");
+
+        match transaction_handler.update_product(
+            &product_update_action,
+            &mut state,
+            PUBLIC_KEY,
+            &perm_checker,
+        ) {
+            Ok(()) => panic!("Product has no color, InvalidTransaction should be returned"),
+            Err(ApplyError::InvalidTransaction(err)) => {
+                assert!(err.contains("Cannot remove property color, it is not set on the product"));
+            }
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+    }
+
+    #[test]
+    /// Test that an update cannot both replace and patch the product's properties
+    fn test_update_product_replace_and_patch() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_agent(PUBLIC_KEY);
+        transaction_context.add_org(AGENT_ORG_ID);
+        transaction_context.add_product(PRODUCT_ID);
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let transaction_handler = ProductTransactionHandler::new();
+        // The builder refuses this combination, so go through the proto as a submitted
+        // payload would
+        let mut action_proto: ProductUpdateActionProto = make_product_update_action()
+            .into_proto()
+            .expect("Failed to convert ProductUpdateAction");
+        action_proto.set_remove_properties(vec!["description".to_string()].into());
+        let product_update_action: ProductUpdateAction = action_proto
+            .into_native()
+            .expect("Failed to convert ProductUpdateAction proto");
+
+        match transaction_handler.update_product(
+            &product_update_action,
+            &mut state,
+            PUBLIC_KEY,
+            &perm_checker,
+        ) {
+            Ok(()) => {
+                panic!("Update mixes properties and a patch, InvalidTransaction should be returned")
+            }
+            Err(ApplyError::InvalidTransaction(err)) => {
+                assert!(err.contains(&format!(
+                    "Cannot both replace and patch the properties of product: {}",
+                    PRODUCT_ID
+                )));
+            }
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+    }
+
+    #[test]
+    /// Test that a required property can be dropped once the schema deprecates it, and that
+    /// the product records the schema version it was validated against
+    fn test_update_product_drops_deprecated_schema_property() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_agent(PUBLIC_KEY);
+        transaction_context.add_org(AGENT_ORG_ID);
+        transaction_context.add_product_with_schema();
+
+        let schema = make_schema();
+        let properties = schema
+            .properties()
+            .iter()
+            .map(|property| {
+                property
+                    .clone()
+                    .into_builder()
+                    .with_deprecated(property.name() == "description")
+                    .build()
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        transaction_context.set_schema(
+            schema
+                .into_builder()
+                .with_version(2)
+                .with_properties(properties)
+                .build()
+                .unwrap(),
+        );
+
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let transaction_handler = ProductTransactionHandler::new();
+        let product_update_action = ProductUpdateActionBuilder::new()
+            .with_product_id(PRODUCT_ID.to_string())
+            .with_product_type(ProductType::GS1)
+            .with_remove_properties(vec!["description".to_string()])
+            .build()
+            .expect("Failed to build ProductUpdateAction");
+
+        transaction_handler
+            .update_product(
+                &product_update_action,
+                &mut state,
+                PUBLIC_KEY,
+                &perm_checker,
+            )
+            .expect("Deprecated property should no longer be required");
+
+        let product = state
+            .get_product(&ProductType::GS1, PRODUCT_ID)
+            .expect("Failed to fetch product")
+            .expect("No product found");
+
+        assert_eq!(*product.schema_version(), 2);
+    }
+
+    #[test]
+    /// Test that the patched properties are checked against the product's schema
+    fn test_update_product_patch_removes_required_schema_property() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_agent(PUBLIC_KEY);
+        transaction_context.add_org(AGENT_ORG_ID);
+        transaction_context.add_schema();
+        transaction_context.add_product_with_schema();
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let transaction_handler = ProductTransactionHandler::new();
+        let product_update_action = ProductUpdateActionBuilder::new()
+            .with_product_id(PRODUCT_ID.to_string())
+            .with_product_type(ProductType::GS1)
+            .with_remove_properties(vec!["description".to_string()])
+            .build()
+            .expect("Failed to build ProductUpdateAction");
+
+        match transaction_handler.update_product(
+            &product_update_action,
             &mut state,
             PUBLIC_KEY,
             &perm_checker,
         ) {
-            Ok(()) => panic!("Product should not exist, InvalidTransaction should be returned"),
+            Ok(()) => panic!("Description is required, InvalidTransaction should be returned"),
             Err(ApplyError::InvalidTransaction(err)) => {
-                assert!(err.contains(&format!("Product already exists: {}", PRODUCT_ID)));
+                assert!(err.contains("Required property description not provided"));
             }
-            Err(err) => panic!("Should have gotten invalid error but go {}", err),
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
         }
     }
 
@@ -763,18 +1683,51 @@ mod tests {
                 &product_update_action,
                 &mut state,
                 PUBLIC_KEY,
-                &perm_checker,
+                &perm_checker
             )
             .is_ok());
 
         let product = state
-            .get_product(PRODUCT_ID)
+            .get_product(&ProductType::GS1, PRODUCT_ID)
             .expect("Failed to fetch product")
             .expect("No product found");
 
         assert_eq!(product, make_updated_product());
     }
 
+    #[test]
+    /// Test that ProductUpdateAction is invalid if the organization no longer holds the
+    /// GS1 company prefix of the product
+    fn test_update_product_gs1_prefix_not_owned() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_agent(PUBLIC_KEY);
+        transaction_context.add_org_with_gs1_prefixes(AGENT_ORG_ID, "0123456");
+        transaction_context.add_product(PRODUCT_ID);
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let transaction_handler = ProductTransactionHandler::new();
+        let product_update_action = make_product_update_action();
+
+        match transaction_handler.update_product(
+            &product_update_action,
+            &mut state,
+            PUBLIC_KEY,
+            &perm_checker,
+        ) {
+            Ok(()) => panic!(
+                "Org does not own the GS1 company prefix, InvalidTransaction should be returned"
+            ),
+            Err(ApplyError::InvalidTransaction(ref err)) => {
+                assert!(err.contains(&format!(
+                    "The agents organization does not own the GS1 company prefix in the product_id: {}",
+                    PRODUCT_ID
+                )));
+            }
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+    }
+
     #[test]
     /// Test that ProductUpdateAction is invalid if the signer is not an Agent.
     fn test_update_product_agent_does_not_exist() {
@@ -814,7 +1767,7 @@ mod tests {
             &product_update_action,
             &mut state,
             PUBLIC_KEY,
-            &perm_checker,
+            &perm_checker
         ) {
             Ok(()) => panic!(
                 "Agent should not have can_update_product role, InvalidTransaction should be returned"
@@ -854,6 +1807,65 @@ mod tests {
         }
     }
 
+    #[test]
+    /// Test that an agent of another organization can update the product once the owning
+    /// organization delegates can_update_product to it, without being able to delete the
+    /// product
+    fn test_update_product_delegated() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_delegate_agent(NEW_ORG_PUBLIC_KEY, NEW_ORG_ID);
+        transaction_context.add_org(AGENT_ORG_ID);
+        transaction_context.add_product(PRODUCT_ID);
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let transaction_handler = ProductTransactionHandler::new();
+        let product_update_action = make_product_update_action();
+
+        match transaction_handler.update_product(
+            &product_update_action,
+            &mut state,
+            NEW_ORG_PUBLIC_KEY,
+            &perm_checker,
+        ) {
+            Ok(()) => {
+                panic!("Update should not be delegated, InvalidTransaction should be returned")
+            }
+            Err(ApplyError::InvalidTransaction(err)) => {
+                assert!(
+                    err.contains("Invalid organization for the agent submitting this transaction")
+                );
+            }
+            Err(err) => panic!("Should have gotten invalid error but go {}", err),
+        }
+
+        transaction_context.add_delegation(NEW_ORG_ID, &["can_update_product"]);
+
+        assert!(transaction_handler
+            .update_product(
+                &product_update_action,
+                &mut state,
+                NEW_ORG_PUBLIC_KEY,
+                &perm_checker
+            )
+            .is_ok());
+
+        let product = state
+            .get_product(&ProductType::GS1, PRODUCT_ID)
+            .expect("Failed to fetch product")
+            .expect("No product found");
+        assert_eq!(product, make_updated_product());
+
+        assert!(transaction_handler
+            .delete_product(
+                &make_product_delete_action(PRODUCT_ID),
+                &mut state,
+                NEW_ORG_PUBLIC_KEY,
+                &perm_checker
+            )
+            .is_err());
+    }
+
     #[test]
     /// Test that if ProductDeleteAction is valid an OK is returned and a Product is deleted from state
     fn test_delete_product_handler_valid() {
@@ -876,7 +1888,9 @@ mod tests {
             )
             .is_ok());
 
-        let product = state.get_product(PRODUCT_ID).expect("No product found");
+        let product = state
+            .get_product(&ProductType::GS1, PRODUCT_ID)
+            .expect("No product found");
 
         assert_eq!(product, None);
     }
@@ -900,11 +1914,13 @@ mod tests {
                 &product_delete_action,
                 &mut state,
                 PUBLIC_KEY,
-                &perm_checker,
+                &perm_checker
             )
             .is_ok());
 
-        let product = state.get_product(PRODUCT_2_ID).expect("No product found");
+        let product = state
+            .get_product(&ProductType::GS1, PRODUCT_2_ID)
+            .expect("No product found");
 
         assert_eq!(product, None);
     }
@@ -926,7 +1942,7 @@ mod tests {
             &product_delete_action,
             &mut state,
             PUBLIC_KEY,
-            &perm_checker,
+            &perm_checker
         ) {
             Ok(()) => panic!(
                 "Agent should not have can_delete_product role, InvalidTransaction should be returned"
@@ -1047,6 +2063,258 @@ mod tests {
         ]
     }
 
+    #[test]
+    /// Test that a transfer to an organization that has not consented leaves the product with
+    /// its owner and records the receiving organization as pending
+    fn test_transfer_product_offer() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_admin(PUBLIC_KEY, AGENT_ORG_ID);
+        transaction_context.add_org(AGENT_ORG_ID);
+        transaction_context.add_org(NEW_ORG_ID);
+        transaction_context.add_product(PRODUCT_ID);
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let transaction_handler = ProductTransactionHandler::new();
+        assert!(transaction_handler
+            .transfer_product(
+                &make_product_transfer_action(NEW_ORG_ID),
+                &mut state,
+                PUBLIC_KEY,
+                &perm_checker,
+            )
+            .is_ok());
+
+        let product = state
+            .get_product(&ProductType::GS1, PRODUCT_ID)
+            .expect("Failed to fetch product")
+            .expect("No product found");
+        assert_eq!(product.owner(), AGENT_ORG_ID);
+        assert_eq!(product.pending_owner(), NEW_ORG_ID);
+    }
+
+    #[test]
+    /// Test that an admin of the receiving organization can accept a pending transfer
+    fn test_transfer_product_accept() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_admin(PUBLIC_KEY, AGENT_ORG_ID);
+        transaction_context.add_admin(NEW_ORG_PUBLIC_KEY, NEW_ORG_ID);
+        transaction_context.add_org(AGENT_ORG_ID);
+        transaction_context.add_org(NEW_ORG_ID);
+        transaction_context.add_product(PRODUCT_ID);
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let transaction_handler = ProductTransactionHandler::new();
+        let product_transfer_action = make_product_transfer_action(NEW_ORG_ID);
+        transaction_handler
+            .transfer_product(
+                &product_transfer_action,
+                &mut state,
+                PUBLIC_KEY,
+                &perm_checker,
+            )
+            .expect("Failed to offer product");
+        transaction_handler
+            .transfer_product(
+                &product_transfer_action,
+                &mut state,
+                NEW_ORG_PUBLIC_KEY,
+                &perm_checker,
+            )
+            .expect("Failed to accept product");
+
+        let product = state
+            .get_product(&ProductType::GS1, PRODUCT_ID)
+            .expect("Failed to fetch product")
+            .expect("No product found");
+        assert_eq!(product.owner(), NEW_ORG_ID);
+        assert_eq!(product.pending_owner(), "");
+        assert_eq!(product.properties(), make_product().properties());
+    }
+
+    #[test]
+    /// Test that a transfer completes immediately if the receiving organization lists the
+    /// owner in its accepts_product_transfers_from metadata
+    fn test_transfer_product_pre_registered_consent() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_admin(PUBLIC_KEY, AGENT_ORG_ID);
+        transaction_context.add_org(AGENT_ORG_ID);
+        transaction_context.add_org_with_metadata(
+            NEW_ORG_ID,
+            &[
+                ("gs1_company_prefixes", GS1_COMPANY_PREFIXES),
+                ("accepts_product_transfers_from", "other_org, test_org"),
+            ],
+        );
+        transaction_context.add_product(PRODUCT_ID);
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let transaction_handler = ProductTransactionHandler::new();
+        assert!(transaction_handler
+            .transfer_product(
+                &make_product_transfer_action(NEW_ORG_ID),
+                &mut state,
+                PUBLIC_KEY,
+                &perm_checker,
+            )
+            .is_ok());
+
+        let product = state
+            .get_product(&ProductType::GS1, PRODUCT_ID)
+            .expect("Failed to fetch product")
+            .expect("No product found");
+        assert_eq!(product.owner(), NEW_ORG_ID);
+        assert_eq!(product.pending_owner(), "");
+    }
+
+    #[test]
+    /// Test that the owner can withdraw a pending transfer by transferring to itself
+    fn test_transfer_product_withdraw_offer() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_admin(PUBLIC_KEY, AGENT_ORG_ID);
+        transaction_context.add_admin(NEW_ORG_PUBLIC_KEY, NEW_ORG_ID);
+        transaction_context.add_org(AGENT_ORG_ID);
+        transaction_context.add_org(NEW_ORG_ID);
+        transaction_context.add_product(PRODUCT_ID);
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let transaction_handler = ProductTransactionHandler::new();
+        transaction_handler
+            .transfer_product(
+                &make_product_transfer_action(NEW_ORG_ID),
+                &mut state,
+                PUBLIC_KEY,
+                &perm_checker,
+            )
+            .expect("Failed to offer product");
+        transaction_handler
+            .transfer_product(
+                &make_product_transfer_action(AGENT_ORG_ID),
+                &mut state,
+                PUBLIC_KEY,
+                &perm_checker,
+            )
+            .expect("Failed to withdraw offer");
+
+        match transaction_handler.transfer_product(
+            &make_product_transfer_action(NEW_ORG_ID),
+            &mut state,
+            NEW_ORG_PUBLIC_KEY,
+            &perm_checker,
+        ) {
+            Ok(()) => panic!("Offer was withdrawn, InvalidTransaction should be returned"),
+            Err(ApplyError::InvalidTransaction(ref err)) => {
+                assert!(
+                    err.contains("Invalid organization for the agent submitting this transaction")
+                );
+            }
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+    }
+
+    #[test]
+    /// Test that ProductTransferAction is invalid if the signer is not an admin
+    fn test_transfer_product_not_admin() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_agent(PUBLIC_KEY);
+        transaction_context.add_org(AGENT_ORG_ID);
+        transaction_context.add_org(NEW_ORG_ID);
+        transaction_context.add_product(PRODUCT_ID);
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let transaction_handler = ProductTransactionHandler::new();
+        match transaction_handler.transfer_product(
+            &make_product_transfer_action(NEW_ORG_ID),
+            &mut state,
+            PUBLIC_KEY,
+            &perm_checker,
+        ) {
+            Ok(()) => panic!("Agent is not an admin, InvalidTransaction should be returned"),
+            Err(ApplyError::InvalidTransaction(ref err)) => {
+                assert!(err.contains(&format!(
+                    "The signer does not have the admin permission: {}",
+                    PUBLIC_KEY
+                )));
+            }
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+    }
+
+    #[test]
+    /// Test that the receiving organization cannot take a product that was not offered to it
+    fn test_transfer_product_accept_without_offer() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_admin(NEW_ORG_PUBLIC_KEY, NEW_ORG_ID);
+        transaction_context.add_org(AGENT_ORG_ID);
+        transaction_context.add_org(NEW_ORG_ID);
+        transaction_context.add_product(PRODUCT_ID);
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let transaction_handler = ProductTransactionHandler::new();
+        match transaction_handler.transfer_product(
+            &make_product_transfer_action(NEW_ORG_ID),
+            &mut state,
+            NEW_ORG_PUBLIC_KEY,
+            &perm_checker,
+        ) {
+            Ok(()) => panic!("Product was not offered, InvalidTransaction should be returned"),
+            Err(ApplyError::InvalidTransaction(ref err)) => {
+                assert!(
+                    err.contains("Invalid organization for the agent submitting this transaction")
+                );
+            }
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+    }
+
+    #[test]
+    /// Test that a transfer cannot complete if the receiving organization does not hold the
+    /// product's GS1 company prefix
+    fn test_transfer_product_receiver_missing_gs1_prefix() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_admin(PUBLIC_KEY, AGENT_ORG_ID);
+        transaction_context.add_admin(NEW_ORG_PUBLIC_KEY, NEW_ORG_ID);
+        transaction_context.add_org(AGENT_ORG_ID);
+        transaction_context.add_org_with_gs1_prefixes(NEW_ORG_ID, "0123456");
+        transaction_context.add_product(PRODUCT_ID);
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let mut state = ProductState::new(&transaction_context);
+
+        let transaction_handler = ProductTransactionHandler::new();
+        let product_transfer_action = make_product_transfer_action(NEW_ORG_ID);
+        transaction_handler
+            .transfer_product(
+                &product_transfer_action,
+                &mut state,
+                PUBLIC_KEY,
+                &perm_checker,
+            )
+            .expect("Failed to offer product");
+
+        match transaction_handler.transfer_product(
+            &product_transfer_action,
+            &mut state,
+            NEW_ORG_PUBLIC_KEY,
+            &perm_checker,
+        ) {
+            Ok(()) => panic!(
+                "Receiver lacks the GS1 company prefix, InvalidTransaction should be returned"
+            ),
+            Err(ApplyError::InvalidTransaction(ref err)) => {
+                assert!(err.contains(&format!(
+                    "The agents organization does not own the GS1 company prefix in the product_id: {}",
+                    PRODUCT_ID
+                )));
+            }
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+    }
+
     fn make_product_create_action() -> ProductCreateAction {
         ProductCreateActionBuilder::new()
             .with_product_id(PRODUCT_ID.to_string())
@@ -1057,6 +2325,42 @@ mod tests {
             .expect("Failed to build ProductCreateAction")
     }
 
+    fn make_schema() -> Schema {
+        let description = PropertyDefinitionBuilder::new()
+            .with_name("description".to_string())
+            .with_data_type(DataType::String)
+            .with_required(true)
+            .build()
+            .unwrap();
+        let price = PropertyDefinitionBuilder::new()
+            .with_name("price".to_string())
+            .with_data_type(DataType::Number)
+            .with_number_exponent(0)
+            .build()
+            .unwrap();
+
+        SchemaBuilder::new()
+            .with_name(SCHEMA_NAME.to_string())
+            .with_description("Test product schema".to_string())
+            .with_owner(AGENT_ORG_ID.to_string())
+            .with_properties(vec![description, price])
+            .build()
+            .unwrap()
+    }
+
+    fn make_product_create_action_with_schema(
+        properties: Vec<PropertyValue>,
+    ) -> ProductCreateAction {
+        ProductCreateActionBuilder::new()
+            .with_product_id(PRODUCT_ID.to_string())
+            .with_owner(AGENT_ORG_ID.to_string())
+            .with_product_type(ProductType::GS1)
+            .with_properties(properties)
+            .with_schema(SCHEMA_NAME.to_string())
+            .build()
+            .expect("Failed to build ProductCreateAction")
+    }
+
     fn make_product_update_action() -> ProductUpdateAction {
         ProductUpdateActionBuilder::new()
             .with_product_id(PRODUCT_ID.to_string())
@@ -1066,6 +2370,15 @@ mod tests {
             .expect("Failed to build ProductUpdateAction")
     }
 
+    fn make_product_transfer_action(new_owner: &str) -> ProductTransferAction {
+        ProductTransferActionBuilder::new()
+            .with_product_id(PRODUCT_ID.to_string())
+            .with_product_type(ProductType::GS1)
+            .with_new_owner(new_owner.to_string())
+            .build()
+            .expect("Failed to build ProductTransferAction")
+    }
+
     fn make_product_delete_action(product_id: &str) -> ProductDeleteAction {
         ProductDeleteActionBuilder::new()
             .with_product_id(product_id.to_string())
@@ -20,12 +20,16 @@ cfg_if! {
     }
 }
 
-use grid_sdk::protocol::product::payload::{Action, ProductCreateAction, ProductPayload};
+use grid_sdk::protocol::product::payload::{
+    Action, ProductCreateAction, ProductPayload, ProductTransferAction, ProductUpdateAction,
+};
 
 pub fn validate_payload(payload: &ProductPayload) -> Result<(), ApplyError> {
     validate_timestamp(*payload.timestamp())?;
     match payload.action() {
         Action::ProductCreate(action_payload) => validate_product_create_action(action_payload),
+        Action::ProductUpdate(action_payload) => validate_product_update_action(action_payload),
+        Action::ProductTransfer(action_payload) => validate_product_transfer_action(action_payload),
         _ => Ok(()),
     }
 }
@@ -46,6 +50,52 @@ fn validate_product_create_action(
     Ok(())
 }
 
+fn validate_product_update_action(
+    product_update_action: &ProductUpdateAction,
+) -> Result<(), ApplyError> {
+    let set_properties = product_update_action.set_properties();
+    let remove_properties = product_update_action.remove_properties();
+
+    if set_properties.is_empty() && remove_properties.is_empty() {
+        return Ok(());
+    }
+    if !product_update_action.properties().is_empty() {
+        return Err(ApplyError::InvalidTransaction(String::from(
+            "properties cannot be combined with set_properties or remove_properties",
+        )));
+    }
+
+    let mut names: Vec<&str> = set_properties
+        .iter()
+        .map(|property| property.name())
+        .chain(remove_properties.iter().map(String::as_str))
+        .collect();
+    names.sort();
+    if let Some(name) = names.windows(2).find(|pair| pair[0] == pair[1]) {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Property {} can only be set or removed once per update",
+            name[0]
+        )));
+    }
+    Ok(())
+}
+
+fn validate_product_transfer_action(
+    product_transfer_action: &ProductTransferAction,
+) -> Result<(), ApplyError> {
+    if product_transfer_action.product_id() == "" {
+        return Err(ApplyError::InvalidTransaction(String::from(
+            "product_id cannot be empty string",
+        )));
+    }
+    if product_transfer_action.new_owner() == "" {
+        return Err(ApplyError::InvalidTransaction(String::from(
+            "New owner cannot be empty string",
+        )));
+    }
+    Ok(())
+}
+
 fn validate_timestamp(timestamp: u64) -> Result<(), ApplyError> {
     match timestamp {
         0 => Err(ApplyError::InvalidTransaction(String::from(
@@ -61,9 +111,12 @@ mod tests {
 
     use grid_sdk::protos::product_payload::{
         ProductCreateAction as ProductCreateActionProto, ProductPayload as ProductPayloadProto,
-        ProductPayload_Action as ActionProto,
+        ProductPayload_Action as ActionProto, ProductUpdateAction as ProductUpdateActionProto,
     };
     use grid_sdk::protos::product_state::Product_ProductType;
+    use grid_sdk::protos::schema_state::{
+        PropertyDefinition_DataType, PropertyValue as PropertyValueProto,
+    };
     use grid_sdk::protos::IntoNative;
 
     #[test]
@@ -133,4 +186,72 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    /// Test that an error is returned if a ProductUpdateAction both replaces and patches the
+    /// product's properties
+    fn test_validate_payload_update_replace_and_patch() {
+        let mut action = make_update_action_proto();
+        action.set_properties(vec![make_string_property_proto("name")].into());
+        action.set_remove_properties(vec!["color".to_string()].into());
+
+        match validate_payload(&make_update_payload(action)) {
+            Ok(_) => panic!("Payload replaces and patches properties, should return error"),
+            Err(err) => {
+                assert!(err.to_string().contains(
+                    "properties cannot be combined with set_properties or remove_properties"
+                ));
+            }
+        }
+    }
+
+    #[test]
+    /// Test that an error is returned if a ProductUpdateAction sets and removes the same property
+    fn test_validate_payload_update_set_and_remove_same_property() {
+        let mut action = make_update_action_proto();
+        action.set_set_properties(vec![make_string_property_proto("color")].into());
+        action.set_remove_properties(vec!["color".to_string()].into());
+
+        match validate_payload(&make_update_payload(action)) {
+            Ok(_) => panic!("Payload sets and removes color, should return error"),
+            Err(err) => {
+                assert!(err
+                    .to_string()
+                    .contains("Property color can only be set or removed once per update"));
+            }
+        }
+    }
+
+    #[test]
+    /// Test that an ok is returned for a ProductUpdateAction that only patches properties
+    fn test_validate_payload_update_patch_valid() {
+        let mut action = make_update_action_proto();
+        action.set_set_properties(vec![make_string_property_proto("name")].into());
+        action.set_remove_properties(vec!["color".to_string()].into());
+
+        assert!(validate_payload(&make_update_payload(action)).is_ok());
+    }
+
+    fn make_update_action_proto() -> ProductUpdateActionProto {
+        let mut action = ProductUpdateActionProto::new();
+        action.set_product_id("688955434684".to_string());
+        action.set_product_type(Product_ProductType::GS1);
+        action
+    }
+
+    fn make_update_payload(action: ProductUpdateActionProto) -> ProductPayload {
+        let mut payload_proto = ProductPayloadProto::new();
+        payload_proto.set_action(ActionProto::PRODUCT_UPDATE);
+        payload_proto.set_timestamp(2);
+        payload_proto.set_product_update(action);
+        payload_proto.into_native().unwrap()
+    }
+
+    fn make_string_property_proto(name: &str) -> PropertyValueProto {
+        let mut property = PropertyValueProto::new();
+        property.set_name(name.to_string());
+        property.set_data_type(PropertyDefinition_DataType::STRING);
+        property.set_string_value("value".to_string());
+        property
+    }
 }
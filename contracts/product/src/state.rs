@@ -24,7 +24,8 @@ cfg_if! {
 
 use grid_sdk::protocol::pike::state::{Agent, AgentList};
 use grid_sdk::protocol::pike::state::{Organization, OrganizationList};
-use grid_sdk::protocol::product::state::{Product, ProductList, ProductListBuilder};
+use grid_sdk::protocol::product::state::{Product, ProductList, ProductListBuilder, ProductType};
+use grid_sdk::protocol::schema::state::{Schema, SchemaList};
 use grid_sdk::protos::{FromBytes, IntoBytes};
 
 use crate::addressing::*;
@@ -38,8 +39,12 @@ impl<'a> ProductState<'a> {
         ProductState { context }
     }
 
-    pub fn get_product(&self, product_id: &str) -> Result<Option<Product>, ApplyError> {
-        let address = make_product_address(product_id); //product id = gtin
+    pub fn get_product(
+        &self,
+        product_type: &ProductType,
+        product_id: &str,
+    ) -> Result<Option<Product>, ApplyError> {
+        let address = make_product_address(product_type, product_id);
         let d = self.context.get_state_entry(&address)?;
         match d {
             Some(packed) => {
@@ -53,10 +58,11 @@ impl<'a> ProductState<'a> {
                 };
 
                 // find the product with the correct id
+                let key = product_key(product_type, product_id);
                 Ok(products
                     .products()
                     .iter()
-                    .find(|p| p.product_id() == product_id)
+                    .find(|p| product_key(p.product_type(), p.product_id()) == key)
                     .cloned())
             }
             None => Ok(None),
@@ -64,7 +70,7 @@ impl<'a> ProductState<'a> {
     }
 
     pub fn set_product(&self, product_id: &str, product: Product) -> Result<(), ApplyError> {
-        let address = make_product_address(product_id);
+        let address = make_product_address(product.product_type(), product_id);
         let d = self.context.get_state_entry(&address)?;
         let mut products = match d {
             Some(packed) => match ProductList::from_bytes(packed.as_slice()) {
@@ -79,9 +85,10 @@ impl<'a> ProductState<'a> {
             None => vec![],
         };
 
+        let key = product_key(product.product_type(), product_id);
         let mut index = None;
         for (i, product) in products.iter().enumerate() {
-            if product.product_id() == product_id {
+            if product_key(product.product_type(), product.product_id()) == key {
                 index = Some(i);
                 break;
             }
@@ -114,9 +121,12 @@ impl<'a> ProductState<'a> {
         Ok(())
     }
 
-    // Currently product_id = gtin
-    pub fn remove_product(&self, product_id: &str) -> Result<(), ApplyError> {
-        let address = make_product_address(product_id);
+    pub fn remove_product(
+        &self,
+        product_type: &ProductType,
+        product_id: &str,
+    ) -> Result<(), ApplyError> {
+        let address = make_product_address(product_type, product_id);
         let d = self.context.get_state_entry(&address)?;
         let products = match d {
             Some(packed) => match ProductList::from_bytes(packed.as_slice()) {
@@ -132,9 +142,10 @@ impl<'a> ProductState<'a> {
         };
 
         // Filter out the product we are deleting
+        let key = product_key(product_type, product_id);
         let filtered_products = products
             .into_iter()
-            .filter(|p| p.product_id() != product_id)
+            .filter(|p| product_key(p.product_type(), p.product_id()) != key)
             .collect::<Vec<_>>();
 
         // If the only product at the address was the one we are removing, we can delete the entire state entry
@@ -167,6 +178,32 @@ impl<'a> ProductState<'a> {
         Ok(())
     }
 
+    /// Gets a Grid Schema. Handles retrieving the correct schema from a SchemaList.
+    pub fn get_schema(&self, schema_name: &str) -> Result<Option<Schema>, ApplyError> {
+        let address = make_schema_address(schema_name);
+        let d = self.context.get_state_entry(&address)?;
+        match d {
+            Some(packed) => {
+                let schemas = match SchemaList::from_bytes(packed.as_slice()) {
+                    Ok(schemas) => schemas,
+                    Err(err) => {
+                        return Err(ApplyError::InternalError(format!(
+                            "Cannot deserialize schema list: {:?}",
+                            err,
+                        )));
+                    }
+                };
+
+                Ok(schemas
+                    .schemas()
+                    .iter()
+                    .find(|schema| schema.name() == schema_name)
+                    .cloned())
+            }
+            None => Ok(None),
+        }
+    }
+
     /// Gets a Pike Agent. Handles retrieving the correct agent from an AgentList.
     pub fn get_agent(&self, public_key: &str) -> Result<Option<Agent>, ApplyError> {
         let address = compute_agent_address(public_key);
@@ -325,7 +362,9 @@ mod tests {
         let mut transaction_context = MockTransactionContext::default();
         let state = ProductState::new(&mut transaction_context);
 
-        let result = state.get_product("not_a_product").unwrap();
+        let result = state
+            .get_product(&ProductType::GS1, "not_a_product")
+            .unwrap();
         assert!(result.is_none())
     }
 
@@ -336,7 +375,7 @@ mod tests {
         let state = ProductState::new(&mut transaction_context);
 
         assert!(state.set_product(PRODUCT_ID, make_product()).is_ok());
-        let result = state.get_product(PRODUCT_ID).unwrap();
+        let result = state.get_product(&ProductType::GS1, PRODUCT_ID).unwrap();
         assert_eq!(result, Some(make_product()));
     }
 
@@ -20,26 +20,37 @@ cfg_if! {
     }
 }
 
+use grid_sdk::protocol::gs1::has_valid_check_digit;
+use grid_sdk::protocol::product::state::ProductType;
+
 /* The purpose of this file is to programmatically express the equation used to validate a GTIN
 It validates gtin format to avoid mistype errors similar to a credit card validation
-Check digit validation: (https://www.gs1.org/services/how-calculate-check-digit-manually) */
+Check digit validation: (https://www.gs1.org/services/how-calculate-check-digit-manually)
+The same check digit is used by the other GS1 keys (GLN, SSCC) accepted as product identifiers,
+and is computed by grid_sdk::protocol::gs1 so that Pike validates GLNs the same way */
+
+/// Validates a product_id as the GS1 key required by the product's type
+pub fn validate_product_id(product_type: &ProductType, product_id: &str) -> Result<(), ApplyError> {
+    match product_type {
+        ProductType::GS1 => validate_gtin(product_id),
+        ProductType::GLN => validate_gln(product_id),
+        ProductType::SSCC => validate_sscc(product_id),
+    }
+}
 
 // Leaving this as an extensible function, so other validation rules can be implemented by GTIN format
 pub fn validate_gtin(gtin: &str) -> Result<(), ApplyError> {
     // Check that gtin is numeric only
     if is_numeric(gtin) {
         match gtin.chars().count() {
-            // GTIN-8 is an 8-digit number used predominately outside of North America on smaller packaging (not supported)
-            8 => Err(ApplyError::InvalidTransaction(format!(
-                "Invalid GTIN, GTIN-8 is not supported at this time: {}",
-                gtin
-            ))),
+            // GTIN-8 is an 8-digit number used predominately outside of North America on smaller packaging
+            8 => check_digit_validation(gtin, "gtin"),
             // GTIN-12 is a 12-digit number used primarily in North America
-            12 => check_digit_validation(gtin),
+            12 => check_digit_validation(gtin, "gtin"),
             // GTIN-13 (it could also be a GLN or the first 13 digits of a GRAI, GDTI or GCN.) (ex: 9781981855728)
-            13 => check_digit_validation(gtin),
+            13 => check_digit_validation(gtin, "gtin"),
             // GTIN-14 is a 14-digit number used to identify trade items at various packaging levels
-            14 => check_digit_validation(gtin),
+            14 => check_digit_validation(gtin, "gtin"),
             // Invalid length
             _ => Err(ApplyError::InvalidTransaction(format!(
                 "Invalid length for GTIN identifier: {}",
@@ -54,76 +65,91 @@ pub fn validate_gtin(gtin: &str) -> Result<(), ApplyError> {
     }
 }
 
-fn check_digit_validation(gtin: &str) -> Result<(), ApplyError> {
-    let mut gtin_vec: Vec<char> = gtin.chars().collect();
-    // Remove the check digit from the gtin_vec and store it for later
-    let check_digit_char = gtin_vec
-        .pop()
-        .expect("No characters found, but string length was > 0");
-    let check_digit = convert_char_to_int(check_digit_char);
-    let mut sum = 0;
-    let mut index = 0;
-
-    if is_even(gtin_vec.len()) {
-        // For gtin-13
-        for digit in &gtin_vec {
-            if is_even(index) {
-                sum += convert_char_to_int(*digit);
-                index += 1;
-            } else {
-                sum += 3 * convert_char_to_int(*digit);
-                index += 1;
-            }
-        }
-    } else {
-        // For gtin 12, 14
-        for digit in &gtin_vec {
-            if is_even(index) {
-                sum += 3 * convert_char_to_int(*digit);
-                index += 1;
-            } else {
-                sum += convert_char_to_int(*digit);
-                index += 1;
-            }
-        }
+// A GLN is a 13-digit number identifying a party or physical location (ex: 0614141000005)
+pub fn validate_gln(gln: &str) -> Result<(), ApplyError> {
+    if !is_numeric(gln) {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Invalid format, GLN identifiers only contain numbers: {}",
+            gln
+        )));
     }
+    if gln.chars().count() != 13 {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Invalid length for GLN identifier: {}",
+            gln
+        )));
+    }
+    check_digit_validation(gln, "gln")
+}
 
-    let nearest_ten = ceiling_to_nearest_ten(sum as f32);
-    let computed_check_digit = nearest_ten - sum;
-
-    if computed_check_digit == check_digit {
-        Ok(())
-    } else {
-        Err(ApplyError::InvalidTransaction(format!(
-            "Invalid gtin, check digit validation failed: {}",
-            gtin
-        )))
+// An SSCC is an 18-digit number identifying a logistic unit such as a pallet (ex: 106141411234567897)
+pub fn validate_sscc(sscc: &str) -> Result<(), ApplyError> {
+    if !is_numeric(sscc) {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Invalid format, SSCC identifiers only contain numbers: {}",
+            sscc
+        )));
+    }
+    if sscc.chars().count() != 18 {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Invalid length for SSCC identifier: {}",
+            sscc
+        )));
     }
+    check_digit_validation(sscc, "sscc")
 }
 
-fn is_even(num: usize) -> bool {
-    num % 2 == 0
+/// Pads a GTIN-8, -12 or -13 with leading zeros to its GTIN-14 form
+pub fn normalize_gtin(gtin: &str) -> String {
+    format!("{:0>14}", gtin)
 }
 
-fn is_numeric(s: &str) -> bool {
-    s.parse::<f64>().is_ok()
+/// Returns the digits of a GS1 key that start with the GS1 Company Prefix. A GTIN
+/// normalized to GTIN-14 and an SSCC both lead with a digit (the indicator or
+/// extension digit) that is not part of the company prefix, while a GLN starts
+/// with the prefix itself.
+pub fn gs1_company_prefix_digits(product_type: &ProductType, product_id: &str) -> String {
+    match product_type {
+        ProductType::GS1 => normalize_gtin(product_id)[1..].to_string(),
+        ProductType::GLN => product_id.to_string(),
+        ProductType::SSCC => product_id.get(1..).unwrap_or_default().to_string(),
+    }
 }
 
-fn ceiling_to_nearest_ten(num: f32) -> i32 {
-    let c: f32 = num / 10.0;
-    let ceil: f32 = c.ceil();
-    let c_ceil = ceil as i32;
-    10 * c_ceil
+fn check_digit_validation(gtin: &str, identifier: &str) -> Result<(), ApplyError> {
+    if has_valid_check_digit(gtin) {
+        Ok(())
+    } else {
+        Err(ApplyError::InvalidTransaction(format!(
+            "Invalid {}, check digit validation failed: {}",
+            identifier, gtin
+        )))
+    }
 }
 
-fn convert_char_to_int(c: char) -> i32 {
-    char::to_digit(c, 10).expect("No conversion for char to i32") as i32
+fn is_numeric(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_digit())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    // This tests that the check-digit validation of the valid gtin-8: "96385074" is true
+    fn valid_gtin_8() {
+        assert!(validate_gtin("96385074").is_ok());
+    }
+
+    #[test]
+    // This tests that the check-digit validation of the valid gtin-8: "96385075" is false
+    fn invalid_gtin_8() {
+        assert_eq!(
+            validate_gtin("96385075").err().unwrap().to_string(),
+            "InvalidTransaction: Invalid gtin, check digit validation failed: 96385075"
+        );
+    }
+
     #[test]
     // This tests that the check-digit validation of the valid gtin-12: "688955434684" is true
     fn valid_gtin_12() {
@@ -200,12 +226,93 @@ mod tests {
     }
 
     #[test]
-    // This tests that a valid gtin-8 returns an error (not supported)
-    fn valid_gtin_8() {
-        assert!(validate_gtin("40170725").is_err());
+    // This tests gtins containing a decimal point, which parse as numbers but are not digits
+    fn invalid_gtin_decimal() {
+        assert_eq!(
+            validate_gtin("6889554346.4").err().unwrap().to_string(),
+            "InvalidTransaction: Invalid format, GTIN identifiers only contain numbers: 6889554346.4"
+        );
+    }
+
+    #[test]
+    // This tests that the check-digit validation of the valid gln: "0614141000005" is true
+    fn valid_gln() {
+        assert!(validate_gln("0614141000005").is_ok());
+    }
+
+    #[test]
+    // This tests that the check-digit validation of the gln: "0614141000006" is false
+    fn invalid_gln() {
+        assert_eq!(
+            validate_gln("0614141000006").err().unwrap().to_string(),
+            "InvalidTransaction: Invalid gln, check digit validation failed: 0614141000006"
+        );
+    }
+
+    #[test]
+    // This tests that a gln must be 13 digits, even if it is a valid gtin
+    fn invalid_gln_length() {
+        assert_eq!(
+            validate_gln("688955434684").err().unwrap().to_string(),
+            "InvalidTransaction: Invalid length for GLN identifier: 688955434684"
+        );
+    }
+
+    #[test]
+    // This tests that the check-digit validation of the valid sscc: "106141411234567897" is true
+    fn valid_sscc() {
+        assert!(validate_sscc("106141411234567897").is_ok());
+    }
+
+    #[test]
+    // This tests that the check-digit validation of the sscc: "106141411234567898" is false
+    fn invalid_sscc() {
+        assert_eq!(
+            validate_sscc("106141411234567898")
+                .err()
+                .unwrap()
+                .to_string(),
+            "InvalidTransaction: Invalid sscc, check digit validation failed: 106141411234567898"
+        );
+    }
+
+    #[test]
+    // This tests that the product type selects which identifier the product_id is validated as
+    fn validate_product_id_by_type() {
+        assert!(validate_product_id(&ProductType::GS1, "96385074").is_ok());
+        assert!(validate_product_id(&ProductType::GLN, "0614141000005").is_ok());
+        assert!(validate_product_id(&ProductType::SSCC, "106141411234567897").is_ok());
+        assert!(validate_product_id(&ProductType::GLN, "106141411234567897").is_err());
+        assert!(validate_product_id(&ProductType::SSCC, "0614141000005").is_err());
+    }
+
+    #[test]
+    // This tests that shorter gtins are padded to gtin-14
+    fn normalize_gtin_to_14() {
+        assert_eq!(normalize_gtin("96385074"), "00000096385074");
+        assert_eq!(normalize_gtin("688955434684"), "00688955434684");
+        assert_eq!(normalize_gtin("9781981855728"), "09781981855728");
+        assert_eq!(normalize_gtin("10012345678902"), "10012345678902");
+    }
+
+    #[test]
+    // This tests that the indicator and extension digits are skipped before the company prefix
+    fn company_prefix_digits() {
+        assert_eq!(
+            gs1_company_prefix_digits(&ProductType::GS1, "688955434684"),
+            "0688955434684"
+        );
+        assert_eq!(
+            gs1_company_prefix_digits(&ProductType::GS1, "10012345678902"),
+            "0012345678902"
+        );
+        assert_eq!(
+            gs1_company_prefix_digits(&ProductType::GLN, "0614141000005"),
+            "0614141000005"
+        );
         assert_eq!(
-            validate_gtin("40170725").err().unwrap().to_string(),
-            "InvalidTransaction: Invalid GTIN, GTIN-8 is not supported at this time: 40170725"
+            gs1_company_prefix_digits(&ProductType::SSCC, "106141411234567897"),
+            "06141411234567897"
         );
     }
 }
@@ -29,9 +29,12 @@ cfg_if! {
 
 use grid_sdk::permissions::PermissionChecker;
 use grid_sdk::protocol::schema::payload::{
-    Action, SchemaCreateAction, SchemaPayload, SchemaUpdateAction,
+    Action, SchemaAddEditorAction, SchemaCreateAction, SchemaPayload, SchemaRemoveEditorAction,
+    SchemaTransferAction, SchemaUpdateAction,
+};
+use grid_sdk::protocol::schema::state::{
+    DataType, PropertyDefinition, PropertyDefinitionBuilder, Schema, SchemaBuilder,
 };
-use grid_sdk::protocol::schema::state::SchemaBuilder;
 use grid_sdk::protos::FromBytes;
 
 use crate::payload::validate_payload;
@@ -116,6 +119,15 @@ impl TransactionHandler for GridSchemaTransactionHandler {
             Action::SchemaUpdate(schema_update_payload) => {
                 schema_update(schema_update_payload, signer, &state, &perm_checker)
             }
+            Action::SchemaAddEditor(schema_add_editor_payload) => {
+                schema_add_editor(schema_add_editor_payload, signer, &state, &perm_checker)
+            }
+            Action::SchemaRemoveEditor(schema_remove_editor_payload) => {
+                schema_remove_editor(schema_remove_editor_payload, signer, &state, &perm_checker)
+            }
+            Action::SchemaTransfer(schema_transfer_payload) => {
+                schema_transfer(schema_transfer_payload, signer, &state, &perm_checker)
+            }
         }
     }
 }
@@ -137,8 +149,6 @@ fn schema_create(
         )));
     }
 
-    check_permission(perm_checker, signer, "can_create_schema")?;
-
     let agent = match state.get_agent(signer)? {
         Some(agent) => agent,
         None => {
@@ -156,10 +166,13 @@ fn schema_create(
         )));
     }
 
+    check_permission(perm_checker, signer, "can_create_schema", agent.org_id())?;
+
     let schema = SchemaBuilder::new()
         .with_name(schema_name.into())
         .with_description(description.into())
         .with_owner(agent.org_id().into())
+        .with_version(1)
         .with_properties(properties.to_vec())
         .build()
         .map_err(|err| ApplyError::InvalidTransaction(format!("Cannot build schema: {}", err)))?;
@@ -186,8 +199,6 @@ fn schema_update(
         }
     };
 
-    check_permission(perm_checker, signer, "can_update_schema")?;
-
     let agent = match state.get_agent(signer)? {
         Some(agent) => agent,
         None => {
@@ -205,7 +216,13 @@ fn schema_update(
         )));
     }
 
-    if agent.org_id() != schema.owner() {
+    // Agents of editor organizations act for their own organization
+    check_permission(perm_checker, signer, "can_update_schema", agent.org_id())?;
+
+    if agent.org_id() != schema.owner()
+        && !schema.editors().iter().any(|e| e == agent.org_id())
+        && !has_delegated_permission(perm_checker, signer, "can_update_schema", schema.owner())?
+    {
         return Err(ApplyError::InvalidTransaction(format!(
             "The signer does not belong to the correct organization: {} != {}",
             agent.org_id(),
@@ -227,25 +244,270 @@ fn schema_update(
             )));
         }
     }
-    properties.append(&mut new_properties);
 
-    let schema = SchemaBuilder::new()
-        .with_name(schema.name().into())
-        .with_description(schema.description().into())
-        .with_owner(schema.owner().into())
-        .with_properties(properties)
+    // Existing definitions may only change in ways that keep every record and
+    // product validated against an earlier version valid
+    for name in payload.deprecate_properties() {
+        modify_property(&mut properties, name, |property| {
+            Ok(property.into_builder().with_deprecated(true))
+        })?;
+    }
+
+    for name in payload.optional_properties() {
+        modify_property(&mut properties, name, |property| {
+            Ok(property.into_builder().with_required(false))
+        })?;
+    }
+
+    for update in payload.add_enum_options() {
+        modify_property(&mut properties, update.property_name(), |property| {
+            if *property.data_type() != DataType::Enum {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Cannot add enum options to {}, it is not an enum property",
+                    property.name()
+                )));
+            }
+            let mut enum_options = property.enum_options().to_vec();
+            for option in update.enum_options() {
+                if enum_options.contains(option) {
+                    return Err(ApplyError::InvalidTransaction(format!(
+                        "Property {} already has enum option {}",
+                        property.name(),
+                        option
+                    )));
+                }
+                enum_options.push(option.to_string());
+            }
+            Ok(property.into_builder().with_enum_options(enum_options))
+        })?;
+    }
+
+    // Keep the schema's original property order, with new properties last
+    let mut updated_properties = schema
+        .properties()
+        .iter()
+        .map(|original| {
+            properties
+                .iter()
+                .find(|p| p.name() == original.name())
+                .cloned()
+                .unwrap_or_else(|| original.clone())
+        })
+        .collect::<Vec<_>>();
+    updated_properties.append(&mut new_properties);
+
+    let version = schema.version() + 1;
+    let schema = schema
+        .into_builder()
+        .with_version(version)
+        .with_properties(updated_properties)
+        .build()
+        .map_err(|err| ApplyError::InvalidTransaction(format!("Cannot build schema: {}", err)))?;
+
+    state.set_schema(schema_name, schema)
+}
+
+fn schema_add_editor(
+    payload: &SchemaAddEditorAction,
+    signer: &str,
+    state: &GridSchemaState,
+    perm_checker: &PermissionChecker,
+) -> Result<(), ApplyError> {
+    let schema_name = payload.schema_name();
+    let editor = payload.editor();
+
+    let schema = get_schema_for_owner_admin(schema_name, signer, state, perm_checker)?;
+
+    if state.get_organization(editor)?.is_none() {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Organization {} does not exist",
+            editor
+        )));
+    }
+
+    if editor == schema.owner() {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Organization {} owns schema {} and cannot be added as an editor",
+            editor, schema_name
+        )));
+    }
+
+    let mut editors = schema.editors().to_vec();
+    if editors.iter().any(|e| e == editor) {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Organization {} is already an editor of schema {}",
+            editor, schema_name
+        )));
+    }
+    editors.push(editor.to_string());
+
+    let schema = schema
+        .into_builder()
+        .with_editors(editors)
         .build()
         .map_err(|err| ApplyError::InvalidTransaction(format!("Cannot build schema: {}", err)))?;
 
     state.set_schema(schema_name, schema)
 }
 
+fn schema_remove_editor(
+    payload: &SchemaRemoveEditorAction,
+    signer: &str,
+    state: &GridSchemaState,
+    perm_checker: &PermissionChecker,
+) -> Result<(), ApplyError> {
+    let schema_name = payload.schema_name();
+    let editor = payload.editor();
+
+    let schema = get_schema_for_owner_admin(schema_name, signer, state, perm_checker)?;
+
+    if !schema.editors().iter().any(|e| e == editor) {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Organization {} is not an editor of schema {}",
+            editor, schema_name
+        )));
+    }
+
+    let editors = schema
+        .editors()
+        .iter()
+        .filter(|e| *e != editor)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let schema = schema
+        .into_builder()
+        .with_editors(editors)
+        .build()
+        .map_err(|err| ApplyError::InvalidTransaction(format!("Cannot build schema: {}", err)))?;
+
+    state.set_schema(schema_name, schema)
+}
+
+fn schema_transfer(
+    payload: &SchemaTransferAction,
+    signer: &str,
+    state: &GridSchemaState,
+    perm_checker: &PermissionChecker,
+) -> Result<(), ApplyError> {
+    let schema_name = payload.schema_name();
+    let new_owner = payload.new_owner();
+
+    let schema = get_schema_for_owner_admin(schema_name, signer, state, perm_checker)?;
+
+    if new_owner == schema.owner() {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Schema {} is already owned by {}",
+            schema_name, new_owner
+        )));
+    }
+
+    if state.get_organization(new_owner)?.is_none() {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Organization {} does not exist",
+            new_owner
+        )));
+    }
+
+    // The new owner no longer needs to be listed as an editor
+    let editors = schema
+        .editors()
+        .iter()
+        .filter(|e| *e != new_owner)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let schema = schema
+        .into_builder()
+        .with_owner(new_owner.to_string())
+        .with_editors(editors)
+        .build()
+        .map_err(|err| ApplyError::InvalidTransaction(format!("Cannot build schema: {}", err)))?;
+
+    state.set_schema(schema_name, schema)
+}
+
+/// Fetches a schema that the signer may administer. Editors and ownership can only be changed
+/// by an active admin Agent of the owning organization.
+fn get_schema_for_owner_admin(
+    schema_name: &str,
+    signer: &str,
+    state: &GridSchemaState,
+    perm_checker: &PermissionChecker,
+) -> Result<Schema, ApplyError> {
+    let schema = match state.get_schema(schema_name)? {
+        Some(schema) => schema,
+        None => {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Schema with name {} does not exist",
+                schema_name
+            )));
+        }
+    };
+
+    let agent = match state.get_agent(signer)? {
+        Some(agent) => agent,
+        None => {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "The signer is not an Agent: {}",
+                signer
+            )));
+        }
+    };
+
+    if !agent.active() {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "The signer is not an active Agent: {}",
+            signer
+        )));
+    }
+
+    if agent.org_id() != schema.owner() {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "The signer does not belong to the correct organization: {} != {}",
+            agent.org_id(),
+            schema.owner()
+        )));
+    }
+
+    check_permission(perm_checker, signer, "admin", schema.owner())?;
+
+    Ok(schema)
+}
+
+/// Replaces the named definition in a list sorted by name with the result of
+/// `modify`
+fn modify_property<F>(
+    properties: &mut [PropertyDefinition],
+    name: &str,
+    modify: F,
+) -> Result<(), ApplyError>
+where
+    F: FnOnce(PropertyDefinition) -> Result<PropertyDefinitionBuilder, ApplyError>,
+{
+    let index = properties
+        .binary_search_by_key(&name, |p| p.name())
+        .map_err(|_| {
+            ApplyError::InvalidTransaction(format!(
+                "Schema does not have PropertyDefinition with name {}",
+                name
+            ))
+        })?;
+
+    properties[index] = modify(properties[index].clone())?.build().map_err(|err| {
+        ApplyError::InvalidTransaction(format!("Cannot build property definition: {}", err))
+    })?;
+
+    Ok(())
+}
+
 fn check_permission(
     perm_checker: &PermissionChecker,
     signer: &str,
     permission: &str,
+    org_id: &str,
 ) -> Result<(), ApplyError> {
-    match perm_checker.has_permission(signer, permission) {
+    match perm_checker.has_permission_for_org(signer, permission, org_id) {
         Ok(true) => Ok(()),
         Ok(false) => Err(ApplyError::InvalidTransaction(format!(
             "The signer does not have the {} permission: {}.",
@@ -255,6 +517,17 @@ fn check_permission(
     }
 }
 
+fn has_delegated_permission(
+    perm_checker: &PermissionChecker,
+    signer: &str,
+    permission: &str,
+    org_id: &str,
+) -> Result<bool, ApplyError> {
+    perm_checker
+        .has_delegated_permission(signer, permission, org_id)
+        .map_err(|e| ApplyError::InvalidTransaction(format!("{}", e)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -262,8 +535,15 @@ mod tests {
     use std::cell::RefCell;
     use std::collections::HashMap;
 
-    use grid_sdk::protocol::pike::state::{AgentBuilder, AgentListBuilder};
-    use grid_sdk::protocol::schema::payload::{SchemaCreateBuilder, SchemaUpdateBuilder};
+    use grid_sdk::protocol::pike::addressing::compute_delegation_address;
+    use grid_sdk::protocol::pike::state::{
+        AgentBuilder, AgentListBuilder, DelegationBuilder, DelegationListBuilder,
+        OrganizationBuilder, OrganizationListBuilder,
+    };
+    use grid_sdk::protocol::schema::payload::{
+        EnumOptionsUpdateBuilder, SchemaAddEditorBuilder, SchemaCreateBuilder,
+        SchemaRemoveEditorBuilder, SchemaTransferBuilder, SchemaUpdateBuilder,
+    };
     use grid_sdk::protocol::schema::state::{
         DataType, PropertyDefinitionBuilder, SchemaBuilder, SchemaListBuilder,
     };
@@ -271,7 +551,7 @@ mod tests {
     use sawtooth_sdk::processor::handler::ApplyError;
     use sawtooth_sdk::processor::handler::{ContextError, TransactionContext};
 
-    use crate::state::{compute_agent_address, compute_schema_address};
+    use crate::state::{compute_agent_address, compute_org_address, compute_schema_address};
 
     #[derive(Default)]
     /// A MockTransactionContext that can be used to test GridSchemaState
@@ -400,6 +680,73 @@ mod tests {
             self.set_state_entry(agent_address, agent_bytes).unwrap();
         }
 
+        fn add_admin_agent(&self) {
+            let builder = AgentBuilder::new();
+            let agent = builder
+                .with_org_id("test_org".to_string())
+                .with_public_key("agent_public_key".to_string())
+                .with_active(true)
+                .with_roles(vec!["admin".to_string(), "can_update_schema".to_string()])
+                .build()
+                .unwrap();
+
+            let builder = AgentListBuilder::new();
+            let agent_list = builder.with_agents(vec![agent.clone()]).build().unwrap();
+            let agent_bytes = agent_list.into_bytes().unwrap();
+            let agent_address = compute_agent_address("agent_public_key");
+            self.set_state_entry(agent_address, agent_bytes).unwrap();
+        }
+
+        fn add_org(&self, org_id: &str) {
+            let builder = OrganizationBuilder::new();
+            let org = builder
+                .with_org_id(org_id.to_string())
+                .with_name("test_org_name".to_string())
+                .with_address("test_org_address".to_string())
+                .build()
+                .unwrap();
+
+            let builder = OrganizationListBuilder::new();
+            let org_list = builder.with_organizations(vec![org]).build().unwrap();
+            let org_bytes = org_list.into_bytes().unwrap();
+            let org_address = compute_org_address(org_id);
+            self.set_state_entry(org_address, org_bytes).unwrap();
+        }
+
+        fn add_schema_editor(&self, editor: &str) {
+            let schema_address = compute_schema_address("TestSchema");
+            let schema = GridSchemaState::new(self)
+                .get_schema("TestSchema")
+                .unwrap()
+                .unwrap()
+                .into_builder()
+                .with_editors(vec![editor.to_string()])
+                .build()
+                .unwrap();
+
+            let builder = SchemaListBuilder::new();
+            let schema_list = builder.with_schemas(vec![schema]).build().unwrap();
+            let schema_bytes = schema_list.into_bytes().unwrap();
+            self.set_state_entry(schema_address, schema_bytes).unwrap();
+        }
+
+        fn add_delegation(&self, delegate_org_id: &str) {
+            let delegation = DelegationBuilder::new()
+                .with_org_id("test_org".to_string())
+                .with_delegate_org_id(delegate_org_id.to_string())
+                .with_permissions(vec!["can_update_schema".to_string()])
+                .build()
+                .unwrap();
+
+            let delegation_list = DelegationListBuilder::new()
+                .with_delegations(vec![delegation])
+                .build()
+                .unwrap();
+            let delegation_address = compute_delegation_address("test_org", delegate_org_id);
+            self.set_state_entry(delegation_address, delegation_list.into_bytes().unwrap())
+                .unwrap();
+        }
+
         fn add_schema(&self) {
             let builder = PropertyDefinitionBuilder::new();
             let property_definition = builder
@@ -833,4 +1180,312 @@ mod tests {
 
         assert!(schema_update(&action, signer, &state, &perm_checker).is_ok());
     }
+
+    #[test]
+    // Test that a SchemaUpdateAction can deprecate a property, make it optional and append
+    // enum options, and that each update bumps the schema version
+    fn test_update_schema_handler_modify_existing_property() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_schema();
+        transaction_context.add_agent();
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let state = GridSchemaState::new(&transaction_context);
+        let signer = "agent_public_key";
+
+        let enum_options_update = EnumOptionsUpdateBuilder::new()
+            .with_property_name("TEST".to_string())
+            .with_enum_options(vec!["Four".to_string()])
+            .build()
+            .unwrap();
+
+        let builder = SchemaUpdateBuilder::new();
+        let action = builder
+            .with_schema_name("TestSchema".to_string())
+            .with_deprecate_properties(vec!["TEST".to_string()])
+            .with_optional_properties(vec!["TEST".to_string()])
+            .with_add_enum_options(vec![enum_options_update])
+            .build()
+            .unwrap();
+
+        assert!(schema_update(&action, signer, &state, &perm_checker).is_ok());
+
+        let schema = state
+            .get_schema("TestSchema")
+            .expect("Failed to fetch schema")
+            .expect("Schema not found");
+        assert_eq!(*schema.version(), 2);
+
+        let property = &schema.properties()[0];
+        assert!(*property.deprecated());
+        assert!(!*property.required());
+        assert_eq!(
+            property.enum_options(),
+            &[
+                "One".to_string(),
+                "Two".to_string(),
+                "Three".to_string(),
+                "Four".to_string()
+            ][..]
+        );
+    }
+
+    #[test]
+    // Test that changing a property the schema does not have returns an InvalidTransaction
+    fn test_update_schema_handler_modify_missing_property() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_schema();
+        transaction_context.add_agent();
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let state = GridSchemaState::new(&transaction_context);
+        let signer = "agent_public_key";
+
+        let builder = SchemaUpdateBuilder::new();
+        let action = builder
+            .with_schema_name("TestSchema".to_string())
+            .with_deprecate_properties(vec!["MISSING".to_string()])
+            .build()
+            .unwrap();
+
+        match schema_update(&action, signer, &state, &perm_checker) {
+            Ok(()) => panic!("Property does not exist, InvalidTransaction should be returned"),
+            Err(ApplyError::InvalidTransaction(err)) => {
+                assert!(err.contains("Schema does not have PropertyDefinition with name MISSING"));
+            }
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+    }
+
+    #[test]
+    // Test that adding an enum option the property already has returns an InvalidTransaction
+    fn test_update_schema_handler_duplicate_enum_option() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_schema();
+        transaction_context.add_agent();
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let state = GridSchemaState::new(&transaction_context);
+        let signer = "agent_public_key";
+
+        let enum_options_update = EnumOptionsUpdateBuilder::new()
+            .with_property_name("TEST".to_string())
+            .with_enum_options(vec!["Two".to_string()])
+            .build()
+            .unwrap();
+
+        let builder = SchemaUpdateBuilder::new();
+        let action = builder
+            .with_schema_name("TestSchema".to_string())
+            .with_add_enum_options(vec![enum_options_update])
+            .build()
+            .unwrap();
+
+        match schema_update(&action, signer, &state, &perm_checker) {
+            Ok(()) => panic!("Enum option already exists, InvalidTransaction should be returned"),
+            Err(ApplyError::InvalidTransaction(err)) => {
+                assert!(err.contains("Property TEST already has enum option Two"));
+            }
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+    }
+
+    #[test]
+    // Test that an agent of an editor organization may update a schema it does not own
+    fn test_update_schema_handler_editor_org() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_schema();
+        transaction_context.add_schema_editor("wrong_org");
+        transaction_context.add_agent_wrong_organization();
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let state = GridSchemaState::new(&transaction_context);
+        let signer = "agent_public_key";
+
+        let builder = PropertyDefinitionBuilder::new();
+        let property_definition = builder
+            .with_name("NEW".to_string())
+            .with_data_type(DataType::String)
+            .with_description("Optional".to_string())
+            .build()
+            .unwrap();
+
+        let builder = SchemaUpdateBuilder::new();
+        let action = builder
+            .with_schema_name("TestSchema".to_string())
+            .with_properties(vec![property_definition.clone()])
+            .build()
+            .unwrap();
+
+        assert!(schema_update(&action, signer, &state, &perm_checker).is_ok());
+
+        let schema = state.get_schema("TestSchema").unwrap().unwrap();
+        assert_eq!(schema.owner(), "test_org");
+        assert_eq!(schema.properties().len(), 2);
+    }
+
+    #[test]
+    // Test that an agent of an organization the owner delegated can_update_schema to can
+    // update the schema
+    fn test_update_schema_handler_delegated() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_schema();
+        transaction_context.add_delegation("wrong_org");
+        transaction_context.add_agent_wrong_organization();
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let state = GridSchemaState::new(&transaction_context);
+        let signer = "agent_public_key";
+
+        let property_definition = PropertyDefinitionBuilder::new()
+            .with_name("NEW".to_string())
+            .with_data_type(DataType::String)
+            .with_description("Optional".to_string())
+            .build()
+            .unwrap();
+
+        let action = SchemaUpdateBuilder::new()
+            .with_schema_name("TestSchema".to_string())
+            .with_properties(vec![property_definition])
+            .build()
+            .unwrap();
+
+        assert!(schema_update(&action, signer, &state, &perm_checker).is_ok());
+
+        let schema = state.get_schema("TestSchema").unwrap().unwrap();
+        assert_eq!(schema.owner(), "test_org");
+        assert_eq!(schema.properties().len(), 2);
+    }
+
+    #[test]
+    // Test that an admin of the owning organization can add and then remove an editor
+    fn test_schema_add_and_remove_editor_valid() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_schema();
+        transaction_context.add_admin_agent();
+        transaction_context.add_org("editor_org");
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let state = GridSchemaState::new(&transaction_context);
+        let signer = "agent_public_key";
+
+        let action = SchemaAddEditorBuilder::new()
+            .with_schema_name("TestSchema".to_string())
+            .with_editor("editor_org".to_string())
+            .build()
+            .unwrap();
+
+        assert!(schema_add_editor(&action, signer, &state, &perm_checker).is_ok());
+        let schema = state.get_schema("TestSchema").unwrap().unwrap();
+        assert_eq!(schema.editors(), &["editor_org".to_string()]);
+        assert_eq!(*schema.version(), 1);
+
+        match schema_add_editor(&action, signer, &state, &perm_checker) {
+            Ok(()) => panic!("Editor already added, InvalidTransaction should be returned"),
+            Err(ApplyError::InvalidTransaction(err)) => {
+                assert!(err.contains("Organization editor_org is already an editor"));
+            }
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+
+        let action = SchemaRemoveEditorBuilder::new()
+            .with_schema_name("TestSchema".to_string())
+            .with_editor("editor_org".to_string())
+            .build()
+            .unwrap();
+
+        assert!(schema_remove_editor(&action, signer, &state, &perm_checker).is_ok());
+        let schema = state.get_schema("TestSchema").unwrap().unwrap();
+        assert!(schema.editors().is_empty());
+
+        match schema_remove_editor(&action, signer, &state, &perm_checker) {
+            Ok(()) => panic!("Editor already removed, InvalidTransaction should be returned"),
+            Err(ApplyError::InvalidTransaction(err)) => {
+                assert!(err.contains("Organization editor_org is not an editor"));
+            }
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+    }
+
+    #[test]
+    // Test that an editor organization must exist in Pike
+    fn test_schema_add_editor_missing_org() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_schema();
+        transaction_context.add_admin_agent();
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let state = GridSchemaState::new(&transaction_context);
+        let signer = "agent_public_key";
+
+        let action = SchemaAddEditorBuilder::new()
+            .with_schema_name("TestSchema".to_string())
+            .with_editor("editor_org".to_string())
+            .build()
+            .unwrap();
+
+        match schema_add_editor(&action, signer, &state, &perm_checker) {
+            Ok(()) => panic!("Organization does not exist, InvalidTransaction should be returned"),
+            Err(ApplyError::InvalidTransaction(err)) => {
+                assert!(err.contains("Organization editor_org does not exist"));
+            }
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+    }
+
+    #[test]
+    // Test that only agents with the admin permission can change a schema's editors
+    fn test_schema_add_editor_not_admin() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_schema();
+        transaction_context.add_agent();
+        transaction_context.add_org("editor_org");
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let state = GridSchemaState::new(&transaction_context);
+        let signer = "agent_public_key";
+
+        let action = SchemaAddEditorBuilder::new()
+            .with_schema_name("TestSchema".to_string())
+            .with_editor("editor_org".to_string())
+            .build()
+            .unwrap();
+
+        match schema_add_editor(&action, signer, &state, &perm_checker) {
+            Ok(()) => panic!("Agent is not an admin, InvalidTransaction should be returned"),
+            Err(ApplyError::InvalidTransaction(err)) => {
+                assert!(err.contains("The signer does not have the admin permission"));
+            }
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+    }
+
+    #[test]
+    // Test that transferring a schema changes its owner and drops the new owner from the
+    // editors, after which the previous owner can no longer manage it
+    fn test_schema_transfer_valid() {
+        let transaction_context = MockTransactionContext::default();
+        transaction_context.add_schema();
+        transaction_context.add_schema_editor("new_org");
+        transaction_context.add_admin_agent();
+        transaction_context.add_org("new_org");
+        let perm_checker = PermissionChecker::new(&transaction_context);
+        let state = GridSchemaState::new(&transaction_context);
+        let signer = "agent_public_key";
+
+        let action = SchemaTransferBuilder::new()
+            .with_schema_name("TestSchema".to_string())
+            .with_new_owner("new_org".to_string())
+            .build()
+            .unwrap();
+
+        assert!(schema_transfer(&action, signer, &state, &perm_checker).is_ok());
+        let schema = state.get_schema("TestSchema").unwrap().unwrap();
+        assert_eq!(schema.owner(), "new_org");
+        assert!(schema.editors().is_empty());
+
+        match schema_transfer(&action, signer, &state, &perm_checker) {
+            Ok(()) => {
+                panic!("Signer no longer owns the schema, InvalidTransaction should be returned")
+            }
+            Err(ApplyError::InvalidTransaction(err)) => {
+                assert!(err.contains(
+                    "The signer does not belong to the correct organization: test_org != new_org"
+                ));
+            }
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+    }
 }
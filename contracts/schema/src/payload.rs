@@ -21,13 +21,20 @@ cfg_if! {
 }
 
 use grid_sdk::protocol::schema::payload::{
-    Action, SchemaCreateAction, SchemaPayload, SchemaUpdateAction,
+    Action, SchemaCreateAction, SchemaPayload, SchemaTransferAction, SchemaUpdateAction,
 };
 
 pub fn validate_payload(payload: &SchemaPayload) -> Result<(), ApplyError> {
     match payload.action() {
         Action::SchemaCreate(payload) => validate_schema_create_action(payload),
         Action::SchemaUpdate(payload) => validate_schema_update_action(payload),
+        Action::SchemaAddEditor(payload) => {
+            validate_editor_change(payload.schema_name(), payload.editor())
+        }
+        Action::SchemaRemoveEditor(payload) => {
+            validate_editor_change(payload.schema_name(), payload.editor())
+        }
+        Action::SchemaTransfer(payload) => validate_schema_transfer_action(payload),
     }
 }
 
@@ -53,9 +60,54 @@ fn validate_schema_update_action(update_action: &SchemaUpdateAction) -> Result<(
         )));
     }
 
-    if update_action.properties().is_empty() {
+    if update_action.properties().is_empty()
+        && update_action.deprecate_properties().is_empty()
+        && update_action.optional_properties().is_empty()
+        && update_action.add_enum_options().is_empty()
+    {
         return Err(ApplyError::InvalidTransaction(String::from(
-            "Properties must not be empty",
+            "Update must add properties or change existing properties",
+        )));
+    }
+
+    for update in update_action.add_enum_options() {
+        if update.enum_options().is_empty() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Enum options to add to {} must not be empty",
+                update.property_name()
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn validate_editor_change(schema_name: &str, editor: &str) -> Result<(), ApplyError> {
+    if schema_name.is_empty() {
+        return Err(ApplyError::InvalidTransaction(String::from(
+            "Schema name must be set",
+        )));
+    }
+
+    if editor.is_empty() {
+        return Err(ApplyError::InvalidTransaction(String::from(
+            "Editor organization must be set",
+        )));
+    }
+    Ok(())
+}
+
+fn validate_schema_transfer_action(
+    transfer_action: &SchemaTransferAction,
+) -> Result<(), ApplyError> {
+    if transfer_action.schema_name().is_empty() {
+        return Err(ApplyError::InvalidTransaction(String::from(
+            "Schema name must be set",
+        )));
+    }
+
+    if transfer_action.new_owner().is_empty() {
+        return Err(ApplyError::InvalidTransaction(String::from(
+            "New owner must be set",
         )));
     }
     Ok(())
@@ -175,7 +227,28 @@ mod tests {
         let payload = payload_proto.clone().into_native().unwrap();
         assert!(
             validate_payload(&payload).is_err(),
-            "Properties must not be empty"
+            "Update must add properties or change existing properties"
+        );
+
+        // create payload with an enum options update without any options
+        let mut enum_options_update = protos::schema_payload::EnumOptionsUpdate::new();
+        enum_options_update.set_property_name("TEST".into());
+        action.set_add_enum_options(vec![enum_options_update].into());
+        payload_proto.set_schema_update(action.clone());
+        let payload = payload_proto.clone().into_native().unwrap();
+        assert!(
+            validate_payload(&payload).is_err(),
+            "Enum options to add to TEST must not be empty"
+        );
+
+        // create payload that only deprecates a property
+        action.clear_add_enum_options();
+        action.set_deprecate_properties(vec!["TEST".to_string()].into());
+        payload_proto.set_schema_update(action.clone());
+        let payload = payload_proto.clone().into_native().unwrap();
+        assert!(
+            validate_payload(&payload).is_ok(),
+            "Payload should be valid"
         );
 
         // create payload with full payload
@@ -205,4 +278,46 @@ mod tests {
             "Payload should be valid"
         );
     }
+
+    #[test]
+    // Test that editor changes and transfers name both the schema and the organization
+    fn test_validate_schema_editor_and_transfer_actions() {
+        let mut payload_proto = protos::schema_payload::SchemaPayload::new();
+
+        payload_proto.set_action(protos::schema_payload::SchemaPayload_Action::SCHEMA_ADD_EDITOR);
+        let mut add_editor = protos::schema_payload::SchemaAddEditorAction::new();
+        add_editor.set_schema_name("test_schema".into());
+        payload_proto.set_schema_add_editor(add_editor.clone());
+        let payload = payload_proto.clone().into_native().unwrap();
+        assert!(
+            validate_payload(&payload).is_err(),
+            "Editor organization must be set"
+        );
+
+        add_editor.set_editor("editor_org".into());
+        payload_proto.set_schema_add_editor(add_editor);
+        let payload = payload_proto.clone().into_native().unwrap();
+        assert!(
+            validate_payload(&payload).is_ok(),
+            "Payload should be valid"
+        );
+
+        payload_proto.set_action(protos::schema_payload::SchemaPayload_Action::SCHEMA_TRANSFER);
+        let mut transfer = protos::schema_payload::SchemaTransferAction::new();
+        transfer.set_new_owner("new_org".into());
+        payload_proto.set_schema_transfer(transfer.clone());
+        let payload = payload_proto.clone().into_native().unwrap();
+        assert!(
+            validate_payload(&payload).is_err(),
+            "Schema name must be set"
+        );
+
+        transfer.set_schema_name("test_schema".into());
+        payload_proto.set_schema_transfer(transfer);
+        let payload = payload_proto.clone().into_native().unwrap();
+        assert!(
+            validate_payload(&payload).is_ok(),
+            "Payload should be valid"
+        );
+    }
 }
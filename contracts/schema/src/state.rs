@@ -14,7 +14,7 @@
 
 use crypto::digest::Digest;
 use crypto::sha2::Sha512;
-use grid_sdk::protocol::pike::state::{Agent, AgentList};
+use grid_sdk::protocol::pike::state::{Agent, AgentList, Organization, OrganizationList};
 use grid_sdk::protocol::schema::state::{Schema, SchemaList, SchemaListBuilder};
 use grid_sdk::protos::{FromBytes, IntoBytes};
 
@@ -33,6 +33,7 @@ pub const GRID_SCHEMA_NAMESPACE: &str = "01";
 
 pub const PIKE_NAMESPACE: &str = "cad11d";
 pub const PIKE_AGENT_NAMESPACE: &str = "00";
+pub const PIKE_ORG_NAMESPACE: &str = "01";
 
 /// Computes the address a Pike Agent is stored at based on its public_key
 pub fn compute_agent_address(public_key: &str) -> String {
@@ -42,6 +43,14 @@ pub fn compute_agent_address(public_key: &str) -> String {
     String::from(PIKE_NAMESPACE) + PIKE_AGENT_NAMESPACE + &sha.result_str()[..62].to_string()
 }
 
+/// Computes the address a Pike Organization is stored at based on its org_id
+pub fn compute_org_address(org_id: &str) -> String {
+    let mut sha = Sha512::new();
+    sha.input(org_id.as_bytes());
+
+    String::from(PIKE_NAMESPACE) + PIKE_ORG_NAMESPACE + &sha.result_str()[..62].to_string()
+}
+
 /// Computes the address a Grid Schema is stored at based on its name
 pub fn compute_schema_address(name: &str) -> String {
     let mut sha = Sha512::new();
@@ -88,6 +97,35 @@ impl<'a> GridSchemaState<'a> {
         }
     }
 
+    /// Gets a Pike Organization. Handles retrieving the correct organization from an
+    /// OrganizationList.
+    pub fn get_organization(&self, org_id: &str) -> Result<Option<Organization>, ApplyError> {
+        let address = compute_org_address(org_id);
+        let d = self.context.get_state_entry(&address)?;
+        match d {
+            Some(packed) => {
+                let orgs = match OrganizationList::from_bytes(packed.as_slice()) {
+                    Ok(orgs) => orgs,
+                    Err(err) => {
+                        return Err(ApplyError::InvalidTransaction(format!(
+                            "Cannot deserialize organization list: {:?}",
+                            err,
+                        )));
+                    }
+                };
+
+                // find the organization with the correct org_id
+                for org in orgs.organizations() {
+                    if org.org_id() == org_id {
+                        return Ok(Some(org.clone()));
+                    }
+                }
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+
     /// Gets a Grid Schema. Handles retrieving the correct Schema from a SchemaList
     pub fn get_schema(&self, name: &str) -> Result<Option<Schema>, ApplyError> {
         let address = compute_schema_address(name);
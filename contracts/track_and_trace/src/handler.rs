@@ -31,6 +31,7 @@ cfg_if! {
 
 use grid_sdk::protocol::errors::BuilderError;
 use grid_sdk::protocol::schema::state::{PropertyDefinition, PropertyValue};
+use grid_sdk::protocol::schema::validation::validate_properties;
 use grid_sdk::protocol::track_and_trace::payload::{
     Action, AnswerProposalAction, CreateProposalAction, CreateRecordAction, FinalizeRecordAction,
     Response, RevokeReporterAction, TrackAndTracePayload, UpdatePropertiesAction,
@@ -75,15 +76,28 @@ impl TrackAndTraceTransactionHandler {
         signer: &str,
         timestamp: u64,
     ) -> Result<(), ApplyError> {
-        match state.get_agent(signer)? {
-            Some(_) => (),
+        let agent = match state.get_agent(signer)? {
+            Some(agent) => agent,
             None => {
                 return Err(ApplyError::InvalidTransaction(format!(
                     "Agent is not registered: {}",
                     signer
                 )));
             }
+        };
+        if !*agent.active() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent is not active: {}",
+                signer
+            )));
         }
+        if agent.org_id().is_empty() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent does not belong to an organization: {}",
+                signer
+            )));
+        }
+
         let record_id = payload.record_id();
         if state.get_record(record_id)?.is_some() {
             return Err(ApplyError::InvalidTransaction(format!(
@@ -103,48 +117,19 @@ impl TrackAndTraceTransactionHandler {
             }
         };
 
+        validate_properties(schema.properties(), payload.properties())
+            .map_err(|err| ApplyError::InvalidTransaction(err.to_string()))?;
+
         let mut type_schemata: HashMap<&str, PropertyDefinition> = HashMap::new();
-        let mut required_properties: HashMap<&str, PropertyDefinition> = HashMap::new();
         let mut provided_properties: HashMap<&str, PropertyValue> = HashMap::new();
         for property in schema.properties() {
             type_schemata.insert(property.name(), property.clone());
-            if *property.required() {
-                required_properties.insert(property.name(), property.clone());
-            }
         }
 
         for property in payload.properties() {
             provided_properties.insert(property.name(), property.clone());
         }
 
-        for name in required_properties.keys() {
-            if !provided_properties.contains_key(name) {
-                return Err(ApplyError::InvalidTransaction(format!(
-                    "Required property {} not provided",
-                    name
-                )));
-            }
-        }
-
-        for (provided_name, provided_properties) in provided_properties.clone() {
-            let required_type = match type_schemata.get(provided_name) {
-                Some(required_type) => required_type.data_type(),
-                None => {
-                    return Err(ApplyError::InvalidTransaction(format!(
-                        "Provided property {} is not in schema",
-                        provided_name
-                    )));
-                }
-            };
-            let provided_type = provided_properties.data_type();
-            if provided_type != required_type {
-                return Err(ApplyError::InvalidTransaction(format!(
-                    "Value provided for {} is the wrong type",
-                    provided_name
-                )));
-            };
-        }
-
         let owner = AssociatedAgentBuilder::new()
             .with_agent_id(signer.to_string())
             .with_timestamp(timestamp)
@@ -154,6 +139,7 @@ impl TrackAndTraceTransactionHandler {
         let new_record = RecordBuilder::new()
             .with_record_id(record_id.to_string())
             .with_schema(schema_name.to_string())
+            .with_schema_version(*schema.version())
             .with_field_final(false)
             .with_owners(vec![owner.clone()])
             .with_custodians(vec![owner])
@@ -243,7 +229,9 @@ impl TrackAndTraceTransactionHandler {
             }
         };
 
-        if owner.agent_id() != signer || custodian.agent_id() != signer {
+        if !state.is_agent_or_successor(signer, owner.agent_id())?
+            || !state.is_agent_or_successor(signer, custodian.agent_id())?
+        {
             return Err(ApplyError::InvalidTransaction(
                 "Must be owner and custodian to finalize record".to_string(),
             ));
@@ -311,7 +299,9 @@ impl TrackAndTraceTransactionHandler {
             let mut allowed = false;
             let mut reporter_index = 0;
             for reporter in prop.reporters() {
-                if reporter.public_key() == signer && *reporter.authorized() {
+                if *reporter.authorized()
+                    && state.is_agent_or_successor(signer, reporter.public_key())?
+                {
                     allowed = true;
                     reporter_index = *reporter.index();
                     break;
@@ -487,7 +477,7 @@ impl TrackAndTraceTransactionHandler {
                     )));
                 }
             };
-            if owner.agent_id() != signer {
+            if !state.is_agent_or_successor(signer, owner.agent_id())? {
                 return Err(ApplyError::InvalidTransaction(String::from(
                     "Only the owner can create a proposal to change ownership",
                 )));
@@ -504,7 +494,7 @@ impl TrackAndTraceTransactionHandler {
                 }
             };
 
-            if custodian.agent_id() != signer {
+            if !state.is_agent_or_successor(signer, custodian.agent_id())? {
                 return Err(ApplyError::InvalidTransaction(String::from(
                     "Only the custodian can create a proposal to change custodianship",
                 )));
@@ -520,7 +510,7 @@ impl TrackAndTraceTransactionHandler {
                     )));
                 }
             };
-            if owner.agent_id() != signer {
+            if !state.is_agent_or_successor(signer, owner.agent_id())? {
                 return Err(ApplyError::InvalidTransaction(String::from(
                     "Only the owner can create a proposal to authorize a reporter",
                 )));
@@ -609,7 +599,7 @@ impl TrackAndTraceTransactionHandler {
 
         match response {
             Response::Cancel => {
-                if current_proposal.issuing_agent() != signer {
+                if !state.is_agent_or_successor(signer, current_proposal.issuing_agent())? {
                     return Err(ApplyError::InvalidTransaction(String::from(
                         "Only the issuing agent can cancel a proposal",
                     )));
@@ -618,7 +608,7 @@ impl TrackAndTraceTransactionHandler {
             }
 
             Response::Reject => {
-                if current_proposal.receiving_agent() != signer {
+                if !state.is_agent_or_successor(signer, current_proposal.receiving_agent())? {
                     return Err(ApplyError::InvalidTransaction(String::from(
                         "Only the receiving agent can reject a proposal",
                     )));
@@ -627,7 +617,7 @@ impl TrackAndTraceTransactionHandler {
             }
 
             Response::Accept => {
-                if current_proposal.receiving_agent() != signer {
+                if !state.is_agent_or_successor(signer, current_proposal.receiving_agent())? {
                     return Err(ApplyError::InvalidTransaction(String::from(
                         "Only the receiving agent can accept a proposal",
                     )));
@@ -904,7 +894,7 @@ impl TrackAndTraceTransactionHandler {
             }
         };
 
-        if owner.agent_id() != signer {
+        if !state.is_agent_or_successor(signer, owner.agent_id())? {
             return Err(ApplyError::InvalidTransaction(
                 "Must be owner to revoke reporters".to_string(),
             ));
@@ -1158,6 +1148,44 @@ mod tests {
             self.set_state_entry(agent_address, agent_bytes).unwrap();
         }
 
+        fn add_inactive_agent(&self, public_key: &str) {
+            let builder = AgentBuilder::new();
+            let agent = builder
+                .with_org_id("test_org".to_string())
+                .with_public_key(public_key.to_string())
+                .with_active(false)
+                .with_roles(vec![])
+                .build()
+                .unwrap();
+
+            let builder = AgentListBuilder::new();
+            let agent_list = builder.with_agents(vec![agent.clone()]).build().unwrap();
+            let agent_bytes = agent_list.into_bytes().unwrap();
+            let agent_address = make_agent_address(public_key);
+            self.set_state_entry(agent_address, agent_bytes).unwrap();
+        }
+
+        /// Adds an agent whose key was rotated from `predecessor`. Keys that have since been
+        /// rotated again are inactive.
+        fn add_rotated_agent(&self, predecessor: &str, public_key: &str, active: bool) {
+            let agent = AgentBuilder::new()
+                .with_org_id("test_org".to_string())
+                .with_public_key(public_key.to_string())
+                .with_active(active)
+                .with_roles(vec![])
+                .with_predecessor(predecessor.to_string())
+                .build()
+                .unwrap();
+
+            let agent_list = AgentListBuilder::new()
+                .with_agents(vec![agent])
+                .build()
+                .unwrap();
+            let agent_bytes = agent_list.into_bytes().unwrap();
+            let agent_address = make_agent_address(public_key);
+            self.set_state_entry(agent_address, agent_bytes).unwrap();
+        }
+
         fn add_schema(&self) {
             let builder = SchemaBuilder::new();
             let schema = builder
@@ -1380,6 +1408,34 @@ mod tests {
         }
     }
 
+    #[test]
+    /// Test that the CreateRecordAction is invalid if the signer is an inactive Agent.
+    fn test_create_record_inactive_agent() {
+        let mut transaction_context = MockTransactionContext::default();
+        transaction_context.add_inactive_agent(PUBLIC_KEY);
+        transaction_context.add_schema();
+        let mut state = TrackAndTraceState::new(&mut transaction_context);
+
+        let transaction_handler = TrackAndTraceTransactionHandler::new();
+        let create_record_action = create_record_action_with_properties(vec![
+            optional_property_value(),
+            required_property_value(),
+        ]);
+
+        match transaction_handler._create_record(
+            &create_record_action,
+            &mut state,
+            PUBLIC_KEY,
+            TIMESTAMP,
+        ) {
+            Ok(()) => panic!("Agent is inactive, InvalidTransaction should be returned"),
+            Err(ApplyError::InvalidTransaction(err)) => {
+                assert!(err.contains(&format!("Agent is not active: {}", PUBLIC_KEY)));
+            }
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+    }
+
     #[test]
     /// Test that if the CreateRecordAction is invalid if the schema does not exist.
     fn test_create_record_schema_does_not_exist() {
@@ -1583,6 +1639,38 @@ mod tests {
         assert!(finalized_record.field_final());
     }
 
+    #[test]
+    /// Test that an agent whose key was rotated, twice, from the record owner's key can
+    /// finalize the record, and that the rotated-away key no longer can
+    fn test_finalize_record_handler_rotated_key() {
+        let mut transaction_context = MockTransactionContext::default();
+        transaction_context.add_schema();
+        transaction_context.add_record();
+        transaction_context.add_inactive_agent(PUBLIC_KEY);
+        transaction_context.add_rotated_agent(PUBLIC_KEY, "rotated_key", false);
+        transaction_context.add_rotated_agent("rotated_key", "new_key", true);
+
+        let mut state = TrackAndTraceState::new(&mut transaction_context);
+
+        let transaction_handler = TrackAndTraceTransactionHandler::new();
+
+        match transaction_handler._finalize_record(
+            &create_finalize_record(),
+            &mut state,
+            PUBLIC_KEY,
+        ) {
+            Ok(()) => panic!("Key was rotated away, InvalidTransaction should be returned"),
+            Err(ApplyError::InvalidTransaction(err)) => {
+                assert!(err.contains("Must be owner and custodian to finalize record"));
+            }
+            Err(err) => panic!("Should have gotten invalid error but got {}", err),
+        }
+
+        assert!(transaction_handler
+            ._finalize_record(&create_finalize_record(), &mut state, "new_key")
+            .is_ok());
+    }
+
     #[test]
     /// Test that if the FinalizeRecordAction fails if a record with the provided id does
     /// not exist.
@@ -3277,6 +3365,7 @@ mod tests {
         RecordBuilder::new()
             .with_record_id(RECORD_ID.to_string())
             .with_schema(SCHEMA_NAME.to_string())
+            .with_schema_version(1)
             .with_owners(vec![associated_agent.clone()])
             .with_custodians(vec![associated_agent.clone()])
             .with_field_final(false)
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+
 cfg_if! {
     if #[cfg(target_arch = "wasm32")] {
         use sabre_sdk::ApplyError;
@@ -42,6 +44,44 @@ impl<'a> TrackAndTraceState<'a> {
         TrackAndTraceState { context }
     }
 
+    /// Returns true if `signer` may act as the agent `public_key`: either it is that agent, or
+    /// its key was rotated from that agent's key, possibly over several rotations. An agent
+    /// that has been deactivated, as a key is when it is rotated away, cannot act as anyone.
+    pub fn is_agent_or_successor(
+        &self,
+        signer: &str,
+        public_key: &str,
+    ) -> Result<bool, ApplyError> {
+        let mut agent = match self.get_agent(signer)? {
+            Some(agent) => agent,
+            None => return Ok(signer == public_key),
+        };
+
+        if !*agent.active() {
+            return Ok(false);
+        }
+
+        let mut visited = HashSet::new();
+        loop {
+            if agent.public_key() == public_key {
+                return Ok(true);
+            }
+
+            let predecessor = agent.predecessor().to_string();
+            if predecessor.is_empty() || !visited.insert(predecessor.clone()) {
+                return Ok(false);
+            }
+            if predecessor == public_key {
+                return Ok(true);
+            }
+
+            agent = match self.get_agent(&predecessor)? {
+                Some(agent) => agent,
+                None => return Ok(false),
+            };
+        }
+    }
+
     pub fn get_record(&self, record_id: &str) -> Result<Option<Record>, ApplyError> {
         let address = make_record_address(record_id);
         let d = self.context.get_state_entry(&address)?;
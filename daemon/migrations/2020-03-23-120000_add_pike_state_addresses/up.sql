@@ -0,0 +1,31 @@
+-- Copyright 2020 Cargill Incorporated
+--
+-- Licensed under the Apache License, Version 2.0 (the "License");
+-- you may not use this file except in compliance with the License.
+-- You may obtain a copy of the License at
+--
+--     http://www.apache.org/licenses/LICENSE-2.0
+--
+-- Unless required by applicable law or agreed to in writing, software
+-- distributed under the License is distributed on an "AS IS" BASIS,
+-- WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+-- See the License for the specific language governing permissions and
+-- limitations under the License.
+-- -----------------------------------------------------------------------------
+
+-- Deletes in state only carry the address of the removed entry, so agents and
+-- organizations are stored with their state address. Existing rows are
+-- backfilled with the address Pike computes: the namespace, the resource
+-- type, and the first 62 characters of the SHA-512 of the natural key.
+ALTER TABLE agent ADD COLUMN state_address VARCHAR;
+UPDATE agent SET state_address = 'cad11d00' ||
+    substr(encode(sha512(convert_to(public_key, 'UTF8')), 'hex'), 1, 62);
+ALTER TABLE agent ALTER COLUMN state_address SET NOT NULL;
+CREATE INDEX IF NOT EXISTS agent_state_address_idx ON agent (state_address);
+
+ALTER TABLE organization ADD COLUMN state_address VARCHAR;
+UPDATE organization SET state_address = 'cad11d01' ||
+    substr(encode(sha512(convert_to(org_id, 'UTF8')), 'hex'), 1, 62);
+ALTER TABLE organization ALTER COLUMN state_address SET NOT NULL;
+CREATE INDEX IF NOT EXISTS organization_state_address_idx
+    ON organization (state_address);
@@ -17,11 +17,11 @@
 
 use super::models::{Agent, NewAgent};
 use super::schema::agent;
-use super::MAX_COMMIT_NUM;
+use super::{Sort, SortOrder, MAX_COMMIT_NUM};
 
 use diesel::{
     dsl::{insert_into, update},
-    pg::PgConnection,
+    pg::{Pg, PgConnection},
     prelude::*,
     result::Error::NotFound,
     QueryResult,
@@ -54,11 +54,58 @@ fn update_agent_end_commit_num(
         .map(|_| ())
 }
 
-pub fn get_agents(conn: &PgConnection, service_id: Option<&str>) -> QueryResult<Vec<Agent>> {
-    let mut query = agent::table
-        .into_boxed()
-        .select(agent::all_columns)
-        .filter(agent::end_commit_num.eq(MAX_COMMIT_NUM));
+/// Ends the current version of an agent that has been removed from state
+pub fn delete_agent(
+    conn: &PgConnection,
+    address: &str,
+    service_id: Option<&str>,
+    current_commit_num: i64,
+) -> QueryResult<()> {
+    let mut query = update(agent::table)
+        .filter(
+            agent::state_address
+                .eq(address)
+                .and(agent::end_commit_num.eq(MAX_COMMIT_NUM)),
+        )
+        .into_boxed();
+
+    if let Some(service_id) = service_id {
+        query = query.filter(agent::service_id.eq(service_id));
+    } else {
+        query = query.filter(agent::service_id.is_null());
+    }
+
+    query
+        .set(agent::end_commit_num.eq(current_commit_num))
+        .execute(conn)
+        .map(|_| ())
+}
+
+/// The fields agents can be sorted by
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AgentSortField {
+    PublicKey,
+    OrgId,
+}
+
+impl Default for AgentSortField {
+    fn default() -> Self {
+        AgentSortField::PublicKey
+    }
+}
+
+fn filtered_agents<'a>(
+    org_id: Option<&'a str>,
+    service_id: Option<&'a str>,
+    as_of: Option<i64>,
+) -> agent::BoxedQuery<'a, Pg> {
+    let mut query = agent::table.into_boxed();
+
+    query = filter_as_of!(query, agent, as_of);
+
+    if let Some(org_id) = org_id {
+        query = query.filter(agent::org_id.eq(org_id));
+    }
 
     if let Some(service_id) = service_id {
         query = query.filter(agent::service_id.eq(service_id));
@@ -66,19 +113,57 @@ pub fn get_agents(conn: &PgConnection, service_id: Option<&str>) -> QueryResult<
         query = query.filter(agent::service_id.is_null());
     }
 
-    query.load::<Agent>(conn)
+    query
+}
+
+pub fn get_agents(
+    conn: &PgConnection,
+    org_id: Option<&str>,
+    service_id: Option<&str>,
+    as_of: Option<i64>,
+    sort: Sort<AgentSortField>,
+    limit: i64,
+    offset: i64,
+) -> QueryResult<Vec<Agent>> {
+    let query = filtered_agents(org_id, service_id, as_of).select(agent::all_columns);
+
+    let query = match (sort.field, sort.order) {
+        (AgentSortField::PublicKey, SortOrder::Asc) => query.order(agent::public_key.asc()),
+        (AgentSortField::PublicKey, SortOrder::Desc) => query.order(agent::public_key.desc()),
+        (AgentSortField::OrgId, SortOrder::Asc) => {
+            query.order((agent::org_id.asc(), agent::public_key.asc()))
+        }
+        (AgentSortField::OrgId, SortOrder::Desc) => {
+            query.order((agent::org_id.desc(), agent::public_key.asc()))
+        }
+    };
+
+    query.limit(limit).offset(offset).load::<Agent>(conn)
+}
+
+pub fn count_agents(
+    conn: &PgConnection,
+    org_id: Option<&str>,
+    service_id: Option<&str>,
+    as_of: Option<i64>,
+) -> QueryResult<i64> {
+    filtered_agents(org_id, service_id, as_of)
+        .count()
+        .get_result(conn)
 }
 
 pub fn get_agent(
     conn: &PgConnection,
     public_key: &str,
     service_id: Option<&str>,
+    as_of: Option<i64>,
 ) -> QueryResult<Option<Agent>> {
-    let mut query = agent::table.into_boxed().select(agent::all_columns).filter(
-        agent::public_key
-            .eq(public_key)
-            .and(agent::end_commit_num.eq(MAX_COMMIT_NUM)),
-    );
+    let mut query = agent::table
+        .into_boxed()
+        .select(agent::all_columns)
+        .filter(agent::public_key.eq(public_key));
+
+    query = filter_as_of!(query, agent, as_of);
 
     if let Some(service_id) = service_id {
         query = query.filter(agent::service_id.eq(service_id));
@@ -91,3 +176,44 @@ pub fn get_agent(
         .map(Some)
         .or_else(|err| if err == NotFound { Ok(None) } else { Err(err) })
 }
+
+fn agent_versions<'a>(
+    public_key: &'a str,
+    service_id: Option<&'a str>,
+) -> agent::BoxedQuery<'a, Pg> {
+    let query = agent::table
+        .into_boxed()
+        .filter(agent::public_key.eq(public_key));
+
+    if let Some(service_id) = service_id {
+        query.filter(agent::service_id.eq(service_id))
+    } else {
+        query.filter(agent::service_id.is_null())
+    }
+}
+
+/// Lists every version of the agent, oldest first
+pub fn list_agent_versions(
+    conn: &PgConnection,
+    public_key: &str,
+    service_id: Option<&str>,
+    limit: i64,
+    offset: i64,
+) -> QueryResult<Vec<Agent>> {
+    agent_versions(public_key, service_id)
+        .select(agent::all_columns)
+        .order(agent::start_commit_num.asc())
+        .limit(limit)
+        .offset(offset)
+        .load::<Agent>(conn)
+}
+
+pub fn count_agent_versions(
+    conn: &PgConnection,
+    public_key: &str,
+    service_id: Option<&str>,
+) -> QueryResult<i64> {
+    agent_versions(public_key, service_id)
+        .count()
+        .get_result(conn)
+}
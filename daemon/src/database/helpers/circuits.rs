@@ -0,0 +1,209 @@
+/*
+ * Copyright 2020 Cargill Incorporated
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * -----------------------------------------------------------------------------
+ */
+
+use super::models::{
+    GridCircuit, GridCircuitMember, GridCircuitProposal, GridCircuitProposalVoteRecord,
+    NewGridCircuitMember, NewGridCircuitProposal, NewGridCircuitProposalVoteRecord,
+};
+use super::schema::{
+    grid_circuit, grid_circuit_member, grid_circuit_proposal, grid_circuit_proposal_vote_record,
+};
+
+use diesel::{
+    dsl::{insert_into, update},
+    pg::PgConnection,
+    prelude::*,
+    result::Error::NotFound,
+    QueryResult,
+};
+
+/// Stores a circuit along with its members, returning false if the circuit
+/// was already stored, in which case nothing is changed.
+pub fn insert_circuit(
+    conn: &PgConnection,
+    circuit: &GridCircuit,
+    members: &[NewGridCircuitMember],
+) -> QueryResult<bool> {
+    conn.transaction(|| {
+        let inserted = insert_into(grid_circuit::table)
+            .values(circuit)
+            .on_conflict(grid_circuit::circuit_id)
+            .do_nothing()
+            .execute(conn)?;
+
+        if inserted > 0 {
+            insert_into(grid_circuit_member::table)
+                .values(members)
+                .execute(conn)?;
+        }
+
+        Ok(inserted > 0)
+    })
+}
+
+/// Lists the circuits on which this node runs a Scabbard service
+pub fn list_circuits_with_service(conn: &PgConnection) -> QueryResult<Vec<GridCircuit>> {
+    grid_circuit::table
+        .select(grid_circuit::all_columns)
+        .filter(grid_circuit::service_id.is_not_null())
+        .order_by(grid_circuit::created_time.asc())
+        .load::<GridCircuit>(conn)
+}
+
+pub fn list_circuits(
+    conn: &PgConnection,
+    limit: i64,
+    offset: i64,
+) -> QueryResult<Vec<GridCircuit>> {
+    grid_circuit::table
+        .select(grid_circuit::all_columns)
+        .order_by((
+            grid_circuit::created_time.asc(),
+            grid_circuit::circuit_id.asc(),
+        ))
+        .limit(limit)
+        .offset(offset)
+        .load::<GridCircuit>(conn)
+}
+
+pub fn count_circuits(conn: &PgConnection) -> QueryResult<i64> {
+    grid_circuit::table.count().get_result(conn)
+}
+
+pub fn fetch_circuit(conn: &PgConnection, circuit_id: &str) -> QueryResult<Option<GridCircuit>> {
+    grid_circuit::table
+        .select(grid_circuit::all_columns)
+        .filter(grid_circuit::circuit_id.eq(circuit_id))
+        .first(conn)
+        .map(Some)
+        .or_else(|err| if err == NotFound { Ok(None) } else { Err(err) })
+}
+
+pub fn list_circuit_members(
+    conn: &PgConnection,
+    circuit_ids: &[String],
+) -> QueryResult<Vec<GridCircuitMember>> {
+    grid_circuit_member::table
+        .select(grid_circuit_member::all_columns)
+        .filter(grid_circuit_member::circuit_id.eq_any(circuit_ids))
+        .order_by(grid_circuit_member::id.asc())
+        .load::<GridCircuitMember>(conn)
+}
+
+/// Records the current state of a circuit proposal. A proposal is identified
+/// by its circuit ID and circuit hash; when it is already stored, only its
+/// status and the votes not yet recorded are saved.
+pub fn store_circuit_proposal(
+    conn: &PgConnection,
+    proposal: &NewGridCircuitProposal,
+    votes: &[NewGridCircuitProposalVoteRecord],
+) -> QueryResult<()> {
+    conn.transaction(|| {
+        let existing_id = grid_circuit_proposal::table
+            .select(grid_circuit_proposal::id)
+            .filter(
+                grid_circuit_proposal::circuit_id
+                    .eq(&proposal.circuit_id)
+                    .and(grid_circuit_proposal::circuit_hash.eq(&proposal.circuit_hash)),
+            )
+            .first::<i64>(conn)
+            .optional()?;
+
+        let proposal_id = match existing_id {
+            Some(proposal_id) => {
+                update(grid_circuit_proposal::table.find(proposal_id))
+                    .set((
+                        grid_circuit_proposal::status.eq(&proposal.status),
+                        grid_circuit_proposal::updated_time.eq(proposal.updated_time),
+                    ))
+                    .execute(conn)?;
+                proposal_id
+            }
+            None => insert_into(grid_circuit_proposal::table)
+                .values(proposal)
+                .returning(grid_circuit_proposal::id)
+                .get_result::<i64>(conn)?,
+        };
+
+        let recorded_voters = grid_circuit_proposal_vote_record::table
+            .select(grid_circuit_proposal_vote_record::voter_public_key)
+            .filter(grid_circuit_proposal_vote_record::proposal_id.eq(proposal_id))
+            .load::<String>(conn)?;
+
+        let new_votes = votes
+            .iter()
+            .filter(|vote| !recorded_voters.contains(&vote.voter_public_key))
+            .map(|vote| NewGridCircuitProposalVoteRecord {
+                proposal_id,
+                voter_public_key: vote.voter_public_key.clone(),
+                voter_node_id: vote.voter_node_id.clone(),
+                vote: vote.vote.clone(),
+                created_time: vote.created_time,
+            })
+            .collect::<Vec<_>>();
+
+        if !new_votes.is_empty() {
+            insert_into(grid_circuit_proposal_vote_record::table)
+                .values(&new_votes)
+                .execute(conn)?;
+        }
+
+        Ok(())
+    })
+}
+
+pub fn list_circuit_proposals(
+    conn: &PgConnection,
+    status: Option<&str>,
+    limit: i64,
+    offset: i64,
+) -> QueryResult<Vec<GridCircuitProposal>> {
+    let mut query = grid_circuit_proposal::table
+        .into_boxed()
+        .select(grid_circuit_proposal::all_columns);
+
+    if let Some(status) = status {
+        query = query.filter(grid_circuit_proposal::status.eq(status));
+    }
+
+    query
+        .order_by(grid_circuit_proposal::id.asc())
+        .limit(limit)
+        .offset(offset)
+        .load::<GridCircuitProposal>(conn)
+}
+
+pub fn count_circuit_proposals(conn: &PgConnection, status: Option<&str>) -> QueryResult<i64> {
+    let mut query = grid_circuit_proposal::table.into_boxed();
+
+    if let Some(status) = status {
+        query = query.filter(grid_circuit_proposal::status.eq(status));
+    }
+
+    query.count().get_result(conn)
+}
+
+pub fn list_circuit_proposal_votes(
+    conn: &PgConnection,
+    proposal_ids: &[i64],
+) -> QueryResult<Vec<GridCircuitProposalVoteRecord>> {
+    grid_circuit_proposal_vote_record::table
+        .select(grid_circuit_proposal_vote_record::all_columns)
+        .filter(grid_circuit_proposal_vote_record::proposal_id.eq_any(proposal_ids))
+        .order_by(grid_circuit_proposal_vote_record::id.asc())
+        .load::<GridCircuitProposalVoteRecord>(conn)
+}
@@ -51,6 +51,28 @@ pub fn resolve_fork(conn: &PgConnection, commit_num: i64) -> QueryResult<()> {
     Ok(())
 }
 
+/// Removes every commit along with the state recorded from them, so the ledger can be replayed
+/// into an empty database. When a service ID is given, only that service's data is removed.
+pub fn clear_commits(conn: &PgConnection, service_id: Option<&str>) -> QueryResult<()> {
+    // Every state table inherits from chain_record, so this clears all of them
+    match service_id {
+        Some(service_id) => {
+            delete(chain_record::table)
+                .filter(chain_record::service_id.eq(service_id))
+                .execute(conn)?;
+            delete(commit::table)
+                .filter(commit::service_id.eq(service_id))
+                .execute(conn)?;
+        }
+        None => {
+            delete(chain_record::table).execute(conn)?;
+            delete(commit::table).execute(conn)?;
+        }
+    }
+
+    Ok(())
+}
+
 pub fn get_commit_by_commit_num(
     conn: &PgConnection,
     commit_num: i64,
@@ -63,9 +85,66 @@ pub fn get_commit_by_commit_num(
         .or_else(|err| if err == NotFound { Ok(None) } else { Err(err) })
 }
 
-pub fn get_current_commit_id(conn: &PgConnection) -> QueryResult<Option<String>> {
+pub fn get_commit_by_commit_id(
+    conn: &PgConnection,
+    commit_id: &str,
+    service_id: Option<&str>,
+) -> QueryResult<Option<Commit>> {
+    let mut query = commit::table
+        .into_boxed()
+        .select(commit::all_columns)
+        .filter(commit::commit_id.eq(commit_id));
+
+    if let Some(service_id) = service_id {
+        query = query.filter(commit::service_id.eq(service_id));
+    } else {
+        query = query.filter(commit::service_id.is_null());
+    }
+
+    query
+        .first(conn)
+        .map(Some)
+        .or_else(|err| if err == NotFound { Ok(None) } else { Err(err) })
+}
+
+pub fn list_commits_by_commit_nums(
+    conn: &PgConnection,
+    commit_nums: &[i64],
+) -> QueryResult<Vec<Commit>> {
+    commit::table
+        .select(commit::all_columns)
+        .filter(commit::commit_num.eq_any(commit_nums))
+        .load::<Commit>(conn)
+}
+
+pub fn get_current_commit(conn: &PgConnection) -> QueryResult<Option<Commit>> {
+    commit::table
+        .select(commit::all_columns)
+        .order_by(commit::commit_num.desc())
+        .first(conn)
+        .map(Some)
+        .or_else(|err| if err == NotFound { Ok(None) } else { Err(err) })
+}
+
+/// Returns the latest commit below the given commit number
+pub fn get_previous_commit(conn: &PgConnection, commit_num: i64) -> QueryResult<Option<Commit>> {
+    commit::table
+        .select(commit::all_columns)
+        .filter(commit::commit_num.lt(commit_num))
+        .order_by(commit::commit_num.desc())
+        .first(conn)
+        .map(Some)
+        .or_else(|err| if err == NotFound { Ok(None) } else { Err(err) })
+}
+
+/// Returns the ID of the latest commit received from the given service
+pub fn get_current_commit_id_for_service(
+    conn: &PgConnection,
+    service_id: &str,
+) -> QueryResult<Option<String>> {
     commit::table
         .select(commit::commit_id)
+        .filter(commit::service_id.eq(service_id))
         .order_by(commit::commit_num.desc())
         .limit(1)
         .first(conn)
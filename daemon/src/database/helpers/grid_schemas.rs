@@ -17,11 +17,11 @@
 
 use super::models::{GridPropertyDefinition, GridSchema, NewGridPropertyDefinition, NewGridSchema};
 use super::schema::{grid_property_definition, grid_schema};
-use super::MAX_COMMIT_NUM;
+use super::{Sort, SortOrder, MAX_COMMIT_NUM};
 
 use diesel::{
     dsl::{insert_into, update},
-    pg::PgConnection,
+    pg::{Pg, PgConnection},
     prelude::*,
     result::Error::NotFound,
     QueryResult,
@@ -91,14 +91,31 @@ pub fn update_definition_end_commit_num(
         .map(|_| ())
 }
 
-pub fn list_grid_schemas(
-    conn: &PgConnection,
-    service_id: Option<&str>,
-) -> QueryResult<Vec<GridSchema>> {
-    let mut query = grid_schema::table
-        .into_boxed()
-        .select(grid_schema::all_columns)
-        .filter(grid_schema::end_commit_num.eq(MAX_COMMIT_NUM));
+/// The fields schemas can be sorted by
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GridSchemaSortField {
+    Name,
+    Owner,
+}
+
+impl Default for GridSchemaSortField {
+    fn default() -> Self {
+        GridSchemaSortField::Name
+    }
+}
+
+fn filtered_grid_schemas<'a>(
+    owner: Option<&'a str>,
+    service_id: Option<&'a str>,
+    as_of: Option<i64>,
+) -> grid_schema::BoxedQuery<'a, Pg> {
+    let mut query = grid_schema::table.into_boxed();
+
+    query = filter_as_of!(query, grid_schema, as_of);
+
+    if let Some(owner) = owner {
+        query = query.filter(grid_schema::owner.eq(owner));
+    }
 
     if let Some(service_id) = service_id {
         query = query.filter(grid_schema::service_id.eq(service_id));
@@ -106,17 +123,57 @@ pub fn list_grid_schemas(
         query = query.filter(grid_schema::service_id.is_null());
     }
 
-    query.load::<GridSchema>(conn)
+    query
+}
+
+pub fn list_grid_schemas(
+    conn: &PgConnection,
+    owner: Option<&str>,
+    service_id: Option<&str>,
+    as_of: Option<i64>,
+    sort: Sort<GridSchemaSortField>,
+    limit: i64,
+    offset: i64,
+) -> QueryResult<Vec<GridSchema>> {
+    let query = filtered_grid_schemas(owner, service_id, as_of).select(grid_schema::all_columns);
+
+    let query = match (sort.field, sort.order) {
+        (GridSchemaSortField::Name, SortOrder::Asc) => query.order(grid_schema::name.asc()),
+        (GridSchemaSortField::Name, SortOrder::Desc) => query.order(grid_schema::name.desc()),
+        (GridSchemaSortField::Owner, SortOrder::Asc) => {
+            query.order((grid_schema::owner.asc(), grid_schema::name.asc()))
+        }
+        (GridSchemaSortField::Owner, SortOrder::Desc) => {
+            query.order((grid_schema::owner.desc(), grid_schema::name.asc()))
+        }
+    };
+
+    query.limit(limit).offset(offset).load::<GridSchema>(conn)
+}
+
+pub fn count_grid_schemas(
+    conn: &PgConnection,
+    owner: Option<&str>,
+    service_id: Option<&str>,
+    as_of: Option<i64>,
+) -> QueryResult<i64> {
+    filtered_grid_schemas(owner, service_id, as_of)
+        .count()
+        .get_result(conn)
 }
 
 pub fn list_grid_property_definitions(
     conn: &PgConnection,
+    schema_names: &[String],
     service_id: Option<&str>,
+    as_of: Option<i64>,
 ) -> QueryResult<Vec<GridPropertyDefinition>> {
     let mut query = grid_property_definition::table
         .into_boxed()
         .select(grid_property_definition::all_columns)
-        .filter(grid_property_definition::end_commit_num.eq(MAX_COMMIT_NUM));
+        .filter(grid_property_definition::schema_name.eq_any(schema_names));
+
+    query = filter_as_of!(query, grid_property_definition, as_of);
 
     if let Some(service_id) = service_id {
         query = query.filter(grid_property_definition::service_id.eq(service_id));
@@ -131,15 +188,14 @@ pub fn fetch_grid_schema(
     conn: &PgConnection,
     name: &str,
     service_id: Option<&str>,
+    as_of: Option<i64>,
 ) -> QueryResult<Option<GridSchema>> {
     let mut query = grid_schema::table
         .into_boxed()
         .select(grid_schema::all_columns)
-        .filter(
-            grid_schema::name
-                .eq(name)
-                .and(grid_schema::end_commit_num.eq(MAX_COMMIT_NUM)),
-        );
+        .filter(grid_schema::name.eq(name));
+
+    query = filter_as_of!(query, grid_schema, as_of);
 
     if let Some(service_id) = service_id {
         query = query.filter(grid_schema::service_id.eq(service_id));
@@ -157,15 +213,14 @@ pub fn list_grid_property_definitions_with_schema_name(
     conn: &PgConnection,
     schema_name: &str,
     service_id: Option<&str>,
+    as_of: Option<i64>,
 ) -> QueryResult<Vec<GridPropertyDefinition>> {
     let mut query = grid_property_definition::table
         .into_boxed()
         .select(grid_property_definition::all_columns)
-        .filter(
-            grid_property_definition::schema_name
-                .eq(schema_name)
-                .and(grid_property_definition::end_commit_num.eq(MAX_COMMIT_NUM)),
-        );
+        .filter(grid_property_definition::schema_name.eq(schema_name));
+
+    query = filter_as_of!(query, grid_property_definition, as_of);
 
     if let Some(service_id) = service_id {
         query = query.filter(grid_property_definition::service_id.eq(service_id));
@@ -20,7 +20,25 @@ use std::i64;
 use super::models;
 use super::schema;
 
+/// Filters a boxed query on a versioned table to the rows that were current as of the given
+/// commit number, or to the rows that are current now if no commit number is given
+macro_rules! filter_as_of {
+    ($query:expr, $table:ident, $as_of:expr) => {
+        match $as_of {
+            Some(commit_num) => $query.filter(
+                $table::start_commit_num
+                    .le(commit_num)
+                    .and($table::end_commit_num.gt(commit_num)),
+            ),
+            None => {
+                $query.filter($table::end_commit_num.eq($crate::database::helpers::MAX_COMMIT_NUM))
+            }
+        }
+    };
+}
+
 mod agents;
+mod circuits;
 mod commits;
 mod grid_schemas;
 mod organizations;
@@ -29,7 +47,28 @@ mod track_and_trace;
 
 pub const MAX_COMMIT_NUM: i64 = i64::MAX;
 
+/// The order in which the results of a list query are returned
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl Default for SortOrder {
+    fn default() -> Self {
+        SortOrder::Asc
+    }
+}
+
+/// The field and order used to sort the results of a list query
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Sort<F> {
+    pub field: F,
+    pub order: SortOrder,
+}
+
 pub use agents::*;
+pub use circuits::*;
 pub use commits::*;
 pub use grid_schemas::*;
 pub use organizations::*;
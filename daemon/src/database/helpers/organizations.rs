@@ -17,11 +17,11 @@
 
 use super::models::{NewOrganization, Organization};
 use super::schema::organization;
-use super::MAX_COMMIT_NUM;
+use super::{Sort, SortOrder, MAX_COMMIT_NUM};
 
 use diesel::{
     dsl::{insert_into, update},
-    pg::PgConnection,
+    pg::{Pg, PgConnection},
     prelude::*,
     result::Error::NotFound,
     QueryResult,
@@ -57,37 +57,122 @@ fn update_org_end_commit_num(
         .map(|_| ())
 }
 
-pub fn list_organizations(
+/// Ends the current version of an organization that has been removed from state
+pub fn delete_organization(
     conn: &PgConnection,
+    address: &str,
     service_id: Option<&str>,
-) -> QueryResult<Vec<Organization>> {
-    let mut query = organization::table
-        .into_boxed()
-        .select(organization::all_columns)
-        .filter(organization::end_commit_num.eq(MAX_COMMIT_NUM));
+    current_commit_num: i64,
+) -> QueryResult<()> {
+    let mut query = update(organization::table)
+        .filter(
+            organization::state_address
+                .eq(address)
+                .and(organization::end_commit_num.eq(MAX_COMMIT_NUM)),
+        )
+        .into_boxed();
+
+    if let Some(service_id) = service_id {
+        query = query.filter(organization::service_id.eq(service_id));
+    } else {
+        query = query.filter(organization::service_id.is_null());
+    }
+
+    query
+        .set(organization::end_commit_num.eq(current_commit_num))
+        .execute(conn)
+        .map(|_| ())
+}
+
+/// The fields organizations can be sorted by
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OrganizationSortField {
+    OrgId,
+    Name,
+}
+
+impl Default for OrganizationSortField {
+    fn default() -> Self {
+        OrganizationSortField::OrgId
+    }
+}
+
+/// Restricts the organizations returned by a list query
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OrganizationFilter<'a> {
+    /// Only return the organization holding the given alternate ID, written as
+    /// `<id_type>:<id>`
+    pub alternate_id: Option<&'a str>,
+}
+
+fn filtered_organizations<'a>(
+    filter: OrganizationFilter<'a>,
+    service_id: Option<&'a str>,
+    as_of: Option<i64>,
+) -> organization::BoxedQuery<'a, Pg> {
+    let mut query = organization::table.into_boxed();
+
+    query = filter_as_of!(query, organization, as_of);
+
+    if let Some(alternate_id) = filter.alternate_id {
+        query = query.filter(organization::alternate_ids.contains(vec![alternate_id.to_string()]));
+    }
 
     if let Some(service_id) = service_id {
-        query = query.filter(organization::service_id.eq(service_id))
+        query.filter(organization::service_id.eq(service_id))
     } else {
-        query = query.filter(organization::service_id.is_null())
+        query.filter(organization::service_id.is_null())
     }
+}
 
-    query.load::<Organization>(conn)
+pub fn list_organizations(
+    conn: &PgConnection,
+    filter: OrganizationFilter,
+    service_id: Option<&str>,
+    as_of: Option<i64>,
+    sort: Sort<OrganizationSortField>,
+    limit: i64,
+    offset: i64,
+) -> QueryResult<Vec<Organization>> {
+    let query = filtered_organizations(filter, service_id, as_of).select(organization::all_columns);
+
+    let query = match (sort.field, sort.order) {
+        (OrganizationSortField::OrgId, SortOrder::Asc) => query.order(organization::org_id.asc()),
+        (OrganizationSortField::OrgId, SortOrder::Desc) => query.order(organization::org_id.desc()),
+        (OrganizationSortField::Name, SortOrder::Asc) => {
+            query.order((organization::name.asc(), organization::org_id.asc()))
+        }
+        (OrganizationSortField::Name, SortOrder::Desc) => {
+            query.order((organization::name.desc(), organization::org_id.asc()))
+        }
+    };
+
+    query.limit(limit).offset(offset).load::<Organization>(conn)
+}
+
+pub fn count_organizations(
+    conn: &PgConnection,
+    filter: OrganizationFilter,
+    service_id: Option<&str>,
+    as_of: Option<i64>,
+) -> QueryResult<i64> {
+    filtered_organizations(filter, service_id, as_of)
+        .count()
+        .get_result(conn)
 }
 
 pub fn fetch_organization(
     conn: &PgConnection,
     organization_id: &str,
     service_id: Option<&str>,
+    as_of: Option<i64>,
 ) -> QueryResult<Option<Organization>> {
     let mut query = organization::table
         .into_boxed()
         .select(organization::all_columns)
-        .filter(
-            organization::org_id
-                .eq(organization_id)
-                .and(organization::end_commit_num.eq(MAX_COMMIT_NUM)),
-        );
+        .filter(organization::org_id.eq(organization_id));
+
+    query = filter_as_of!(query, organization, as_of);
 
     if let Some(service_id) = service_id {
         query = query.filter(organization::service_id.eq(service_id));
@@ -100,3 +185,44 @@ pub fn fetch_organization(
         .map(Some)
         .or_else(|err| if err == NotFound { Ok(None) } else { Err(err) })
 }
+
+fn organization_versions<'a>(
+    org_id: &'a str,
+    service_id: Option<&'a str>,
+) -> organization::BoxedQuery<'a, Pg> {
+    let query = organization::table
+        .into_boxed()
+        .filter(organization::org_id.eq(org_id));
+
+    if let Some(service_id) = service_id {
+        query.filter(organization::service_id.eq(service_id))
+    } else {
+        query.filter(organization::service_id.is_null())
+    }
+}
+
+/// Lists the current and superseded versions of the organization, oldest first
+pub fn list_organization_versions(
+    conn: &PgConnection,
+    org_id: &str,
+    service_id: Option<&str>,
+    limit: i64,
+    offset: i64,
+) -> QueryResult<Vec<Organization>> {
+    organization_versions(org_id, service_id)
+        .select(organization::all_columns)
+        .order(organization::start_commit_num.asc())
+        .limit(limit)
+        .offset(offset)
+        .load::<Organization>(conn)
+}
+
+pub fn count_organization_versions(
+    conn: &PgConnection,
+    org_id: &str,
+    service_id: Option<&str>,
+) -> QueryResult<i64> {
+    organization_versions(org_id, service_id)
+        .count()
+        .get_result(conn)
+}
@@ -17,11 +17,11 @@
 
 use super::models::{NewProduct, NewProductPropertyValue, Product, ProductPropertyValue};
 use super::schema::{product, product_property_value};
-use super::MAX_COMMIT_NUM;
+use super::{Sort, SortOrder, MAX_COMMIT_NUM};
 
 use diesel::{
     dsl::{insert_into, update},
-    pg::PgConnection,
+    pg::{Pg, PgConnection},
     prelude::*,
     result::Error::NotFound,
     QueryResult,
@@ -29,7 +29,7 @@ use diesel::{
 
 pub fn insert_products(conn: &PgConnection, products: &[NewProduct]) -> QueryResult<()> {
     for prod in products {
-        update_prod_end_commit_num(conn, &prod.product_id, prod.start_commit_num)?;
+        update_prod_end_commit_num(conn, &prod.product_address, prod.start_commit_num)?;
     }
 
     insert_into(product::table)
@@ -43,7 +43,7 @@ pub fn insert_product_property_values(
     property_values: &[NewProductPropertyValue],
 ) -> QueryResult<()> {
     for value in property_values {
-        update_prod_property_values(conn, &value.product_id, value.start_commit_num)?;
+        update_prod_property_values(conn, &value.product_address, value.start_commit_num)?;
     }
 
     insert_into(product_property_value::table)
@@ -86,13 +86,13 @@ pub fn delete_product_property_values(
 
 fn update_prod_end_commit_num(
     conn: &PgConnection,
-    product_id: &str,
+    address: &str,
     current_commit_num: i64,
 ) -> QueryResult<()> {
     update(product::table)
         .filter(
-            product::product_id
-                .eq(product_id)
+            product::product_address
+                .eq(address)
                 .and(product::end_commit_num.eq(MAX_COMMIT_NUM)),
         )
         .set(product::end_commit_num.eq(current_commit_num))
@@ -102,13 +102,13 @@ fn update_prod_end_commit_num(
 
 fn update_prod_property_values(
     conn: &PgConnection,
-    product_id: &str,
+    address: &str,
     current_commit_num: i64,
 ) -> QueryResult<()> {
     update(product_property_value::table)
         .filter(
-            product_property_value::product_id
-                .eq(product_id)
+            product_property_value::product_address
+                .eq(address)
                 .and(product_property_value::end_commit_num.eq(MAX_COMMIT_NUM)),
         )
         .set(product_property_value::end_commit_num.eq(current_commit_num))
@@ -116,28 +116,101 @@ fn update_prod_property_values(
         .map(|_| ())
 }
 
-pub fn list_products(conn: &PgConnection, service_id: Option<&str>) -> QueryResult<Vec<Product>> {
-    let mut query = product::table
-        .into_boxed()
-        .select(product::all_columns)
-        .filter(product::end_commit_num.eq(MAX_COMMIT_NUM));
+/// The fields products can be sorted by
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ProductSortField {
+    ProductId,
+    Owner,
+}
+
+impl Default for ProductSortField {
+    fn default() -> Self {
+        ProductSortField::ProductId
+    }
+}
+
+/// Restricts the products returned by a list query
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProductFilter<'a> {
+    pub owner: Option<&'a str>,
+    /// Only return products with a transfer pending to the given organization
+    pub pending_owner: Option<&'a str>,
+}
+
+fn filtered_products<'a>(
+    filter: ProductFilter<'a>,
+    service_id: Option<&'a str>,
+    as_of: Option<i64>,
+) -> product::BoxedQuery<'a, Pg> {
+    let mut query = product::table.into_boxed();
+
+    query = filter_as_of!(query, product, as_of);
+
+    if let Some(owner) = filter.owner {
+        query = query.filter(product::owner.eq(owner));
+    }
+
+    if let Some(pending_owner) = filter.pending_owner {
+        query = query.filter(product::pending_owner.eq(pending_owner));
+    }
 
     if let Some(service_id) = service_id {
         query = query.filter(product::service_id.eq(service_id));
     } else {
         query = query.filter(product::service_id.is_null());
     }
-    query.load::<Product>(conn)
+
+    query
+}
+
+pub fn list_products(
+    conn: &PgConnection,
+    filter: ProductFilter,
+    service_id: Option<&str>,
+    as_of: Option<i64>,
+    sort: Sort<ProductSortField>,
+    limit: i64,
+    offset: i64,
+) -> QueryResult<Vec<Product>> {
+    let query = filtered_products(filter, service_id, as_of).select(product::all_columns);
+
+    let query = match (sort.field, sort.order) {
+        (ProductSortField::ProductId, SortOrder::Asc) => query.order(product::product_id.asc()),
+        (ProductSortField::ProductId, SortOrder::Desc) => query.order(product::product_id.desc()),
+        (ProductSortField::Owner, SortOrder::Asc) => {
+            query.order((product::owner.asc(), product::product_id.asc()))
+        }
+        (ProductSortField::Owner, SortOrder::Desc) => {
+            query.order((product::owner.desc(), product::product_id.asc()))
+        }
+    };
+
+    query.limit(limit).offset(offset).load::<Product>(conn)
+}
+
+pub fn count_products(
+    conn: &PgConnection,
+    filter: ProductFilter,
+    service_id: Option<&str>,
+    as_of: Option<i64>,
+) -> QueryResult<i64> {
+    filtered_products(filter, service_id, as_of)
+        .count()
+        .get_result(conn)
 }
 
 pub fn list_product_property_values(
     conn: &PgConnection,
+    product_addresses: &[String],
     service_id: Option<&str>,
+    as_of: Option<i64>,
 ) -> QueryResult<Vec<ProductPropertyValue>> {
     let mut query = product_property_value::table
         .into_boxed()
         .select(product_property_value::all_columns)
-        .filter(product_property_value::end_commit_num.eq(MAX_COMMIT_NUM));
+        .filter(product_property_value::product_address.eq_any(product_addresses));
+
+    query = filter_as_of!(query, product_property_value, as_of);
 
     if let Some(service_id) = service_id {
         query = query.filter(product_property_value::service_id.eq(service_id));
@@ -147,10 +220,14 @@ pub fn list_product_property_values(
     query.load::<ProductPropertyValue>(conn)
 }
 
+/// Fetches a product by its ID within a namespace; the same ID may identify a different product
+/// in another namespace, e.g. a GTIN-13 and a GLN with the same digits.
 pub fn fetch_product(
     conn: &PgConnection,
     product_id: &str,
+    product_namespace: &str,
     service_id: Option<&str>,
+    as_of: Option<i64>,
 ) -> QueryResult<Option<Product>> {
     let mut query = product::table
         .into_boxed()
@@ -158,9 +235,11 @@ pub fn fetch_product(
         .filter(
             product::product_id
                 .eq(product_id)
-                .and(product::end_commit_num.eq(MAX_COMMIT_NUM)),
+                .and(product::product_namespace.eq(product_namespace)),
         );
 
+    query = filter_as_of!(query, product, as_of);
+
     if let Some(service_id) = service_id {
         query = query.filter(product::service_id.eq(service_id));
     } else {
@@ -175,17 +254,16 @@ pub fn fetch_product(
 
 pub fn fetch_product_property_values(
     conn: &PgConnection,
-    product_id: &str,
+    product_address: &str,
     service_id: Option<&str>,
+    as_of: Option<i64>,
 ) -> QueryResult<Vec<ProductPropertyValue>> {
     let mut query = product_property_value::table
         .into_boxed()
         .select(product_property_value::all_columns)
-        .filter(
-            product_property_value::product_id
-                .eq(product_id)
-                .and(product_property_value::end_commit_num.eq(MAX_COMMIT_NUM)),
-        );
+        .filter(product_property_value::product_address.eq(product_address));
+
+    query = filter_as_of!(query, product_property_value, as_of);
 
     if let Some(service_id) = service_id {
         query = query.filter(product_property_value::service_id.eq(service_id));
@@ -194,3 +272,72 @@ pub fn fetch_product_property_values(
     }
     query.load::<ProductPropertyValue>(conn)
 }
+
+fn product_versions<'a>(
+    product_id: &'a str,
+    product_namespace: &'a str,
+    service_id: Option<&'a str>,
+) -> product::BoxedQuery<'a, Pg> {
+    let query = product::table.into_boxed().filter(
+        product::product_id
+            .eq(product_id)
+            .and(product::product_namespace.eq(product_namespace)),
+    );
+
+    if let Some(service_id) = service_id {
+        query.filter(product::service_id.eq(service_id))
+    } else {
+        query.filter(product::service_id.is_null())
+    }
+}
+
+/// Lists every version of the product in commit order, including the version retired when the
+/// product was deleted
+pub fn list_product_versions(
+    conn: &PgConnection,
+    product_id: &str,
+    product_namespace: &str,
+    service_id: Option<&str>,
+    limit: i64,
+    offset: i64,
+) -> QueryResult<Vec<Product>> {
+    product_versions(product_id, product_namespace, service_id)
+        .select(product::all_columns)
+        .order(product::start_commit_num.asc())
+        .limit(limit)
+        .offset(offset)
+        .load::<Product>(conn)
+}
+
+pub fn count_product_versions(
+    conn: &PgConnection,
+    product_id: &str,
+    product_namespace: &str,
+    service_id: Option<&str>,
+) -> QueryResult<i64> {
+    product_versions(product_id, product_namespace, service_id)
+        .count()
+        .get_result(conn)
+}
+
+/// Lists the property values of every version of the product
+pub fn list_product_property_value_versions(
+    conn: &PgConnection,
+    product_address: &str,
+    service_id: Option<&str>,
+) -> QueryResult<Vec<ProductPropertyValue>> {
+    let mut query = product_property_value::table
+        .into_boxed()
+        .select(product_property_value::all_columns)
+        .filter(product_property_value::product_address.eq(product_address));
+
+    if let Some(service_id) = service_id {
+        query = query.filter(product_property_value::service_id.eq(service_id));
+    } else {
+        query = query.filter(product_property_value::service_id.is_null());
+    }
+
+    query
+        .order(product_property_value::start_commit_num.asc())
+        .load::<ProductPropertyValue>(conn)
+}
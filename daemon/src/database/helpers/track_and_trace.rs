@@ -23,13 +23,14 @@ use super::schema::{
     associated_agent, grid_property_definition, property, proposal, record, reported_value,
     reported_value_reporter_to_agent_metadata, reporter,
 };
-use super::MAX_COMMIT_NUM;
+use super::{Sort, SortOrder, MAX_COMMIT_NUM};
 
 use diesel::{
-    dsl::{insert_into, update},
-    pg::PgConnection,
+    dsl::{insert_into, sql, update},
+    pg::{Pg, PgConnection},
     prelude::*,
     result::Error::NotFound,
+    sql_types::{Bool, Text},
     QueryResult,
 };
 
@@ -77,15 +78,14 @@ pub fn list_associated_agents(
     conn: &PgConnection,
     record_ids: &[String],
     service_id: Option<&str>,
+    as_of: Option<i64>,
 ) -> QueryResult<Vec<AssociatedAgent>> {
     let mut query = associated_agent::table
         .into_boxed()
         .select(associated_agent::all_columns)
-        .filter(
-            associated_agent::end_commit_num
-                .eq(MAX_COMMIT_NUM)
-                .and(associated_agent::record_id.eq_any(record_ids)),
-        );
+        .filter(associated_agent::record_id.eq_any(record_ids));
+
+    query = filter_as_of!(query, associated_agent, as_of);
 
     if let Some(service_id) = service_id {
         query = query.filter(associated_agent::service_id.eq(service_id));
@@ -170,15 +170,14 @@ pub fn list_proposals(
     conn: &PgConnection,
     record_ids: &[String],
     service_id: Option<&str>,
+    as_of: Option<i64>,
 ) -> QueryResult<Vec<Proposal>> {
     let mut query = proposal::table
         .into_boxed()
         .select(proposal::all_columns)
-        .filter(
-            proposal::end_commit_num
-                .eq(MAX_COMMIT_NUM)
-                .and(proposal::record_id.eq_any(record_ids)),
-        );
+        .filter(proposal::record_id.eq_any(record_ids));
+
+    query = filter_as_of!(query, proposal, as_of);
 
     if let Some(service_id) = service_id {
         query = query.filter(proposal::service_id.eq(service_id));
@@ -216,31 +215,113 @@ pub fn update_record_end_commit_num(
         .map(|_| ())
 }
 
-pub fn fetch_record(conn: &PgConnection, record_id: &str) -> QueryResult<Option<Record>> {
-    record::table
+pub fn fetch_record(
+    conn: &PgConnection,
+    record_id: &str,
+    as_of: Option<i64>,
+) -> QueryResult<Option<Record>> {
+    let mut query = record::table
+        .into_boxed()
         .select(record::all_columns)
-        .filter(
-            record::record_id
-                .eq(record_id)
-                .and(record::end_commit_num.eq(MAX_COMMIT_NUM)),
-        )
+        .filter(record::record_id.eq(record_id));
+
+    query = filter_as_of!(query, record, as_of);
+
+    query
         .first(conn)
         .map(Some)
         .or_else(|err| if err == NotFound { Ok(None) } else { Err(err) })
 }
 
-pub fn list_records(conn: &PgConnection, service_id: Option<&str>) -> QueryResult<Vec<Record>> {
-    let mut query = record::table
-        .into_boxed()
-        .select(record::all_columns)
-        .filter(record::end_commit_num.eq(MAX_COMMIT_NUM));
+/// The fields records can be sorted by
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RecordSortField {
+    RecordId,
+    Schema,
+}
+
+impl Default for RecordSortField {
+    fn default() -> Self {
+        RecordSortField::RecordId
+    }
+}
+
+/// Restricts the records returned by a list query
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RecordFilter<'a> {
+    pub schema: Option<&'a str>,
+    /// Only return records whose current owner is the given agent
+    pub owner: Option<&'a str>,
+    pub final_: Option<bool>,
+}
+
+fn filtered_records<'a>(
+    filter: RecordFilter<'a>,
+    service_id: Option<&'a str>,
+    as_of: Option<i64>,
+) -> record::BoxedQuery<'a, Pg> {
+    let mut query = record::table.into_boxed();
+
+    query = filter_as_of!(query, record, as_of);
+
+    if let Some(schema) = filter.schema {
+        query = query.filter(record::schema.eq(schema));
+    }
+
+    if let Some(owner) = filter.owner {
+        // The last entry of the owners array is the current owner of the record
+        query = query.filter(
+            sql::<Bool>("record.owners[array_upper(record.owners, 1)] = ").bind::<Text, _>(owner),
+        );
+    }
+
+    if let Some(final_) = filter.final_ {
+        query = query.filter(record::final_.eq(final_));
+    }
 
     if let Some(service_id) = service_id {
         query = query.filter(record::service_id.eq(service_id));
     } else {
         query = query.filter(record::service_id.is_null());
     }
-    query.load::<Record>(conn)
+
+    query
+}
+
+pub fn list_records(
+    conn: &PgConnection,
+    filter: RecordFilter,
+    service_id: Option<&str>,
+    as_of: Option<i64>,
+    sort: Sort<RecordSortField>,
+    limit: i64,
+    offset: i64,
+) -> QueryResult<Vec<Record>> {
+    let query = filtered_records(filter, service_id, as_of).select(record::all_columns);
+
+    let query = match (sort.field, sort.order) {
+        (RecordSortField::RecordId, SortOrder::Asc) => query.order(record::record_id.asc()),
+        (RecordSortField::RecordId, SortOrder::Desc) => query.order(record::record_id.desc()),
+        (RecordSortField::Schema, SortOrder::Asc) => {
+            query.order((record::schema.asc(), record::record_id.asc()))
+        }
+        (RecordSortField::Schema, SortOrder::Desc) => {
+            query.order((record::schema.desc(), record::record_id.asc()))
+        }
+    };
+
+    query.limit(limit).offset(offset).load::<Record>(conn)
+}
+
+pub fn count_records(
+    conn: &PgConnection,
+    filter: RecordFilter,
+    service_id: Option<&str>,
+    as_of: Option<i64>,
+) -> QueryResult<i64> {
+    filtered_records(filter, service_id, as_of)
+        .count()
+        .get_result(conn)
 }
 
 pub fn insert_reported_values(conn: &PgConnection, values: &[NewReportedValue]) -> QueryResult<()> {
@@ -314,30 +395,42 @@ pub fn update_reporter_end_commit_num(
         .map(|_| ())
 }
 
+/// Returns the commit number the rows joined to a property must be valid at. A row is valid from
+/// its start commit up to, but not including, its end commit, so the current rows are the ones
+/// valid at the commit before `MAX_COMMIT_NUM`.
+fn join_commit_num(as_of: Option<i64>) -> i64 {
+    as_of.unwrap_or(MAX_COMMIT_NUM - 1)
+}
+
 pub fn fetch_property_with_data_type(
     conn: &PgConnection,
     record_id: &str,
     property_name: &str,
     service_id: Option<&str>,
+    as_of: Option<i64>,
 ) -> QueryResult<Option<(Property, Option<String>)>> {
+    let commit_num = join_commit_num(as_of);
     let mut query = property::table
         .into_boxed()
         .left_join(
             record::table.on(property::record_id
                 .eq(record::record_id)
-                .and(property::end_commit_num.eq(record::end_commit_num))),
+                .and(record::start_commit_num.le(commit_num))
+                .and(record::end_commit_num.gt(commit_num))),
         )
         .left_join(
             grid_property_definition::table.on(record::schema
                 .eq(grid_property_definition::schema_name)
                 .and(property::name.eq(grid_property_definition::name))
-                .and(property::end_commit_num.eq(record::end_commit_num))),
+                .and(grid_property_definition::start_commit_num.le(commit_num))
+                .and(grid_property_definition::end_commit_num.gt(commit_num))),
         )
         .filter(
             property::name
                 .eq(property_name)
                 .and(property::record_id.eq(record_id))
-                .and(property::end_commit_num.eq(MAX_COMMIT_NUM)),
+                .and(property::start_commit_num.le(commit_num))
+                .and(property::end_commit_num.gt(commit_num)),
         );
 
     if let Some(service_id) = service_id {
@@ -377,28 +470,59 @@ pub fn fetch_reported_value_reporter_to_agent_metadata(
         .or_else(|err| if err == NotFound { Ok(None) } else { Err(err) })
 }
 
+/// Fetches the value a property had at the given commit
+pub fn fetch_reported_value_reporter_to_agent_metadata_as_of(
+    conn: &PgConnection,
+    record_id: &str,
+    property_name: &str,
+    commit_num: i64,
+) -> QueryResult<Option<ReportedValueReporterToAgentMetadata>> {
+    reported_value_reporter_to_agent_metadata::table
+        .filter(
+            reported_value_reporter_to_agent_metadata::property_name
+                .eq(property_name)
+                .and(reported_value_reporter_to_agent_metadata::record_id.eq(record_id))
+                .and(
+                    reported_value_reporter_to_agent_metadata::reported_value_start_commit_num
+                        .le(commit_num),
+                )
+                .and(
+                    reported_value_reporter_to_agent_metadata::reported_value_end_commit_num
+                        .gt(commit_num),
+                ),
+        )
+        .first(conn)
+        .map(Some)
+        .or_else(|err| if err == NotFound { Ok(None) } else { Err(err) })
+}
+
 pub fn list_properties_with_data_type(
     conn: &PgConnection,
     record_ids: &[String],
     service_id: Option<&str>,
+    as_of: Option<i64>,
 ) -> QueryResult<Vec<(Property, Option<String>)>> {
+    let commit_num = join_commit_num(as_of);
     let mut query = property::table
         .into_boxed()
         .left_join(
             record::table.on(property::record_id
                 .eq(record::record_id)
-                .and(property::end_commit_num.eq(record::end_commit_num))),
+                .and(record::start_commit_num.le(commit_num))
+                .and(record::end_commit_num.gt(commit_num))),
         )
         .left_join(
             grid_property_definition::table.on(record::schema
                 .eq(grid_property_definition::schema_name)
                 .and(property::name.eq(grid_property_definition::name))
-                .and(property::end_commit_num.eq(record::end_commit_num))),
+                .and(grid_property_definition::start_commit_num.le(commit_num))
+                .and(grid_property_definition::end_commit_num.gt(commit_num))),
         )
         .filter(
             property::record_id
                 .eq_any(record_ids)
-                .and(property::end_commit_num.eq(MAX_COMMIT_NUM)),
+                .and(property::start_commit_num.le(commit_num))
+                .and(property::end_commit_num.gt(commit_num)),
         );
 
     if let Some(service_id) = service_id {
@@ -418,23 +542,27 @@ pub fn list_reporters(
     conn: &PgConnection,
     record_id: &str,
     property_name: &str,
+    as_of: Option<i64>,
 ) -> QueryResult<Vec<Reporter>> {
-    reporter::table
-        .filter(
-            reporter::property_name
-                .eq(property_name)
-                .and(reporter::record_id.eq(record_id))
-                .and(reporter::end_commit_num.eq(MAX_COMMIT_NUM)),
-        )
-        .load::<Reporter>(conn)
+    let mut query = reporter::table.into_boxed().filter(
+        reporter::property_name
+            .eq(property_name)
+            .and(reporter::record_id.eq(record_id)),
+    );
+
+    query = filter_as_of!(query, reporter, as_of);
+
+    query.load::<Reporter>(conn)
 }
 
 pub fn list_reported_value_reporter_to_agent_metadata(
     conn: &PgConnection,
     record_id: &str,
     property_name: &str,
+    as_of: Option<i64>,
 ) -> QueryResult<Vec<ReportedValueReporterToAgentMetadata>> {
-    reported_value_reporter_to_agent_metadata::table
+    let mut query = reported_value_reporter_to_agent_metadata::table
+        .into_boxed()
         .filter(
             reported_value_reporter_to_agent_metadata::property_name
                 .eq(property_name)
@@ -443,6 +571,15 @@ pub fn list_reported_value_reporter_to_agent_metadata(
                     reported_value_reporter_to_agent_metadata::reported_value_end_commit_num
                         .le(MAX_COMMIT_NUM),
                 ),
-        )
-        .load::<ReportedValueReporterToAgentMetadata>(conn)
+        );
+
+    // Values reported after the given commit are not part of the property's history yet
+    if let Some(commit_num) = as_of {
+        query = query.filter(
+            reported_value_reporter_to_agent_metadata::reported_value_start_commit_num
+                .le(commit_num),
+        );
+    }
+
+    query.load::<ReportedValueReporterToAgentMetadata>(conn)
 }
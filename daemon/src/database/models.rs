@@ -54,12 +54,16 @@ pub struct NewAgent {
     pub active: bool,
     pub roles: Vec<String>,
     pub metadata: JsonValue,
+    /// The public key the agent held before its key was rotated
+    pub predecessor: Option<String>,
 
     // The indicators of the start and stop for the slowly-changing dimensions.
     pub start_commit_num: i64,
     pub end_commit_num: i64,
 
     pub service_id: Option<String>,
+    /// The address of the agent in state
+    pub state_address: String,
 }
 
 #[derive(Queryable, Debug)]
@@ -74,7 +78,9 @@ pub struct Agent {
     pub active: bool,
     pub roles: Vec<String>,
     pub metadata: JsonValue,
+    pub predecessor: Option<String>,
     pub service_id: Option<String>,
+    pub state_address: String,
 }
 
 #[derive(Insertable, Debug)]
@@ -84,12 +90,17 @@ pub struct NewOrganization {
     pub name: String,
     pub address: String,
     pub metadata: Vec<JsonValue>,
+    /// Alternate IDs, each stored as `<id_type>:<id>`
+    pub alternate_ids: Vec<String>,
+    pub locations: Vec<JsonValue>,
 
     // The indicators of the start and stop for the slowly-changing dimensions.
     pub start_commit_num: i64,
     pub end_commit_num: i64,
 
     pub service_id: Option<String>,
+    /// The address of the organization in state; `address` is its physical address
+    pub state_address: String,
 }
 
 #[derive(Queryable, Debug)]
@@ -100,12 +111,16 @@ pub struct Organization {
     pub name: String,
     pub address: String,
     pub metadata: Vec<JsonValue>,
+    /// Alternate IDs, each stored as `<id_type>:<id>`
+    pub alternate_ids: Vec<String>,
+    pub locations: Vec<JsonValue>,
 
     // The indicators of the start and stop for the slowly-changing dimensions.
     pub start_commit_num: i64,
     pub end_commit_num: i64,
 
     pub service_id: Option<String>,
+    pub state_address: String,
 }
 
 #[derive(Clone, Insertable, Debug)]
@@ -115,6 +130,8 @@ pub struct NewProduct {
     pub product_address: String,
     pub product_namespace: String,
     pub owner: String,
+    /// The organization a transfer of the product is pending to
+    pub pending_owner: Option<String>,
 
     // The indicators of the start and stop for the slowly-changing dimensions.
     pub start_commit_num: i64,
@@ -131,6 +148,8 @@ pub struct Product {
     pub product_address: String,
     pub product_namespace: String,
     pub owner: String,
+    /// The organization a transfer of the product is pending to
+    pub pending_owner: Option<String>,
 
     // The indicators of the start and stop for the slowly-changing dimensions.
     pub start_commit_num: i64,
@@ -162,7 +181,7 @@ pub struct NewProductPropertyValue {
 }
 
 #[allow(dead_code)]
-#[derive(Queryable, Debug)]
+#[derive(Queryable, Debug, Clone)]
 pub struct ProductPropertyValue {
     ///  This is the product id for the slowly-changing-dimensions table.
     pub id: i64,
@@ -193,6 +212,8 @@ pub struct NewGridSchema {
     pub name: String,
     pub description: String,
     pub owner: String,
+    pub version: i64,
+    pub editors: Vec<String>,
     pub service_id: Option<String>,
 }
 
@@ -205,6 +226,8 @@ pub struct GridSchema {
     pub name: String,
     pub description: String,
     pub owner: String,
+    pub version: i64,
+    pub editors: Vec<String>,
     pub service_id: Option<String>,
 }
 
@@ -218,6 +241,7 @@ pub struct NewGridPropertyDefinition {
     pub data_type: String,
     pub required: bool,
     pub description: String,
+    pub deprecated: bool,
     pub number_exponent: i64,
     pub enum_options: Vec<String>,
     pub struct_properties: Vec<String>,
@@ -235,6 +259,7 @@ pub struct GridPropertyDefinition {
     pub data_type: String,
     pub required: bool,
     pub description: String,
+    pub deprecated: bool,
     pub number_exponent: i64,
     pub enum_options: Vec<String>,
     pub struct_properties: Vec<String>,
@@ -361,6 +386,7 @@ pub struct NewRecord {
     pub end_commit_num: i64,
     pub record_id: String,
     pub schema: String,
+    pub schema_version: i64,
     pub final_: bool,
     pub owners: Vec<String>,
     pub custodians: Vec<String>,
@@ -375,6 +401,7 @@ pub struct Record {
     pub end_commit_num: i64,
     pub record_id: String,
     pub schema: String,
+    pub schema_version: i64,
     pub final_: bool,
     pub owners: Vec<String>,
     pub custodians: Vec<String>,
@@ -467,6 +494,7 @@ pub struct ReportedValueReporterToAgentMetadata {
     pub public_key: Option<String>,
     pub authorized: Option<bool>,
     pub metadata: Option<JsonValue>,
+    pub reported_value_start_commit_num: i64,
     pub reported_value_end_commit_num: i64,
     pub reporter_end_commit_num: Option<i64>,
     pub service_id: Option<String>,
@@ -486,6 +514,8 @@ pub struct GridCircuit {
     pub status: String,
     pub created_time: SystemTime,
     pub updated_time: SystemTime,
+    // The Scabbard service this node runs on the circuit, if any
+    pub service_id: Option<String>,
 }
 
 #[derive(Queryable, Identifiable, Associations, PartialEq, Debug)]
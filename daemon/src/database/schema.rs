@@ -27,7 +27,9 @@ table! {
         active -> Bool,
         roles -> Array<Text>,
         metadata -> Json,
+        predecessor -> Nullable<Text>,
         service_id -> Nullable<Text>,
+        state_address -> Varchar,
     }
 }
 
@@ -72,6 +74,7 @@ table! {
         data_type -> Text,
         required -> Bool,
         description -> Text,
+        deprecated -> Bool,
         number_exponent -> Int8,
         enum_options -> Array<Text>,
         struct_properties -> Array<Text>,
@@ -87,6 +90,8 @@ table! {
         name -> Text,
         description -> Text,
         owner -> Text,
+        version -> Int8,
+        editors -> Array<Text>,
         service_id -> Nullable<Text>,
     }
 }
@@ -98,9 +103,12 @@ table! {
         name -> Varchar,
         address -> Varchar,
         metadata -> Array<Json>,
+        alternate_ids -> Array<Text>,
+        locations -> Array<Json>,
         start_commit_num -> Int8,
         end_commit_num -> Int8,
         service_id -> Nullable<Text>,
+        state_address -> Varchar,
     }
 }
 
@@ -111,6 +119,7 @@ table! {
         product_address -> Varchar,
         product_namespace -> Text,
         owner -> Varchar,
+        pending_owner -> Nullable<Text>,
         start_commit_num -> Int8,
         end_commit_num -> Int8,
         service_id -> Nullable<Text>,
@@ -177,6 +186,7 @@ table! {
         end_commit_num -> Int8,
         record_id -> Text,
         schema -> Text,
+        schema_version -> Int8,
         #[sql_name = "final"]
         final_ -> Bool,
         owners -> Array<Text>,
@@ -242,6 +252,7 @@ table! {
         public_key ->  Nullable<Text>,
         authorized ->  Nullable<Bool>,
         metadata ->  Nullable<Json>,
+        reported_value_start_commit_num -> Int8,
         reported_value_end_commit_num -> Int8,
         reporter_end_commit_num ->  Nullable<Int8>,
         service_id -> Nullable<Text>,
@@ -274,6 +285,7 @@ table! {
         status -> Text,
         created_time -> Timestamp,
         updated_time -> Timestamp,
+        service_id -> Nullable<Text>,
     }
 }
 
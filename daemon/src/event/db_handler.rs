@@ -46,8 +46,8 @@ use crate::database::{
 
 use super::{
     CommitEvent, EventError, EventHandler, StateChange, GRID_PRODUCT, GRID_SCHEMA,
-    IGNORED_NAMESPACES, PIKE_AGENT, PIKE_ORG, TRACK_AND_TRACE_PROPERTY, TRACK_AND_TRACE_PROPOSAL,
-    TRACK_AND_TRACE_RECORD,
+    IGNORED_NAMESPACES, PIKE_AGENT, PIKE_ALTERNATE_ID_INDEX, PIKE_DELEGATION, PIKE_ORG, PIKE_ROLE,
+    TRACK_AND_TRACE_PROPERTY, TRACK_AND_TRACE_PROPOSAL, TRACK_AND_TRACE_RECORD,
 };
 
 pub struct DatabaseEventHandler {
@@ -179,9 +179,13 @@ fn state_change_to_db_operation(
                                 acc
                             }
                         )),
+                        predecessor: Some(agent.predecessor())
+                            .filter(|predecessor| !predecessor.is_empty())
+                            .map(String::from),
                         start_commit_num: commit_num,
                         end_commit_num: db::MAX_COMMIT_NUM,
                         service_id: service_id.cloned(),
+                        state_address: key.to_string(),
                     })
                     .collect::<Vec<NewAgent>>();
 
@@ -207,9 +211,32 @@ fn state_change_to_db_operation(
                                 })
                             })
                             .collect::<Vec<JsonValue>>(),
+                        alternate_ids: org
+                            .alternate_ids()
+                            .iter()
+                            .map(|alternate_id| {
+                                format!("{}:{}", alternate_id.id_type(), alternate_id.id())
+                            })
+                            .collect::<Vec<String>>(),
+                        locations: org
+                            .locations()
+                            .iter()
+                            .map(|location| {
+                                json!({
+                                    "name": location.name(),
+                                    "street_address": location.street_address(),
+                                    "locality": location.locality(),
+                                    "region": location.region(),
+                                    "postal_code": location.postal_code(),
+                                    "country": location.country(),
+                                    "gln": location.gln(),
+                                })
+                            })
+                            .collect::<Vec<JsonValue>>(),
                         start_commit_num: commit_num,
                         end_commit_num: db::MAX_COMMIT_NUM,
                         service_id: service_id.cloned(),
+                        state_address: key.to_string(),
                     })
                     .collect::<Vec<NewOrganization>>();
 
@@ -225,6 +252,8 @@ fn state_change_to_db_operation(
                             name: state_schema.name().to_string(),
                             description: state_schema.description().to_string(),
                             owner: state_schema.owner().to_string(),
+                            version: i64::from(*state_schema.version()),
+                            editors: state_schema.editors().to_vec(),
                             start_commit_num: commit_num,
                             end_commit_num: db::MAX_COMMIT_NUM,
                             service_id: service_id.cloned(),
@@ -360,6 +389,7 @@ fn state_change_to_db_operation(
                         record_id: record.record_id().to_string(),
                         final_: *record.field_final(),
                         schema: record.schema().to_string(),
+                        schema_version: i64::from(*record.schema_version()),
                         owners: record
                             .owners()
                             .iter()
@@ -424,6 +454,10 @@ fn state_change_to_db_operation(
                             product_address: key.to_string(),
                             product_namespace: format!("{:?}", product.product_type()),
                             owner: product.owner().to_string(),
+                            pending_owner: match product.pending_owner() {
+                                "" => None,
+                                pending_owner => Some(pending_owner.to_string()),
+                            },
                             start_commit_num: commit_num,
                             end_commit_num: db::MAX_COMMIT_NUM,
                             service_id: service_id.cloned(),
@@ -457,19 +491,27 @@ fn state_change_to_db_operation(
                 Ok(None)
             }
         },
-        StateChange::Delete { key } => {
-            if &key[0..8] == GRID_PRODUCT {
-                Ok(Some(DbInsertOperation::RemoveProduct(
-                    key.to_string(),
-                    commit_num,
-                )))
-            } else {
-                Err(EventError(format!(
-                    "could not handle state change; unexpected delete of key {}",
-                    key
-                )))
-            }
-        }
+        StateChange::Delete { key } => match &key[0..8] {
+            GRID_PRODUCT => Ok(Some(DbInsertOperation::RemoveProduct(
+                key.to_string(),
+                commit_num,
+            ))),
+            PIKE_AGENT => Ok(Some(DbInsertOperation::RemoveAgent(
+                key.to_string(),
+                commit_num,
+                service_id.cloned(),
+            ))),
+            PIKE_ORG => Ok(Some(DbInsertOperation::RemoveOrganization(
+                key.to_string(),
+                commit_num,
+                service_id.cloned(),
+            ))),
+            PIKE_ROLE | PIKE_DELEGATION | PIKE_ALTERNATE_ID_INDEX => Ok(None),
+            _ => Err(EventError(format!(
+                "could not handle state change; unexpected delete of key {}",
+                key
+            ))),
+        },
     }
 }
 
@@ -484,6 +526,8 @@ enum DbInsertOperation {
     Records(Vec<NewRecord>, Vec<NewAssociatedAgent>),
     Products(Vec<NewProduct>, Vec<NewProductPropertyValue>),
     RemoveProduct(String, i64),
+    RemoveAgent(String, i64, Option<String>),
+    RemoveOrganization(String, i64, Option<String>),
 }
 
 impl DbInsertOperation {
@@ -529,6 +573,18 @@ impl DbInsertOperation {
                 db::delete_product(conn, address, current_commit_num)?;
                 db::delete_product_property_values(conn, address, current_commit_num)
             }
+            DbInsertOperation::RemoveAgent(ref address, current_commit_num, ref service_id) => {
+                debug!("Removing agent at address {}", address);
+                db::delete_agent(conn, address, service_id.as_deref(), current_commit_num)
+            }
+            DbInsertOperation::RemoveOrganization(
+                ref address,
+                current_commit_num,
+                ref service_id,
+            ) => {
+                debug!("Removing organization at address {}", address);
+                db::delete_organization(conn, address, service_id.as_deref(), current_commit_num)
+            }
         }
     }
 }
@@ -632,6 +688,7 @@ fn make_property_definitions(
             data_type: format!("{:?}", def.data_type()),
             required: *def.required(),
             description: def.description().to_string(),
+            deprecated: *def.deprecated(),
             number_exponent: i64::from(*def.number_exponent()),
             enum_options: def.enum_options().to_vec(),
             struct_properties: def
@@ -17,6 +17,7 @@
 
 pub mod db_handler;
 mod error;
+pub mod progress;
 
 use std::cell::RefCell;
 use std::thread;
@@ -26,6 +27,9 @@ pub use self::error::{EventError, EventIoError, EventProcessorError};
 const PIKE_NAMESPACE: &str = "cad11d";
 const PIKE_AGENT: &str = "cad11d00";
 const PIKE_ORG: &str = "cad11d01";
+const PIKE_ROLE: &str = "cad11d02";
+const PIKE_DELEGATION: &str = "cad11d03";
+const PIKE_ALTERNATE_ID_INDEX: &str = "cad11d04";
 
 const GRID_NAMESPACE: &str = "621dee";
 const GRID_SCHEMA: &str = "621dee01";
@@ -40,7 +44,14 @@ const ALL_GRID_NAMESPACES: &[&str] = &[PIKE_NAMESPACE, GRID_NAMESPACE, TRACK_AND
 
 const SABRE_NAMESPACE: &str = "00ec";
 
-const IGNORED_NAMESPACES: &[&str] = &[SABRE_NAMESPACE];
+// Pike roles and delegations are resolved on-chain and are not stored in the database. The
+// alternate ID index only repeats what the organization records already hold.
+const IGNORED_NAMESPACES: &[&str] = &[
+    SABRE_NAMESPACE,
+    PIKE_ROLE,
+    PIKE_DELEGATION,
+    PIKE_ALTERNATE_ID_INDEX,
+];
 
 /// A notification that some source has committed a set of changes to state
 pub struct CommitEvent {
@@ -0,0 +1,73 @@
+/*
+ * Copyright 2020 Cargill Incorporated
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * -----------------------------------------------------------------------------
+ */
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::{CommitEvent, EventError, EventHandler};
+
+/// Number of commits between progress reports
+const REPORT_INTERVAL: u64 = 100;
+
+/// Reports progress while an event connection replays past commits.
+///
+/// If the height the source was at on start-up is known, progress is reported against it and
+/// reporting stops once it is reached. Otherwise, the number of commits processed so far is
+/// reported.
+pub struct ReplayProgressHandler {
+    source: String,
+    target_height: Option<u64>,
+    processed: AtomicU64,
+}
+
+impl ReplayProgressHandler {
+    pub fn new(source: &str, target_height: Option<u64>) -> Self {
+        Self {
+            source: source.into(),
+            target_height,
+            processed: AtomicU64::new(0),
+        }
+    }
+}
+
+impl EventHandler for ReplayProgressHandler {
+    fn handle_event(&self, event: &CommitEvent) -> Result<(), EventError> {
+        let processed = self.processed.fetch_add(1, Ordering::SeqCst) + 1;
+
+        match (event.height, self.target_height) {
+            (Some(height), Some(target_height)) if height == target_height => info!(
+                "Caught up with {} at height {} after {} commits",
+                self.source, height, processed
+            ),
+            (Some(height), Some(target_height)) if height < target_height => {
+                if processed % REPORT_INTERVAL == 0 {
+                    info!(
+                        "Processed {} commits from {} (height {} of {})",
+                        processed, self.source, height, target_height
+                    );
+                }
+            }
+            (_, None) => {
+                if processed % REPORT_INTERVAL == 0 {
+                    info!("Processed {} commits from {}", processed, self.source);
+                }
+            }
+            _ => (),
+        }
+
+        Ok(())
+    }
+}
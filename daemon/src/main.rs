@@ -45,12 +45,16 @@ use std::sync::atomic::{AtomicBool, Ordering};
 
 #[cfg(feature = "splinter-support")]
 use ::splinter::events::Reactor;
+use diesel::Connection;
 use flexi_logger::{LogSpecBuilder, Logger};
 
 use crate::config::{GridConfig, GridConfigBuilder};
 use crate::database::{error::DatabaseError, helpers as db, ConnectionPool};
-use crate::error::DaemonError;
-use crate::event::{db_handler::DatabaseEventHandler, EventProcessor};
+use crate::error::{ConfigurationError, DaemonError};
+use crate::event::{
+    db_handler::DatabaseEventHandler, progress::ReplayProgressHandler, EventConnection,
+    EventProcessor,
+};
 #[cfg(feature = "sawtooth-support")]
 use crate::sawtooth::{batch_submitter::SawtoothBatchSubmitter, connection::SawtoothConnection};
 #[cfg(feature = "splinter-support")]
@@ -73,10 +77,20 @@ fn run() -> Result<(), DaemonError> {
         (@arg database_url: --("database-url") +takes_value
          "specifies the database URL to connect to.")
         (@arg bind: -b --bind +takes_value "connection endpoint for rest API")
-        (@arg admin_key_dir: --("admin-key-dir") +takes_value "directory containing the Scabbard admin key files"))
+        (@arg admin_key_dir: --("admin-key-dir") +takes_value "directory containing the Scabbard admin key files")
+        (@arg circuit_contracts: --("circuit-contracts") +takes_value +multiple +use_delimiter
+         "comma-separated Grid contracts to deploy on new circuits (pike, schema, product, track_and_trace); defaults to all")
+        (@subcommand reindex =>
+            (about: "Clear the database and rebuild it by replaying every commit from the ledger")
+            (@arg service_id: --("service-id") +takes_value
+             "only rebuild the data of the given Splinter service (<circuit_id>::<service_id>)")))
     .get_matches();
 
+    let reindex_matches = matches.subcommand_matches("reindex");
+
     let log_level = match matches.occurrences_of("verbose") {
+        // Progress of a rebuild is reported at the info level
+        0 if reindex_matches.is_some() => log::LevelFilter::Info,
         0 => log::LevelFilter::Warn,
         1 => log::LevelFilter::Info,
         2 => log::LevelFilter::Debug,
@@ -95,6 +109,14 @@ fn run() -> Result<(), DaemonError> {
 
     let connection_pool = database::create_connection_pool(config.database_url())?;
 
+    if let Some(reindex_matches) = reindex_matches {
+        clear_database(
+            &config,
+            &connection_pool,
+            reindex_matches.value_of("service_id"),
+        )?;
+    }
+
     if config.endpoint().is_sawtooth() {
         run_sawtooth(config, connection_pool)?;
     } else if config.endpoint().is_splinter() {
@@ -108,11 +130,60 @@ fn run() -> Result<(), DaemonError> {
     Ok(())
 }
 
+/// Removes everything recorded from the ledger, so that the event processors replay it from the
+/// first commit once they start. Circuits are kept, since they determine which Splinter services
+/// are replayed.
+fn clear_database(
+    config: &GridConfig,
+    connection_pool: &ConnectionPool,
+    service_id: Option<&str>,
+) -> Result<(), DaemonError> {
+    let conn = connection_pool.get()?;
+
+    if let Some(service_id) = service_id {
+        if !config.endpoint().is_splinter() {
+            return Err(DaemonError::from(ConfigurationError::InvalidValue(
+                "a service ID can only be given when connected to Splinter".into(),
+            )));
+        }
+
+        let is_known_service = db::list_circuits_with_service(&*conn)
+            .map_err(DatabaseError::from)?
+            .iter()
+            .any(|circuit| match circuit.service_id {
+                Some(ref circuit_service_id) => {
+                    format!("{}::{}", circuit.circuit_id, circuit_service_id) == service_id
+                }
+                None => false,
+            });
+        if !is_known_service {
+            return Err(DaemonError::from(ConfigurationError::InvalidValue(
+                format!("no circuit is known with the service {}", service_id),
+            )));
+        }
+    }
+
+    conn.transaction(|| db::clear_commits(&*conn, service_id))
+        .map_err(DatabaseError::from)?;
+
+    match service_id {
+        Some(service_id) => info!("Cleared the data of {}; rebuilding it", service_id),
+        None => info!("Cleared the database; rebuilding it"),
+    }
+
+    Ok(())
+}
+
 #[cfg(feature = "sawtooth-support")]
 fn run_sawtooth(config: GridConfig, connection_pool: ConnectionPool) -> Result<(), DaemonError> {
     let sawtooth_connection = SawtoothConnection::new(&config.endpoint().url());
-    let current_commit =
-        db::get_current_commit_id(&*connection_pool.get()?).map_err(DatabaseError::from)?;
+    let current_commit = find_common_commit_id(&connection_pool, &sawtooth_connection)?;
+    let progress_handler = ReplayProgressHandler::new(
+        sawtooth_connection.name(),
+        sawtooth_connection
+            .get_chain_height()
+            .map_err(|err| DaemonError::StartUpError(Box::new(err)))?,
+    );
 
     let batch_submitter = Box::new(SawtoothBatchSubmitter::new(
         sawtooth_connection.get_sender(),
@@ -128,7 +199,7 @@ fn run_sawtooth(config: GridConfig, connection_pool: ConnectionPool) -> Result<(
     let evt_processor = EventProcessor::start(
         sawtooth_connection,
         current_commit.as_deref(),
-        event_handlers![DatabaseEventHandler::new(connection_pool)],
+        event_handlers![DatabaseEventHandler::new(connection_pool), progress_handler],
     )
     .map_err(|err| DaemonError::EventProcessorError(Box::new(err)))?;
 
@@ -169,6 +240,53 @@ fn run_sawtooth(config: GridConfig, connection_pool: ConnectionPool) -> Result<(
     Ok(())
 }
 
+/// Finds the latest stored commit that is still on the validator's chain, walking back from the
+/// current commit. Any commits above it belong to a fork the validator has since abandoned, so
+/// they are removed, along with the state they introduced, before events are replayed from it.
+#[cfg(feature = "sawtooth-support")]
+fn find_common_commit_id(
+    connection_pool: &ConnectionPool,
+    sawtooth_connection: &SawtoothConnection,
+) -> Result<Option<String>, DaemonError> {
+    let conn = connection_pool.get()?;
+
+    let mut candidate = db::get_current_commit(&*conn).map_err(DatabaseError::from)?;
+    let current_commit_num = candidate.as_ref().map(|commit| commit.commit_num);
+
+    while let Some(commit) = candidate {
+        let block_id = sawtooth_connection
+            .get_block_id(commit.commit_num as u64)
+            .map_err(|err| DaemonError::StartUpError(Box::new(err)))?;
+
+        if block_id.as_deref() == Some(commit.commit_id.as_str()) {
+            if current_commit_num != Some(commit.commit_num) {
+                info!(
+                    "Resuming from commit {} at height {}; later commits were on an abandoned fork",
+                    commit.commit_id, commit.commit_num
+                );
+                conn.transaction(|| db::resolve_fork(&*conn, commit.commit_num + 1))
+                    .map_err(DatabaseError::from)?;
+            }
+            return Ok(Some(commit.commit_id));
+        }
+
+        debug!(
+            "Commit {} at height {} is not on the validator's chain",
+            commit.commit_id, commit.commit_num
+        );
+        candidate =
+            db::get_previous_commit(&*conn, commit.commit_num).map_err(DatabaseError::from)?;
+    }
+
+    if current_commit_num.is_some() {
+        warn!("No stored commit is on the validator's chain; replaying from the genesis block");
+        conn.transaction(|| db::resolve_fork(&*conn, 0))
+            .map_err(DatabaseError::from)?;
+    }
+
+    Ok(None)
+}
+
 #[cfg(not(feature = "sawtooth-support"))]
 fn run_sawtooth(config: GridConfig, _connection_pool: ConnectionPool) -> Result<(), DaemonError> {
     Err(DaemonError::UnsupportedEndpoint(format!(
@@ -193,6 +311,7 @@ fn run_splinter(config: GridConfig, connection_pool: ConnectionPool) -> Result<(
         connection_pool.clone(),
         reactor.igniter(),
         scabbard_admin_key,
+        config.circuit_contracts().to_vec(),
     )?;
 
     let batch_submitter = Box::new(SplinterBatchSubmitter::new(config.endpoint().url()));
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 pub mod error;
+mod paging;
 mod routes;
 
 use std::sync::mpsc;
@@ -24,8 +25,9 @@ pub use crate::rest_api::error::RestApiServerError;
 use crate::rest_api::routes::DbExecutor;
 use crate::rest_api::routes::{
     fetch_agent, fetch_grid_schema, fetch_organization, fetch_product, fetch_record,
-    fetch_record_property, get_batch_statuses, list_agents, list_grid_schemas, list_organizations,
-    list_products, list_records, submit_batches,
+    fetch_record_property, get_batch_statuses, list_agent_history, list_agents, list_grid_schemas,
+    list_organization_history, list_organizations, list_product_history, list_products,
+    list_records, submit_batches,
 };
 use crate::submitter::BatchSubmitter;
 use actix::{Addr, SyncArbiter};
@@ -67,6 +69,22 @@ pub struct QueryServiceId {
     pub service_id: Option<String>,
 }
 
+/// Query parameters accepted by the routes that fetch a single item. `as_of` is either the number
+/// or the id of the commit the item should be returned as of.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FetchQuery {
+    pub service_id: Option<String>,
+    pub as_of: Option<String>,
+}
+
+/// Query parameters accepted by the routes that list the versions of a single item
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HistoryQuery {
+    pub service_id: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
 pub struct AcceptServiceIdParam;
 
 impl FromRequest for AcceptServiceIdParam {
@@ -148,19 +166,40 @@ pub fn run(
                             .service(web::resource("").route(web::get().to(list_agents)))
                             .service(
                                 web::resource("/{public_key}").route(web::get().to(fetch_agent)),
+                            )
+                            .service(
+                                web::resource("/{public_key}/history")
+                                    .route(web::get().to(list_agent_history)),
                             ),
                     )
+                    .service(
+                        web::scope("/circuit")
+                            .service(web::resource("").route(web::get().to(list_circuits)))
+                            .service(
+                                web::resource("/proposal")
+                                    .route(web::get().to(list_circuit_proposals)),
+                            )
+                            .service(web::resource("/{id}").route(web::get().to(fetch_circuit))),
+                    )
                     .service(
                         web::scope("/organization")
                             .service(web::resource("").route(web::get().to(list_organizations)))
                             .service(
                                 web::resource("/{id}").route(web::get().to(fetch_organization)),
+                            )
+                            .service(
+                                web::resource("/{id}/history")
+                                    .route(web::get().to(list_organization_history)),
                             ),
                     )
                     .service(
                         web::scope("/product")
                             .service(web::resource("").route(web::get().to(list_products)))
-                            .service(web::resource("/{id}").route(web::get().to(fetch_product))),
+                            .service(web::resource("/{id}").route(web::get().to(fetch_product)))
+                            .service(
+                                web::resource("/{id}/history")
+                                    .route(web::get().to(list_product_history)),
+                            ),
                     )
                     .service(
                         web::scope("/schema")
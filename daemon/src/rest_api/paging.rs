@@ -0,0 +1,197 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::database::helpers::SortOrder;
+use crate::rest_api::error::RestApiResponseError;
+
+use actix_web::HttpRequest;
+use serde::{Deserialize, Serialize};
+use url::form_urlencoded;
+
+pub const DEFAULT_LIMIT: i64 = 100;
+pub const MAX_LIMIT: i64 = 1000;
+
+/// A page of results returned by a list endpoint
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListSlice<T> {
+    pub data: Vec<T>,
+    pub paging: Paging,
+}
+
+/// Links and counts that allow a client to walk through the pages of a list endpoint. `prev`
+/// and `next` are omitted on the first and last page respectively.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Paging {
+    pub current: String,
+    pub offset: i64,
+    pub limit: i64,
+    pub total: i64,
+    pub first: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prev: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next: Option<String>,
+    pub last: String,
+}
+
+impl<T> ListSlice<T> {
+    pub fn new(data: Vec<T>, paging: Paging) -> Self {
+        Self { data, paging }
+    }
+}
+
+/// Checks the limit and offset query parameters of a list request, falling back to the
+/// defaults when they are not provided.
+pub fn validate_paging(
+    limit: Option<i64>,
+    offset: Option<i64>,
+) -> Result<(i64, i64), RestApiResponseError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let offset = offset.unwrap_or(0);
+
+    if limit <= 0 || limit > MAX_LIMIT {
+        return Err(RestApiResponseError::BadRequest(format!(
+            "Invalid limit {}: must be between 1 and {}",
+            limit, MAX_LIMIT
+        )));
+    }
+
+    if offset < 0 {
+        return Err(RestApiResponseError::BadRequest(format!(
+            "Invalid offset {}: must not be negative",
+            offset
+        )));
+    }
+
+    Ok((limit, offset))
+}
+
+/// Splits a sort query parameter of the form `<field>` or `-<field>` into the field name and the
+/// order the results should be sorted in. A leading `-` sorts in descending order.
+pub fn parse_sort(sort: &str) -> (&str, SortOrder) {
+    if sort.starts_with('-') {
+        (&sort[1..], SortOrder::Desc)
+    } else {
+        (sort, SortOrder::Asc)
+    }
+}
+
+/// Returns the link used to build the paging links for the given request: the request path and
+/// every query parameter other than `limit` and `offset`.
+pub fn get_base_link(req: &HttpRequest) -> String {
+    let query = form_urlencoded::parse(req.query_string().as_bytes())
+        .filter(|(key, _)| key != "limit" && key != "offset")
+        .fold(
+            form_urlencoded::Serializer::new(String::new()),
+            |mut serializer, (key, value)| {
+                serializer.append_pair(&key, &value);
+                serializer
+            },
+        )
+        .finish();
+
+    if query.is_empty() {
+        format!("{}?", req.uri().path())
+    } else {
+        format!("{}?{}&", req.uri().path(), query)
+    }
+}
+
+pub fn get_response_paging_info(limit: i64, offset: i64, link: &str, total: i64) -> Paging {
+    let last_offset = if total > 0 {
+        ((total - 1) / limit) * limit
+    } else {
+        0
+    };
+
+    let build_link = |offset: i64| format!("{}limit={}&offset={}", link, limit, offset);
+
+    Paging {
+        current: build_link(offset),
+        offset,
+        limit,
+        total,
+        first: build_link(0),
+        prev: if offset > 0 {
+            Some(build_link((offset - limit).max(0)))
+        } else {
+            None
+        },
+        next: if offset + limit < total {
+            Some(build_link(offset + limit))
+        } else {
+            None
+        },
+        last: build_link(last_offset),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    static LINK: &str = "/product?owner=my_org&";
+
+    #[test]
+    fn test_paging_first_page() {
+        let paging = get_response_paging_info(10, 0, LINK, 25);
+
+        assert_eq!(paging.current, "/product?owner=my_org&limit=10&offset=0");
+        assert_eq!(paging.first, "/product?owner=my_org&limit=10&offset=0");
+        assert_eq!(paging.prev, None);
+        assert_eq!(
+            paging.next,
+            Some("/product?owner=my_org&limit=10&offset=10".to_string())
+        );
+        assert_eq!(paging.last, "/product?owner=my_org&limit=10&offset=20");
+    }
+
+    #[test]
+    fn test_paging_last_page() {
+        let paging = get_response_paging_info(10, 20, LINK, 25);
+
+        assert_eq!(
+            paging.prev,
+            Some("/product?owner=my_org&limit=10&offset=10".to_string())
+        );
+        assert_eq!(paging.next, None);
+        assert_eq!(paging.last, "/product?owner=my_org&limit=10&offset=20");
+    }
+
+    #[test]
+    fn test_paging_empty() {
+        let paging = get_response_paging_info(10, 0, LINK, 0);
+
+        assert_eq!(paging.prev, None);
+        assert_eq!(paging.next, None);
+        assert_eq!(paging.last, "/product?owner=my_org&limit=10&offset=0");
+    }
+
+    #[test]
+    fn test_validate_paging() {
+        assert_eq!(validate_paging(None, None).unwrap(), (DEFAULT_LIMIT, 0));
+        assert_eq!(validate_paging(Some(5), Some(10)).unwrap(), (5, 10));
+        assert!(validate_paging(Some(0), None).is_err());
+        assert!(validate_paging(Some(MAX_LIMIT + 1), None).is_err());
+        assert!(validate_paging(None, Some(-1)).is_err());
+    }
+
+    #[test]
+    fn test_parse_sort() {
+        assert_eq!(parse_sort("product_id"), ("product_id", SortOrder::Asc));
+        assert_eq!(parse_sort("-owner"), ("owner", SortOrder::Desc));
+    }
+}
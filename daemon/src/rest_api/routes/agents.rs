@@ -12,13 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::database::{helpers as db, models::Agent};
+use crate::database::{
+    helpers::{self as db, AgentSortField, Sort},
+    models::Agent,
+};
 use crate::rest_api::{
-    error::RestApiResponseError, routes::DbExecutor, AcceptServiceIdParam, AppState, QueryServiceId,
+    error::RestApiResponseError,
+    paging::{get_base_link, get_response_paging_info, parse_sort, validate_paging, ListSlice},
+    routes::{get_commit_ids, resolve_as_of, DbExecutor, VersionSlice},
+    AcceptServiceIdParam, AppState, FetchQuery, HistoryQuery,
 };
 
 use actix::{Handler, Message, SyncContext};
-use actix_web::{web, HttpResponse};
+use actix_web::{web, HttpRequest, HttpResponse};
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 
@@ -31,6 +37,9 @@ pub struct AgentSlice {
     pub metadata: JsonValue,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub predecessor: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub service_id: Option<String>,
 }
 
@@ -42,50 +51,126 @@ impl AgentSlice {
             active: agent.active,
             roles: agent.roles.clone(),
             metadata: agent.metadata.clone(),
+            predecessor: agent.predecessor.clone(),
             service_id: agent.service_id.clone(),
         }
     }
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ListAgentsQuery {
+    pub service_id: Option<String>,
+    pub as_of: Option<String>,
+    pub org_id: Option<String>,
+    pub sort: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
 struct ListAgents {
+    org_id: Option<String>,
     service_id: Option<String>,
+    as_of: Option<String>,
+    sort: Sort<AgentSortField>,
+    limit: i64,
+    offset: i64,
 }
 
 impl Message for ListAgents {
-    type Result = Result<Vec<AgentSlice>, RestApiResponseError>;
+    type Result = Result<(Vec<AgentSlice>, i64), RestApiResponseError>;
 }
 
 impl Handler<ListAgents> for DbExecutor {
-    type Result = Result<Vec<AgentSlice>, RestApiResponseError>;
+    type Result = Result<(Vec<AgentSlice>, i64), RestApiResponseError>;
 
     fn handle(&mut self, msg: ListAgents, _: &mut SyncContext<Self>) -> Self::Result {
-        let fetched_agents =
-            db::get_agents(&*self.connection_pool.get()?, msg.service_id.as_deref())?
+        let conn = self.connection_pool.get()?;
+
+        conn.build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run(|| {
+                let as_of = resolve_as_of(&*conn, msg.as_of.as_deref(), msg.service_id.as_deref())?;
+
+                let fetched_agents = db::get_agents(
+                    &*conn,
+                    msg.org_id.as_deref(),
+                    msg.service_id.as_deref(),
+                    as_of,
+                    msg.sort,
+                    msg.limit,
+                    msg.offset,
+                )?
                 .iter()
                 .map(|agent| AgentSlice::from_agent(agent))
                 .collect::<Vec<AgentSlice>>();
 
-        Ok(fetched_agents)
+                let total = db::count_agents(
+                    &*conn,
+                    msg.org_id.as_deref(),
+                    msg.service_id.as_deref(),
+                    as_of,
+                )?;
+
+                Ok((fetched_agents, total))
+            })
     }
 }
 
+fn parse_agent_sort(sort: Option<&str>) -> Result<Sort<AgentSortField>, RestApiResponseError> {
+    let (field, order) = match sort {
+        Some(sort) => parse_sort(sort),
+        None => return Ok(Sort::default()),
+    };
+
+    let field = match field {
+        "public_key" => AgentSortField::PublicKey,
+        "org_id" => AgentSortField::OrgId,
+        _ => {
+            return Err(RestApiResponseError::BadRequest(format!(
+                "Unable to sort agents by {}",
+                field
+            )))
+        }
+    };
+
+    Ok(Sort { field, order })
+}
+
 pub async fn list_agents(
+    req: HttpRequest,
     state: web::Data<AppState>,
-    query: web::Query<QueryServiceId>,
+    query: web::Query<ListAgentsQuery>,
     _: AcceptServiceIdParam,
 ) -> Result<HttpResponse, RestApiResponseError> {
+    let query = query.into_inner();
+    let (limit, offset) = validate_paging(query.limit, query.offset)?;
+    let sort = parse_agent_sort(query.sort.as_deref())?;
+    let link = get_base_link(&req);
+
     state
         .database_connection
         .send(ListAgents {
-            service_id: query.into_inner().service_id,
+            org_id: query.org_id,
+            service_id: query.service_id,
+            as_of: query.as_of,
+            sort,
+            limit,
+            offset,
         })
         .await?
-        .map(|agents| HttpResponse::Ok().json(agents))
+        .map(|(agents, total)| {
+            HttpResponse::Ok().json(ListSlice::new(
+                agents,
+                get_response_paging_info(limit, offset, &link, total),
+            ))
+        })
 }
 
 struct FetchAgent {
     public_key: String,
     service_id: Option<String>,
+    as_of: Option<String>,
 }
 
 impl Message for FetchAgent {
@@ -96,10 +181,17 @@ impl Handler<FetchAgent> for DbExecutor {
     type Result = Result<AgentSlice, RestApiResponseError>;
 
     fn handle(&mut self, msg: FetchAgent, _: &mut SyncContext<Self>) -> Self::Result {
+        let as_of = resolve_as_of(
+            &*self.connection_pool.get()?,
+            msg.as_of.as_deref(),
+            msg.service_id.as_deref(),
+        )?;
+
         let fetched_agent = match db::get_agent(
             &*self.connection_pool.get()?,
             &msg.public_key,
             msg.service_id.as_deref(),
+            as_of,
         )? {
             Some(agent) => AgentSlice::from_agent(&agent),
             None => {
@@ -117,15 +209,110 @@ impl Handler<FetchAgent> for DbExecutor {
 pub async fn fetch_agent(
     state: web::Data<AppState>,
     public_key: web::Path<String>,
-    query: web::Query<QueryServiceId>,
+    query: web::Query<FetchQuery>,
     _: AcceptServiceIdParam,
 ) -> Result<HttpResponse, RestApiResponseError> {
+    let query = query.into_inner();
+
     state
         .database_connection
         .send(FetchAgent {
             public_key: public_key.into_inner(),
-            service_id: query.into_inner().service_id,
+            service_id: query.service_id,
+            as_of: query.as_of,
         })
         .await?
         .map(|agent| HttpResponse::Ok().json(agent))
 }
+
+struct ListAgentHistory {
+    public_key: String,
+    service_id: Option<String>,
+    limit: i64,
+    offset: i64,
+}
+
+impl Message for ListAgentHistory {
+    type Result = Result<(Vec<VersionSlice<AgentSlice>>, i64), RestApiResponseError>;
+}
+
+impl Handler<ListAgentHistory> for DbExecutor {
+    type Result = Result<(Vec<VersionSlice<AgentSlice>>, i64), RestApiResponseError>;
+
+    fn handle(&mut self, msg: ListAgentHistory, _: &mut SyncContext<Self>) -> Self::Result {
+        let conn = self.connection_pool.get()?;
+
+        conn.build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run(|| {
+                let total =
+                    db::count_agent_versions(&*conn, &msg.public_key, msg.service_id.as_deref())?;
+
+                if total == 0 {
+                    return Err(RestApiResponseError::NotFoundError(format!(
+                        "Could not find agent with public key: {}",
+                        msg.public_key
+                    )));
+                }
+
+                let agents = db::list_agent_versions(
+                    &*conn,
+                    &msg.public_key,
+                    msg.service_id.as_deref(),
+                    msg.limit,
+                    msg.offset,
+                )?;
+
+                let commit_ids = get_commit_ids(
+                    &*conn,
+                    &agents
+                        .iter()
+                        .map(|agent| (agent.start_commit_num, agent.end_commit_num))
+                        .collect::<Vec<_>>(),
+                )?;
+
+                let versions = agents
+                    .iter()
+                    .map(|agent| {
+                        VersionSlice::new(
+                            AgentSlice::from_agent(agent),
+                            agent.start_commit_num,
+                            agent.end_commit_num,
+                            &commit_ids,
+                        )
+                    })
+                    .collect();
+
+                Ok((versions, total))
+            })
+    }
+}
+
+pub async fn list_agent_history(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    public_key: web::Path<String>,
+    query: web::Query<HistoryQuery>,
+    _: AcceptServiceIdParam,
+) -> Result<HttpResponse, RestApiResponseError> {
+    let query = query.into_inner();
+    let (limit, offset) = validate_paging(query.limit, query.offset)?;
+    let link = get_base_link(&req);
+
+    state
+        .database_connection
+        .send(ListAgentHistory {
+            public_key: public_key.into_inner(),
+            service_id: query.service_id,
+            limit,
+            offset,
+        })
+        .await?
+        .map(|(versions, total)| {
+            HttpResponse::Ok().json(ListSlice::new(
+                versions,
+                get_response_paging_info(limit, offset, &link, total),
+            ))
+        })
+}
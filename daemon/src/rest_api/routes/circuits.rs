@@ -0,0 +1,339 @@
+// Copyright 2020 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::database::{
+    helpers as db,
+    models::{GridCircuit, GridCircuitMember, GridCircuitProposal, GridCircuitProposalVoteRecord},
+};
+use crate::rest_api::{
+    error::RestApiResponseError,
+    paging::{get_base_link, get_response_paging_info, validate_paging, ListSlice},
+    routes::DbExecutor,
+    AppState,
+};
+
+use actix::{Handler, Message, SyncContext};
+use actix_web::{web, HttpRequest, HttpResponse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CircuitSlice {
+    pub circuit_id: String,
+    pub alias: String,
+    pub authorization_type: String,
+    pub persistence: String,
+    pub durability: String,
+    pub routes: String,
+    pub circuit_management_type: String,
+    pub status: String,
+    pub members: Vec<CircuitMemberSlice>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_id: Option<String>,
+    pub created_time: u64,
+    pub updated_time: u64,
+}
+
+impl CircuitSlice {
+    pub fn from_circuit(circuit: &GridCircuit, members: &[GridCircuitMember]) -> Self {
+        Self {
+            circuit_id: circuit.circuit_id.clone(),
+            alias: circuit.alias.clone(),
+            authorization_type: circuit.authorization_type.clone(),
+            persistence: circuit.persistence.clone(),
+            durability: circuit.durability.clone(),
+            routes: circuit.routes.clone(),
+            circuit_management_type: circuit.circuit_management_type.clone(),
+            status: circuit.status.clone(),
+            members: members
+                .iter()
+                .filter(|member| member.circuit_id == circuit.circuit_id)
+                .map(CircuitMemberSlice::from_member)
+                .collect(),
+            service_id: circuit.service_id.clone(),
+            created_time: epoch_seconds(circuit.created_time),
+            updated_time: epoch_seconds(circuit.updated_time),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CircuitMemberSlice {
+    pub node_id: String,
+    pub endpoint: String,
+}
+
+impl CircuitMemberSlice {
+    pub fn from_member(member: &GridCircuitMember) -> Self {
+        Self {
+            node_id: member.node_id.clone(),
+            endpoint: member.endpoint.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CircuitProposalSlice {
+    pub proposal_id: i64,
+    pub proposal_type: String,
+    pub circuit_id: String,
+    pub circuit_hash: String,
+    pub requester: String,
+    pub requester_node_id: String,
+    pub status: String,
+    pub votes: Vec<CircuitProposalVoteSlice>,
+    pub created_time: u64,
+    pub updated_time: u64,
+}
+
+impl CircuitProposalSlice {
+    pub fn from_proposal(
+        proposal: &GridCircuitProposal,
+        votes: &[GridCircuitProposalVoteRecord],
+    ) -> Self {
+        Self {
+            proposal_id: proposal.id,
+            proposal_type: proposal.proposal_type.clone(),
+            circuit_id: proposal.circuit_id.clone(),
+            circuit_hash: proposal.circuit_hash.clone(),
+            requester: proposal.requester.clone(),
+            requester_node_id: proposal.requester_node_id.clone(),
+            status: proposal.status.clone(),
+            votes: votes
+                .iter()
+                .filter(|vote| vote.proposal_id == proposal.id)
+                .map(CircuitProposalVoteSlice::from_vote)
+                .collect(),
+            created_time: epoch_seconds(proposal.created_time),
+            updated_time: epoch_seconds(proposal.updated_time),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CircuitProposalVoteSlice {
+    pub voter_public_key: String,
+    pub voter_node_id: String,
+    pub vote: String,
+    pub created_time: u64,
+}
+
+impl CircuitProposalVoteSlice {
+    pub fn from_vote(vote: &GridCircuitProposalVoteRecord) -> Self {
+        Self {
+            voter_public_key: vote.voter_public_key.clone(),
+            voter_node_id: vote.voter_node_id.clone(),
+            vote: vote.vote.clone(),
+            created_time: epoch_seconds(vote.created_time),
+        }
+    }
+}
+
+fn epoch_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListCircuitsQuery {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+struct ListCircuits {
+    limit: i64,
+    offset: i64,
+}
+
+impl Message for ListCircuits {
+    type Result = Result<(Vec<CircuitSlice>, i64), RestApiResponseError>;
+}
+
+impl Handler<ListCircuits> for DbExecutor {
+    type Result = Result<(Vec<CircuitSlice>, i64), RestApiResponseError>;
+
+    fn handle(&mut self, msg: ListCircuits, _: &mut SyncContext<Self>) -> Self::Result {
+        let conn = self.connection_pool.get()?;
+
+        conn.build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run(|| {
+                let circuits = db::list_circuits(&*conn, msg.limit, msg.offset)?;
+
+                let members = db::list_circuit_members(
+                    &*conn,
+                    &circuits
+                        .iter()
+                        .map(|circuit| circuit.circuit_id.clone())
+                        .collect::<Vec<_>>(),
+                )?;
+
+                let total = db::count_circuits(&*conn)?;
+
+                Ok((
+                    circuits
+                        .iter()
+                        .map(|circuit| CircuitSlice::from_circuit(circuit, &members))
+                        .collect(),
+                    total,
+                ))
+            })
+    }
+}
+
+pub async fn list_circuits(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    query: web::Query<ListCircuitsQuery>,
+) -> Result<HttpResponse, RestApiResponseError> {
+    let (limit, offset) = validate_paging(query.limit, query.offset)?;
+    let link = get_base_link(&req);
+
+    state
+        .database_connection
+        .send(ListCircuits { limit, offset })
+        .await?
+        .map(|(circuits, total)| {
+            HttpResponse::Ok().json(ListSlice::new(
+                circuits,
+                get_response_paging_info(limit, offset, &link, total),
+            ))
+        })
+}
+
+struct FetchCircuit {
+    circuit_id: String,
+}
+
+impl Message for FetchCircuit {
+    type Result = Result<CircuitSlice, RestApiResponseError>;
+}
+
+impl Handler<FetchCircuit> for DbExecutor {
+    type Result = Result<CircuitSlice, RestApiResponseError>;
+
+    fn handle(&mut self, msg: FetchCircuit, _: &mut SyncContext<Self>) -> Self::Result {
+        let circuit = match db::fetch_circuit(&*self.connection_pool.get()?, &msg.circuit_id)? {
+            Some(circuit) => circuit,
+            None => {
+                return Err(RestApiResponseError::NotFoundError(format!(
+                    "Could not find circuit with id: {}",
+                    msg.circuit_id
+                )));
+            }
+        };
+
+        let members =
+            db::list_circuit_members(&*self.connection_pool.get()?, &[circuit.circuit_id.clone()])?;
+
+        Ok(CircuitSlice::from_circuit(&circuit, &members))
+    }
+}
+
+pub async fn fetch_circuit(
+    state: web::Data<AppState>,
+    circuit_id: web::Path<String>,
+) -> Result<HttpResponse, RestApiResponseError> {
+    state
+        .database_connection
+        .send(FetchCircuit {
+            circuit_id: circuit_id.into_inner(),
+        })
+        .await?
+        .map(|circuit| HttpResponse::Ok().json(circuit))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListCircuitProposalsQuery {
+    pub status: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+struct ListCircuitProposals {
+    status: Option<String>,
+    limit: i64,
+    offset: i64,
+}
+
+impl Message for ListCircuitProposals {
+    type Result = Result<(Vec<CircuitProposalSlice>, i64), RestApiResponseError>;
+}
+
+impl Handler<ListCircuitProposals> for DbExecutor {
+    type Result = Result<(Vec<CircuitProposalSlice>, i64), RestApiResponseError>;
+
+    fn handle(&mut self, msg: ListCircuitProposals, _: &mut SyncContext<Self>) -> Self::Result {
+        let conn = self.connection_pool.get()?;
+
+        conn.build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run(|| {
+                let proposals = db::list_circuit_proposals(
+                    &*conn,
+                    msg.status.as_deref(),
+                    msg.limit,
+                    msg.offset,
+                )?;
+
+                let votes = db::list_circuit_proposal_votes(
+                    &*conn,
+                    &proposals
+                        .iter()
+                        .map(|proposal| proposal.id)
+                        .collect::<Vec<_>>(),
+                )?;
+
+                let total = db::count_circuit_proposals(&*conn, msg.status.as_deref())?;
+
+                Ok((
+                    proposals
+                        .iter()
+                        .map(|proposal| CircuitProposalSlice::from_proposal(proposal, &votes))
+                        .collect(),
+                    total,
+                ))
+            })
+    }
+}
+
+pub async fn list_circuit_proposals(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    query: web::Query<ListCircuitProposalsQuery>,
+) -> Result<HttpResponse, RestApiResponseError> {
+    let query = query.into_inner();
+    let (limit, offset) = validate_paging(query.limit, query.offset)?;
+    let link = get_base_link(&req);
+
+    state
+        .database_connection
+        .send(ListCircuitProposals {
+            status: query.status,
+            limit,
+            offset,
+        })
+        .await?
+        .map(|(proposals, total)| {
+            HttpResponse::Ok().json(ListSlice::new(
+                proposals,
+                get_response_paging_info(limit, offset, &link, total),
+            ))
+        })
+}
@@ -14,6 +14,7 @@
 
 mod agents;
 mod batches;
+mod circuits;
 mod organizations;
 mod products;
 mod records;
@@ -21,14 +22,23 @@ mod schemas;
 
 pub use agents::*;
 pub use batches::*;
+pub use circuits::*;
 pub use organizations::*;
 pub use products::*;
 pub use records::*;
 pub use schemas::*;
 
-use crate::database::ConnectionPool;
+use std::collections::HashMap;
+
+use crate::database::{
+    helpers::{self as db, MAX_COMMIT_NUM},
+    ConnectionPool,
+};
+use crate::rest_api::error::RestApiResponseError;
 
 use actix::{Actor, SyncContext};
+use diesel::pg::PgConnection;
+use serde::{Deserialize, Serialize};
 
 pub struct DbExecutor {
     connection_pool: ConnectionPool,
@@ -44,6 +54,92 @@ impl DbExecutor {
     }
 }
 
+/// Resolves the `as_of` query parameter, which is either a commit number or a commit id, to the
+/// number of the commit the results should be returned as of. The commit must have come from the
+/// service being queried.
+fn resolve_as_of(
+    conn: &PgConnection,
+    as_of: Option<&str>,
+    service_id: Option<&str>,
+) -> Result<Option<i64>, RestApiResponseError> {
+    let as_of = match as_of {
+        Some(as_of) => as_of,
+        None => return Ok(None),
+    };
+
+    let commit = match as_of.parse::<i64>() {
+        Ok(commit_num) => db::get_commit_by_commit_num(conn, commit_num)?
+            .filter(|commit| commit.service_id.as_deref() == service_id),
+        Err(_) => db::get_commit_by_commit_id(conn, as_of, service_id)?,
+    };
+
+    match commit {
+        Some(commit) => Ok(Some(commit.commit_num)),
+        None => Err(RestApiResponseError::BadRequest(format!(
+            "Unknown commit: {}",
+            as_of
+        ))),
+    }
+}
+
+/// A single version of an item returned by a history endpoint. The end commit is omitted for the
+/// current version.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VersionSlice<T> {
+    pub start_commit_num: i64,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_commit_id: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_commit_num: Option<i64>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_commit_id: Option<String>,
+    #[serde(flatten)]
+    pub version: T,
+}
+
+impl<T> VersionSlice<T> {
+    fn new(
+        version: T,
+        start_commit_num: i64,
+        end_commit_num: i64,
+        commit_ids: &HashMap<i64, String>,
+    ) -> Self {
+        let end_commit_num = if end_commit_num == MAX_COMMIT_NUM {
+            None
+        } else {
+            Some(end_commit_num)
+        };
+
+        Self {
+            start_commit_num,
+            start_commit_id: commit_ids.get(&start_commit_num).cloned(),
+            end_commit_num,
+            end_commit_id: end_commit_num.and_then(|num| commit_ids.get(&num).cloned()),
+            version,
+        }
+    }
+}
+
+/// Looks up the ids of the commits that introduced and retired the given versions
+fn get_commit_ids(
+    conn: &PgConnection,
+    commit_ranges: &[(i64, i64)],
+) -> Result<HashMap<i64, String>, RestApiResponseError> {
+    let commit_nums = commit_ranges
+        .iter()
+        .flat_map(|(start, end)| vec![*start, *end])
+        .filter(|commit_num| *commit_num != MAX_COMMIT_NUM)
+        .collect::<Vec<i64>>();
+
+    Ok(db::list_commits_by_commit_nums(conn, &commit_nums)?
+        .into_iter()
+        .map(|commit| (commit.commit_num, commit.commit_id))
+        .collect())
+}
+
 #[cfg(all(feature = "test-api", test))]
 mod test {
     use super::*;
@@ -52,18 +148,24 @@ mod test {
     use crate::database::{
         helpers::MAX_COMMIT_NUM,
         models::{
-            LatLongValue, NewAgent, NewAssociatedAgent, NewGridPropertyDefinition, NewGridSchema,
-            NewOrganization, NewProduct, NewProductPropertyValue, NewProperty, NewProposal,
-            NewRecord, NewReportedValue, NewReporter,
+            GridCircuit, LatLongValue, NewAgent, NewAssociatedAgent, NewCommit,
+            NewGridCircuitMember, NewGridCircuitProposal, NewGridCircuitProposalVoteRecord,
+            NewGridPropertyDefinition, NewGridSchema, NewOrganization, NewProduct,
+            NewProductPropertyValue, NewProperty, NewProposal, NewRecord, NewReportedValue,
+            NewReporter,
         },
         schema::{
-            associated_agent, grid_property_definition, grid_schema, product,
+            associated_agent, grid_circuit, grid_circuit_member, grid_circuit_proposal,
+            grid_circuit_proposal_vote_record, grid_property_definition, grid_schema, product,
             product_property_value, property, proposal, record, reported_value, reporter,
         },
     };
     use crate::rest_api::{
         error::RestApiResponseError,
-        routes::{AgentSlice, OrganizationSlice},
+        paging::ListSlice,
+        routes::{
+            AgentSlice, AlternateIdSlice, CircuitProposalSlice, CircuitSlice, OrganizationSlice,
+        },
         AppState,
     };
     use crate::sawtooth::batch_submitter::{
@@ -100,6 +202,8 @@ mod test {
     static ORG_NAME_1: &str = "my_org";
     static ORG_NAME_2: &str = "other_org";
 
+    static ORG_GLN_1: &str = "1234567890128";
+
     static ADDRESS_1: &str = "my_address";
     static ADDRESS_2: &str = "my_address_2";
     static UPDATED_ADDRESS_2: &str = "my_updated_address";
@@ -109,6 +213,7 @@ mod test {
     static BATCH_ID_3: &str = "batch_3";
 
     static TEST_SERVICE_ID: &str = "test_service";
+    static TEST_CIRCUIT_ID: &str = "grid-partners";
 
     #[derive(Clone)]
     enum Backend {
@@ -311,17 +416,37 @@ mod test {
                 .service(
                     web::scope("/agent")
                         .service(web::resource("").route(web::get().to(list_agents)))
-                        .service(web::resource("/{public_key}").route(web::get().to(fetch_agent))),
+                        .service(web::resource("/{public_key}").route(web::get().to(fetch_agent)))
+                        .service(
+                            web::resource("/{public_key}/history")
+                                .route(web::get().to(list_agent_history)),
+                        ),
+                )
+                .service(
+                    web::scope("/circuit")
+                        .service(web::resource("").route(web::get().to(list_circuits)))
+                        .service(
+                            web::resource("/proposal").route(web::get().to(list_circuit_proposals)),
+                        )
+                        .service(web::resource("/{id}").route(web::get().to(fetch_circuit))),
                 )
                 .service(
                     web::scope("/organization")
                         .service(web::resource("").route(web::get().to(list_organizations)))
-                        .service(web::resource("/{id}").route(web::get().to(fetch_organization))),
+                        .service(web::resource("/{id}").route(web::get().to(fetch_organization)))
+                        .service(
+                            web::resource("/{id}/history")
+                                .route(web::get().to(list_organization_history)),
+                        ),
                 )
                 .service(
                     web::scope("/product")
                         .service(web::resource("").route(web::get().to(list_products)))
-                        .service(web::resource("/{id}").route(web::get().to(fetch_product))),
+                        .service(web::resource("/{id}").route(web::get().to(fetch_product)))
+                        .service(
+                            web::resource("/{id}/history")
+                                .route(web::get().to(list_product_history)),
+                        ),
                 )
                 .service(
                     web::scope("/schema")
@@ -615,8 +740,9 @@ mod test {
             .await
             .unwrap();
         assert!(response.status().is_success());
-        let body: Vec<AgentSlice> =
+        let body: ListSlice<AgentSlice> =
             serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        let body = body.data;
         assert!(body.is_empty());
 
         // Adds a single Agent to the test database
@@ -630,8 +756,9 @@ mod test {
             .unwrap();
 
         assert!(response.status().is_success());
-        let body: Vec<AgentSlice> =
+        let body: ListSlice<AgentSlice> =
             serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        let body = body.data;
         assert_eq!(body.len(), 1);
         let agent = body.first().unwrap();
         assert_eq!(agent.public_key, KEY1.to_string());
@@ -662,8 +789,9 @@ mod test {
             .await
             .unwrap();
         assert!(response.status().is_success());
-        let body: Vec<AgentSlice> =
+        let body: ListSlice<AgentSlice> =
             serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        let body = body.data;
         assert!(body.is_empty());
 
         // Adds a single Agent to the test database
@@ -683,8 +811,9 @@ mod test {
             .unwrap();
 
         assert!(response.status().is_success());
-        let body: Vec<AgentSlice> =
+        let body: ListSlice<AgentSlice> =
             serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        let body = body.data;
         assert_eq!(body.len(), 1);
         let agent = body.first().unwrap();
         assert_eq!(agent.public_key, KEY1.to_string());
@@ -692,6 +821,109 @@ mod test {
         assert_eq!(agent.service_id, Some(TEST_SERVICE_ID.to_string()));
     }
 
+    ///
+    /// Verifies a GET /agent with limit, offset, org_id and sort parameters responds with an Ok
+    ///     response containing the requested page of Agents and the paging links.
+    ///
+    ///     The TestServer will receive requests for two Agents belonging to the same org
+    ///     It will receive responses with status Ok
+    ///     It should send back responses with:
+    ///         - body containing the requested page of Agents
+    ///         - paging with a next link only when there are more Agents
+    #[actix_rt::test]
+    async fn test_list_agents_paging() {
+        run_migrations(&DATABASE_URL);
+        let test_pool = get_connection_pool();
+        let srv = create_test_server(Backend::Sawtooth, ResponseType::ClientBatchStatusResponseOK);
+        // Adds two Agents to the test database
+        populate_agent_table(&test_pool.get().unwrap(), &get_agents_with_roles(None));
+
+        let mut response = srv
+            .request(http::Method::GET, srv.url("/agent?limit=1"))
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let body: ListSlice<AgentSlice> =
+            serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        assert_eq!(body.data.len(), 1);
+        assert_eq!(body.data[0].public_key, KEY1.to_string());
+        assert_eq!(body.paging.total, 2);
+        assert_eq!(
+            body.paging.next,
+            Some("/agent?limit=1&offset=1".to_string())
+        );
+
+        let mut response = srv
+            .request(http::Method::GET, srv.url("/agent?limit=1&offset=1"))
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let body: ListSlice<AgentSlice> =
+            serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        assert_eq!(body.data.len(), 1);
+        assert_eq!(body.data[0].public_key, KEY2.to_string());
+        assert_eq!(
+            body.paging.prev,
+            Some("/agent?limit=1&offset=0".to_string())
+        );
+        assert_eq!(body.paging.next, None);
+
+        let mut response = srv
+            .request(
+                http::Method::GET,
+                srv.url(&format!("/agent?org_id={}&sort=-public_key", KEY3)),
+            )
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let body: ListSlice<AgentSlice> =
+            serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        assert_eq!(body.data.len(), 2);
+        assert_eq!(body.data[0].public_key, KEY2.to_string());
+        assert_eq!(body.data[1].public_key, KEY1.to_string());
+
+        let mut response = srv
+            .request(
+                http::Method::GET,
+                srv.url(&format!("/agent?org_id={}", KEY1)),
+            )
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let body: ListSlice<AgentSlice> =
+            serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        assert!(body.data.is_empty());
+        assert_eq!(body.paging.total, 0);
+    }
+
+    ///
+    /// Verifies a GET /agent with an invalid limit or sort parameter responds with a
+    ///     BadRequest response.
+    ///
+    #[actix_rt::test]
+    async fn test_list_agents_invalid_paging() {
+        run_migrations(&DATABASE_URL);
+        let srv = create_test_server(Backend::Sawtooth, ResponseType::ClientBatchStatusResponseOK);
+
+        let response = srv
+            .request(http::Method::GET, srv.url("/agent?limit=0"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), http::StatusCode::BAD_REQUEST);
+
+        let response = srv
+            .request(http::Method::GET, srv.url("/agent?sort=roles"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), http::StatusCode::BAD_REQUEST);
+    }
+
     ///
     /// Verifies a GET /organization responds with an Ok response
     ///     with an empty organization table
@@ -709,8 +941,9 @@ mod test {
             .await
             .unwrap();
         assert!(response.status().is_success());
-        let body: Vec<OrganizationSlice> =
+        let body: ListSlice<OrganizationSlice> =
             serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        let body = body.data;
         assert!(body.is_empty());
     }
 
@@ -734,8 +967,9 @@ mod test {
             .await
             .unwrap();
         assert!(response.status().is_success());
-        let body: Vec<OrganizationSlice> =
+        let body: ListSlice<OrganizationSlice> =
             serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        let body = body.data;
         assert_eq!(body.len(), 1);
         let org = body.first().unwrap();
         assert_eq!(org.name, ORG_NAME_1.to_string());
@@ -769,8 +1003,9 @@ mod test {
             .await
             .unwrap();
         assert!(response.status().is_success());
-        let body: Vec<OrganizationSlice> =
+        let body: ListSlice<OrganizationSlice> =
             serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        let body = body.data;
         assert_eq!(body.len(), 1);
         let org = body.first().unwrap();
         assert_eq!(org.name, ORG_NAME_1.to_string());
@@ -802,8 +1037,9 @@ mod test {
             .await
             .unwrap();
         assert!(response.status().is_success());
-        let body: Vec<OrganizationSlice> =
+        let body: ListSlice<OrganizationSlice> =
             serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        let body = body.data;
         assert_eq!(body.len(), 1);
         let org = body.first().unwrap();
         assert_eq!(org.name, ORG_NAME_2.to_string());
@@ -812,6 +1048,59 @@ mod test {
         assert_eq!(org.address, UPDATED_ADDRESS_2.to_string());
     }
 
+    ///
+    /// Verifies a GET /organization?alternate_id=gln:<gln> responds with an Ok response
+    ///     with a list containing only the organization holding that GLN
+    ///
+    #[actix_rt::test]
+    async fn test_list_organizations_by_alternate_id() {
+        run_migrations(&DATABASE_URL);
+        let test_pool = get_connection_pool();
+        let srv = create_test_server(Backend::Sawtooth, ResponseType::ClientBatchStatusResponseOK);
+
+        // Adds two organizations, one of which holds the GLN, to the test database
+        populate_organization_table(&test_pool.get().unwrap(), get_organization(None));
+        database::helpers::insert_organizations(
+            &test_pool.get().unwrap(),
+            &get_updated_organization(),
+        )
+        .unwrap();
+
+        let mut response = srv
+            .request(
+                http::Method::GET,
+                srv.url(&format!("/organization?alternate_id=gln:{}", ORG_GLN_1)),
+            )
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let body: ListSlice<OrganizationSlice> =
+            serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        let body = body.data;
+        assert_eq!(body.len(), 1);
+        let org = body.first().unwrap();
+        assert_eq!(org.org_id, KEY2.to_string());
+        assert_eq!(
+            org.alternate_ids,
+            vec![AlternateIdSlice {
+                id_type: "gln".to_string(),
+                id: ORG_GLN_1.to_string(),
+            }]
+        );
+
+        // An alternate ID without a type is rejected
+        let response = srv
+            .request(
+                http::Method::GET,
+                srv.url(&format!("/organization?alternate_id={}", ORG_GLN_1)),
+            )
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), http::StatusCode::BAD_REQUEST);
+    }
+
     ///
     /// Verifies a GET /organization/{id} responds with NotFound response
     /// when there is no organization with the specified id.
@@ -1033,6 +1322,229 @@ mod test {
         assert_eq!(response.status(), http::StatusCode::NOT_FOUND);
     }
 
+    ///
+    /// Verifies a GET /agent/{public_key}?as_of={commit} responds with an Ok response
+    ///     with the Agent as it was at the given commit.
+    ///
+    ///     The TestServer will receive requests for an Agent that was deactivated at commit 2
+    ///     It should send back responses with:
+    ///         - the active Agent when the commit is given by number
+    ///         - the inactive Agent when the commit is given by id
+    ///         - the inactive Agent when no commit is given
+    #[actix_rt::test]
+    async fn test_fetch_agent_as_of() {
+        run_migrations(&DATABASE_URL);
+        let test_pool = get_connection_pool();
+        let srv = create_test_server(Backend::Sawtooth, ResponseType::ClientBatchStatusResponseOK);
+
+        populate_commit_table(&test_pool.get().unwrap(), &get_commits(3));
+        // Adds an agent at commit 0, which is deactivated at commit 2
+        populate_agent_table(&test_pool.get().unwrap(), &get_agent(None));
+        let mut deactivated_agent = get_agent(None);
+        deactivated_agent[0].active = false;
+        deactivated_agent[0].start_commit_num = 2;
+        database::helpers::insert_agents(&test_pool.get().unwrap(), &deactivated_agent).unwrap();
+
+        let mut response = srv
+            .request(
+                http::Method::GET,
+                srv.url(&format!("/agent/{}?as_of=1", KEY1)),
+            )
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let agent: AgentSlice = serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        assert_eq!(agent.public_key, KEY1.to_string());
+        assert!(agent.active);
+
+        let mut response = srv
+            .request(
+                http::Method::GET,
+                srv.url(&format!("/agent/{}?as_of={}", KEY1, get_commit_id(2))),
+            )
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let agent: AgentSlice = serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        assert!(!agent.active);
+
+        let mut response = srv
+            .request(http::Method::GET, srv.url(&format!("/agent/{}", KEY1)))
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let agent: AgentSlice = serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        assert!(!agent.active);
+    }
+
+    ///
+    /// Verifies a GET /agent/{public_key}?service_id=test_service responds with a Bad Request
+    ///     when as_of names a commit that came from a different service.
+    ///
+    #[actix_rt::test]
+    async fn test_fetch_agent_as_of_other_service() {
+        run_migrations(&DATABASE_URL);
+        let test_pool = get_connection_pool();
+        let srv = create_test_server(Backend::Splinter, ResponseType::ClientBatchStatusResponseOK);
+
+        let mut commits = get_commits(2);
+        commits[0].service_id = Some(TEST_SERVICE_ID.to_string());
+        commits[1].service_id = Some("other_service".to_string());
+        populate_commit_table(&test_pool.get().unwrap(), &commits);
+        populate_agent_table(
+            &test_pool.get().unwrap(),
+            &get_agent(Some(TEST_SERVICE_ID.to_string())),
+        );
+
+        for as_of in &[get_commit_id(1), "1".to_string()] {
+            let response = srv
+                .request(
+                    http::Method::GET,
+                    srv.url(&format!(
+                        "/agent/{}?service_id={}&as_of={}",
+                        KEY1, TEST_SERVICE_ID, as_of
+                    )),
+                )
+                .send()
+                .await
+                .unwrap();
+            assert_eq!(response.status(), http::StatusCode::BAD_REQUEST);
+        }
+
+        let response = srv
+            .request(
+                http::Method::GET,
+                srv.url(&format!(
+                    "/agent/{}?service_id={}&as_of={}",
+                    KEY1,
+                    TEST_SERVICE_ID,
+                    get_commit_id(0)
+                )),
+            )
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+    }
+
+    ///
+    /// Verifies a GET /agent?as_of={commit} only lists the Agents that existed at the given
+    ///     commit, and that an unknown commit results in a Bad Request response.
+    ///
+    #[actix_rt::test]
+    async fn test_list_agents_as_of() {
+        run_migrations(&DATABASE_URL);
+        let test_pool = get_connection_pool();
+        let srv = create_test_server(Backend::Sawtooth, ResponseType::ClientBatchStatusResponseOK);
+
+        populate_commit_table(&test_pool.get().unwrap(), &get_commits(3));
+        // Adds one agent at commit 0 and another at commit 2
+        let mut agents = get_agents_with_roles(None);
+        agents[1].start_commit_num = 2;
+        populate_agent_table(&test_pool.get().unwrap(), &agents);
+
+        let mut response = srv
+            .request(http::Method::GET, srv.url("/agent?as_of=1"))
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let body: ListSlice<AgentSlice> =
+            serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        assert_eq!(body.data.len(), 1);
+        assert_eq!(body.data[0].public_key, KEY1.to_string());
+        assert_eq!(body.paging.total, 1);
+
+        let mut response = srv
+            .request(http::Method::GET, srv.url("/agent?as_of=2"))
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let body: ListSlice<AgentSlice> =
+            serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        assert_eq!(body.data.len(), 2);
+
+        let response = srv
+            .request(http::Method::GET, srv.url("/agent?as_of=unknown_commit_id"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), http::StatusCode::BAD_REQUEST);
+    }
+
+    ///
+    /// Verifies a GET /agent/{public_key}/history responds with an Ok response containing every
+    ///     version of the Agent, along with the commits that introduced and retired them.
+    ///
+    ///     The TestServer will receive a request for an Agent that was deactivated at commit 2
+    ///     It should send back a response with:
+    ///         - the active version, introduced at commit 0 and retired at commit 2
+    ///         - the inactive version, introduced at commit 2 and without an end commit
+    #[actix_rt::test]
+    async fn test_list_agent_history() {
+        run_migrations(&DATABASE_URL);
+        let test_pool = get_connection_pool();
+        let srv = create_test_server(Backend::Sawtooth, ResponseType::ClientBatchStatusResponseOK);
+
+        populate_commit_table(&test_pool.get().unwrap(), &get_commits(3));
+        populate_agent_table(&test_pool.get().unwrap(), &get_agent(None));
+        let mut deactivated_agent = get_agent(None);
+        deactivated_agent[0].active = false;
+        deactivated_agent[0].start_commit_num = 2;
+        database::helpers::insert_agents(&test_pool.get().unwrap(), &deactivated_agent).unwrap();
+
+        let mut response = srv
+            .request(
+                http::Method::GET,
+                srv.url(&format!("/agent/{}/history", KEY1)),
+            )
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let body: ListSlice<VersionSlice<AgentSlice>> =
+            serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        assert_eq!(body.paging.total, 2);
+        assert_eq!(body.data.len(), 2);
+
+        assert!(body.data[0].version.active);
+        assert_eq!(body.data[0].start_commit_num, 0);
+        assert_eq!(body.data[0].start_commit_id, Some(get_commit_id(0)));
+        assert_eq!(body.data[0].end_commit_num, Some(2));
+        assert_eq!(body.data[0].end_commit_id, Some(get_commit_id(2)));
+
+        assert!(!body.data[1].version.active);
+        assert_eq!(body.data[1].start_commit_num, 2);
+        assert_eq!(body.data[1].end_commit_num, None);
+        assert_eq!(body.data[1].end_commit_id, None);
+    }
+
+    ///
+    /// Verifies a GET /agent/{public_key}/history responds with a Not Found response when the
+    ///     public key was never assigned to an Agent.
+    ///
+    #[actix_rt::test]
+    async fn test_list_agent_history_not_found() {
+        run_migrations(&DATABASE_URL);
+        let test_pool = get_connection_pool();
+        let srv = create_test_server(Backend::Sawtooth, ResponseType::ClientBatchStatusResponseOK);
+        clear_agents_table(&test_pool.get().unwrap());
+
+        let response = srv
+            .request(
+                http::Method::GET,
+                srv.url("/agent/unknown_public_key/history"),
+            )
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), http::StatusCode::NOT_FOUND);
+    }
+
     ///
     /// Verifies a GET /agent/{public_key}?service_id=test_service responds with a Not Found response
     ///     when the public key is not assigned to any Agent with the service_id.
@@ -1077,8 +1589,9 @@ mod test {
             .await
             .unwrap();
         assert!(response.status().is_success());
-        let empty_body: Vec<GridSchemaSlice> =
+        let empty_body: ListSlice<GridSchemaSlice> =
             serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        let empty_body = empty_body.data;
         assert!(empty_body.is_empty());
 
         populate_grid_schema_table(&test_pool.get().unwrap(), &get_grid_schema(None), None);
@@ -1088,8 +1601,9 @@ mod test {
             .await
             .unwrap();
         assert!(response.status().is_success());
-        let body: Vec<GridSchemaSlice> =
+        let body: ListSlice<GridSchemaSlice> =
             serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        let body = body.data;
         assert_eq!(body.len(), 1);
 
         let test_schema = body.first().unwrap();
@@ -1119,8 +1633,9 @@ mod test {
             .await
             .unwrap();
         assert!(response.status().is_success());
-        let empty_body: Vec<GridSchemaSlice> =
+        let empty_body: ListSlice<GridSchemaSlice> =
             serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        let empty_body = empty_body.data;
         assert!(empty_body.is_empty());
 
         populate_grid_schema_table(
@@ -1137,8 +1652,9 @@ mod test {
             .await
             .unwrap();
         assert!(response.status().is_success());
-        let body: Vec<GridSchemaSlice> =
+        let body: ListSlice<GridSchemaSlice> =
             serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        let body = body.data;
         assert_eq!(body.len(), 1);
 
         let test_schema = body.first().unwrap();
@@ -1268,8 +1784,9 @@ mod test {
             .await
             .unwrap();
         assert!(response.status().is_success());
-        let empty_body: Vec<ProductSlice> =
+        let empty_body: ListSlice<ProductSlice> =
             serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        let empty_body = empty_body.data;
         assert!(empty_body.is_empty());
 
         populate_product_table(&test_pool.get().unwrap(), &get_product(None), None);
@@ -1279,18 +1796,91 @@ mod test {
             .await
             .unwrap();
         assert!(response.status().is_success());
-        let body: Vec<ProductSlice> =
+        let body: ListSlice<ProductSlice> =
             serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        let body = body.data;
         assert_eq!(body.len(), 1);
 
         let test_product = body.first().unwrap();
         assert_eq!(test_product.product_id, "041205707820".to_string());
         assert_eq!(test_product.product_address, "test_address".to_string());
-        assert_eq!(test_product.product_namespace, "Grid Product".to_string());
+        assert_eq!(test_product.product_namespace, "GS1".to_string());
         assert_eq!(test_product.owner, "phillips001".to_string());
         assert_eq!(test_product.properties.len(), 2);
     }
 
+    /// Verifies a GET /product?owner=<owner> responds with an OK response containing only the
+    ///     Products owned by the given organization.
+    #[actix_rt::test]
+    async fn test_list_products_with_owner() {
+        run_migrations(&DATABASE_URL);
+        let test_pool = get_connection_pool();
+        let srv = create_test_server(Backend::Sawtooth, ResponseType::ClientBatchStatusResponseOK);
+        populate_product_table(&test_pool.get().unwrap(), &get_product(None), None);
+
+        let mut response = srv
+            .request(http::Method::GET, srv.url("/product?owner=phillips001"))
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let body: ListSlice<ProductSlice> =
+            serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        assert_eq!(body.data.len(), 1);
+        assert_eq!(body.data[0].properties.len(), 2);
+        assert_eq!(body.paging.total, 1);
+
+        let mut response = srv
+            .request(http::Method::GET, srv.url("/product?owner=target001"))
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let body: ListSlice<ProductSlice> =
+            serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        assert!(body.data.is_empty());
+        assert_eq!(body.paging.total, 0);
+    }
+
+    /// Verifies a GET /product?pending_owner=<org> responds with an OK response containing only
+    ///     the Products with a transfer pending to the given organization.
+    #[actix_rt::test]
+    async fn test_list_products_with_pending_owner() {
+        run_migrations(&DATABASE_URL);
+        let test_pool = get_connection_pool();
+        let srv = create_test_server(Backend::Sawtooth, ResponseType::ClientBatchStatusResponseOK);
+        populate_product_table(&test_pool.get().unwrap(), &get_product(None), None);
+
+        let mut response = srv
+            .request(
+                http::Method::GET,
+                srv.url("/product?pending_owner=target001"),
+            )
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let body: ListSlice<ProductSlice> =
+            serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        assert_eq!(body.data.len(), 1);
+        assert_eq!(body.data[0].owner, "phillips001".to_string());
+        assert_eq!(body.data[0].pending_owner, Some("target001".to_string()));
+
+        let mut response = srv
+            .request(
+                http::Method::GET,
+                srv.url("/product?pending_owner=phillips001"),
+            )
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let body: ListSlice<ProductSlice> =
+            serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        assert!(body.data.is_empty());
+        assert_eq!(body.paging.total, 0);
+    }
+
     /// Verifies a GET /product?service_id=test_service responds with an OK response with a
     ///     list_products request.
     ///
@@ -1312,8 +1902,9 @@ mod test {
             .await
             .unwrap();
         assert!(response.status().is_success());
-        let empty_body: Vec<ProductSlice> =
+        let empty_body: ListSlice<ProductSlice> =
             serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        let empty_body = empty_body.data;
         assert!(empty_body.is_empty());
 
         populate_product_table(
@@ -1330,14 +1921,15 @@ mod test {
             .await
             .unwrap();
         assert!(response.status().is_success());
-        let body: Vec<ProductSlice> =
+        let body: ListSlice<ProductSlice> =
             serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        let body = body.data;
         assert_eq!(body.len(), 1);
 
         let test_product = body.first().unwrap();
         assert_eq!(test_product.product_id, "041205707820".to_string());
         assert_eq!(test_product.product_address, "test_address".to_string());
-        assert_eq!(test_product.product_namespace, "Grid Product".to_string());
+        assert_eq!(test_product.product_namespace, "GS1".to_string());
         assert_eq!(test_product.owner, "phillips001".to_string());
         assert_eq!(test_product.properties.len(), 2);
         assert_eq!(test_product.service_id, Some(TEST_SERVICE_ID.to_string()));
@@ -1366,7 +1958,7 @@ mod test {
             serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
         assert_eq!(test_product.product_id, "041205707820".to_string());
         assert_eq!(test_product.product_address, "test_address".to_string());
-        assert_eq!(test_product.product_namespace, "Grid Product".to_string());
+        assert_eq!(test_product.product_namespace, "GS1".to_string());
         assert_eq!(test_product.owner, "phillips001".to_string());
         assert_eq!(test_product.properties.len(), 2);
     }
@@ -1401,12 +1993,66 @@ mod test {
             serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
         assert_eq!(test_product.product_id, "041205707820".to_string());
         assert_eq!(test_product.product_address, "test_address".to_string());
-        assert_eq!(test_product.product_namespace, "Grid Product".to_string());
+        assert_eq!(test_product.product_namespace, "GS1".to_string());
         assert_eq!(test_product.owner, "phillips001".to_string());
         assert_eq!(test_product.properties.len(), 2);
         assert_eq!(test_product.service_id, Some(TEST_SERVICE_ID.to_string()));
     }
 
+    ///
+    /// Verifies a GET /product/{id}?namespace=GLN responds with an OK response
+    ///     and the Product with the specified id in that namespace, when a
+    ///     Product in the GS1 namespace has the same id
+    ///
+    #[actix_rt::test]
+    async fn test_fetch_product_by_namespace_ok() {
+        run_migrations(&DATABASE_URL);
+        let test_pool = get_connection_pool();
+        let srv = create_test_server(Backend::Sawtooth, ResponseType::ClientBatchStatusResponseOK);
+        let mut products = get_product(None);
+        products.push(NewProduct {
+            product_id: "041205707820".to_string(),
+            product_address: "test_gln_address".to_string(),
+            product_namespace: "GLN".to_string(),
+            owner: "target001".to_string(),
+            pending_owner: None,
+            start_commit_num: 0,
+            end_commit_num: MAX_COMMIT_NUM,
+            service_id: None,
+        });
+        populate_product_table(&test_pool.get().unwrap(), &products, None);
+
+        let mut response = srv
+            .request(
+                http::Method::GET,
+                srv.url(&format!("/product/{}?namespace=GLN", "041205707820")),
+            )
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let test_product: ProductSlice =
+            serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        assert_eq!(test_product.product_address, "test_gln_address".to_string());
+        assert_eq!(test_product.product_namespace, "GLN".to_string());
+        assert_eq!(test_product.owner, "target001".to_string());
+        assert!(test_product.properties.is_empty());
+
+        let mut response = srv
+            .request(
+                http::Method::GET,
+                srv.url(&format!("/product/{}", "041205707820")),
+            )
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let test_product: ProductSlice =
+            serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        assert_eq!(test_product.product_namespace, "GS1".to_string());
+        assert_eq!(test_product.properties.len(), 2);
+    }
+
     ///
     /// Verifies a GET /product/{id} responds with a Not Found error
     ///     when there is no Product with the specified id
@@ -1479,8 +2125,9 @@ mod test {
             .await
             .unwrap();
         assert!(response.status().is_success());
-        let body: Vec<RecordSlice> =
+        let body: ListSlice<RecordSlice> =
             serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        let body = body.data;
         assert_eq!(body.len(), 1);
         let test_record = body.first().unwrap();
         assert_eq!(test_record.record_id, "TestRecord".to_string());
@@ -1636,8 +2283,9 @@ mod test {
             .await
             .unwrap();
         assert!(response.status().is_success());
-        let body: Vec<RecordSlice> =
+        let body: ListSlice<RecordSlice> =
             serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        let body = body.data;
         assert_eq!(body.len(), 1);
         let test_record = body.first().unwrap();
         assert_eq!(test_record.record_id, "TestRecord".to_string());
@@ -1801,8 +2449,9 @@ mod test {
             .await
             .unwrap();
         assert!(response.status().is_success());
-        let body: Vec<RecordSlice> =
+        let body: ListSlice<RecordSlice> =
             serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        let body = body.data;
         assert_eq!(body.len(), 1);
         let test_record = body.first().unwrap();
         assert_eq!(test_record.record_id, "TestRecord".to_string());
@@ -1837,8 +2486,9 @@ mod test {
             .await
             .unwrap();
         assert!(response.status().is_success());
-        let body: Vec<RecordSlice> =
+        let body: ListSlice<RecordSlice> =
             serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        let body = body.data;
 
         assert_eq!(body.len(), 2);
         let record_1 = &body[0];
@@ -2691,6 +3341,83 @@ mod test {
         assert_eq!(response.status(), http::StatusCode::NOT_FOUND);
     }
 
+    ///
+    /// Verifies a GET /circuit responds with an OK response and the stored circuits,
+    ///     each with its members.
+    ///
+    #[actix_rt::test]
+    async fn test_list_circuits() {
+        run_migrations(&DATABASE_URL);
+        let test_pool = get_connection_pool();
+        let srv = create_test_server(Backend::Splinter, ResponseType::ClientBatchStatusResponseOK);
+        populate_circuit_tables(&test_pool.get().unwrap());
+        let mut response = srv
+            .request(http::Method::GET, srv.url("/circuit"))
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let body: ListSlice<CircuitSlice> =
+            serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        assert_eq!(body.data.len(), 1);
+        let test_circuit = &body.data[0];
+        assert_eq!(test_circuit.circuit_id, TEST_CIRCUIT_ID.to_string());
+        assert_eq!(test_circuit.alias, "partners".to_string());
+        assert_eq!(test_circuit.service_id, Some(TEST_SERVICE_ID.to_string()));
+        assert_eq!(test_circuit.members.len(), 2);
+        assert_eq!(body.paging.total, 1);
+    }
+
+    ///
+    /// Verifies a GET /circuit/{id} responds with a Not Found error
+    ///     when there is no circuit with the specified id.
+    ///
+    #[actix_rt::test]
+    async fn test_fetch_circuit_not_found() {
+        run_migrations(&DATABASE_URL);
+        let test_pool = get_connection_pool();
+        let srv = create_test_server(Backend::Splinter, ResponseType::ClientBatchStatusResponseOK);
+        populate_circuit_tables(&test_pool.get().unwrap());
+        let response = srv
+            .request(http::Method::GET, srv.url("/circuit/not_in_database"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), http::StatusCode::NOT_FOUND);
+    }
+
+    ///
+    /// Verifies a GET /circuit/proposal?status=Pending only returns pending proposals,
+    ///     along with the votes recorded for them.
+    ///
+    #[actix_rt::test]
+    async fn test_list_circuit_proposals_with_status() {
+        run_migrations(&DATABASE_URL);
+        let test_pool = get_connection_pool();
+        let srv = create_test_server(Backend::Splinter, ResponseType::ClientBatchStatusResponseOK);
+        populate_circuit_tables(&test_pool.get().unwrap());
+        let mut response = srv
+            .request(
+                http::Method::GET,
+                srv.url("/circuit/proposal?status=Pending"),
+            )
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let body: ListSlice<CircuitProposalSlice> =
+            serde_json::from_slice(&*response.body().await.unwrap()).unwrap();
+        assert_eq!(body.data.len(), 1);
+        let test_proposal = &body.data[0];
+        assert_eq!(test_proposal.circuit_id, "pending-circuit".to_string());
+        assert_eq!(test_proposal.status, "Pending".to_string());
+        assert_eq!(test_proposal.votes.len(), 1);
+        assert_eq!(
+            test_proposal.votes[0].voter_node_id,
+            "beta-node".to_string()
+        );
+    }
+
     fn get_batch_statuses_response_one_id() -> Vec<u8> {
         let mut batch_status_response = ClientBatchStatusResponse::new();
         batch_status_response.set_status(ClientBatchStatusResponse_Status::OK);
@@ -2761,9 +3488,11 @@ mod test {
             active: true,
             roles: vec![],
             metadata: JsonValue::Object(Map::new()),
+            predecessor: None,
             start_commit_num: 0,
             end_commit_num: MAX_COMMIT_NUM,
             service_id,
+            state_address: "test_address".to_string(),
         }]
     }
 
@@ -2775,9 +3504,11 @@ mod test {
                 active: true,
                 roles: vec!["OWNER".to_string()],
                 metadata: JsonValue::Object(Map::new()),
+                predecessor: None,
                 start_commit_num: 0,
                 end_commit_num: MAX_COMMIT_NUM,
                 service_id: service_id.clone(),
+                state_address: "test_address".to_string(),
             },
             NewAgent {
                 public_key: KEY2.to_string(),
@@ -2785,13 +3516,41 @@ mod test {
                 active: true,
                 roles: vec!["CUSTODIAN".to_string()],
                 metadata: JsonValue::Object(Map::new()),
+                predecessor: None,
                 start_commit_num: 0,
                 end_commit_num: MAX_COMMIT_NUM,
                 service_id,
+                state_address: "test_address".to_string(),
             },
         ]
     }
 
+    fn get_commit_id(commit_num: i64) -> String {
+        format!("commit_{}", commit_num)
+    }
+
+    fn get_commits(count: i64) -> Vec<NewCommit> {
+        (0..count)
+            .map(|commit_num| NewCommit {
+                commit_id: get_commit_id(commit_num),
+                commit_num,
+                service_id: None,
+            })
+            .collect()
+    }
+
+    fn populate_commit_table(conn: &PgConnection, commits: &[NewCommit]) {
+        clear_commit_table(conn);
+        for commit in commits {
+            database::helpers::insert_commit(conn, commit).unwrap();
+        }
+    }
+
+    fn clear_commit_table(conn: &PgConnection) {
+        use crate::database::schema::commit::dsl::*;
+        diesel::delete(commit).execute(conn).unwrap();
+    }
+
     fn populate_agent_table(conn: &PgConnection, agents: &[NewAgent]) {
         clear_agents_table(conn);
         database::helpers::insert_agents(conn, agents).unwrap();
@@ -2808,9 +3567,12 @@ mod test {
             name: ORG_NAME_1.to_string(),
             address: ADDRESS_1.to_string(),
             metadata: vec![],
+            alternate_ids: vec![format!("gln:{}", ORG_GLN_1)],
+            locations: vec![],
             start_commit_num: 1,
             end_commit_num: database::helpers::MAX_COMMIT_NUM,
             service_id,
+            state_address: "test_address".to_string(),
         }]
     }
 
@@ -2821,22 +3583,96 @@ mod test {
                 name: ORG_NAME_2.to_string(),
                 address: ADDRESS_2.to_string(),
                 metadata: vec![],
+                alternate_ids: vec![],
+                locations: vec![],
                 start_commit_num: 2,
                 end_commit_num: 4,
                 service_id: None,
+                state_address: "test_address".to_string(),
             },
             NewOrganization {
                 org_id: KEY3.to_string(),
                 name: ORG_NAME_2.to_string(),
                 address: UPDATED_ADDRESS_2.to_string(),
                 metadata: vec![],
+                alternate_ids: vec![],
+                locations: vec![],
                 start_commit_num: 4,
                 end_commit_num: database::helpers::MAX_COMMIT_NUM,
                 service_id: None,
+                state_address: "test_address".to_string(),
             },
         ]
     }
 
+    fn populate_circuit_tables(conn: &PgConnection) {
+        clear_circuit_tables(conn);
+        let now = std::time::SystemTime::now();
+
+        let circuit = GridCircuit {
+            circuit_id: TEST_CIRCUIT_ID.to_string(),
+            authorization_type: "Trust".to_string(),
+            persistence: "Any".to_string(),
+            durability: "NoDurability".to_string(),
+            routes: "Any".to_string(),
+            circuit_management_type: "grid".to_string(),
+            alias: "partners".to_string(),
+            status: "Ready".to_string(),
+            created_time: now,
+            updated_time: now,
+            service_id: Some(TEST_SERVICE_ID.to_string()),
+        };
+        let members = ["alpha-node", "beta-node"]
+            .iter()
+            .map(|node_id| NewGridCircuitMember {
+                circuit_id: TEST_CIRCUIT_ID.to_string(),
+                node_id: node_id.to_string(),
+                endpoint: format!("tcp://{}:8044", node_id),
+                status: "Ready".to_string(),
+                created_time: now,
+                updated_time: now,
+            })
+            .collect::<Vec<_>>();
+        database::helpers::insert_circuit(conn, &circuit, &members).unwrap();
+
+        for (circuit_id, status) in &[
+            (TEST_CIRCUIT_ID, "Accepted"),
+            ("pending-circuit", "Pending"),
+        ] {
+            let proposal = NewGridCircuitProposal {
+                proposal_type: "Create".to_string(),
+                circuit_id: circuit_id.to_string(),
+                circuit_hash: format!("{}-hash", circuit_id),
+                requester: KEY1.to_string(),
+                requester_node_id: "alpha-node".to_string(),
+                status: status.to_string(),
+                created_time: now,
+                updated_time: now,
+            };
+            let votes = [NewGridCircuitProposalVoteRecord {
+                proposal_id: 0,
+                voter_public_key: KEY2.to_string(),
+                voter_node_id: "beta-node".to_string(),
+                vote: "Accept".to_string(),
+                created_time: now,
+            }];
+            database::helpers::store_circuit_proposal(conn, &proposal, &votes).unwrap();
+        }
+    }
+
+    fn clear_circuit_tables(conn: &PgConnection) {
+        diesel::delete(grid_circuit_proposal_vote_record::table)
+            .execute(conn)
+            .unwrap();
+        diesel::delete(grid_circuit_proposal::table)
+            .execute(conn)
+            .unwrap();
+        diesel::delete(grid_circuit_member::table)
+            .execute(conn)
+            .unwrap();
+        diesel::delete(grid_circuit::table).execute(conn).unwrap();
+    }
+
     fn populate_organization_table(conn: &PgConnection, organizations: Vec<NewOrganization>) {
         clear_organization_table(conn);
         database::helpers::insert_organizations(conn, &organizations).unwrap();
@@ -2854,6 +3690,8 @@ mod test {
             name: "TestGridSchema".to_string(),
             description: "Example test grid schema".to_string(),
             owner: "phillips001".to_string(),
+            version: 1,
+            editors: vec![],
             service_id,
         }]
     }
@@ -2862,8 +3700,9 @@ mod test {
         vec![NewProduct {
             product_id: "041205707820".to_string(),
             product_address: "test_address".to_string(),
-            product_namespace: "Grid Product".to_string(),
+            product_namespace: "GS1".to_string(),
             owner: "phillips001".to_string(),
+            pending_owner: Some("target001".to_string()),
             start_commit_num: 0,
             end_commit_num: MAX_COMMIT_NUM,
             service_id,
@@ -2987,6 +3826,7 @@ mod test {
             end_commit_num: MAX_COMMIT_NUM,
             record_id: record_id.to_string(),
             schema: "TestGridSchema".to_string(),
+            schema_version: 1,
             final_: false,
             owners: vec![KEY1.to_string()],
             custodians: vec![KEY2.to_string()],
@@ -3001,6 +3841,7 @@ mod test {
                 end_commit_num: 1,
                 record_id: "TestRecord".to_string(),
                 schema: "TestGridSchema".to_string(),
+                schema_version: 1,
                 final_: false,
                 owners: vec![KEY1.to_string()],
                 custodians: vec![KEY2.to_string()],
@@ -3011,6 +3852,7 @@ mod test {
                 end_commit_num: MAX_COMMIT_NUM,
                 record_id: "TestRecord".to_string(),
                 schema: "TestGridSchema".to_string(),
+                schema_version: 1,
                 final_: true,
                 owners: vec![KEY2.to_string(), KEY1.to_string()],
                 custodians: vec![KEY1.to_string(), KEY2.to_string()],
@@ -3026,6 +3868,7 @@ mod test {
                 end_commit_num: 1,
                 record_id: "TestRecord".to_string(),
                 schema: "TestGridSchema".to_string(),
+                schema_version: 1,
                 final_: false,
                 owners: vec![KEY1.to_string()],
                 custodians: vec![KEY2.to_string()],
@@ -3036,6 +3879,7 @@ mod test {
                 end_commit_num: MAX_COMMIT_NUM,
                 record_id: "TestRecord".to_string(),
                 schema: "TestGridSchema".to_string(),
+                schema_version: 1,
                 final_: true,
                 owners: vec![KEY2.to_string(), KEY1.to_string()],
                 custodians: vec![KEY1.to_string(), KEY2.to_string()],
@@ -3046,6 +3890,7 @@ mod test {
                 end_commit_num: MAX_COMMIT_NUM,
                 record_id: "TestRecord 2".to_string(),
                 schema: "TestGridSchema".to_string(),
+                schema_version: 1,
                 final_: false,
                 owners: vec![KEY1.to_string()],
                 custodians: vec![KEY2.to_string()],
@@ -3219,9 +4064,11 @@ mod test {
             active: true,
             roles: vec![],
             metadata: JsonValue::Object(metadata.clone()),
+            predecessor: None,
             start_commit_num: 0,
             end_commit_num: MAX_COMMIT_NUM,
             service_id: None,
+            state_address: "test_address".to_string(),
         };
 
         let value2 = JsonValue::String("Jon Snow".to_string());
@@ -3233,9 +4080,11 @@ mod test {
             active: true,
             roles: vec![],
             metadata: JsonValue::Object(metadata),
+            predecessor: None,
             start_commit_num: 0,
             end_commit_num: MAX_COMMIT_NUM,
             service_id: None,
+            state_address: "test_address".to_string(),
         };
 
         vec![agent, agent2]
@@ -3313,6 +4162,7 @@ mod test {
                 data_type: "Lightbulb".to_string(),
                 required: false,
                 description: "Definition Description".to_string(),
+                deprecated: false,
                 number_exponent: 0,
                 enum_options: vec![],
                 struct_properties: vec![],
@@ -3326,6 +4176,7 @@ mod test {
                 data_type: "New Lightbulb".to_string(),
                 required: false,
                 description: "Definition Description".to_string(),
+                deprecated: false,
                 number_exponent: 0,
                 enum_options: vec![],
                 struct_properties: vec![],
@@ -3345,6 +4196,7 @@ mod test {
             data_type: "Struct".to_string(),
             required: false,
             description: "Definition Description".to_string(),
+            deprecated: false,
             number_exponent: 0,
             enum_options: vec![],
             struct_properties: vec![],
@@ -3364,6 +4216,7 @@ mod test {
                 data_type: "String".to_string(),
                 required: false,
                 description: "Definition Description".to_string(),
+                deprecated: false,
                 number_exponent: 0,
                 enum_options: vec![],
                 struct_properties: vec![],
@@ -3377,6 +4230,7 @@ mod test {
                 data_type: "Boolean".to_string(),
                 required: false,
                 description: "Definition Description".to_string(),
+                deprecated: false,
                 number_exponent: 0,
                 enum_options: vec![],
                 struct_properties: vec![],
@@ -12,13 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::database::{helpers as db, models::Organization};
+use crate::database::{
+    helpers::{self as db, OrganizationFilter, OrganizationSortField, Sort},
+    models::Organization,
+};
 use crate::rest_api::{
-    error::RestApiResponseError, routes::DbExecutor, AcceptServiceIdParam, AppState, QueryServiceId,
+    error::RestApiResponseError,
+    paging::{get_base_link, get_response_paging_info, parse_sort, validate_paging, ListSlice},
+    routes::{get_commit_ids, resolve_as_of, DbExecutor, VersionSlice},
+    AcceptServiceIdParam, AppState, FetchQuery, HistoryQuery,
 };
 
 use actix::{Handler, Message, SyncContext};
-use actix_web::{web, HttpResponse};
+use actix_web::{web, HttpRequest, HttpResponse};
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 
@@ -28,6 +34,8 @@ pub struct OrganizationSlice {
     pub name: String,
     pub address: String,
     pub metadata: Vec<JsonValue>,
+    pub alternate_ids: Vec<AlternateIdSlice>,
+    pub locations: Vec<JsonValue>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub service_id: Option<String>,
@@ -40,49 +48,160 @@ impl OrganizationSlice {
             name: organization.name.clone(),
             address: organization.address.clone(),
             metadata: organization.metadata.clone(),
+            alternate_ids: organization
+                .alternate_ids
+                .iter()
+                .map(|alternate_id| AlternateIdSlice::from_alternate_id(alternate_id))
+                .collect(),
+            locations: organization.locations.clone(),
             service_id: organization.service_id.clone(),
         }
     }
 }
 
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AlternateIdSlice {
+    pub id_type: String,
+    pub id: String,
+}
+
+impl AlternateIdSlice {
+    /// Splits an alternate ID stored as `<id_type>:<id>`. ID types cannot
+    /// contain a colon, but IDs may.
+    pub fn from_alternate_id(alternate_id: &str) -> Self {
+        let mut parts = alternate_id.splitn(2, ':');
+        Self {
+            id_type: parts.next().unwrap_or_default().to_string(),
+            id: parts.next().unwrap_or_default().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListOrganizationsQuery {
+    pub service_id: Option<String>,
+    pub as_of: Option<String>,
+    pub alternate_id: Option<String>,
+    pub sort: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
 struct ListOrganizations {
     service_id: Option<String>,
+    as_of: Option<String>,
+    alternate_id: Option<String>,
+    sort: Sort<OrganizationSortField>,
+    limit: i64,
+    offset: i64,
 }
 
 impl Message for ListOrganizations {
-    type Result = Result<Vec<OrganizationSlice>, RestApiResponseError>;
+    type Result = Result<(Vec<OrganizationSlice>, i64), RestApiResponseError>;
 }
 
 impl Handler<ListOrganizations> for DbExecutor {
-    type Result = Result<Vec<OrganizationSlice>, RestApiResponseError>;
+    type Result = Result<(Vec<OrganizationSlice>, i64), RestApiResponseError>;
 
     fn handle(&mut self, msg: ListOrganizations, _: &mut SyncContext<Self>) -> Self::Result {
-        let fetched_organizations =
-            db::list_organizations(&*self.connection_pool.get()?, msg.service_id.as_deref())?
+        let conn = self.connection_pool.get()?;
+
+        conn.build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run(|| {
+                let as_of = resolve_as_of(&*conn, msg.as_of.as_deref(), msg.service_id.as_deref())?;
+
+                let filter = OrganizationFilter {
+                    alternate_id: msg.alternate_id.as_deref(),
+                };
+
+                let fetched_organizations = db::list_organizations(
+                    &*conn,
+                    filter,
+                    msg.service_id.as_deref(),
+                    as_of,
+                    msg.sort,
+                    msg.limit,
+                    msg.offset,
+                )?
                 .iter()
                 .map(|organization| OrganizationSlice::from_organization(organization))
                 .collect();
-        Ok(fetched_organizations)
+
+                let total =
+                    db::count_organizations(&*conn, filter, msg.service_id.as_deref(), as_of)?;
+
+                Ok((fetched_organizations, total))
+            })
     }
 }
 
+fn parse_organization_sort(
+    sort: Option<&str>,
+) -> Result<Sort<OrganizationSortField>, RestApiResponseError> {
+    let (field, order) = match sort {
+        Some(sort) => parse_sort(sort),
+        None => return Ok(Sort::default()),
+    };
+
+    let field = match field {
+        "org_id" => OrganizationSortField::OrgId,
+        "name" => OrganizationSortField::Name,
+        _ => {
+            return Err(RestApiResponseError::BadRequest(format!(
+                "Unable to sort organizations by {}",
+                field
+            )))
+        }
+    };
+
+    Ok(Sort { field, order })
+}
+
 pub async fn list_organizations(
+    req: HttpRequest,
     state: web::Data<AppState>,
-    query: web::Query<QueryServiceId>,
+    query: web::Query<ListOrganizationsQuery>,
     _: AcceptServiceIdParam,
 ) -> Result<HttpResponse, RestApiResponseError> {
+    let query = query.into_inner();
+    let (limit, offset) = validate_paging(query.limit, query.offset)?;
+    let sort = parse_organization_sort(query.sort.as_deref())?;
+    let link = get_base_link(&req);
+
+    if let Some(ref alternate_id) = query.alternate_id {
+        if !alternate_id.contains(':') {
+            return Err(RestApiResponseError::BadRequest(format!(
+                "Alternate ID must be given as <id_type>:<id>: {}",
+                alternate_id
+            )));
+        }
+    }
+
     state
         .database_connection
         .send(ListOrganizations {
-            service_id: query.into_inner().service_id,
+            service_id: query.service_id,
+            as_of: query.as_of,
+            alternate_id: query.alternate_id,
+            sort,
+            limit,
+            offset,
         })
         .await?
-        .map(|organizations| HttpResponse::Ok().json(organizations))
+        .map(|(organizations, total)| {
+            HttpResponse::Ok().json(ListSlice::new(
+                organizations,
+                get_response_paging_info(limit, offset, &link, total),
+            ))
+        })
 }
 
 struct FetchOrganization {
     organization_id: String,
     service_id: Option<String>,
+    as_of: Option<String>,
 }
 
 impl Message for FetchOrganization {
@@ -93,10 +212,17 @@ impl Handler<FetchOrganization> for DbExecutor {
     type Result = Result<OrganizationSlice, RestApiResponseError>;
 
     fn handle(&mut self, msg: FetchOrganization, _: &mut SyncContext<Self>) -> Self::Result {
+        let as_of = resolve_as_of(
+            &*self.connection_pool.get()?,
+            msg.as_of.as_deref(),
+            msg.service_id.as_deref(),
+        )?;
+
         let organization = match db::fetch_organization(
             &*self.connection_pool.get()?,
             &msg.organization_id,
             msg.service_id.as_deref(),
+            as_of,
         )? {
             Some(organization) => OrganizationSlice::from_organization(&organization),
             None => {
@@ -114,15 +240,115 @@ impl Handler<FetchOrganization> for DbExecutor {
 pub async fn fetch_organization(
     state: web::Data<AppState>,
     organization_id: web::Path<String>,
-    query: web::Query<QueryServiceId>,
+    query: web::Query<FetchQuery>,
     _: AcceptServiceIdParam,
 ) -> Result<HttpResponse, RestApiResponseError> {
+    let query = query.into_inner();
+
     state
         .database_connection
         .send(FetchOrganization {
             organization_id: organization_id.into_inner(),
-            service_id: query.into_inner().service_id,
+            service_id: query.service_id,
+            as_of: query.as_of,
         })
         .await?
         .map(|organization| HttpResponse::Ok().json(organization))
 }
+
+struct ListOrganizationHistory {
+    organization_id: String,
+    service_id: Option<String>,
+    limit: i64,
+    offset: i64,
+}
+
+impl Message for ListOrganizationHistory {
+    type Result = Result<(Vec<VersionSlice<OrganizationSlice>>, i64), RestApiResponseError>;
+}
+
+impl Handler<ListOrganizationHistory> for DbExecutor {
+    type Result = Result<(Vec<VersionSlice<OrganizationSlice>>, i64), RestApiResponseError>;
+
+    fn handle(&mut self, msg: ListOrganizationHistory, _: &mut SyncContext<Self>) -> Self::Result {
+        let conn = self.connection_pool.get()?;
+
+        conn.build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run(|| {
+                let total = db::count_organization_versions(
+                    &*conn,
+                    &msg.organization_id,
+                    msg.service_id.as_deref(),
+                )?;
+
+                if total == 0 {
+                    return Err(RestApiResponseError::NotFoundError(format!(
+                        "Could not find organization with id: {}",
+                        msg.organization_id
+                    )));
+                }
+
+                let organizations = db::list_organization_versions(
+                    &*conn,
+                    &msg.organization_id,
+                    msg.service_id.as_deref(),
+                    msg.limit,
+                    msg.offset,
+                )?;
+
+                let commit_ids = get_commit_ids(
+                    &*conn,
+                    &organizations
+                        .iter()
+                        .map(|organization| {
+                            (organization.start_commit_num, organization.end_commit_num)
+                        })
+                        .collect::<Vec<_>>(),
+                )?;
+
+                let versions = organizations
+                    .iter()
+                    .map(|organization| {
+                        VersionSlice::new(
+                            OrganizationSlice::from_organization(organization),
+                            organization.start_commit_num,
+                            organization.end_commit_num,
+                            &commit_ids,
+                        )
+                    })
+                    .collect();
+
+                Ok((versions, total))
+            })
+    }
+}
+
+pub async fn list_organization_history(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    organization_id: web::Path<String>,
+    query: web::Query<HistoryQuery>,
+    _: AcceptServiceIdParam,
+) -> Result<HttpResponse, RestApiResponseError> {
+    let query = query.into_inner();
+    let (limit, offset) = validate_paging(query.limit, query.offset)?;
+    let link = get_base_link(&req);
+
+    state
+        .database_connection
+        .send(ListOrganizationHistory {
+            organization_id: organization_id.into_inner(),
+            service_id: query.service_id,
+            limit,
+            offset,
+        })
+        .await?
+        .map(|(versions, total)| {
+            HttpResponse::Ok().json(ListSlice::new(
+                versions,
+                get_response_paging_info(limit, offset, &link, total),
+            ))
+        })
+}
@@ -16,25 +16,35 @@
  */
 
 use crate::database::{
-    helpers as db,
+    helpers::{self as db, ProductFilter, ProductSortField, Sort},
     models::{LatLongValue, Product, ProductPropertyValue},
 };
 
 use crate::rest_api::{
-    error::RestApiResponseError, routes::DbExecutor, AcceptServiceIdParam, AppState, QueryServiceId,
+    error::RestApiResponseError,
+    paging::{get_base_link, get_response_paging_info, parse_sort, validate_paging, ListSlice},
+    routes::{get_commit_ids, resolve_as_of, DbExecutor, VersionSlice},
+    AcceptServiceIdParam, AppState,
 };
 
 use actix::{Handler, Message, SyncContext};
-use actix_web::{web, HttpResponse};
+use actix_web::{web, HttpRequest, HttpResponse};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// The namespace a product ID is looked up in when the request does not name one; GTINs were
+/// the only kind of product ID before GLNs and SSCCs were added
+const DEFAULT_PRODUCT_NAMESPACE: &str = "GS1";
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ProductSlice {
     pub product_id: String,
     pub product_address: String,
     pub product_namespace: String,
     pub owner: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_owner: Option<String>,
     pub properties: Vec<ProductPropertyValueSlice>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -48,6 +58,7 @@ impl ProductSlice {
             product_address: product.product_address.clone(),
             product_namespace: product.product_namespace.clone(),
             owner: product.owner.clone(),
+            pending_owner: product.pending_owner.clone(),
             properties: properties
                 .iter()
                 .map(|prop| ProductPropertyValueSlice::from_model(prop))
@@ -112,63 +123,161 @@ impl LatLongSlice {
     }
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ListProductsQuery {
+    pub service_id: Option<String>,
+    pub as_of: Option<String>,
+    pub owner: Option<String>,
+    pub pending_owner: Option<String>,
+    pub sort: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
 struct ListProducts {
+    owner: Option<String>,
+    pending_owner: Option<String>,
     service_id: Option<String>,
+    as_of: Option<String>,
+    sort: Sort<ProductSortField>,
+    limit: i64,
+    offset: i64,
 }
 
 impl Message for ListProducts {
-    type Result = Result<Vec<ProductSlice>, RestApiResponseError>;
+    type Result = Result<(Vec<ProductSlice>, i64), RestApiResponseError>;
 }
 
 impl Handler<ListProducts> for DbExecutor {
-    type Result = Result<Vec<ProductSlice>, RestApiResponseError>;
+    type Result = Result<(Vec<ProductSlice>, i64), RestApiResponseError>;
 
     fn handle(&mut self, msg: ListProducts, _: &mut SyncContext<Self>) -> Self::Result {
-        let mut product_properties = db::list_product_property_values(
-            &*self.connection_pool.get()?,
-            msg.service_id.as_deref(),
-        )?
-        .into_iter()
-        .fold(HashMap::new(), |mut acc, product_property| {
-            acc.entry(product_property.product_id.to_string())
-                .or_insert_with(|| vec![])
-                .push(product_property);
-            acc
-        });
-
-        let fetched_products =
-            db::list_products(&*self.connection_pool.get()?, msg.service_id.as_deref())?
-                .iter()
-                .map(|product| {
-                    ProductSlice::from_model(
-                        product,
-                        product_properties
-                            .remove(&product.product_id)
-                            .unwrap_or_else(|| vec![]),
-                    )
-                })
-                .collect();
-        Ok(fetched_products)
+        let conn = self.connection_pool.get()?;
+
+        conn.build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run(|| {
+                let as_of = resolve_as_of(&*conn, msg.as_of.as_deref(), msg.service_id.as_deref())?;
+
+                let filter = ProductFilter {
+                    owner: msg.owner.as_deref(),
+                    pending_owner: msg.pending_owner.as_deref(),
+                };
+
+                let products = db::list_products(
+                    &*conn,
+                    filter,
+                    msg.service_id.as_deref(),
+                    as_of,
+                    msg.sort,
+                    msg.limit,
+                    msg.offset,
+                )?;
+
+                let product_addresses: Vec<String> = products
+                    .iter()
+                    .map(|product| product.product_address.to_string())
+                    .collect();
+
+                let mut product_properties = db::list_product_property_values(
+                    &*conn,
+                    &product_addresses,
+                    msg.service_id.as_deref(),
+                    as_of,
+                )?
+                .into_iter()
+                .fold(HashMap::new(), |mut acc, product_property| {
+                    acc.entry(product_property.product_address.to_string())
+                        .or_insert_with(|| vec![])
+                        .push(product_property);
+                    acc
+                });
+
+                let fetched_products = products
+                    .iter()
+                    .map(|product| {
+                        ProductSlice::from_model(
+                            product,
+                            product_properties
+                                .remove(&product.product_address)
+                                .unwrap_or_else(|| vec![]),
+                        )
+                    })
+                    .collect();
+
+                let total = db::count_products(&*conn, filter, msg.service_id.as_deref(), as_of)?;
+
+                Ok((fetched_products, total))
+            })
     }
 }
 
+fn parse_product_sort(sort: Option<&str>) -> Result<Sort<ProductSortField>, RestApiResponseError> {
+    let (field, order) = match sort {
+        Some(sort) => parse_sort(sort),
+        None => return Ok(Sort::default()),
+    };
+
+    let field = match field {
+        "product_id" => ProductSortField::ProductId,
+        "owner" => ProductSortField::Owner,
+        _ => {
+            return Err(RestApiResponseError::BadRequest(format!(
+                "Unable to sort products by {}",
+                field
+            )))
+        }
+    };
+
+    Ok(Sort { field, order })
+}
+
 pub async fn list_products(
+    req: HttpRequest,
     state: web::Data<AppState>,
-    query: web::Query<QueryServiceId>,
+    query: web::Query<ListProductsQuery>,
     _: AcceptServiceIdParam,
 ) -> Result<HttpResponse, RestApiResponseError> {
+    let query = query.into_inner();
+    let (limit, offset) = validate_paging(query.limit, query.offset)?;
+    let sort = parse_product_sort(query.sort.as_deref())?;
+    let link = get_base_link(&req);
+
     state
         .database_connection
         .send(ListProducts {
-            service_id: query.into_inner().service_id,
+            owner: query.owner,
+            pending_owner: query.pending_owner,
+            service_id: query.service_id,
+            as_of: query.as_of,
+            sort,
+            limit,
+            offset,
         })
         .await?
-        .map(|products| HttpResponse::Ok().json(products))
+        .map(|(products, total)| {
+            HttpResponse::Ok().json(ListSlice::new(
+                products,
+                get_response_paging_info(limit, offset, &link, total),
+            ))
+        })
+}
+
+/// Query parameters accepted by the route that fetches a single product
+#[derive(Debug, Deserialize)]
+pub struct FetchProductQuery {
+    pub service_id: Option<String>,
+    pub as_of: Option<String>,
+    /// The namespace of the product ID, e.g. GS1 or GLN
+    pub namespace: Option<String>,
 }
 
 struct FetchProduct {
     product_id: String,
+    product_namespace: String,
     service_id: Option<String>,
+    as_of: Option<String>,
 }
 
 impl Message for FetchProduct {
@@ -179,10 +288,18 @@ impl Handler<FetchProduct> for DbExecutor {
     type Result = Result<ProductSlice, RestApiResponseError>;
 
     fn handle(&mut self, msg: FetchProduct, _: &mut SyncContext<Self>) -> Self::Result {
+        let as_of = resolve_as_of(
+            &*self.connection_pool.get()?,
+            msg.as_of.as_deref(),
+            msg.service_id.as_deref(),
+        )?;
+
         let product = match db::fetch_product(
             &*self.connection_pool.get()?,
             &msg.product_id,
+            &msg.product_namespace,
             msg.service_id.as_deref(),
+            as_of,
         )? {
             Some(product) => product,
             None => {
@@ -195,8 +312,9 @@ impl Handler<FetchProduct> for DbExecutor {
 
         let product_properties = db::fetch_product_property_values(
             &*self.connection_pool.get()?,
-            &msg.product_id,
+            &product.product_address,
             msg.service_id.as_deref(),
+            as_of,
         )?;
 
         Ok(ProductSlice::from_model(&product, product_properties))
@@ -206,15 +324,152 @@ impl Handler<FetchProduct> for DbExecutor {
 pub async fn fetch_product(
     state: web::Data<AppState>,
     product_id: web::Path<String>,
-    query: web::Query<QueryServiceId>,
+    query: web::Query<FetchProductQuery>,
     _: AcceptServiceIdParam,
 ) -> Result<HttpResponse, RestApiResponseError> {
+    let query = query.into_inner();
+
     state
         .database_connection
         .send(FetchProduct {
             product_id: product_id.into_inner(),
-            service_id: query.into_inner().service_id,
+            product_namespace: query
+                .namespace
+                .unwrap_or_else(|| DEFAULT_PRODUCT_NAMESPACE.to_string()),
+            service_id: query.service_id,
+            as_of: query.as_of,
         })
         .await?
         .map(|product| HttpResponse::Ok().json(product))
 }
+
+/// Query parameters accepted by the route that lists the versions of a product
+#[derive(Debug, Deserialize)]
+pub struct ProductHistoryQuery {
+    pub service_id: Option<String>,
+    /// The namespace of the product ID, e.g. GS1 or GLN
+    pub namespace: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+struct ListProductHistory {
+    product_id: String,
+    product_namespace: String,
+    service_id: Option<String>,
+    limit: i64,
+    offset: i64,
+}
+
+impl Message for ListProductHistory {
+    type Result = Result<(Vec<VersionSlice<ProductSlice>>, i64), RestApiResponseError>;
+}
+
+impl Handler<ListProductHistory> for DbExecutor {
+    type Result = Result<(Vec<VersionSlice<ProductSlice>>, i64), RestApiResponseError>;
+
+    fn handle(&mut self, msg: ListProductHistory, _: &mut SyncContext<Self>) -> Self::Result {
+        let conn = self.connection_pool.get()?;
+
+        conn.build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run(|| {
+                let total = db::count_product_versions(
+                    &*conn,
+                    &msg.product_id,
+                    &msg.product_namespace,
+                    msg.service_id.as_deref(),
+                )?;
+
+                if total == 0 {
+                    return Err(RestApiResponseError::NotFoundError(format!(
+                        "Could not find product with id: {}",
+                        msg.product_id
+                    )));
+                }
+
+                let products = db::list_product_versions(
+                    &*conn,
+                    &msg.product_id,
+                    &msg.product_namespace,
+                    msg.service_id.as_deref(),
+                    msg.limit,
+                    msg.offset,
+                )?;
+
+                // Every version of the product is stored at the same address
+                let property_values = match products.first() {
+                    Some(product) => db::list_product_property_value_versions(
+                        &*conn,
+                        &product.product_address,
+                        msg.service_id.as_deref(),
+                    )?,
+                    None => vec![],
+                };
+
+                let commit_ids = get_commit_ids(
+                    &*conn,
+                    &products
+                        .iter()
+                        .map(|product| (product.start_commit_num, product.end_commit_num))
+                        .collect::<Vec<_>>(),
+                )?;
+
+                let versions = products
+                    .iter()
+                    .map(|product| {
+                        // The property values that were current when this version was introduced
+                        let properties = property_values
+                            .iter()
+                            .filter(|value| {
+                                value.start_commit_num <= product.start_commit_num
+                                    && value.end_commit_num > product.start_commit_num
+                            })
+                            .cloned()
+                            .collect();
+
+                        VersionSlice::new(
+                            ProductSlice::from_model(product, properties),
+                            product.start_commit_num,
+                            product.end_commit_num,
+                            &commit_ids,
+                        )
+                    })
+                    .collect();
+
+                Ok((versions, total))
+            })
+    }
+}
+
+pub async fn list_product_history(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    product_id: web::Path<String>,
+    query: web::Query<ProductHistoryQuery>,
+    _: AcceptServiceIdParam,
+) -> Result<HttpResponse, RestApiResponseError> {
+    let query = query.into_inner();
+    let (limit, offset) = validate_paging(query.limit, query.offset)?;
+    let link = get_base_link(&req);
+
+    state
+        .database_connection
+        .send(ListProductHistory {
+            product_id: product_id.into_inner(),
+            product_namespace: query
+                .namespace
+                .unwrap_or_else(|| DEFAULT_PRODUCT_NAMESPACE.to_string()),
+            service_id: query.service_id,
+            limit,
+            offset,
+        })
+        .await?
+        .map(|(versions, total)| {
+            HttpResponse::Ok().json(ListSlice::new(
+                versions,
+                get_response_paging_info(limit, offset, &link, total),
+            ))
+        })
+}
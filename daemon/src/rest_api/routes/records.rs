@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::database::{
-    helpers as db,
+    helpers::{self as db, RecordFilter, RecordSortField, Sort},
     models::{
         AssociatedAgent, LatLongValue, Property, Proposal, Record,
         ReportedValueReporterToAgentMetadata,
@@ -22,11 +22,14 @@ use crate::database::{
 };
 
 use crate::rest_api::{
-    error::RestApiResponseError, routes::DbExecutor, AcceptServiceIdParam, AppState, QueryServiceId,
+    error::RestApiResponseError,
+    paging::{get_base_link, get_response_paging_info, parse_sort, validate_paging, ListSlice},
+    routes::{resolve_as_of, DbExecutor},
+    AcceptServiceIdParam, AppState, FetchQuery,
 };
 
 use actix::{Handler, Message, SyncContext};
-use actix_web::{web, HttpResponse};
+use actix_web::{web, HttpRequest, HttpResponse};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value as JsonValue};
 
@@ -82,6 +85,7 @@ impl ProposalSlice {
 pub struct RecordSlice {
     pub record_id: String,
     pub schema: String,
+    pub schema_version: i64,
     pub owner: String,
     pub custodian: String,
     pub properties: Vec<PropertySlice>,
@@ -118,6 +122,7 @@ impl RecordSlice {
         Self {
             record_id: record.record_id.clone(),
             schema: record.schema.clone(),
+            schema_version: record.schema_version,
             owner: match owner_updates.last() {
                 Some(owner) => owner.agent_id.clone(),
                 None => "".to_string(),
@@ -136,90 +141,175 @@ impl RecordSlice {
     }
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ListRecordsQuery {
+    pub service_id: Option<String>,
+    pub as_of: Option<String>,
+    pub schema: Option<String>,
+    pub owner: Option<String>,
+    #[serde(rename = "final")]
+    pub final_: Option<bool>,
+    pub sort: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
 struct ListRecords {
+    schema: Option<String>,
+    owner: Option<String>,
+    final_: Option<bool>,
     service_id: Option<String>,
+    as_of: Option<String>,
+    sort: Sort<RecordSortField>,
+    limit: i64,
+    offset: i64,
 }
 
 impl Message for ListRecords {
-    type Result = Result<Vec<RecordSlice>, RestApiResponseError>;
+    type Result = Result<(Vec<RecordSlice>, i64), RestApiResponseError>;
 }
 
 impl Handler<ListRecords> for DbExecutor {
-    type Result = Result<Vec<RecordSlice>, RestApiResponseError>;
+    type Result = Result<(Vec<RecordSlice>, i64), RestApiResponseError>;
 
     fn handle(&mut self, msg: ListRecords, _: &mut SyncContext<Self>) -> Self::Result {
-        let records = db::list_records(&*self.connection_pool.get()?, msg.service_id.as_deref())?;
-
-        let record_ids: Vec<String> = records
-            .iter()
-            .map(|record| record.record_id.to_string())
-            .collect();
-
-        let proposals = db::list_proposals(
-            &*self.connection_pool.get()?,
-            &record_ids,
-            msg.service_id.as_deref(),
-        )?;
-        let associated_agents = db::list_associated_agents(
-            &*self.connection_pool.get()?,
-            &record_ids,
-            msg.service_id.as_deref(),
-        )?;
-
-        let properties = db::list_properties_with_data_type(
-            &*self.connection_pool.get()?,
-            &record_ids,
-            msg.service_id.as_deref(),
-        )?
-        .iter()
-        .map(|(property, data_type)| {
-            parse_property_slice(&self.connection_pool, property, data_type)
-        })
-        .collect::<Result<Vec<PropertySlice>, _>>()?;
-
-        Ok(records
-            .iter()
-            .map(|record| {
-                let props: Vec<Proposal> = proposals
-                    .iter()
-                    .filter(|proposal| proposal.record_id.eq(&record.record_id))
-                    .cloned()
-                    .collect();
-                let agents: Vec<AssociatedAgent> = associated_agents
+        let conn = self.connection_pool.get()?;
+
+        conn.build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run(|| {
+                let as_of = resolve_as_of(&*conn, msg.as_of.as_deref(), msg.service_id.as_deref())?;
+
+                let filter = RecordFilter {
+                    schema: msg.schema.as_deref(),
+                    owner: msg.owner.as_deref(),
+                    final_: msg.final_,
+                };
+
+                let records = db::list_records(
+                    &*conn,
+                    filter,
+                    msg.service_id.as_deref(),
+                    as_of,
+                    msg.sort,
+                    msg.limit,
+                    msg.offset,
+                )?;
+
+                let total = db::count_records(&*conn, filter, msg.service_id.as_deref(), as_of)?;
+
+                let record_ids: Vec<String> = records
                     .iter()
-                    .filter(|agent| agent.record_id.eq(&record.record_id))
-                    .cloned()
+                    .map(|record| record.record_id.to_string())
                     .collect();
 
-                let record_properties: Vec<PropertySlice> = properties
+                let proposals =
+                    db::list_proposals(&*conn, &record_ids, msg.service_id.as_deref(), as_of)?;
+                let associated_agents = db::list_associated_agents(
+                    &*conn,
+                    &record_ids,
+                    msg.service_id.as_deref(),
+                    as_of,
+                )?;
+
+                let properties = db::list_properties_with_data_type(
+                    &*conn,
+                    &record_ids,
+                    msg.service_id.as_deref(),
+                    as_of,
+                )?
+                .iter()
+                .map(|(property, data_type)| {
+                    parse_property_slice(&self.connection_pool, property, data_type, as_of)
+                })
+                .collect::<Result<Vec<PropertySlice>, _>>()?;
+
+                let fetched_records = records
                     .iter()
-                    .filter(|property| property.record_id.eq(&record.record_id))
-                    .cloned()
+                    .map(|record| {
+                        let props: Vec<Proposal> = proposals
+                            .iter()
+                            .filter(|proposal| proposal.record_id.eq(&record.record_id))
+                            .cloned()
+                            .collect();
+                        let agents: Vec<AssociatedAgent> = associated_agents
+                            .iter()
+                            .filter(|agent| agent.record_id.eq(&record.record_id))
+                            .cloned()
+                            .collect();
+
+                        let record_properties: Vec<PropertySlice> = properties
+                            .iter()
+                            .filter(|property| property.record_id.eq(&record.record_id))
+                            .cloned()
+                            .collect();
+
+                        RecordSlice::from_models(record, &props, &agents, &record_properties)
+                    })
                     .collect();
 
-                RecordSlice::from_models(record, &props, &agents, &record_properties)
+                Ok((fetched_records, total))
             })
-            .collect())
     }
 }
 
+fn parse_record_sort(sort: Option<&str>) -> Result<Sort<RecordSortField>, RestApiResponseError> {
+    let (field, order) = match sort {
+        Some(sort) => parse_sort(sort),
+        None => return Ok(Sort::default()),
+    };
+
+    let field = match field {
+        "record_id" => RecordSortField::RecordId,
+        "schema" => RecordSortField::Schema,
+        _ => {
+            return Err(RestApiResponseError::BadRequest(format!(
+                "Unable to sort records by {}",
+                field
+            )))
+        }
+    };
+
+    Ok(Sort { field, order })
+}
+
 pub async fn list_records(
+    req: HttpRequest,
     state: web::Data<AppState>,
-    query: web::Query<QueryServiceId>,
+    query: web::Query<ListRecordsQuery>,
     _: AcceptServiceIdParam,
 ) -> Result<HttpResponse, RestApiResponseError> {
+    let query = query.into_inner();
+    let (limit, offset) = validate_paging(query.limit, query.offset)?;
+    let sort = parse_record_sort(query.sort.as_deref())?;
+    let link = get_base_link(&req);
+
     state
         .database_connection
         .send(ListRecords {
-            service_id: query.into_inner().service_id,
+            schema: query.schema,
+            owner: query.owner,
+            final_: query.final_,
+            service_id: query.service_id,
+            as_of: query.as_of,
+            sort,
+            limit,
+            offset,
         })
         .await?
-        .map(|records| HttpResponse::Ok().json(records))
+        .map(|(records, total)| {
+            HttpResponse::Ok().json(ListSlice::new(
+                records,
+                get_response_paging_info(limit, offset, &link, total),
+            ))
+        })
 }
 
 struct FetchRecord {
     record_id: String,
     service_id: Option<String>,
+    as_of: Option<String>,
 }
 
 impl Message for FetchRecord {
@@ -230,7 +320,13 @@ impl Handler<FetchRecord> for DbExecutor {
     type Result = Result<RecordSlice, RestApiResponseError>;
 
     fn handle(&mut self, msg: FetchRecord, _: &mut SyncContext<Self>) -> Self::Result {
-        let record = match db::fetch_record(&*self.connection_pool.get()?, &msg.record_id)? {
+        let as_of = resolve_as_of(
+            &*self.connection_pool.get()?,
+            msg.as_of.as_deref(),
+            msg.service_id.as_deref(),
+        )?;
+
+        let record = match db::fetch_record(&*self.connection_pool.get()?, &msg.record_id, as_of)? {
             Some(record) => record,
             None => {
                 return Err(RestApiResponseError::NotFoundError(format!(
@@ -244,16 +340,18 @@ impl Handler<FetchRecord> for DbExecutor {
             &*self.connection_pool.get()?,
             &[msg.record_id.clone()],
             msg.service_id.as_deref(),
+            as_of,
         )?;
 
         let properties = db::list_properties_with_data_type(
             &*self.connection_pool.get()?,
             &[msg.record_id.clone()],
             msg.service_id.as_deref(),
+            as_of,
         )?
         .iter()
         .map(|(property, data_type)| {
-            parse_property_slice(&self.connection_pool, property, data_type)
+            parse_property_slice(&self.connection_pool, property, data_type, as_of)
         })
         .collect::<Result<Vec<PropertySlice>, _>>()?;
 
@@ -261,6 +359,7 @@ impl Handler<FetchRecord> for DbExecutor {
             &*self.connection_pool.get()?,
             &[msg.record_id],
             msg.service_id.as_deref(),
+            as_of,
         )?;
 
         Ok(RecordSlice::from_models(
@@ -275,14 +374,17 @@ impl Handler<FetchRecord> for DbExecutor {
 pub async fn fetch_record(
     state: web::Data<AppState>,
     record_id: web::Path<String>,
-    query: web::Query<QueryServiceId>,
+    query: web::Query<FetchQuery>,
     _: AcceptServiceIdParam,
 ) -> Result<HttpResponse, RestApiResponseError> {
+    let query = query.into_inner();
+
     state
         .database_connection
         .send(FetchRecord {
             record_id: record_id.into_inner(),
-            service_id: query.into_inner().service_id,
+            service_id: query.service_id,
+            as_of: query.as_of,
         })
         .await?
         .map(|record| HttpResponse::Ok().json(record))
@@ -494,6 +596,7 @@ struct FetchRecordProperty {
     record_id: String,
     property_name: String,
     service_id: Option<String>,
+    as_of: Option<String>,
 }
 
 impl Message for FetchRecordProperty {
@@ -503,15 +606,18 @@ impl Message for FetchRecordProperty {
 pub async fn fetch_record_property(
     state: web::Data<AppState>,
     params: web::Path<(String, String)>,
-    query: web::Query<QueryServiceId>,
+    query: web::Query<FetchQuery>,
     _: AcceptServiceIdParam,
 ) -> Result<HttpResponse, RestApiResponseError> {
+    let query = query.into_inner();
+
     state
         .database_connection
         .send(FetchRecordProperty {
             record_id: params.0.clone(),
             property_name: params.1.clone(),
-            service_id: query.into_inner().service_id,
+            service_id: query.service_id,
+            as_of: query.as_of,
         })
         .await?
         .map(|record| HttpResponse::Ok().json(record))
@@ -521,11 +627,18 @@ impl Handler<FetchRecordProperty> for DbExecutor {
     type Result = Result<PropertySlice, RestApiResponseError>;
 
     fn handle(&mut self, msg: FetchRecordProperty, _: &mut SyncContext<Self>) -> Self::Result {
+        let as_of = resolve_as_of(
+            &*self.connection_pool.get()?,
+            msg.as_of.as_deref(),
+            msg.service_id.as_deref(),
+        )?;
+
         let (property, data_type) = db::fetch_property_with_data_type(
             &*self.connection_pool.get()?,
             &msg.record_id,
             &msg.property_name,
             msg.service_id.as_deref(),
+            as_of,
         )?
         .ok_or_else(|| {
             RestApiResponseError::NotFoundError(format!(
@@ -534,7 +647,7 @@ impl Handler<FetchRecordProperty> for DbExecutor {
             ))
         })?;
 
-        parse_property_slice(&self.connection_pool, &property, &data_type)
+        parse_property_slice(&self.connection_pool, &property, &data_type, as_of)
     }
 }
 
@@ -542,15 +655,24 @@ fn parse_property_slice(
     conn: &ConnectionPool,
     property: &Property,
     data_type: &Option<String>,
+    as_of: Option<i64>,
 ) -> Result<PropertySlice, RestApiResponseError> {
-    let reporters = db::list_reporters(&*conn.get()?, &property.record_id, &property.name)?;
+    let reporters = db::list_reporters(&*conn.get()?, &property.record_id, &property.name, as_of)?;
 
-    let reported_value = db::fetch_reported_value_reporter_to_agent_metadata(
-        &*conn.get()?,
-        &property.record_id,
-        &property.name,
-        None,
-    )?;
+    let reported_value = match as_of {
+        Some(commit_num) => db::fetch_reported_value_reporter_to_agent_metadata_as_of(
+            &*conn.get()?,
+            &property.record_id,
+            &property.name,
+            commit_num,
+        )?,
+        None => db::fetch_reported_value_reporter_to_agent_metadata(
+            &*conn.get()?,
+            &property.record_id,
+            &property.name,
+            None,
+        )?,
+    };
 
     let property_value_slice = match reported_value {
         Some(value) => Some(parse_reported_values(&conn, &value)?),
@@ -572,6 +694,7 @@ fn parse_property_slice(
         &*conn.get()?,
         &property.record_id,
         &property.name,
+        as_of,
     )?
     .iter()
     .map(|reported_value| parse_reported_values(&conn, reported_value))
@@ -13,15 +13,18 @@
 // limitations under the License.
 
 use crate::database::{
-    helpers as db,
+    helpers::{self as db, GridSchemaSortField, Sort},
     models::{GridPropertyDefinition, GridSchema},
 };
 use crate::rest_api::{
-    error::RestApiResponseError, routes::DbExecutor, AcceptServiceIdParam, AppState, QueryServiceId,
+    error::RestApiResponseError,
+    paging::{get_base_link, get_response_paging_info, parse_sort, validate_paging, ListSlice},
+    routes::{resolve_as_of, DbExecutor},
+    AcceptServiceIdParam, AppState, FetchQuery,
 };
 
 use actix::{Handler, Message, SyncContext};
-use actix_web::{web, HttpResponse};
+use actix_web::{web, HttpRequest, HttpResponse};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -30,6 +33,8 @@ pub struct GridSchemaSlice {
     pub name: String,
     pub description: String,
     pub owner: String,
+    pub version: i64,
+    pub editors: Vec<String>,
     pub properties: Vec<GridPropertyDefinitionSlice>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -42,6 +47,8 @@ impl GridSchemaSlice {
             name: schema.name.clone(),
             description: schema.description.clone(),
             owner: schema.owner.clone(),
+            version: schema.version,
+            editors: schema.editors.clone(),
             properties: properties
                 .iter()
                 .map(|prop| GridPropertyDefinitionSlice::from_definition(prop))
@@ -58,6 +65,7 @@ pub struct GridPropertyDefinitionSlice {
     pub data_type: String,
     pub required: bool,
     pub description: String,
+    pub deprecated: bool,
     pub number_exponent: i64,
     pub enum_options: Vec<String>,
     pub struct_properties: Vec<String>,
@@ -74,6 +82,7 @@ impl GridPropertyDefinitionSlice {
             data_type: definition.data_type.clone(),
             required: definition.required,
             description: definition.description.clone(),
+            deprecated: definition.deprecated,
             number_exponent: definition.number_exponent,
             enum_options: definition.enum_options.clone(),
             struct_properties: definition.struct_properties.clone(),
@@ -82,61 +91,148 @@ impl GridPropertyDefinitionSlice {
     }
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ListGridSchemasQuery {
+    pub service_id: Option<String>,
+    pub as_of: Option<String>,
+    pub owner: Option<String>,
+    pub sort: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
 struct ListGridSchemas {
+    owner: Option<String>,
     service_id: Option<String>,
+    as_of: Option<String>,
+    sort: Sort<GridSchemaSortField>,
+    limit: i64,
+    offset: i64,
 }
 
 impl Message for ListGridSchemas {
-    type Result = Result<Vec<GridSchemaSlice>, RestApiResponseError>;
+    type Result = Result<(Vec<GridSchemaSlice>, i64), RestApiResponseError>;
 }
 
 impl Handler<ListGridSchemas> for DbExecutor {
-    type Result = Result<Vec<GridSchemaSlice>, RestApiResponseError>;
+    type Result = Result<(Vec<GridSchemaSlice>, i64), RestApiResponseError>;
 
     fn handle(&mut self, msg: ListGridSchemas, _: &mut SyncContext<Self>) -> Self::Result {
-        let mut properties = db::list_grid_property_definitions(
-            &*self.connection_pool.get()?,
-            msg.service_id.as_deref(),
-        )?
-        .into_iter()
-        .fold(HashMap::new(), |mut acc, definition| {
-            acc.entry(definition.schema_name.to_string())
-                .or_insert_with(|| vec![])
-                .push(definition);
-            acc
-        });
-
-        let fetched_schemas =
-            db::list_grid_schemas(&*self.connection_pool.get()?, msg.service_id.as_deref())?
-                .iter()
-                .map(|schema| {
-                    GridSchemaSlice::from_schema(
-                        schema,
-                        properties.remove(&schema.name).unwrap_or_else(|| vec![]),
-                    )
-                })
-                .collect();
-        Ok(fetched_schemas)
+        let conn = self.connection_pool.get()?;
+
+        conn.build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run(|| {
+                let as_of = resolve_as_of(&*conn, msg.as_of.as_deref(), msg.service_id.as_deref())?;
+
+                let schemas = db::list_grid_schemas(
+                    &*conn,
+                    msg.owner.as_deref(),
+                    msg.service_id.as_deref(),
+                    as_of,
+                    msg.sort,
+                    msg.limit,
+                    msg.offset,
+                )?;
+
+                let schema_names: Vec<String> = schemas
+                    .iter()
+                    .map(|schema| schema.name.to_string())
+                    .collect();
+
+                let mut properties = db::list_grid_property_definitions(
+                    &*conn,
+                    &schema_names,
+                    msg.service_id.as_deref(),
+                    as_of,
+                )?
+                .into_iter()
+                .fold(HashMap::new(), |mut acc, definition| {
+                    acc.entry(definition.schema_name.to_string())
+                        .or_insert_with(|| vec![])
+                        .push(definition);
+                    acc
+                });
+
+                let fetched_schemas = schemas
+                    .iter()
+                    .map(|schema| {
+                        GridSchemaSlice::from_schema(
+                            schema,
+                            properties.remove(&schema.name).unwrap_or_else(|| vec![]),
+                        )
+                    })
+                    .collect();
+
+                let total = db::count_grid_schemas(
+                    &*conn,
+                    msg.owner.as_deref(),
+                    msg.service_id.as_deref(),
+                    as_of,
+                )?;
+
+                Ok((fetched_schemas, total))
+            })
     }
 }
 
+fn parse_grid_schema_sort(
+    sort: Option<&str>,
+) -> Result<Sort<GridSchemaSortField>, RestApiResponseError> {
+    let (field, order) = match sort {
+        Some(sort) => parse_sort(sort),
+        None => return Ok(Sort::default()),
+    };
+
+    let field = match field {
+        "name" => GridSchemaSortField::Name,
+        "owner" => GridSchemaSortField::Owner,
+        _ => {
+            return Err(RestApiResponseError::BadRequest(format!(
+                "Unable to sort schemas by {}",
+                field
+            )))
+        }
+    };
+
+    Ok(Sort { field, order })
+}
+
 pub async fn list_grid_schemas(
+    req: HttpRequest,
     state: web::Data<AppState>,
-    query: web::Query<QueryServiceId>,
+    query: web::Query<ListGridSchemasQuery>,
     _: AcceptServiceIdParam,
 ) -> Result<HttpResponse, RestApiResponseError> {
+    let query = query.into_inner();
+    let (limit, offset) = validate_paging(query.limit, query.offset)?;
+    let sort = parse_grid_schema_sort(query.sort.as_deref())?;
+    let link = get_base_link(&req);
+
     state
         .database_connection
         .send(ListGridSchemas {
-            service_id: query.into_inner().service_id,
+            owner: query.owner,
+            service_id: query.service_id,
+            as_of: query.as_of,
+            sort,
+            limit,
+            offset,
         })
         .await?
-        .map(|schemas| HttpResponse::Ok().json(schemas))
+        .map(|(schemas, total)| {
+            HttpResponse::Ok().json(ListSlice::new(
+                schemas,
+                get_response_paging_info(limit, offset, &link, total),
+            ))
+        })
 }
 
 struct FetchGridSchema {
     name: String,
     service_id: Option<String>,
+    as_of: Option<String>,
 }
 
 impl Message for FetchGridSchema {
@@ -147,15 +243,23 @@ impl Handler<FetchGridSchema> for DbExecutor {
     type Result = Result<GridSchemaSlice, RestApiResponseError>;
 
     fn handle(&mut self, msg: FetchGridSchema, _: &mut SyncContext<Self>) -> Self::Result {
+        let as_of = resolve_as_of(
+            &*self.connection_pool.get()?,
+            msg.as_of.as_deref(),
+            msg.service_id.as_deref(),
+        )?;
+
         let properties = db::list_grid_property_definitions_with_schema_name(
             &*self.connection_pool.get()?,
             &msg.name,
             msg.service_id.as_deref(),
+            as_of,
         )?;
         let fetched_schema = match db::fetch_grid_schema(
             &*self.connection_pool.get()?,
             &msg.name,
             msg.service_id.as_deref(),
+            as_of,
         )? {
             Some(schema) => GridSchemaSlice::from_schema(&schema, properties),
             None => {
@@ -173,14 +277,17 @@ impl Handler<FetchGridSchema> for DbExecutor {
 pub async fn fetch_grid_schema(
     state: web::Data<AppState>,
     schema_name: web::Path<String>,
-    query: web::Query<QueryServiceId>,
+    query: web::Query<FetchQuery>,
     _: AcceptServiceIdParam,
 ) -> Result<HttpResponse, RestApiResponseError> {
+    let query = query.into_inner();
+
     state
         .database_connection
         .send(FetchGridSchema {
             name: schema_name.into_inner(),
-            service_id: query.into_inner().service_id,
+            service_id: query.service_id,
+            as_of: query.as_of,
         })
         .await?
         .map(|schema| HttpResponse::Ok().json(schema))
@@ -22,6 +22,11 @@ use protobuf::Message as _;
 
 use sawtooth_sdk::{
     messages::{
+        block::BlockHeader,
+        client_block::{
+            ClientBlockGetByNumRequest, ClientBlockGetResponse, ClientBlockGetResponse_Status,
+            ClientBlockListRequest, ClientBlockListResponse, ClientBlockListResponse_Status,
+        },
         client_event::{
             ClientEventsSubscribeRequest, ClientEventsSubscribeResponse,
             ClientEventsSubscribeResponse_Status,
@@ -116,6 +121,81 @@ impl EventConnection for SawtoothConnection {
     }
 }
 
+impl SawtoothConnection {
+    /// Returns the ID of the block at the given height on the validator's current chain, or
+    /// `None` if the chain is not that long.
+    pub fn get_block_id(&self, block_num: u64) -> Result<Option<String>, EventIoError> {
+        let mut request = ClientBlockGetByNumRequest::new();
+        request.set_block_num(block_num);
+
+        let mut future = self.get_sender().send(
+            Message_MessageType::CLIENT_BLOCK_GET_BY_NUM_REQUEST,
+            &correlation_id(),
+            &request.write_to_bytes().map_err(|err| {
+                EventIoError::ConnectionError(format!("Failed to serialize block request: {}", err))
+            })?,
+        )?;
+
+        let mut response: ClientBlockGetResponse = content_of_type(
+            Message_MessageType::CLIENT_BLOCK_GET_RESPONSE,
+            future.get()?,
+        )?;
+
+        match response.get_status() {
+            ClientBlockGetResponse_Status::OK => {
+                Ok(Some(response.take_block().take_header_signature()))
+            }
+            ClientBlockGetResponse_Status::NO_RESOURCE => Ok(None),
+            status => Err(EventIoError::ConnectionError(format!(
+                "Failed to fetch block {}: {:?}",
+                block_num, status
+            ))),
+        }
+    }
+
+    /// Returns the height of the validator's current chain head, or `None` if there is no
+    /// genesis block yet.
+    pub fn get_chain_height(&self) -> Result<Option<u64>, EventIoError> {
+        let mut request = ClientBlockListRequest::new();
+        request.mut_paging().set_limit(1);
+
+        let mut future = self.get_sender().send(
+            Message_MessageType::CLIENT_BLOCK_LIST_REQUEST,
+            &correlation_id(),
+            &request.write_to_bytes().map_err(|err| {
+                EventIoError::ConnectionError(format!(
+                    "Failed to serialize block list request: {}",
+                    err
+                ))
+            })?,
+        )?;
+
+        let response: ClientBlockListResponse = content_of_type(
+            Message_MessageType::CLIENT_BLOCK_LIST_RESPONSE,
+            future.get()?,
+        )?;
+
+        match response.get_status() {
+            ClientBlockListResponse_Status::OK => match response.get_blocks().first() {
+                Some(block) => protobuf::parse_from_bytes::<BlockHeader>(block.get_header())
+                    .map(|header| Some(header.get_block_num()))
+                    .map_err(|err| {
+                        EventIoError::InvalidMessage(format!(
+                            "Unable to parse block header: {}",
+                            err
+                        ))
+                    }),
+                None => Ok(None),
+            },
+            ClientBlockListResponse_Status::NOT_READY => Ok(None),
+            status => Err(EventIoError::ConnectionError(format!(
+                "Failed to fetch the chain head: {:?}",
+                status
+            ))),
+        }
+    }
+}
+
 pub struct SawtoothEventUnsubscriber {
     message_sender: ZmqMessageSender,
 }
@@ -21,6 +21,7 @@ use splinter::{events, service::scabbard::client::Error as ScabbardError};
 use std::error::Error;
 use std::fmt;
 
+use crate::database::DatabaseError;
 use crate::event::EventIoError;
 use crate::splinter::{app_auth_handler::node::GetNodeError, event::ScabbardEventConnectionError};
 use transact::{
@@ -41,6 +42,7 @@ pub enum AppAuthHandlerError {
     BatchSubmitError(String),
     ScabbardError(String),
     TransactError(String),
+    DatabaseError(DatabaseError),
 }
 
 impl Error for AppAuthHandlerError {
@@ -57,6 +59,7 @@ impl Error for AppAuthHandlerError {
             AppAuthHandlerError::BatchSubmitError(_) => None,
             AppAuthHandlerError::ScabbardError(_) => None,
             AppAuthHandlerError::TransactError(_) => None,
+            AppAuthHandlerError::DatabaseError(err) => Some(err),
         }
     }
 }
@@ -97,6 +100,7 @@ impl fmt::Display for AppAuthHandlerError {
                 "An error occurred while building a transaction or batch: {}",
                 msg
             ),
+            AppAuthHandlerError::DatabaseError(err) => write!(f, "Database error: {}", err),
         }
     }
 }
@@ -174,3 +178,15 @@ impl From<TransactionBuildError> for AppAuthHandlerError {
         Self::TransactError(err.to_string())
     }
 }
+
+impl From<DatabaseError> for AppAuthHandlerError {
+    fn from(err: DatabaseError) -> Self {
+        AppAuthHandlerError::DatabaseError(err)
+    }
+}
+
+impl From<diesel::result::Error> for AppAuthHandlerError {
+    fn from(err: diesel::result::Error) -> Self {
+        AppAuthHandlerError::DatabaseError(DatabaseError::from(err))
+    }
+}
@@ -19,17 +19,33 @@ pub mod error;
 mod node;
 mod sabre;
 
+pub use sabre::GridContract;
+
 use std::collections::HashMap;
+use std::time::SystemTime;
 
 use splinter::{
-    admin::messages::AdminServiceEvent,
+    admin::messages::{AdminServiceEvent, CircuitProposal, SplinterService},
     events::{Igniter, ParseBytes, ParseError, WebSocketClient, WebSocketError, WsResponse},
 };
 
-use crate::database::ConnectionPool;
-use crate::event::{db_handler::DatabaseEventHandler, EventProcessor};
+use crate::database::{
+    helpers as db,
+    models::{
+        GridCircuit, NewGridCircuitMember, NewGridCircuitProposal, NewGridCircuitProposalVoteRecord,
+    },
+    ConnectionPool,
+};
+use crate::event::{
+    db_handler::DatabaseEventHandler, progress::ReplayProgressHandler, EventConnection,
+    EventHandler, EventProcessor,
+};
 use crate::splinter::{
-    app_auth_handler::{error::AppAuthHandlerError, node::get_node_id, sabre::setup_grid},
+    app_auth_handler::{
+        error::AppAuthHandlerError,
+        node::get_node_id,
+        sabre::{bytes_to_hex_str, setup_grid},
+    },
     event::ScabbardEventConnectionFactory,
 };
 
@@ -42,6 +58,11 @@ const RECONNECT_LIMIT: u64 = 10;
 /// default timeout in seconds if no message is received from server
 const CONNECTION_TIMEOUT: u64 = 60;
 
+/// statuses recorded for circuit proposals
+const PROPOSAL_PENDING: &str = "Pending";
+const PROPOSAL_ACCEPTED: &str = "Accepted";
+const PROPOSAL_REJECTED: &str = "Rejected";
+
 #[derive(Deserialize, Debug, Clone)]
 struct AdminEvent {
     timestamp: u64,
@@ -62,11 +83,16 @@ pub fn run(
     connection_pool: ConnectionPool,
     igniter: Igniter,
     scabbard_admin_key: String,
+    circuit_contracts: Vec<GridContract>,
 ) -> Result<(), AppAuthHandlerError> {
     let registration_route = format!("{}/ws/admin/register/grid", &splinterd_url);
 
     let node_id = get_node_id(splinterd_url.clone())?;
 
+    // Circuits that became ready before a restart will not send another
+    // CircuitReady event, so their event processors are started here
+    resume_circuits(&event_connection_factory, &connection_pool)?;
+
     let mut ws = WebSocketClient::new(&registration_route, move |_ctx, event| {
         if let Err(err) = process_admin_event(
             event,
@@ -75,6 +101,7 @@ pub fn run(
             &node_id,
             &scabbard_admin_key,
             &splinterd_url,
+            &circuit_contracts,
         ) {
             error!("Failed to process admin event: {}", err);
         }
@@ -112,10 +139,25 @@ fn process_admin_event(
     node_id: &str,
     scabbard_admin_key: &str,
     splinterd_url: &str,
+    circuit_contracts: &[GridContract],
 ) -> Result<(), AppAuthHandlerError> {
     debug!("Received the event at {}", event.timestamp);
     match event.admin_event {
+        AdminServiceEvent::ProposalSubmitted(msg_proposal) => {
+            store_proposal(connection_pool, &msg_proposal, PROPOSAL_PENDING)
+        }
+        AdminServiceEvent::ProposalVote((msg_proposal, _)) => {
+            store_proposal(connection_pool, &msg_proposal, PROPOSAL_PENDING)
+        }
+        AdminServiceEvent::ProposalAccepted((msg_proposal, _)) => {
+            store_proposal(connection_pool, &msg_proposal, PROPOSAL_ACCEPTED)
+        }
+        AdminServiceEvent::ProposalRejected((msg_proposal, _)) => {
+            store_proposal(connection_pool, &msg_proposal, PROPOSAL_REJECTED)
+        }
         AdminServiceEvent::CircuitReady(msg_proposal) => {
+            store_proposal(connection_pool, &msg_proposal, PROPOSAL_ACCEPTED)?;
+
             let service = match msg_proposal.circuit.roster.iter().find_map(|service| {
                 if service.allowed_nodes.contains(&node_id.to_string()) {
                     Some(service)
@@ -129,6 +171,11 @@ fn process_admin_event(
                         "New circuit does not have any services for this node: {}",
                         node_id
                     );
+                    db::insert_circuit(
+                        &*connection_pool.get()?,
+                        &make_circuit(&msg_proposal, None),
+                        &make_circuit_members(&msg_proposal),
+                    )?;
                     return Ok(());
                 }
             };
@@ -152,25 +199,184 @@ fn process_admin_event(
                     })
                 })?;
 
-            let event_connection = event_connection_factory
-                .create_connection(&msg_proposal.circuit_id, &service.service_id)?;
-
-            EventProcessor::start(
-                event_connection,
-                None,
-                vec![Box::new(DatabaseEventHandler::new(connection_pool.clone()))],
-            )
-            .map_err(|err| AppAuthHandlerError::EventProcessorError(err.0))?;
+            if db::fetch_circuit(&*connection_pool.get()?, &msg_proposal.circuit_id)?.is_some() {
+                // Already set up, and its event processor was resumed on startup
+                debug!("Circuit {} is already known", msg_proposal.circuit_id);
+                return Ok(());
+            }
 
+            // The circuit is only recorded once the contracts have been submitted, so that a
+            // CircuitReady event delivered again after a failure retries the setup
             setup_grid(
                 scabbard_admin_key,
                 proposed_admin_pubkeys,
                 &splinterd_url,
                 &service.service_id,
                 &msg_proposal.circuit_id,
+                circuit_contracts,
+            )?;
+
+            let is_new_circuit = db::insert_circuit(
+                &*connection_pool.get()?,
+                &make_circuit(&msg_proposal, Some(service)),
+                &make_circuit_members(&msg_proposal),
+            )?;
+            if !is_new_circuit {
+                debug!("Circuit {} is already known", msg_proposal.circuit_id);
+                return Ok(());
+            }
+
+            // The event processor replays the service's commits from the start, including the
+            // contract setup submitted above; if it fails to start, it is started along with the
+            // other stored circuits on the next restart
+            start_event_processor(
+                event_connection_factory,
+                connection_pool,
+                &msg_proposal.circuit_id,
+                &service.service_id,
+            )
+        }
+    }
+}
+
+/// Starts an event processor for each stored circuit on which this node runs
+/// a service, picking up after the last commit recorded for that service
+fn resume_circuits(
+    event_connection_factory: &ScabbardEventConnectionFactory,
+    connection_pool: &ConnectionPool,
+) -> Result<(), AppAuthHandlerError> {
+    let circuits = db::list_circuits_with_service(&*connection_pool.get()?)?;
+
+    for circuit in circuits {
+        if let Some(service_id) = circuit.service_id {
+            debug!(
+                "Resuming event processing for circuit {} service {}",
+                circuit.circuit_id, service_id
+            );
+            start_event_processor(
+                event_connection_factory,
+                connection_pool,
+                &circuit.circuit_id,
+                &service_id,
             )?;
-            Ok(())
         }
-        _ => Ok(()),
     }
+
+    Ok(())
+}
+
+fn start_event_processor(
+    event_connection_factory: &ScabbardEventConnectionFactory,
+    connection_pool: &ConnectionPool,
+    circuit_id: &str,
+    service_id: &str,
+) -> Result<(), AppAuthHandlerError> {
+    let event_connection = event_connection_factory.create_connection(circuit_id, service_id)?;
+
+    // Commits are stored under the name of the connection they arrived on
+    let last_commit_id =
+        db::get_current_commit_id_for_service(&*connection_pool.get()?, event_connection.name())?;
+
+    let mut event_handlers: Vec<Box<dyn EventHandler>> =
+        vec![Box::new(DatabaseEventHandler::new(connection_pool.clone()))];
+    // Scabbard does not report a height, so only the number of commits replayed can be reported
+    if last_commit_id.is_none() {
+        event_handlers.push(Box::new(ReplayProgressHandler::new(
+            event_connection.name(),
+            None,
+        )));
+    }
+
+    EventProcessor::start(event_connection, last_commit_id.as_deref(), event_handlers)
+        .map_err(|err| AppAuthHandlerError::EventProcessorError(err.0))?;
+
+    Ok(())
+}
+
+/// Records a circuit proposal with the given status, along with its votes
+fn store_proposal(
+    connection_pool: &ConnectionPool,
+    proposal: &CircuitProposal,
+    status: &str,
+) -> Result<(), AppAuthHandlerError> {
+    let now = SystemTime::now();
+
+    let new_proposal = NewGridCircuitProposal {
+        proposal_type: format!("{:?}", proposal.proposal_type),
+        circuit_id: proposal.circuit_id.clone(),
+        circuit_hash: proposal.circuit_hash.clone(),
+        requester: bytes_to_hex_str(&proposal.requester),
+        requester_node_id: proposal.requester_node_id.clone(),
+        status: status.into(),
+        created_time: now,
+        updated_time: now,
+    };
+
+    // The proposal ID is assigned when the votes are stored
+    let votes = proposal
+        .votes
+        .iter()
+        .map(|vote| NewGridCircuitProposalVoteRecord {
+            proposal_id: 0,
+            voter_public_key: bytes_to_hex_str(&vote.public_key),
+            voter_node_id: vote.voter_node_id.clone(),
+            vote: format!("{:?}", vote.vote),
+            created_time: now,
+        })
+        .collect::<Vec<_>>();
+
+    db::store_circuit_proposal(&*connection_pool.get()?, &new_proposal, &votes)?;
+
+    Ok(())
+}
+
+fn make_circuit(proposal: &CircuitProposal, service: Option<&SplinterService>) -> GridCircuit {
+    let now = SystemTime::now();
+    let circuit = &proposal.circuit;
+
+    GridCircuit {
+        circuit_id: proposal.circuit_id.clone(),
+        authorization_type: format!("{:?}", circuit.authorization_type),
+        persistence: format!("{:?}", circuit.persistence),
+        durability: format!("{:?}", circuit.durability),
+        routes: format!("{:?}", circuit.routes),
+        circuit_management_type: circuit.circuit_management_type.clone(),
+        alias: circuit_alias(proposal),
+        status: "Ready".into(),
+        created_time: now,
+        updated_time: now,
+        service_id: service.map(|service| service.service_id.clone()),
+    }
+}
+
+/// Returns the alias given in the circuit's application metadata, falling
+/// back to the circuit ID
+fn circuit_alias(proposal: &CircuitProposal) -> String {
+    serde_json::from_slice::<serde_json::Value>(&proposal.circuit.application_metadata)
+        .ok()
+        .and_then(|metadata| {
+            metadata
+                .get("alias")
+                .and_then(|alias| alias.as_str())
+                .map(String::from)
+        })
+        .unwrap_or_else(|| proposal.circuit_id.clone())
+}
+
+fn make_circuit_members(proposal: &CircuitProposal) -> Vec<NewGridCircuitMember> {
+    let now = SystemTime::now();
+
+    proposal
+        .circuit
+        .members
+        .iter()
+        .map(|member| NewGridCircuitMember {
+            circuit_id: proposal.circuit_id.clone(),
+            node_id: member.node_id.clone(),
+            endpoint: member.endpoint.clone(),
+            status: "Ready".into(),
+            created_time: now,
+            updated_time: now,
+        })
+        .collect()
 }
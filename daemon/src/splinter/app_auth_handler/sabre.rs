@@ -16,6 +16,8 @@
  */
 
 use std::convert::TryInto;
+use std::fmt;
+use std::str::FromStr;
 
 use sabre_sdk::protocol::payload::{
     CreateContractActionBuilder, CreateContractRegistryActionBuilder,
@@ -51,12 +53,114 @@ const SCHEMA_PREFIX: &str = "621dee01";
 const SCHEMA_CONTRACT_NAME: &str = "grid-schema";
 const SCHEMA_CONTRACT_VERSION_REQ: &str = "0.1.0-dev";
 
+// Track and Trace constants
+const TRACK_AND_TRACE_PREFIX: &str = "a43b46";
+const TRACK_AND_TRACE_CONTRACT_NAME: &str = "grid-track-and-trace";
+const TRACK_AND_TRACE_CONTRACT_VERSION_REQ: &str = "0.1.0-dev";
+
+/// A Grid smart contract that can be deployed on a new circuit
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GridContract {
+    Pike,
+    Schema,
+    Product,
+    TrackAndTrace,
+}
+
+impl GridContract {
+    /// Every Grid contract, in the order they are deployed. A contract is
+    /// always deployed after the contracts whose namespaces it uses.
+    pub const ALL: [GridContract; 4] = [
+        GridContract::Pike,
+        GridContract::Schema,
+        GridContract::Product,
+        GridContract::TrackAndTrace,
+    ];
+
+    /// The other contracts that must be deployed on the same circuit for
+    /// this one to work
+    pub fn dependencies(self) -> &'static [GridContract] {
+        match self {
+            GridContract::Pike => &[],
+            GridContract::Schema => &[GridContract::Pike],
+            GridContract::Product | GridContract::TrackAndTrace => {
+                &[GridContract::Pike, GridContract::Schema]
+            }
+        }
+    }
+
+    fn scar_name(self) -> &'static str {
+        match self {
+            GridContract::Pike => PIKE_CONTRACT_NAME,
+            GridContract::Schema => SCHEMA_CONTRACT_NAME,
+            GridContract::Product => PRODUCT_CONTRACT_NAME,
+            GridContract::TrackAndTrace => TRACK_AND_TRACE_CONTRACT_NAME,
+        }
+    }
+
+    fn version_req(self) -> &'static str {
+        match self {
+            GridContract::Pike => PIKE_CONTRACT_VERSION_REQ,
+            GridContract::Schema => SCHEMA_CONTRACT_VERSION_REQ,
+            GridContract::Product => PRODUCT_CONTRACT_VERSION_REQ,
+            GridContract::TrackAndTrace => TRACK_AND_TRACE_CONTRACT_VERSION_REQ,
+        }
+    }
+
+    fn prefix(self) -> &'static str {
+        match self {
+            GridContract::Pike => PIKE_PREFIX,
+            GridContract::Schema => SCHEMA_PREFIX,
+            GridContract::Product => PRODUCT_PREFIX,
+            GridContract::TrackAndTrace => TRACK_AND_TRACE_PREFIX,
+        }
+    }
+
+    /// The namespaces of other contracts this contract is granted access to,
+    /// and whether that access includes writing
+    fn foreign_namespaces(self) -> &'static [(&'static str, bool)] {
+        match self {
+            GridContract::Pike => &[],
+            GridContract::Schema => &[(PIKE_PREFIX, true)],
+            GridContract::Product => &[(PIKE_PREFIX, true), (SCHEMA_PREFIX, true)],
+            // Track and Trace only looks up agents and schemas
+            GridContract::TrackAndTrace => &[(PIKE_PREFIX, false), (SCHEMA_PREFIX, false)],
+        }
+    }
+}
+
+impl FromStr for GridContract {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_ref() {
+            "pike" => Ok(GridContract::Pike),
+            "schema" => Ok(GridContract::Schema),
+            "product" => Ok(GridContract::Product),
+            "track_and_trace" => Ok(GridContract::TrackAndTrace),
+            _ => Err(format!("Unknown Grid contract: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for GridContract {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GridContract::Pike => write!(f, "pike"),
+            GridContract::Schema => write!(f, "schema"),
+            GridContract::Product => write!(f, "product"),
+            GridContract::TrackAndTrace => write!(f, "track_and_trace"),
+        }
+    }
+}
+
 pub fn setup_grid(
     scabbard_admin_key: &str,
     proposed_admin_pubkeys: Vec<String>,
     splinterd_url: &str,
     service_id: &str,
     circuit_id: &str,
+    contracts: &[GridContract],
 ) -> Result<(), AppAuthHandlerError> {
     let signer = new_signer(&scabbard_admin_key)?;
 
@@ -70,69 +174,14 @@ pub fn setup_grid(
         return Ok(());
     }
 
-    // Make Pike transactions
-    let pike_contract = SmartContractArchive::from_scar_file(
-        PIKE_CONTRACT_NAME,
-        PIKE_CONTRACT_VERSION_REQ,
-        &default_scar_path(),
-    )?;
-    let pike_contract_registry_txn =
-        make_contract_registry_txn(&signer, &pike_contract.metadata.name)?;
-    let pike_contract_txn = make_upload_contract_txn(&signer, &pike_contract, PIKE_PREFIX)?;
-    let pike_namespace_registry_txn = make_namespace_registry_txn(&signer, PIKE_PREFIX)?;
-    let pike_namespace_permissions_txn =
-        make_namespace_permissions_txn(&signer, &pike_contract, PIKE_PREFIX)?;
-
-    // Make Product transactions
-    let product_contract = SmartContractArchive::from_scar_file(
-        PRODUCT_CONTRACT_NAME,
-        PRODUCT_CONTRACT_VERSION_REQ,
-        &default_scar_path(),
-    )?;
-    let product_contract_registry_txn =
-        make_contract_registry_txn(&signer, &product_contract.metadata.name)?;
-    let product_contract_txn =
-        make_upload_contract_txn(&signer, &product_contract, PRODUCT_PREFIX)?;
-    let product_namespace_registry_txn = make_namespace_registry_txn(&signer, PRODUCT_PREFIX)?;
-    let product_namespace_permissions_txn =
-        make_namespace_permissions_txn(&signer, &product_contract, PRODUCT_PREFIX)?;
-    let product_pike_namespace_permissions_txn =
-        make_namespace_permissions_txn(&signer, &product_contract, PIKE_PREFIX)?;
-    let product_schema_namespace_permissions_txn =
-        make_namespace_permissions_txn(&signer, &product_contract, SCHEMA_PREFIX)?;
-
-    // Make schema transactions
-    let schema_contract = SmartContractArchive::from_scar_file(
-        SCHEMA_CONTRACT_NAME,
-        SCHEMA_CONTRACT_VERSION_REQ,
-        &default_scar_path(),
-    )?;
-    let schema_contract_registry_txn =
-        make_contract_registry_txn(&signer, &schema_contract.metadata.name)?;
-    let schema_contract_txn = make_upload_contract_txn(&signer, &schema_contract, SCHEMA_PREFIX)?;
-    let schema_namespace_registry_txn = make_namespace_registry_txn(&signer, SCHEMA_PREFIX)?;
-    let schema_namespace_permissions_txn =
-        make_namespace_permissions_txn(&signer, &schema_contract, SCHEMA_PREFIX)?;
-    let schema_pike_namespace_permissions_txn =
-        make_namespace_permissions_txn(&signer, &schema_contract, PIKE_PREFIX)?;
-
-    let txns = vec![
-        pike_contract_registry_txn,
-        pike_contract_txn,
-        pike_namespace_registry_txn,
-        pike_namespace_permissions_txn,
-        product_contract_registry_txn,
-        product_contract_txn,
-        product_namespace_registry_txn,
-        product_pike_namespace_permissions_txn,
-        product_namespace_permissions_txn,
-        schema_contract_registry_txn,
-        schema_contract_txn,
-        schema_namespace_registry_txn,
-        product_schema_namespace_permissions_txn,
-        schema_pike_namespace_permissions_txn,
-        schema_namespace_permissions_txn,
-    ];
+    let mut txns = Vec::new();
+    for contract in GridContract::ALL
+        .iter()
+        .filter(|contract| contracts.contains(*contract))
+    {
+        txns.append(&mut make_contract_txns(&signer, *contract)?);
+    }
+
     let batch = BatchBuilder::new().with_transactions(txns).build(&signer)?;
 
     ScabbardClient::new(&splinterd_url)
@@ -146,6 +195,34 @@ pub fn setup_grid(
     Ok(())
 }
 
+/// Makes the transactions that upload a contract and give it access to its
+/// own namespace and to the namespaces of the contracts it depends on
+fn make_contract_txns(
+    signer: &dyn Signer,
+    contract: GridContract,
+) -> Result<Vec<Transaction>, AppAuthHandlerError> {
+    let archive = SmartContractArchive::from_scar_file(
+        contract.scar_name(),
+        contract.version_req(),
+        &default_scar_path(),
+    )?;
+
+    let mut txns = vec![
+        make_contract_registry_txn(signer, &archive.metadata.name)?,
+        make_upload_contract_txn(signer, &archive, contract.prefix())?,
+        make_namespace_registry_txn(signer, contract.prefix())?,
+        make_namespace_permissions_txn(signer, &archive, contract.prefix(), true)?,
+    ];
+
+    for (prefix, write) in contract.foreign_namespaces() {
+        txns.push(make_namespace_permissions_txn(
+            signer, &archive, prefix, *write,
+        )?);
+    }
+
+    Ok(txns)
+}
+
 fn new_signer(private_key: &str) -> Result<TransactSigner, AppAuthHandlerError> {
     let context = create_context("secp256k1")?;
     let private_key = Box::new(Secp256k1PrivateKey::from_hex(private_key)?);
@@ -197,12 +274,13 @@ fn make_namespace_permissions_txn(
     signer: &dyn Signer,
     contract: &SmartContractArchive,
     contract_prefix: &str,
+    write: bool,
 ) -> Result<Transaction, AppAuthHandlerError> {
     Ok(CreateNamespaceRegistryPermissionActionBuilder::new()
         .with_namespace(contract_prefix.into())
         .with_contract_name(contract.metadata.name.clone())
         .with_read(true)
-        .with_write(true)
+        .with_write(write)
         .into_payload_builder()?
         .into_transaction_builder(signer)?
         .build(signer)?)
@@ -213,7 +291,7 @@ fn make_namespace_permissions_txn(
 /// # Arguments
 ///
 /// * `b` - input bytes
-fn bytes_to_hex_str(b: &[u8]) -> String {
+pub(super) fn bytes_to_hex_str(b: &[u8]) -> String {
     b.iter()
         .map(|b| format!("{:02x}", b))
         .collect::<Vec<_>>()
@@ -20,13 +20,22 @@ import { get } from './requests';
 
 const { gridURL } = getSharedConfig().appConfig;
 
+// The product list is paged; follow the next links until every page is read.
 export const listProducts = async serviceID => {
-  const result = await get(`${gridURL}/product?service_id=${serviceID}`);
+  let products = [];
+  let next = `/product?service_id=${serviceID}`;
 
-  if (result.ok) {
-    return result.json;
+  while (next) {
+    // eslint-disable-next-line no-await-in-loop
+    const result = await get(`${gridURL}${next}`);
+    if (!result.ok) {
+      throw Error(result.data);
+    }
+    products = products.concat(result.json.data);
+    next = result.json.paging.next;
   }
-  throw Error(result.data);
+
+  return products;
 };
 
 export const fetchProduct = async (serviceID, productID) => {
@@ -12,8 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crypto::digest::Digest;
-use crypto::sha2::Sha512;
+use std::collections::HashSet;
 use std::error::Error;
 use std::fmt;
 
@@ -27,18 +26,14 @@ cfg_if! {
     }
 }
 
-use crate::protocol::pike::state::{Agent, AgentList};
+use crate::protocol::block_info::{compute_block_info_address, compute_block_info_config_address};
+use crate::protocol::pike::addressing::{
+    compute_agent_address, compute_delegation_address, compute_role_address,
+};
+use crate::protocol::pike::state::{Agent, AgentList, Delegation, DelegationList, Role, RoleList};
+use crate::protos::block_info::{BlockInfo, BlockInfoConfig};
 use crate::protos::{FromBytes, ProtoConversionError};
 
-const PIKE_NAMESPACE: &str = "cad11d";
-const PIKE_AGENT_RESOURCE: &str = "00";
-
-fn compute_agent_address(public_key: &str) -> String {
-    let mut sha = Sha512::new();
-    sha.input(public_key.as_bytes());
-    String::from(PIKE_NAMESPACE) + PIKE_AGENT_RESOURCE + &sha.result_str()[..62].to_string()
-}
-
 #[derive(Debug)]
 pub enum PermissionCheckerError {
     /// Returned for an error originating at the TransactionContext.
@@ -101,7 +96,8 @@ impl<'a> PermissionChecker<'a> {
         PermissionChecker { context }
     }
 
-    /// Checks whether an agent with a given public key has a certain role.
+    /// Checks whether an agent with a given public key has a certain role,
+    /// either directly or through a role defined by its organization.
     ///
     /// # Arguments
     ///
@@ -115,7 +111,35 @@ impl<'a> PermissionChecker<'a> {
     ) -> Result<bool, PermissionCheckerError> {
         let agent = self.get_agent(public_key)?;
         match agent {
-            Some(agent) => Ok(agent.roles().iter().any(|r| r == permission)),
+            Some(agent) => Ok(self.effective_permissions(&agent)?.contains(permission)),
+            None => Err(PermissionCheckerError::InvalidPublicKey(format!(
+                "The signer is not an Agent: {}",
+                public_key
+            ))),
+        }
+    }
+
+    /// Checks whether an agent with a given public key has a certain role
+    /// while acting on behalf of an organization. Only an active agent that
+    /// belongs to the organization can hold a permission for it.
+    ///
+    /// # Arguments
+    ///
+    /// * `public_key` - Public key of a Pike agent.
+    /// * `permission` - Permission string to be checked.
+    /// * `org_id` - ID of the organization the agent is acting for.
+    ///
+    pub fn has_permission_for_org(
+        &self,
+        public_key: &str,
+        permission: &str,
+        org_id: &str,
+    ) -> Result<bool, PermissionCheckerError> {
+        let agent = self.get_agent(public_key)?;
+        match agent {
+            Some(agent) => Ok(*agent.active()
+                && agent.org_id() == org_id
+                && self.effective_permissions(&agent)?.contains(permission)),
             None => Err(PermissionCheckerError::InvalidPublicKey(format!(
                 "The signer is not an Agent: {}",
                 public_key
@@ -123,6 +147,159 @@ impl<'a> PermissionChecker<'a> {
         }
     }
 
+    /// Checks whether an agent with a given public key holds a permission for
+    /// an organization other than its own through a delegation. The agent must
+    /// be active and hold the permission within its own organization, and the
+    /// organization must have delegated the permission to the agent's
+    /// organization. A delegation with an expiry is only honoured while the
+    /// timestamp of the latest block, as recorded by the BlockInfo transaction
+    /// family, is before the expiry.
+    ///
+    /// # Arguments
+    ///
+    /// * `public_key` - Public key of a Pike agent.
+    /// * `permission` - Permission string to be checked.
+    /// * `org_id` - ID of the organization the agent is acting for.
+    ///
+    pub fn has_delegated_permission(
+        &self,
+        public_key: &str,
+        permission: &str,
+        org_id: &str,
+    ) -> Result<bool, PermissionCheckerError> {
+        let agent = match self.get_agent(public_key)? {
+            Some(agent) => agent,
+            None => {
+                return Err(PermissionCheckerError::InvalidPublicKey(format!(
+                    "The signer is not an Agent: {}",
+                    public_key
+                )))
+            }
+        };
+
+        if !*agent.active() || agent.org_id() == org_id {
+            return Ok(false);
+        }
+
+        let delegation = match self.get_delegation(org_id, agent.org_id())? {
+            Some(delegation) => delegation,
+            None => return Ok(false),
+        };
+
+        if delegation.expiry() != 0 {
+            match self.get_block_timestamp()? {
+                Some(timestamp) if timestamp < delegation.expiry() => (),
+                _ => return Ok(false),
+            }
+        }
+
+        Ok(delegation.permissions().iter().any(|p| p == permission)
+            && self.effective_permissions(&agent)?.contains(permission))
+    }
+
+    /// Collects every permission an agent holds. Each of the agent's roles is
+    /// a permission in its own right; when the agent's organization defines a
+    /// role with that name, the role's permissions are added as well, followed
+    /// transitively by those of the roles it inherits from.
+    fn effective_permissions(
+        &self,
+        agent: &Agent,
+    ) -> Result<HashSet<String>, PermissionCheckerError> {
+        let mut permissions: HashSet<String> = agent.roles().iter().cloned().collect();
+        let mut visited = HashSet::new();
+        let mut pending = agent.roles().to_vec();
+
+        while let Some(role_name) = pending.pop() {
+            // an inheritance cycle must not loop forever
+            if !visited.insert(role_name.clone()) {
+                continue;
+            }
+
+            if let Some(role) = self.get_role(agent.org_id(), &role_name)? {
+                permissions.extend(role.permissions().iter().cloned());
+                pending.extend(role.inherit_from().iter().cloned());
+            }
+        }
+
+        Ok(permissions)
+    }
+
+    fn get_role(&self, org_id: &str, name: &str) -> Result<Option<Role>, PermissionCheckerError> {
+        let address = compute_role_address(org_id, name);
+        let d = self.context.get_state_entry(&address)?;
+        match d {
+            Some(packed) => {
+                let role_list = RoleList::from_bytes(packed.as_slice())?;
+                for role in role_list.roles() {
+                    if role.org_id() == org_id && role.name() == name {
+                        return Ok(Some(role.clone()));
+                    }
+                }
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn get_delegation(
+        &self,
+        org_id: &str,
+        delegate_org_id: &str,
+    ) -> Result<Option<Delegation>, PermissionCheckerError> {
+        let address = compute_delegation_address(org_id, delegate_org_id);
+        let d = self.context.get_state_entry(&address)?;
+        match d {
+            Some(packed) => {
+                let delegation_list = DelegationList::from_bytes(packed.as_slice())?;
+                for delegation in delegation_list.delegations() {
+                    if delegation.org_id() == org_id
+                        && delegation.delegate_org_id() == delegate_org_id
+                    {
+                        return Ok(Some(delegation.clone()));
+                    }
+                }
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the timestamp of the latest block recorded by the BlockInfo
+    /// transaction family, if there is one. The validator sets this timestamp,
+    /// so the submitter of a transaction cannot choose it.
+    fn get_block_timestamp(&self) -> Result<Option<u64>, PermissionCheckerError> {
+        // an address that was never set may be returned with no data
+        let config = match self
+            .context
+            .get_state_entry(&compute_block_info_config_address())?
+        {
+            Some(ref packed) if !packed.is_empty() => {
+                protobuf::parse_from_bytes::<BlockInfoConfig>(packed.as_slice()).map_err(|_| {
+                    ProtoConversionError::SerializationError(
+                        "Unable to get BlockInfoConfig from bytes".to_string(),
+                    )
+                })?
+            }
+            _ => return Ok(None),
+        };
+
+        let d = self
+            .context
+            .get_state_entry(&compute_block_info_address(config.get_latest_block()))?;
+        match d {
+            Some(ref packed) if !packed.is_empty() => {
+                let block_info = protobuf::parse_from_bytes::<BlockInfo>(packed.as_slice())
+                    .map_err(|_| {
+                        ProtoConversionError::SerializationError(
+                            "Unable to get BlockInfo from bytes".to_string(),
+                        )
+                    })?;
+                Ok(Some(block_info.get_timestamp()))
+            }
+            _ => Ok(None),
+        }
+    }
+
     fn get_agent(&self, public_key: &str) -> Result<Option<Agent>, PermissionCheckerError> {
         let address = compute_agent_address(public_key);
         let d = self.context.get_state_entry(&address)?;
@@ -148,9 +325,14 @@ mod tests {
     use std::cell::RefCell;
     use std::collections::HashMap;
 
-    use crate::protocol::pike::state::{AgentBuilder, AgentListBuilder};
+    use crate::protocol::pike::state::{
+        AgentBuilder, AgentListBuilder, DelegationBuilder, DelegationListBuilder, RoleBuilder,
+        RoleListBuilder,
+    };
     use crate::protos::IntoBytes;
 
+    use protobuf::Message;
+
     const ROLE_A: &str = "Role A";
     const ROLE_B: &str = "Role B";
 
@@ -325,4 +507,218 @@ mod tests {
         let result = pc.has_permission(PUBLIC_KEY, ROLE_B).unwrap();
         assert!(result);
     }
+
+    fn set_agent(context: &MockTransactionContext, org_id: &str, active: bool) {
+        let builder = AgentBuilder::new();
+        let agent = builder
+            .with_org_id(org_id.to_string())
+            .with_public_key(PUBLIC_KEY.to_string())
+            .with_active(active)
+            .with_roles(vec![ROLE_A.to_string()])
+            .build()
+            .unwrap();
+        let builder = AgentListBuilder::new();
+        let agent_list = builder.with_agents(vec![agent]).build().unwrap();
+        let agent_bytes = agent_list.into_bytes().unwrap();
+        let agent_address = compute_agent_address(PUBLIC_KEY);
+        context.set_state_entry(agent_address, agent_bytes).unwrap();
+    }
+
+    #[test]
+    // Test that an active agent with Role A has it for its own organization, but not for Role B
+    // or for another organization
+    fn test_has_permission_for_org() {
+        let context = MockTransactionContext::default();
+        let pc = PermissionChecker::new(&context);
+        set_agent(&context, ORG_ID, true);
+
+        assert!(pc
+            .has_permission_for_org(PUBLIC_KEY, ROLE_A, ORG_ID)
+            .unwrap());
+        assert!(!pc
+            .has_permission_for_org(PUBLIC_KEY, ROLE_B, ORG_ID)
+            .unwrap());
+        assert!(!pc
+            .has_permission_for_org(PUBLIC_KEY, ROLE_A, "other_org")
+            .unwrap());
+    }
+
+    #[test]
+    // Test that an inactive agent has no permissions for its organization
+    fn test_has_permission_for_org_inactive() {
+        let context = MockTransactionContext::default();
+        let pc = PermissionChecker::new(&context);
+        set_agent(&context, ORG_ID, false);
+
+        assert!(pc.has_permission(PUBLIC_KEY, ROLE_A).unwrap());
+        assert!(!pc
+            .has_permission_for_org(PUBLIC_KEY, ROLE_A, ORG_ID)
+            .unwrap());
+    }
+
+    #[test]
+    // Test that checking the permissions of an unknown public key is an error
+    fn test_has_permission_for_org_no_agent() {
+        let context = MockTransactionContext::default();
+        let pc = PermissionChecker::new(&context);
+
+        assert!(pc
+            .has_permission_for_org(PUBLIC_KEY, ROLE_A, ORG_ID)
+            .is_err());
+    }
+
+    fn set_role(
+        context: &MockTransactionContext,
+        name: &str,
+        permissions: &[&str],
+        inherit: &[&str],
+    ) {
+        let role = RoleBuilder::new()
+            .with_org_id(ORG_ID.to_string())
+            .with_name(name.to_string())
+            .with_permissions(permissions.iter().map(|p| p.to_string()).collect())
+            .with_inherit_from(inherit.iter().map(|r| r.to_string()).collect())
+            .build()
+            .unwrap();
+        let role_list = RoleListBuilder::new()
+            .with_roles(vec![role])
+            .build()
+            .unwrap();
+        context
+            .set_state_entry(
+                compute_role_address(ORG_ID, name),
+                role_list.into_bytes().unwrap(),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    // Test that an agent holding a role defined by its organization has the role's permissions
+    // and those of the roles it inherits from, including through an inheritance cycle
+    fn test_has_permission_through_roles() {
+        let context = MockTransactionContext::default();
+        let pc = PermissionChecker::new(&context);
+        set_agent(&context, ORG_ID, true);
+
+        // the agent holds Role A, which inherits from Role B, which inherits back from Role A
+        set_role(&context, ROLE_A, &["can_update_product"], &[ROLE_B]);
+        set_role(&context, ROLE_B, &["can_create_product"], &[ROLE_A]);
+
+        assert!(pc.has_permission(PUBLIC_KEY, ROLE_A).unwrap());
+        assert!(pc.has_permission(PUBLIC_KEY, "can_update_product").unwrap());
+        assert!(pc
+            .has_permission_for_org(PUBLIC_KEY, "can_create_product", ORG_ID)
+            .unwrap());
+        assert!(!pc.has_permission(PUBLIC_KEY, "can_delete_product").unwrap());
+    }
+
+    #[test]
+    // Test that an agent of another organization holds a permission for ORG_ID only while
+    // ORG_ID delegates it to the agent's organization
+    fn test_has_delegated_permission() {
+        let context = MockTransactionContext::default();
+        let pc = PermissionChecker::new(&context);
+        set_agent(&context, "manufacturer", true);
+
+        assert!(!pc
+            .has_delegated_permission(PUBLIC_KEY, ROLE_A, ORG_ID)
+            .unwrap());
+
+        let delegation = DelegationBuilder::new()
+            .with_org_id(ORG_ID.to_string())
+            .with_delegate_org_id("manufacturer".to_string())
+            .with_permissions(vec![ROLE_A.to_string(), ROLE_B.to_string()])
+            .build()
+            .unwrap();
+        let delegation_list = DelegationListBuilder::new()
+            .with_delegations(vec![delegation])
+            .build()
+            .unwrap();
+        context
+            .set_state_entry(
+                compute_delegation_address(ORG_ID, "manufacturer"),
+                delegation_list.into_bytes().unwrap(),
+            )
+            .unwrap();
+
+        assert!(pc
+            .has_delegated_permission(PUBLIC_KEY, ROLE_A, ORG_ID)
+            .unwrap());
+        // the agent does not hold Role B itself
+        assert!(!pc
+            .has_delegated_permission(PUBLIC_KEY, ROLE_B, ORG_ID)
+            .unwrap());
+        // a delegation is not a permission for the agent's own organization
+        assert!(!pc
+            .has_delegated_permission(PUBLIC_KEY, ROLE_A, "manufacturer")
+            .unwrap());
+
+        set_agent(&context, "manufacturer", false);
+        assert!(!pc
+            .has_delegated_permission(PUBLIC_KEY, ROLE_A, ORG_ID)
+            .unwrap());
+    }
+
+    fn set_block_timestamp(context: &MockTransactionContext, block_num: u64, timestamp: u64) {
+        let mut config = BlockInfoConfig::new();
+        config.set_latest_block(block_num);
+        context
+            .set_state_entry(
+                compute_block_info_config_address(),
+                config.write_to_bytes().unwrap(),
+            )
+            .unwrap();
+
+        let mut block_info = BlockInfo::new();
+        block_info.set_block_num(block_num);
+        block_info.set_timestamp(timestamp);
+        context
+            .set_state_entry(
+                compute_block_info_address(block_num),
+                block_info.write_to_bytes().unwrap(),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    // Test that a delegation with an expiry is honoured only while the timestamp of the latest
+    // block is before the expiry
+    fn test_has_delegated_permission_expiry() {
+        let context = MockTransactionContext::default();
+        let pc = PermissionChecker::new(&context);
+        set_agent(&context, "manufacturer", true);
+
+        let delegation = DelegationBuilder::new()
+            .with_org_id(ORG_ID.to_string())
+            .with_delegate_org_id("manufacturer".to_string())
+            .with_permissions(vec![ROLE_A.to_string()])
+            .with_expiry(100)
+            .build()
+            .unwrap();
+        let delegation_list = DelegationListBuilder::new()
+            .with_delegations(vec![delegation])
+            .build()
+            .unwrap();
+        context
+            .set_state_entry(
+                compute_delegation_address(ORG_ID, "manufacturer"),
+                delegation_list.into_bytes().unwrap(),
+            )
+            .unwrap();
+
+        // no block timestamp to check the expiry against
+        assert!(!pc
+            .has_delegated_permission(PUBLIC_KEY, ROLE_A, ORG_ID)
+            .unwrap());
+
+        set_block_timestamp(&context, 1, 50);
+        assert!(pc
+            .has_delegated_permission(PUBLIC_KEY, ROLE_A, ORG_ID)
+            .unwrap());
+
+        set_block_timestamp(&context, 2, 100);
+        assert!(!pc
+            .has_delegated_permission(PUBLIC_KEY, ROLE_A, ORG_ID)
+            .unwrap());
+    }
 }
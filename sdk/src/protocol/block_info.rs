@@ -0,0 +1,51 @@
+// Copyright 2020 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Addresses of the state kept by the Sawtooth BlockInfo transaction family.
+//! Transactions that read the timestamp of the latest block must list
+//! `BLOCK_INFO_NAMESPACE` among their inputs.
+
+pub const BLOCK_INFO_NAMESPACE: &str = "00b10c";
+pub const BLOCK_INFO_RESOURCE: &str = "00";
+pub const BLOCK_INFO_CONFIG_RESOURCE: &str = "01";
+
+/// Computes the state address of the BlockInfoConfig, which records the
+/// number of the latest block
+pub fn compute_block_info_config_address() -> String {
+    String::from(BLOCK_INFO_NAMESPACE) + BLOCK_INFO_CONFIG_RESOURCE + &"0".repeat(62)
+}
+
+/// Computes the state address of the BlockInfo of the block with the given
+/// number
+pub fn compute_block_info_address(block_num: u64) -> String {
+    String::from(BLOCK_INFO_NAMESPACE) + BLOCK_INFO_RESOURCE + &format!("{:062x}", block_num)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    // check that the addresses match those written by the BlockInfo transaction family
+    fn check_block_info_addresses() {
+        assert_eq!(
+            compute_block_info_config_address(),
+            "00b10c0100000000000000000000000000000000000000000000000000000000000000"
+        );
+        assert_eq!(
+            compute_block_info_address(255),
+            "00b10c00000000000000000000000000000000000000000000000000000000000000ff"
+        );
+    }
+}
@@ -1,12 +1,14 @@
 #[derive(Debug)]
 pub enum BuilderError {
     MissingField(String),
+    InvalidField(String),
 }
 
 impl std::fmt::Display for BuilderError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match *self {
             BuilderError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+            BuilderError::InvalidField(ref s) => write!(f, "InvalidField: {}", s),
         }
     }
 }
@@ -0,0 +1,64 @@
+// Copyright 2020 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Checks shared by the GS1 identification keys (GTIN, GLN, SSCC) used
+//! across Grid.
+
+/// Returns true if `key` is all digits and its last digit is the GS1 check
+/// digit of the digits before it. The weights alternate 3 and 1 starting from
+/// the digit next to the check digit, so the same check applies to keys of any
+/// length, such as a GTIN-8, -12, -13 or -14, a GLN or an SSCC.
+pub fn has_valid_check_digit(key: &str) -> bool {
+    if key.len() < 2 || !key.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+
+    let mut digits = key.chars().rev().filter_map(|c| c.to_digit(10));
+    let check_digit = match digits.next() {
+        Some(digit) => digit,
+        None => return false,
+    };
+    let sum: u32 = digits
+        .enumerate()
+        .map(|(i, d)| if i % 2 == 0 { 3 * d } else { d })
+        .sum();
+
+    (10 - sum % 10) % 10 == check_digit
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    // check that keys of each length are accepted only with a correct check digit
+    fn check_has_valid_check_digit() {
+        // GTIN-8, -12, -13 and -14
+        assert!(has_valid_check_digit("96385074"));
+        assert!(!has_valid_check_digit("96385075"));
+        assert!(has_valid_check_digit("688955434684"));
+        assert!(has_valid_check_digit("9781981855728"));
+        assert!(has_valid_check_digit("00688955434684"));
+        // GLN
+        assert!(has_valid_check_digit("0614141000005"));
+        assert!(!has_valid_check_digit("0614141000006"));
+        // SSCC
+        assert!(has_valid_check_digit("106141411234567897"));
+        assert!(!has_valid_check_digit("106141411234567898"));
+
+        assert!(!has_valid_check_digit(""));
+        assert!(!has_valid_check_digit("0"));
+        assert!(!has_valid_check_digit("12345678901a8"));
+    }
+}
@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod block_info;
 pub mod errors;
+pub mod gs1;
 pub mod pike;
 pub mod product;
 pub mod schema;
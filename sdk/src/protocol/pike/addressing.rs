@@ -0,0 +1,110 @@
+// Copyright 2020 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crypto::digest::Digest;
+use crypto::sha2::Sha512;
+
+pub const PIKE_NAMESPACE: &str = "cad11d";
+pub const PIKE_AGENT_RESOURCE: &str = "00";
+pub const PIKE_ORGANIZATION_RESOURCE: &str = "01";
+pub const PIKE_ROLE_RESOURCE: &str = "02";
+pub const PIKE_DELEGATION_RESOURCE: &str = "03";
+pub const PIKE_ALTERNATE_ID_INDEX_RESOURCE: &str = "04";
+
+fn compute_address(name: &str, resource: &str) -> String {
+    let mut sha = Sha512::new();
+    sha.input(name.as_bytes());
+    String::from(PIKE_NAMESPACE) + resource + &sha.result_str()[..62]
+}
+
+/// Computes the state address of the agent with the given public key
+pub fn compute_agent_address(public_key: &str) -> String {
+    compute_address(public_key, PIKE_AGENT_RESOURCE)
+}
+
+/// Computes the state address of the organization with the given id
+pub fn compute_organization_address(org_id: &str) -> String {
+    compute_address(org_id, PIKE_ORGANIZATION_RESOURCE)
+}
+
+/// Computes the state address of an organization's role. The first part of the
+/// hash depends only on the organization, so an organization's roles share an
+/// address prefix.
+pub fn compute_role_address(org_id: &str, name: &str) -> String {
+    let mut org_sha = Sha512::new();
+    org_sha.input(org_id.as_bytes());
+    let mut name_sha = Sha512::new();
+    name_sha.input(name.as_bytes());
+    String::from(PIKE_NAMESPACE)
+        + PIKE_ROLE_RESOURCE
+        + &org_sha.result_str()[..48]
+        + &name_sha.result_str()[..14]
+}
+
+/// Computes the state address of the delegation from one organization to
+/// another. Delegations granted by an organization share an address prefix.
+pub fn compute_delegation_address(org_id: &str, delegate_org_id: &str) -> String {
+    let mut org_sha = Sha512::new();
+    org_sha.input(org_id.as_bytes());
+    let mut delegate_sha = Sha512::new();
+    delegate_sha.input(delegate_org_id.as_bytes());
+    String::from(PIKE_NAMESPACE)
+        + PIKE_DELEGATION_RESOURCE
+        + &org_sha.result_str()[..48]
+        + &delegate_sha.result_str()[..14]
+}
+
+/// Computes the state address of the index entry recording which organization
+/// holds the given alternate ID
+pub fn compute_alternate_id_index_address(id_type: &str, id: &str) -> String {
+    compute_address(
+        &format!("{}:{}", id_type, id),
+        PIKE_ALTERNATE_ID_INDEX_RESOURCE,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    // check that agent and organization addresses land in their own resource prefixes
+    fn check_pike_addresses() {
+        let agent_address = compute_agent_address("public_key");
+        assert_eq!(agent_address.len(), 70);
+        assert!(agent_address.starts_with("cad11d00"));
+
+        let org_address = compute_organization_address("public_key");
+        assert_eq!(org_address.len(), 70);
+        assert!(org_address.starts_with("cad11d01"));
+        assert_eq!(agent_address[8..], org_address[8..]);
+
+        let role_address = compute_role_address("org", "role");
+        assert_eq!(role_address.len(), 70);
+        assert!(role_address.starts_with("cad11d02"));
+        assert_eq!(
+            role_address[..56],
+            compute_role_address("org", "other_role")[..56]
+        );
+
+        let delegation_address = compute_delegation_address("org", "other_org");
+        assert_eq!(delegation_address.len(), 70);
+        assert!(delegation_address.starts_with("cad11d03"));
+        assert_eq!(delegation_address[8..56], role_address[8..56]);
+
+        let index_address = compute_alternate_id_index_address("gln", "1234567890128");
+        assert_eq!(index_address.len(), 70);
+        assert!(index_address.starts_with("cad11d04"));
+    }
+}
@@ -12,5 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod addressing;
 pub mod payload;
 pub mod state;
+pub mod validation;
@@ -17,7 +17,7 @@ use protobuf::RepeatedField;
 
 use std::error::Error as StdError;
 
-use crate::protocol::pike::state::KeyValueEntry;
+use crate::protocol::pike::state::{AlternateId, KeyValueEntry, Location};
 use crate::protos;
 use crate::protos::{
     FromBytes, FromNative, FromProto, IntoBytes, IntoNative, IntoProto, ProtoConversionError,
@@ -30,6 +30,14 @@ pub enum Action {
     UpdateAgent,
     CreateOrganization,
     UpdateOrganization,
+    DeleteAgent,
+    DeleteOrganization,
+    CreateRole,
+    UpdateRole,
+    DeleteRole,
+    CreateDelegation,
+    DeleteDelegation,
+    RotateAgentKey,
 }
 
 impl FromProto<protos::pike_payload::PikePayload_Action> for Action {
@@ -45,6 +53,22 @@ impl FromProto<protos::pike_payload::PikePayload_Action> for Action {
             protos::pike_payload::PikePayload_Action::UPDATE_ORGANIZATION => {
                 Ok(Action::UpdateOrganization)
             }
+            protos::pike_payload::PikePayload_Action::DELETE_AGENT => Ok(Action::DeleteAgent),
+            protos::pike_payload::PikePayload_Action::DELETE_ORGANIZATION => {
+                Ok(Action::DeleteOrganization)
+            }
+            protos::pike_payload::PikePayload_Action::CREATE_ROLE => Ok(Action::CreateRole),
+            protos::pike_payload::PikePayload_Action::UPDATE_ROLE => Ok(Action::UpdateRole),
+            protos::pike_payload::PikePayload_Action::DELETE_ROLE => Ok(Action::DeleteRole),
+            protos::pike_payload::PikePayload_Action::CREATE_DELEGATION => {
+                Ok(Action::CreateDelegation)
+            }
+            protos::pike_payload::PikePayload_Action::DELETE_DELEGATION => {
+                Ok(Action::DeleteDelegation)
+            }
+            protos::pike_payload::PikePayload_Action::ROTATE_AGENT_KEY => {
+                Ok(Action::RotateAgentKey)
+            }
             protos::pike_payload::PikePayload_Action::ACTION_UNSET => {
                 Err(ProtoConversionError::InvalidTypeError(
                     "Cannot convert PikePayload_Action with type unset.".to_string(),
@@ -65,6 +89,22 @@ impl FromNative<Action> for protos::pike_payload::PikePayload_Action {
             Action::UpdateOrganization => {
                 Ok(protos::pike_payload::PikePayload_Action::UPDATE_ORGANIZATION)
             }
+            Action::DeleteAgent => Ok(protos::pike_payload::PikePayload_Action::DELETE_AGENT),
+            Action::DeleteOrganization => {
+                Ok(protos::pike_payload::PikePayload_Action::DELETE_ORGANIZATION)
+            }
+            Action::CreateRole => Ok(protos::pike_payload::PikePayload_Action::CREATE_ROLE),
+            Action::UpdateRole => Ok(protos::pike_payload::PikePayload_Action::UPDATE_ROLE),
+            Action::DeleteRole => Ok(protos::pike_payload::PikePayload_Action::DELETE_ROLE),
+            Action::CreateDelegation => {
+                Ok(protos::pike_payload::PikePayload_Action::CREATE_DELEGATION)
+            }
+            Action::DeleteDelegation => {
+                Ok(protos::pike_payload::PikePayload_Action::DELETE_DELEGATION)
+            }
+            Action::RotateAgentKey => {
+                Ok(protos::pike_payload::PikePayload_Action::ROTATE_AGENT_KEY)
+            }
         }
     }
 }
@@ -461,6 +501,8 @@ pub struct CreateOrganizationAction {
     name: String,
     address: String,
     metadata: Vec<KeyValueEntry>,
+    alternate_ids: Vec<AlternateId>,
+    locations: Vec<Location>,
 }
 
 impl CreateOrganizationAction {
@@ -479,6 +521,14 @@ impl CreateOrganizationAction {
     pub fn metadata(&self) -> &[KeyValueEntry] {
         &self.metadata
     }
+
+    pub fn alternate_ids(&self) -> &[AlternateId] {
+        &self.alternate_ids
+    }
+
+    pub fn locations(&self) -> &[Location] {
+        &self.locations
+    }
 }
 
 impl FromProto<protos::pike_payload::CreateOrganizationAction> for CreateOrganizationAction {
@@ -495,6 +545,18 @@ impl FromProto<protos::pike_payload::CreateOrganizationAction> for CreateOrganiz
                 .into_iter()
                 .map(KeyValueEntry::from_proto)
                 .collect::<Result<Vec<KeyValueEntry>, ProtoConversionError>>()?,
+            alternate_ids: create_org
+                .get_alternate_ids()
+                .to_vec()
+                .into_iter()
+                .map(AlternateId::from_proto)
+                .collect::<Result<Vec<AlternateId>, ProtoConversionError>>()?,
+            locations: create_org
+                .get_locations()
+                .to_vec()
+                .into_iter()
+                .map(Location::from_proto)
+                .collect::<Result<Vec<Location>, ProtoConversionError>>()?,
         })
     }
 }
@@ -515,6 +577,22 @@ impl FromNative<CreateOrganizationAction> for protos::pike_payload::CreateOrgani
                 .collect::<Result<Vec<protos::pike_state::KeyValueEntry>, ProtoConversionError>>(
                 )?,
         ));
+        proto_create_org.set_alternate_ids(RepeatedField::from_vec(
+            create_org
+                .alternate_ids()
+                .to_vec()
+                .into_iter()
+                .map(AlternateId::into_proto)
+                .collect::<Result<Vec<protos::pike_state::AlternateId>, ProtoConversionError>>()?,
+        ));
+        proto_create_org.set_locations(RepeatedField::from_vec(
+            create_org
+                .locations()
+                .to_vec()
+                .into_iter()
+                .map(Location::into_proto)
+                .collect::<Result<Vec<protos::pike_state::Location>, ProtoConversionError>>()?,
+        ));
 
         Ok(proto_create_org)
     }
@@ -583,6 +661,8 @@ pub struct CreateOrganizationActionBuilder {
     pub name: Option<String>,
     pub address: Option<String>,
     pub metadata: Vec<KeyValueEntry>,
+    pub alternate_ids: Vec<AlternateId>,
+    pub locations: Vec<Location>,
 }
 
 impl CreateOrganizationActionBuilder {
@@ -613,6 +693,19 @@ impl CreateOrganizationActionBuilder {
         self
     }
 
+    pub fn with_alternate_ids(
+        mut self,
+        alternate_ids: Vec<AlternateId>,
+    ) -> CreateOrganizationActionBuilder {
+        self.alternate_ids = alternate_ids;
+        self
+    }
+
+    pub fn with_locations(mut self, locations: Vec<Location>) -> CreateOrganizationActionBuilder {
+        self.locations = locations;
+        self
+    }
+
     pub fn build(self) -> Result<CreateOrganizationAction, CreateOrganizationActionBuildError> {
         let org_id = self.org_id.ok_or_else(|| {
             CreateOrganizationActionBuildError::MissingField(
@@ -631,12 +724,16 @@ impl CreateOrganizationActionBuilder {
         })?;
 
         let metadata = self.metadata;
+        let alternate_ids = self.alternate_ids;
+        let locations = self.locations;
 
         Ok(CreateOrganizationAction {
             org_id,
             name,
             address,
             metadata,
+            alternate_ids,
+            locations,
         })
     }
 }
@@ -648,6 +745,10 @@ pub struct UpdateOrganizationAction {
     name: String,
     address: String,
     metadata: Vec<KeyValueEntry>,
+    alternate_ids: Vec<AlternateId>,
+    locations: Vec<Location>,
+    clear_alternate_ids: bool,
+    agents: Vec<String>,
 }
 
 impl UpdateOrganizationAction {
@@ -666,6 +767,22 @@ impl UpdateOrganizationAction {
     pub fn metadata(&self) -> &[KeyValueEntry] {
         &self.metadata
     }
+
+    pub fn alternate_ids(&self) -> &[AlternateId] {
+        &self.alternate_ids
+    }
+
+    pub fn locations(&self) -> &[Location] {
+        &self.locations
+    }
+
+    pub fn clear_alternate_ids(&self) -> bool {
+        self.clear_alternate_ids
+    }
+
+    pub fn agents(&self) -> &[String] {
+        &self.agents
+    }
 }
 
 impl FromProto<protos::pike_payload::UpdateOrganizationAction> for UpdateOrganizationAction {
@@ -682,6 +799,20 @@ impl FromProto<protos::pike_payload::UpdateOrganizationAction> for UpdateOrganiz
                 .into_iter()
                 .map(KeyValueEntry::from_proto)
                 .collect::<Result<Vec<KeyValueEntry>, ProtoConversionError>>()?,
+            alternate_ids: create_org
+                .get_alternate_ids()
+                .to_vec()
+                .into_iter()
+                .map(AlternateId::from_proto)
+                .collect::<Result<Vec<AlternateId>, ProtoConversionError>>()?,
+            locations: create_org
+                .get_locations()
+                .to_vec()
+                .into_iter()
+                .map(Location::from_proto)
+                .collect::<Result<Vec<Location>, ProtoConversionError>>()?,
+            clear_alternate_ids: create_org.get_clear_alternate_ids(),
+            agents: create_org.get_agents().to_vec(),
         })
     }
 }
@@ -702,6 +833,24 @@ impl FromNative<UpdateOrganizationAction> for protos::pike_payload::UpdateOrgani
                 .collect::<Result<Vec<protos::pike_state::KeyValueEntry>, ProtoConversionError>>(
                 )?,
         ));
+        proto_update_org.set_alternate_ids(RepeatedField::from_vec(
+            update_org
+                .alternate_ids()
+                .to_vec()
+                .into_iter()
+                .map(AlternateId::into_proto)
+                .collect::<Result<Vec<protos::pike_state::AlternateId>, ProtoConversionError>>()?,
+        ));
+        proto_update_org.set_locations(RepeatedField::from_vec(
+            update_org
+                .locations()
+                .to_vec()
+                .into_iter()
+                .map(Location::into_proto)
+                .collect::<Result<Vec<protos::pike_state::Location>, ProtoConversionError>>()?,
+        ));
+        proto_update_org.set_clear_alternate_ids(update_org.clear_alternate_ids());
+        proto_update_org.set_agents(RepeatedField::from_vec(update_org.agents().to_vec()));
 
         Ok(proto_update_org)
     }
@@ -770,6 +919,10 @@ pub struct UpdateOrganizationActionBuilder {
     pub name: Option<String>,
     pub address: Option<String>,
     pub metadata: Vec<KeyValueEntry>,
+    pub alternate_ids: Vec<AlternateId>,
+    pub locations: Vec<Location>,
+    pub clear_alternate_ids: bool,
+    pub agents: Vec<String>,
 }
 
 impl UpdateOrganizationActionBuilder {
@@ -800,6 +953,32 @@ impl UpdateOrganizationActionBuilder {
         self
     }
 
+    pub fn with_alternate_ids(
+        mut self,
+        alternate_ids: Vec<AlternateId>,
+    ) -> UpdateOrganizationActionBuilder {
+        self.alternate_ids = alternate_ids;
+        self
+    }
+
+    pub fn with_locations(mut self, locations: Vec<Location>) -> UpdateOrganizationActionBuilder {
+        self.locations = locations;
+        self
+    }
+
+    pub fn with_clear_alternate_ids(
+        mut self,
+        clear_alternate_ids: bool,
+    ) -> UpdateOrganizationActionBuilder {
+        self.clear_alternate_ids = clear_alternate_ids;
+        self
+    }
+
+    pub fn with_agents(mut self, agents: Vec<String>) -> UpdateOrganizationActionBuilder {
+        self.agents = agents;
+        self
+    }
+
     pub fn build(self) -> Result<UpdateOrganizationAction, UpdateOrganizationActionBuildError> {
         let org_id = self.org_id.ok_or_else(|| {
             UpdateOrganizationActionBuildError::MissingField(
@@ -812,250 +991,1692 @@ impl UpdateOrganizationActionBuilder {
         let address = self.address.unwrap_or_default();
 
         let metadata = self.metadata;
+        let alternate_ids = self.alternate_ids;
+        let locations = self.locations;
+        let clear_alternate_ids = self.clear_alternate_ids;
+        let agents = self.agents;
 
         Ok(UpdateOrganizationAction {
             org_id,
             name,
             address,
             metadata,
+            alternate_ids,
+            locations,
+            clear_alternate_ids,
+            agents,
         })
     }
 }
 
-/// Native implementation for PikePayload
-#[derive(Debug, Clone, PartialEq)]
-pub struct PikePayload {
-    action: Action,
-    create_agent: CreateAgentAction,
-    update_agent: UpdateAgentAction,
-    create_organization: CreateOrganizationAction,
-    update_organization: UpdateOrganizationAction,
+/// Native implementation for DeleteAgentAction
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DeleteAgentAction {
+    org_id: String,
+    public_key: String,
 }
 
-impl PikePayload {
-    pub fn action(&self) -> &Action {
-        &self.action
-    }
-
-    pub fn create_agent(&self) -> &CreateAgentAction {
-        &self.create_agent
-    }
-
-    pub fn update_agent(&self) -> &UpdateAgentAction {
-        &self.update_agent
-    }
-
-    pub fn create_organization(&self) -> &CreateOrganizationAction {
-        &self.create_organization
+impl DeleteAgentAction {
+    pub fn org_id(&self) -> &str {
+        &self.org_id
     }
 
-    pub fn update_organization(&self) -> &UpdateOrganizationAction {
-        &self.update_organization
+    pub fn public_key(&self) -> &str {
+        &self.public_key
     }
 }
 
-impl FromProto<protos::pike_payload::PikePayload> for PikePayload {
+impl FromProto<protos::pike_payload::DeleteAgentAction> for DeleteAgentAction {
     fn from_proto(
-        payload: protos::pike_payload::PikePayload,
+        delete_agent: protos::pike_payload::DeleteAgentAction,
     ) -> Result<Self, ProtoConversionError> {
-        Ok(PikePayload {
-            action: Action::from_proto(payload.get_action())?,
-            create_agent: CreateAgentAction::from_proto(payload.get_create_agent().clone())?,
-            update_agent: UpdateAgentAction::from_proto(payload.get_update_agent().clone())?,
-            create_organization: CreateOrganizationAction::from_proto(
-                payload.get_create_organization().clone(),
-            )?,
-            update_organization: UpdateOrganizationAction::from_proto(
-                payload.get_update_organization().clone(),
-            )?,
+        Ok(DeleteAgentAction {
+            org_id: delete_agent.get_org_id().to_string(),
+            public_key: delete_agent.get_public_key().to_string(),
         })
     }
 }
 
-impl FromNative<PikePayload> for protos::pike_payload::PikePayload {
-    fn from_native(payload: PikePayload) -> Result<Self, ProtoConversionError> {
-        let mut proto_payload = protos::pike_payload::PikePayload::new();
+impl FromNative<DeleteAgentAction> for protos::pike_payload::DeleteAgentAction {
+    fn from_native(delete_agent: DeleteAgentAction) -> Result<Self, ProtoConversionError> {
+        let mut proto_delete_agent = protos::pike_payload::DeleteAgentAction::new();
 
-        proto_payload.set_action(payload.action().clone().into_proto()?);
-        proto_payload.set_create_agent(payload.create_agent().clone().into_proto()?);
-        proto_payload.set_update_agent(payload.update_agent().clone().into_proto()?);
-        proto_payload.set_create_organization(payload.create_organization().clone().into_proto()?);
-        proto_payload.set_update_organization(payload.update_organization().clone().into_proto()?);
+        proto_delete_agent.set_org_id(delete_agent.org_id().to_string());
+        proto_delete_agent.set_public_key(delete_agent.public_key().to_string());
 
-        Ok(proto_payload)
+        Ok(proto_delete_agent)
     }
 }
 
-impl FromBytes<PikePayload> for PikePayload {
-    fn from_bytes(bytes: &[u8]) -> Result<PikePayload, ProtoConversionError> {
-        let proto: protos::pike_payload::PikePayload =
-            protobuf::parse_from_bytes(bytes).map_err(|_| {
+impl FromBytes<DeleteAgentAction> for DeleteAgentAction {
+    fn from_bytes(bytes: &[u8]) -> Result<DeleteAgentAction, ProtoConversionError> {
+        let proto: protos::pike_payload::DeleteAgentAction = protobuf::parse_from_bytes(bytes)
+            .map_err(|_| {
                 ProtoConversionError::SerializationError(
-                    "Unable to get PikePayload from bytes".to_string(),
+                    "Unable to get DeleteAgentAction from bytes".to_string(),
                 )
             })?;
         proto.into_native()
     }
 }
 
-impl IntoBytes for PikePayload {
+impl IntoBytes for DeleteAgentAction {
     fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
         let proto = self.into_proto()?;
         let bytes = proto.write_to_bytes().map_err(|_| {
             ProtoConversionError::SerializationError(
-                "Unable to get bytes from PikePayload".to_string(),
+                "Unable to get bytes from DeleteAgentAction".to_string(),
             )
         })?;
         Ok(bytes)
     }
 }
 
-impl IntoProto<protos::pike_payload::PikePayload> for PikePayload {}
-impl IntoNative<PikePayload> for protos::pike_payload::PikePayload {}
+impl IntoProto<protos::pike_payload::DeleteAgentAction> for DeleteAgentAction {}
+impl IntoNative<DeleteAgentAction> for protos::pike_payload::DeleteAgentAction {}
 
 #[derive(Debug)]
-pub enum PikePayloadBuildError {
+pub enum DeleteAgentActionBuildError {
     MissingField(String),
 }
 
-impl StdError for PikePayloadBuildError {
+impl StdError for DeleteAgentActionBuildError {
     fn description(&self) -> &str {
         match *self {
-            PikePayloadBuildError::MissingField(ref msg) => msg,
+            DeleteAgentActionBuildError::MissingField(ref msg) => msg,
         }
     }
 
     fn cause(&self) -> Option<&dyn StdError> {
         match *self {
-            PikePayloadBuildError::MissingField(_) => None,
+            DeleteAgentActionBuildError::MissingField(_) => None,
         }
     }
 }
 
-impl std::fmt::Display for PikePayloadBuildError {
+impl std::fmt::Display for DeleteAgentActionBuildError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match *self {
-            PikePayloadBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+            DeleteAgentActionBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
         }
     }
 }
 
-/// Builder used to create a PikePayload
+/// Builder used to create a DeleteAgentAction
 #[derive(Default, Clone)]
-pub struct PikePayloadBuilder {
-    pub action: Option<Action>,
-    pub create_agent: Option<CreateAgentAction>,
-    pub update_agent: Option<UpdateAgentAction>,
-    pub create_organization: Option<CreateOrganizationAction>,
-    pub update_organization: Option<UpdateOrganizationAction>,
+pub struct DeleteAgentActionBuilder {
+    pub org_id: Option<String>,
+    pub public_key: Option<String>,
 }
 
-impl PikePayloadBuilder {
+impl DeleteAgentActionBuilder {
     pub fn new() -> Self {
-        PikePayloadBuilder::default()
+        DeleteAgentActionBuilder::default()
     }
 
-    pub fn with_action(mut self, action: Action) -> PikePayloadBuilder {
-        self.action = Some(action);
+    pub fn with_org_id(mut self, org_id: String) -> DeleteAgentActionBuilder {
+        self.org_id = Some(org_id);
         self
     }
 
-    pub fn with_create_agent(mut self, create_agent: CreateAgentAction) -> PikePayloadBuilder {
-        self.create_agent = Some(create_agent);
+    pub fn with_public_key(mut self, public_key: String) -> DeleteAgentActionBuilder {
+        self.public_key = Some(public_key);
         self
     }
 
-    pub fn with_update_agent(mut self, update_agent: UpdateAgentAction) -> PikePayloadBuilder {
-        self.update_agent = Some(update_agent);
-        self
-    }
+    pub fn build(self) -> Result<DeleteAgentAction, DeleteAgentActionBuildError> {
+        let org_id = self.org_id.ok_or_else(|| {
+            DeleteAgentActionBuildError::MissingField("'org_id' field is required".to_string())
+        })?;
 
-    pub fn with_create_organization(
-        mut self,
-        create_organization: CreateOrganizationAction,
-    ) -> PikePayloadBuilder {
-        self.create_organization = Some(create_organization);
-        self
-    }
+        let public_key = self.public_key.ok_or_else(|| {
+            DeleteAgentActionBuildError::MissingField("'public_key' field is required".to_string())
+        })?;
 
-    pub fn with_update_organization(
-        mut self,
-        update_organization: UpdateOrganizationAction,
-    ) -> PikePayloadBuilder {
-        self.update_organization = Some(update_organization);
-        self
+        Ok(DeleteAgentAction { org_id, public_key })
     }
+}
 
-    pub fn build(self) -> Result<PikePayload, PikePayloadBuildError> {
-        let action = self.action.ok_or_else(|| {
-            PikePayloadBuildError::MissingField("'action' field is required".to_string())
-        })?;
+/// Native implementation for DeleteOrganizationAction
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DeleteOrganizationAction {
+    org_id: String,
+}
 
-        let create_agent = {
-            if action == Action::CreateAgent {
-                self.create_agent.ok_or_else(|| {
-                    PikePayloadBuildError::MissingField(
-                        "'create_agent' field is required".to_string(),
-                    )
-                })?
-            } else {
-                CreateAgentAction::default()
-            }
-        };
+impl DeleteOrganizationAction {
+    pub fn org_id(&self) -> &str {
+        &self.org_id
+    }
+}
 
-        let update_agent = {
-            if action == Action::UpdateAgent {
-                self.update_agent.ok_or_else(|| {
-                    PikePayloadBuildError::MissingField(
-                        "'update_agent' field is required".to_string(),
-                    )
-                })?
-            } else {
-                UpdateAgentAction::default()
-            }
-        };
+impl FromProto<protos::pike_payload::DeleteOrganizationAction> for DeleteOrganizationAction {
+    fn from_proto(
+        delete_org: protos::pike_payload::DeleteOrganizationAction,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(DeleteOrganizationAction {
+            org_id: delete_org.get_id().to_string(),
+        })
+    }
+}
 
-        let create_organization = {
-            if action == Action::CreateOrganization {
-                self.create_organization.ok_or_else(|| {
-                    PikePayloadBuildError::MissingField(
-                        "'create_organization' field is required".to_string(),
-                    )
-                })?
-            } else {
-                CreateOrganizationAction::default()
-            }
-        };
+impl FromNative<DeleteOrganizationAction> for protos::pike_payload::DeleteOrganizationAction {
+    fn from_native(delete_org: DeleteOrganizationAction) -> Result<Self, ProtoConversionError> {
+        let mut proto_delete_org = protos::pike_payload::DeleteOrganizationAction::new();
 
-        let update_organization = {
-            if action == Action::UpdateOrganization {
-                self.update_organization.ok_or_else(|| {
-                    PikePayloadBuildError::MissingField(
-                        "'update_organization' field is required".to_string(),
-                    )
-                })?
-            } else {
-                UpdateOrganizationAction::default()
-            }
-        };
+        proto_delete_org.set_id(delete_org.org_id().to_string());
 
-        Ok(PikePayload {
-            action,
-            create_agent,
-            update_agent,
-            create_organization,
-            update_organization,
-        })
+        Ok(proto_delete_org)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    use crate::protocol::pike::state::KeyValueEntryBuilder;
+impl FromBytes<DeleteOrganizationAction> for DeleteOrganizationAction {
+    fn from_bytes(bytes: &[u8]) -> Result<DeleteOrganizationAction, ProtoConversionError> {
+        let proto: protos::pike_payload::DeleteOrganizationAction =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get DeleteOrganizationAction from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
 
-    #[test]
+impl IntoBytes for DeleteOrganizationAction {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from DeleteOrganizationAction".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::pike_payload::DeleteOrganizationAction> for DeleteOrganizationAction {}
+impl IntoNative<DeleteOrganizationAction> for protos::pike_payload::DeleteOrganizationAction {}
+
+#[derive(Debug)]
+pub enum DeleteOrganizationActionBuildError {
+    MissingField(String),
+}
+
+impl StdError for DeleteOrganizationActionBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            DeleteOrganizationActionBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn StdError> {
+        match *self {
+            DeleteOrganizationActionBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for DeleteOrganizationActionBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            DeleteOrganizationActionBuildError::MissingField(ref s) => {
+                write!(f, "MissingField: {}", s)
+            }
+        }
+    }
+}
+
+/// Builder used to create a DeleteOrganizationAction
+#[derive(Default, Clone)]
+pub struct DeleteOrganizationActionBuilder {
+    pub org_id: Option<String>,
+}
+
+impl DeleteOrganizationActionBuilder {
+    pub fn new() -> Self {
+        DeleteOrganizationActionBuilder::default()
+    }
+
+    pub fn with_org_id(mut self, org_id: String) -> DeleteOrganizationActionBuilder {
+        self.org_id = Some(org_id);
+        self
+    }
+
+    pub fn build(self) -> Result<DeleteOrganizationAction, DeleteOrganizationActionBuildError> {
+        let org_id = self.org_id.ok_or_else(|| {
+            DeleteOrganizationActionBuildError::MissingField(
+                "'org_id' field is required".to_string(),
+            )
+        })?;
+
+        Ok(DeleteOrganizationAction { org_id })
+    }
+}
+
+/// Native implementation for CreateRoleAction
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CreateRoleAction {
+    org_id: String,
+    name: String,
+    description: String,
+    permissions: Vec<String>,
+    inherit_from: Vec<String>,
+}
+
+impl CreateRoleAction {
+    pub fn org_id(&self) -> &str {
+        &self.org_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn permissions(&self) -> &[String] {
+        &self.permissions
+    }
+
+    pub fn inherit_from(&self) -> &[String] {
+        &self.inherit_from
+    }
+}
+
+impl FromProto<protos::pike_payload::CreateRoleAction> for CreateRoleAction {
+    fn from_proto(
+        create_role: protos::pike_payload::CreateRoleAction,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(CreateRoleAction {
+            org_id: create_role.get_org_id().to_string(),
+            name: create_role.get_name().to_string(),
+            description: create_role.get_description().to_string(),
+            permissions: create_role.get_permissions().to_vec(),
+            inherit_from: create_role.get_inherit_from().to_vec(),
+        })
+    }
+}
+
+impl FromNative<CreateRoleAction> for protos::pike_payload::CreateRoleAction {
+    fn from_native(create_role: CreateRoleAction) -> Result<Self, ProtoConversionError> {
+        let mut create_role_proto = protos::pike_payload::CreateRoleAction::new();
+
+        create_role_proto.set_org_id(create_role.org_id().to_string());
+        create_role_proto.set_name(create_role.name().to_string());
+        create_role_proto.set_description(create_role.description().to_string());
+        create_role_proto
+            .set_permissions(RepeatedField::from_vec(create_role.permissions().to_vec()));
+        create_role_proto
+            .set_inherit_from(RepeatedField::from_vec(create_role.inherit_from().to_vec()));
+
+        Ok(create_role_proto)
+    }
+}
+
+impl FromBytes<CreateRoleAction> for CreateRoleAction {
+    fn from_bytes(bytes: &[u8]) -> Result<CreateRoleAction, ProtoConversionError> {
+        let proto: protos::pike_payload::CreateRoleAction = protobuf::parse_from_bytes(bytes)
+            .map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get CreateRoleAction from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for CreateRoleAction {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from CreateRoleAction".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::pike_payload::CreateRoleAction> for CreateRoleAction {}
+impl IntoNative<CreateRoleAction> for protos::pike_payload::CreateRoleAction {}
+
+#[derive(Debug)]
+pub enum CreateRoleActionBuildError {
+    MissingField(String),
+}
+
+impl StdError for CreateRoleActionBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            CreateRoleActionBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn StdError> {
+        match *self {
+            CreateRoleActionBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for CreateRoleActionBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            CreateRoleActionBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a CreateRoleAction
+#[derive(Default, Clone)]
+pub struct CreateRoleActionBuilder {
+    pub org_id: Option<String>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub permissions: Vec<String>,
+    pub inherit_from: Vec<String>,
+}
+
+impl CreateRoleActionBuilder {
+    pub fn new() -> Self {
+        CreateRoleActionBuilder::default()
+    }
+
+    pub fn with_org_id(mut self, org_id: String) -> CreateRoleActionBuilder {
+        self.org_id = Some(org_id);
+        self
+    }
+
+    pub fn with_name(mut self, name: String) -> CreateRoleActionBuilder {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn with_description(mut self, description: String) -> CreateRoleActionBuilder {
+        self.description = Some(description);
+        self
+    }
+
+    pub fn with_permissions(mut self, permissions: Vec<String>) -> CreateRoleActionBuilder {
+        self.permissions = permissions;
+        self
+    }
+
+    pub fn with_inherit_from(mut self, inherit_from: Vec<String>) -> CreateRoleActionBuilder {
+        self.inherit_from = inherit_from;
+        self
+    }
+
+    pub fn build(self) -> Result<CreateRoleAction, CreateRoleActionBuildError> {
+        let org_id = self.org_id.ok_or_else(|| {
+            CreateRoleActionBuildError::MissingField("'org_id' field is required".to_string())
+        })?;
+
+        let name = self.name.ok_or_else(|| {
+            CreateRoleActionBuildError::MissingField("'name' field is required".to_string())
+        })?;
+
+        let description = self.description.unwrap_or_default();
+        let permissions = self.permissions;
+        let inherit_from = self.inherit_from;
+
+        Ok(CreateRoleAction {
+            org_id,
+            name,
+            description,
+            permissions,
+            inherit_from,
+        })
+    }
+}
+
+/// Native implementation for UpdateRoleAction
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct UpdateRoleAction {
+    org_id: String,
+    name: String,
+    description: String,
+    permissions: Vec<String>,
+    inherit_from: Vec<String>,
+}
+
+impl UpdateRoleAction {
+    pub fn org_id(&self) -> &str {
+        &self.org_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn permissions(&self) -> &[String] {
+        &self.permissions
+    }
+
+    pub fn inherit_from(&self) -> &[String] {
+        &self.inherit_from
+    }
+}
+
+impl FromProto<protos::pike_payload::UpdateRoleAction> for UpdateRoleAction {
+    fn from_proto(
+        update_role: protos::pike_payload::UpdateRoleAction,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(UpdateRoleAction {
+            org_id: update_role.get_org_id().to_string(),
+            name: update_role.get_name().to_string(),
+            description: update_role.get_description().to_string(),
+            permissions: update_role.get_permissions().to_vec(),
+            inherit_from: update_role.get_inherit_from().to_vec(),
+        })
+    }
+}
+
+impl FromNative<UpdateRoleAction> for protos::pike_payload::UpdateRoleAction {
+    fn from_native(update_role: UpdateRoleAction) -> Result<Self, ProtoConversionError> {
+        let mut update_role_proto = protos::pike_payload::UpdateRoleAction::new();
+
+        update_role_proto.set_org_id(update_role.org_id().to_string());
+        update_role_proto.set_name(update_role.name().to_string());
+        update_role_proto.set_description(update_role.description().to_string());
+        update_role_proto
+            .set_permissions(RepeatedField::from_vec(update_role.permissions().to_vec()));
+        update_role_proto
+            .set_inherit_from(RepeatedField::from_vec(update_role.inherit_from().to_vec()));
+
+        Ok(update_role_proto)
+    }
+}
+
+impl FromBytes<UpdateRoleAction> for UpdateRoleAction {
+    fn from_bytes(bytes: &[u8]) -> Result<UpdateRoleAction, ProtoConversionError> {
+        let proto: protos::pike_payload::UpdateRoleAction = protobuf::parse_from_bytes(bytes)
+            .map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get UpdateRoleAction from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for UpdateRoleAction {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from UpdateRoleAction".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::pike_payload::UpdateRoleAction> for UpdateRoleAction {}
+impl IntoNative<UpdateRoleAction> for protos::pike_payload::UpdateRoleAction {}
+
+#[derive(Debug)]
+pub enum UpdateRoleActionBuildError {
+    MissingField(String),
+}
+
+impl StdError for UpdateRoleActionBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            UpdateRoleActionBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn StdError> {
+        match *self {
+            UpdateRoleActionBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for UpdateRoleActionBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            UpdateRoleActionBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a UpdateRoleAction
+#[derive(Default, Clone)]
+pub struct UpdateRoleActionBuilder {
+    pub org_id: Option<String>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub permissions: Vec<String>,
+    pub inherit_from: Vec<String>,
+}
+
+impl UpdateRoleActionBuilder {
+    pub fn new() -> Self {
+        UpdateRoleActionBuilder::default()
+    }
+
+    pub fn with_org_id(mut self, org_id: String) -> UpdateRoleActionBuilder {
+        self.org_id = Some(org_id);
+        self
+    }
+
+    pub fn with_name(mut self, name: String) -> UpdateRoleActionBuilder {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn with_description(mut self, description: String) -> UpdateRoleActionBuilder {
+        self.description = Some(description);
+        self
+    }
+
+    pub fn with_permissions(mut self, permissions: Vec<String>) -> UpdateRoleActionBuilder {
+        self.permissions = permissions;
+        self
+    }
+
+    pub fn with_inherit_from(mut self, inherit_from: Vec<String>) -> UpdateRoleActionBuilder {
+        self.inherit_from = inherit_from;
+        self
+    }
+
+    pub fn build(self) -> Result<UpdateRoleAction, UpdateRoleActionBuildError> {
+        let org_id = self.org_id.ok_or_else(|| {
+            UpdateRoleActionBuildError::MissingField("'org_id' field is required".to_string())
+        })?;
+
+        let name = self.name.ok_or_else(|| {
+            UpdateRoleActionBuildError::MissingField("'name' field is required".to_string())
+        })?;
+
+        let description = self.description.unwrap_or_default();
+        let permissions = self.permissions;
+        let inherit_from = self.inherit_from;
+
+        Ok(UpdateRoleAction {
+            org_id,
+            name,
+            description,
+            permissions,
+            inherit_from,
+        })
+    }
+}
+
+/// Native implementation for DeleteRoleAction
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DeleteRoleAction {
+    org_id: String,
+    name: String,
+}
+
+impl DeleteRoleAction {
+    pub fn org_id(&self) -> &str {
+        &self.org_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl FromProto<protos::pike_payload::DeleteRoleAction> for DeleteRoleAction {
+    fn from_proto(
+        delete_role: protos::pike_payload::DeleteRoleAction,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(DeleteRoleAction {
+            org_id: delete_role.get_org_id().to_string(),
+            name: delete_role.get_name().to_string(),
+        })
+    }
+}
+
+impl FromNative<DeleteRoleAction> for protos::pike_payload::DeleteRoleAction {
+    fn from_native(delete_role: DeleteRoleAction) -> Result<Self, ProtoConversionError> {
+        let mut proto_delete_role = protos::pike_payload::DeleteRoleAction::new();
+
+        proto_delete_role.set_org_id(delete_role.org_id().to_string());
+        proto_delete_role.set_name(delete_role.name().to_string());
+
+        Ok(proto_delete_role)
+    }
+}
+
+impl FromBytes<DeleteRoleAction> for DeleteRoleAction {
+    fn from_bytes(bytes: &[u8]) -> Result<DeleteRoleAction, ProtoConversionError> {
+        let proto: protos::pike_payload::DeleteRoleAction = protobuf::parse_from_bytes(bytes)
+            .map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get DeleteRoleAction from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for DeleteRoleAction {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from DeleteRoleAction".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::pike_payload::DeleteRoleAction> for DeleteRoleAction {}
+impl IntoNative<DeleteRoleAction> for protos::pike_payload::DeleteRoleAction {}
+
+#[derive(Debug)]
+pub enum DeleteRoleActionBuildError {
+    MissingField(String),
+}
+
+impl StdError for DeleteRoleActionBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            DeleteRoleActionBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn StdError> {
+        match *self {
+            DeleteRoleActionBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for DeleteRoleActionBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            DeleteRoleActionBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a DeleteRoleAction
+#[derive(Default, Clone)]
+pub struct DeleteRoleActionBuilder {
+    pub org_id: Option<String>,
+    pub name: Option<String>,
+}
+
+impl DeleteRoleActionBuilder {
+    pub fn new() -> Self {
+        DeleteRoleActionBuilder::default()
+    }
+
+    pub fn with_org_id(mut self, org_id: String) -> DeleteRoleActionBuilder {
+        self.org_id = Some(org_id);
+        self
+    }
+
+    pub fn with_name(mut self, name: String) -> DeleteRoleActionBuilder {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn build(self) -> Result<DeleteRoleAction, DeleteRoleActionBuildError> {
+        let org_id = self.org_id.ok_or_else(|| {
+            DeleteRoleActionBuildError::MissingField("'org_id' field is required".to_string())
+        })?;
+
+        let name = self.name.ok_or_else(|| {
+            DeleteRoleActionBuildError::MissingField("'name' field is required".to_string())
+        })?;
+
+        Ok(DeleteRoleAction { org_id, name })
+    }
+}
+
+/// Native implementation for CreateDelegationAction
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CreateDelegationAction {
+    org_id: String,
+    delegate_org_id: String,
+    permissions: Vec<String>,
+    expiry: u64,
+}
+
+impl CreateDelegationAction {
+    pub fn org_id(&self) -> &str {
+        &self.org_id
+    }
+
+    pub fn delegate_org_id(&self) -> &str {
+        &self.delegate_org_id
+    }
+
+    pub fn permissions(&self) -> &[String] {
+        &self.permissions
+    }
+
+    pub fn expiry(&self) -> u64 {
+        self.expiry
+    }
+}
+
+impl FromProto<protos::pike_payload::CreateDelegationAction> for CreateDelegationAction {
+    fn from_proto(
+        create_delegation: protos::pike_payload::CreateDelegationAction,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(CreateDelegationAction {
+            org_id: create_delegation.get_org_id().to_string(),
+            delegate_org_id: create_delegation.get_delegate_org_id().to_string(),
+            permissions: create_delegation.get_permissions().to_vec(),
+            expiry: create_delegation.get_expiry(),
+        })
+    }
+}
+
+impl FromNative<CreateDelegationAction> for protos::pike_payload::CreateDelegationAction {
+    fn from_native(
+        create_delegation: CreateDelegationAction,
+    ) -> Result<Self, ProtoConversionError> {
+        let mut create_delegation_proto = protos::pike_payload::CreateDelegationAction::new();
+
+        create_delegation_proto.set_org_id(create_delegation.org_id().to_string());
+        create_delegation_proto
+            .set_delegate_org_id(create_delegation.delegate_org_id().to_string());
+        create_delegation_proto.set_permissions(RepeatedField::from_vec(
+            create_delegation.permissions().to_vec(),
+        ));
+        create_delegation_proto.set_expiry(create_delegation.expiry());
+
+        Ok(create_delegation_proto)
+    }
+}
+
+impl FromBytes<CreateDelegationAction> for CreateDelegationAction {
+    fn from_bytes(bytes: &[u8]) -> Result<CreateDelegationAction, ProtoConversionError> {
+        let proto: protos::pike_payload::CreateDelegationAction = protobuf::parse_from_bytes(bytes)
+            .map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get CreateDelegationAction from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for CreateDelegationAction {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from CreateDelegationAction".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::pike_payload::CreateDelegationAction> for CreateDelegationAction {}
+impl IntoNative<CreateDelegationAction> for protos::pike_payload::CreateDelegationAction {}
+
+#[derive(Debug)]
+pub enum CreateDelegationActionBuildError {
+    MissingField(String),
+}
+
+impl StdError for CreateDelegationActionBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            CreateDelegationActionBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn StdError> {
+        match *self {
+            CreateDelegationActionBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for CreateDelegationActionBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            CreateDelegationActionBuildError::MissingField(ref s) => {
+                write!(f, "MissingField: {}", s)
+            }
+        }
+    }
+}
+
+/// Builder used to create a CreateDelegationAction
+#[derive(Default, Clone)]
+pub struct CreateDelegationActionBuilder {
+    pub org_id: Option<String>,
+    pub delegate_org_id: Option<String>,
+    pub permissions: Vec<String>,
+    pub expiry: Option<u64>,
+}
+
+impl CreateDelegationActionBuilder {
+    pub fn new() -> Self {
+        CreateDelegationActionBuilder::default()
+    }
+
+    pub fn with_org_id(mut self, org_id: String) -> CreateDelegationActionBuilder {
+        self.org_id = Some(org_id);
+        self
+    }
+
+    pub fn with_delegate_org_id(
+        mut self,
+        delegate_org_id: String,
+    ) -> CreateDelegationActionBuilder {
+        self.delegate_org_id = Some(delegate_org_id);
+        self
+    }
+
+    pub fn with_permissions(mut self, permissions: Vec<String>) -> CreateDelegationActionBuilder {
+        self.permissions = permissions;
+        self
+    }
+
+    pub fn with_expiry(mut self, expiry: u64) -> CreateDelegationActionBuilder {
+        self.expiry = Some(expiry);
+        self
+    }
+
+    pub fn build(self) -> Result<CreateDelegationAction, CreateDelegationActionBuildError> {
+        let org_id = self.org_id.ok_or_else(|| {
+            CreateDelegationActionBuildError::MissingField("'org_id' field is required".to_string())
+        })?;
+
+        let delegate_org_id = self.delegate_org_id.ok_or_else(|| {
+            CreateDelegationActionBuildError::MissingField(
+                "'delegate_org_id' field is required".to_string(),
+            )
+        })?;
+
+        let permissions = {
+            if self.permissions.is_empty() {
+                return Err(CreateDelegationActionBuildError::MissingField(
+                    "'permissions' cannot be empty".to_string(),
+                ));
+            } else {
+                self.permissions
+            }
+        };
+
+        let expiry = self.expiry.unwrap_or(0);
+
+        Ok(CreateDelegationAction {
+            org_id,
+            delegate_org_id,
+            permissions,
+            expiry,
+        })
+    }
+}
+
+/// Native implementation for DeleteDelegationAction
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DeleteDelegationAction {
+    org_id: String,
+    delegate_org_id: String,
+}
+
+impl DeleteDelegationAction {
+    pub fn org_id(&self) -> &str {
+        &self.org_id
+    }
+
+    pub fn delegate_org_id(&self) -> &str {
+        &self.delegate_org_id
+    }
+}
+
+impl FromProto<protos::pike_payload::DeleteDelegationAction> for DeleteDelegationAction {
+    fn from_proto(
+        delete_delegation: protos::pike_payload::DeleteDelegationAction,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(DeleteDelegationAction {
+            org_id: delete_delegation.get_org_id().to_string(),
+            delegate_org_id: delete_delegation.get_delegate_org_id().to_string(),
+        })
+    }
+}
+
+impl FromNative<DeleteDelegationAction> for protos::pike_payload::DeleteDelegationAction {
+    fn from_native(
+        delete_delegation: DeleteDelegationAction,
+    ) -> Result<Self, ProtoConversionError> {
+        let mut proto_delete_delegation = protos::pike_payload::DeleteDelegationAction::new();
+
+        proto_delete_delegation.set_org_id(delete_delegation.org_id().to_string());
+        proto_delete_delegation
+            .set_delegate_org_id(delete_delegation.delegate_org_id().to_string());
+
+        Ok(proto_delete_delegation)
+    }
+}
+
+impl FromBytes<DeleteDelegationAction> for DeleteDelegationAction {
+    fn from_bytes(bytes: &[u8]) -> Result<DeleteDelegationAction, ProtoConversionError> {
+        let proto: protos::pike_payload::DeleteDelegationAction = protobuf::parse_from_bytes(bytes)
+            .map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get DeleteDelegationAction from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for DeleteDelegationAction {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from DeleteDelegationAction".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::pike_payload::DeleteDelegationAction> for DeleteDelegationAction {}
+impl IntoNative<DeleteDelegationAction> for protos::pike_payload::DeleteDelegationAction {}
+
+#[derive(Debug)]
+pub enum DeleteDelegationActionBuildError {
+    MissingField(String),
+}
+
+impl StdError for DeleteDelegationActionBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            DeleteDelegationActionBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn StdError> {
+        match *self {
+            DeleteDelegationActionBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for DeleteDelegationActionBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            DeleteDelegationActionBuildError::MissingField(ref s) => {
+                write!(f, "MissingField: {}", s)
+            }
+        }
+    }
+}
+
+/// Builder used to create a DeleteDelegationAction
+#[derive(Default, Clone)]
+pub struct DeleteDelegationActionBuilder {
+    pub org_id: Option<String>,
+    pub delegate_org_id: Option<String>,
+}
+
+impl DeleteDelegationActionBuilder {
+    pub fn new() -> Self {
+        DeleteDelegationActionBuilder::default()
+    }
+
+    pub fn with_org_id(mut self, org_id: String) -> DeleteDelegationActionBuilder {
+        self.org_id = Some(org_id);
+        self
+    }
+
+    pub fn with_delegate_org_id(
+        mut self,
+        delegate_org_id: String,
+    ) -> DeleteDelegationActionBuilder {
+        self.delegate_org_id = Some(delegate_org_id);
+        self
+    }
+
+    pub fn build(self) -> Result<DeleteDelegationAction, DeleteDelegationActionBuildError> {
+        let org_id = self.org_id.ok_or_else(|| {
+            DeleteDelegationActionBuildError::MissingField("'org_id' field is required".to_string())
+        })?;
+
+        let delegate_org_id = self.delegate_org_id.ok_or_else(|| {
+            DeleteDelegationActionBuildError::MissingField(
+                "'delegate_org_id' field is required".to_string(),
+            )
+        })?;
+
+        Ok(DeleteDelegationAction {
+            org_id,
+            delegate_org_id,
+        })
+    }
+}
+
+/// Native implementation for RotateAgentKeyAction
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct RotateAgentKeyAction {
+    org_id: String,
+    public_key: String,
+    new_public_key: String,
+}
+
+impl RotateAgentKeyAction {
+    pub fn org_id(&self) -> &str {
+        &self.org_id
+    }
+
+    pub fn public_key(&self) -> &str {
+        &self.public_key
+    }
+
+    pub fn new_public_key(&self) -> &str {
+        &self.new_public_key
+    }
+}
+
+impl FromProto<protos::pike_payload::RotateAgentKeyAction> for RotateAgentKeyAction {
+    fn from_proto(
+        rotate_key: protos::pike_payload::RotateAgentKeyAction,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(RotateAgentKeyAction {
+            org_id: rotate_key.get_org_id().to_string(),
+            public_key: rotate_key.get_public_key().to_string(),
+            new_public_key: rotate_key.get_new_public_key().to_string(),
+        })
+    }
+}
+
+impl FromNative<RotateAgentKeyAction> for protos::pike_payload::RotateAgentKeyAction {
+    fn from_native(rotate_key: RotateAgentKeyAction) -> Result<Self, ProtoConversionError> {
+        let mut rotate_key_proto = protos::pike_payload::RotateAgentKeyAction::new();
+
+        rotate_key_proto.set_org_id(rotate_key.org_id().to_string());
+        rotate_key_proto.set_public_key(rotate_key.public_key().to_string());
+        rotate_key_proto.set_new_public_key(rotate_key.new_public_key().to_string());
+
+        Ok(rotate_key_proto)
+    }
+}
+
+impl FromBytes<RotateAgentKeyAction> for RotateAgentKeyAction {
+    fn from_bytes(bytes: &[u8]) -> Result<RotateAgentKeyAction, ProtoConversionError> {
+        let proto: protos::pike_payload::RotateAgentKeyAction = protobuf::parse_from_bytes(bytes)
+            .map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get RotateAgentKeyAction from bytes".to_string(),
+            )
+        })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for RotateAgentKeyAction {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from RotateAgentKeyAction".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::pike_payload::RotateAgentKeyAction> for RotateAgentKeyAction {}
+impl IntoNative<RotateAgentKeyAction> for protos::pike_payload::RotateAgentKeyAction {}
+
+#[derive(Debug)]
+pub enum RotateAgentKeyActionBuildError {
+    MissingField(String),
+}
+
+impl StdError for RotateAgentKeyActionBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            RotateAgentKeyActionBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn StdError> {
+        match *self {
+            RotateAgentKeyActionBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for RotateAgentKeyActionBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            RotateAgentKeyActionBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a RotateAgentKeyAction
+#[derive(Default, Clone)]
+pub struct RotateAgentKeyActionBuilder {
+    pub org_id: Option<String>,
+    pub public_key: Option<String>,
+    pub new_public_key: Option<String>,
+}
+
+impl RotateAgentKeyActionBuilder {
+    pub fn new() -> Self {
+        RotateAgentKeyActionBuilder::default()
+    }
+
+    pub fn with_org_id(mut self, org_id: String) -> RotateAgentKeyActionBuilder {
+        self.org_id = Some(org_id);
+        self
+    }
+
+    pub fn with_public_key(mut self, public_key: String) -> RotateAgentKeyActionBuilder {
+        self.public_key = Some(public_key);
+        self
+    }
+
+    pub fn with_new_public_key(mut self, new_public_key: String) -> RotateAgentKeyActionBuilder {
+        self.new_public_key = Some(new_public_key);
+        self
+    }
+
+    pub fn build(self) -> Result<RotateAgentKeyAction, RotateAgentKeyActionBuildError> {
+        let org_id = self.org_id.ok_or_else(|| {
+            RotateAgentKeyActionBuildError::MissingField("'org_id' field is required".to_string())
+        })?;
+
+        let public_key = self.public_key.ok_or_else(|| {
+            RotateAgentKeyActionBuildError::MissingField(
+                "'public_key' field is required".to_string(),
+            )
+        })?;
+
+        let new_public_key = self.new_public_key.ok_or_else(|| {
+            RotateAgentKeyActionBuildError::MissingField(
+                "'new_public_key' field is required".to_string(),
+            )
+        })?;
+
+        Ok(RotateAgentKeyAction {
+            org_id,
+            public_key,
+            new_public_key,
+        })
+    }
+}
+
+/// Native implementation for PikePayload
+#[derive(Debug, Clone, PartialEq)]
+pub struct PikePayload {
+    action: Action,
+    create_agent: CreateAgentAction,
+    update_agent: UpdateAgentAction,
+    create_organization: CreateOrganizationAction,
+    update_organization: UpdateOrganizationAction,
+    delete_agent: DeleteAgentAction,
+    delete_organization: DeleteOrganizationAction,
+    create_role: CreateRoleAction,
+    update_role: UpdateRoleAction,
+    delete_role: DeleteRoleAction,
+    create_delegation: CreateDelegationAction,
+    delete_delegation: DeleteDelegationAction,
+    rotate_agent_key: RotateAgentKeyAction,
+}
+
+impl PikePayload {
+    pub fn action(&self) -> &Action {
+        &self.action
+    }
+
+    pub fn create_agent(&self) -> &CreateAgentAction {
+        &self.create_agent
+    }
+
+    pub fn update_agent(&self) -> &UpdateAgentAction {
+        &self.update_agent
+    }
+
+    pub fn create_organization(&self) -> &CreateOrganizationAction {
+        &self.create_organization
+    }
+
+    pub fn update_organization(&self) -> &UpdateOrganizationAction {
+        &self.update_organization
+    }
+
+    pub fn delete_agent(&self) -> &DeleteAgentAction {
+        &self.delete_agent
+    }
+
+    pub fn delete_organization(&self) -> &DeleteOrganizationAction {
+        &self.delete_organization
+    }
+
+    pub fn create_role(&self) -> &CreateRoleAction {
+        &self.create_role
+    }
+
+    pub fn update_role(&self) -> &UpdateRoleAction {
+        &self.update_role
+    }
+
+    pub fn delete_role(&self) -> &DeleteRoleAction {
+        &self.delete_role
+    }
+
+    pub fn create_delegation(&self) -> &CreateDelegationAction {
+        &self.create_delegation
+    }
+
+    pub fn delete_delegation(&self) -> &DeleteDelegationAction {
+        &self.delete_delegation
+    }
+
+    pub fn rotate_agent_key(&self) -> &RotateAgentKeyAction {
+        &self.rotate_agent_key
+    }
+}
+
+impl FromProto<protos::pike_payload::PikePayload> for PikePayload {
+    fn from_proto(
+        payload: protos::pike_payload::PikePayload,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(PikePayload {
+            action: Action::from_proto(payload.get_action())?,
+            create_agent: CreateAgentAction::from_proto(payload.get_create_agent().clone())?,
+            update_agent: UpdateAgentAction::from_proto(payload.get_update_agent().clone())?,
+            create_organization: CreateOrganizationAction::from_proto(
+                payload.get_create_organization().clone(),
+            )?,
+            update_organization: UpdateOrganizationAction::from_proto(
+                payload.get_update_organization().clone(),
+            )?,
+            delete_agent: DeleteAgentAction::from_proto(payload.get_delete_agent().clone())?,
+            delete_organization: DeleteOrganizationAction::from_proto(
+                payload.get_delete_organization().clone(),
+            )?,
+            create_role: CreateRoleAction::from_proto(payload.get_create_role().clone())?,
+            update_role: UpdateRoleAction::from_proto(payload.get_update_role().clone())?,
+            delete_role: DeleteRoleAction::from_proto(payload.get_delete_role().clone())?,
+            create_delegation: CreateDelegationAction::from_proto(
+                payload.get_create_delegation().clone(),
+            )?,
+            delete_delegation: DeleteDelegationAction::from_proto(
+                payload.get_delete_delegation().clone(),
+            )?,
+            rotate_agent_key: RotateAgentKeyAction::from_proto(
+                payload.get_rotate_agent_key().clone(),
+            )?,
+        })
+    }
+}
+
+impl FromNative<PikePayload> for protos::pike_payload::PikePayload {
+    fn from_native(payload: PikePayload) -> Result<Self, ProtoConversionError> {
+        let mut proto_payload = protos::pike_payload::PikePayload::new();
+
+        proto_payload.set_action(payload.action().clone().into_proto()?);
+        proto_payload.set_create_agent(payload.create_agent().clone().into_proto()?);
+        proto_payload.set_update_agent(payload.update_agent().clone().into_proto()?);
+        proto_payload.set_create_organization(payload.create_organization().clone().into_proto()?);
+        proto_payload.set_update_organization(payload.update_organization().clone().into_proto()?);
+        proto_payload.set_delete_agent(payload.delete_agent().clone().into_proto()?);
+        proto_payload.set_delete_organization(payload.delete_organization().clone().into_proto()?);
+        proto_payload.set_create_role(payload.create_role().clone().into_proto()?);
+        proto_payload.set_update_role(payload.update_role().clone().into_proto()?);
+        proto_payload.set_delete_role(payload.delete_role().clone().into_proto()?);
+        proto_payload.set_create_delegation(payload.create_delegation().clone().into_proto()?);
+        proto_payload.set_delete_delegation(payload.delete_delegation().clone().into_proto()?);
+        proto_payload.set_rotate_agent_key(payload.rotate_agent_key().clone().into_proto()?);
+
+        Ok(proto_payload)
+    }
+}
+
+impl FromBytes<PikePayload> for PikePayload {
+    fn from_bytes(bytes: &[u8]) -> Result<PikePayload, ProtoConversionError> {
+        let proto: protos::pike_payload::PikePayload =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get PikePayload from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for PikePayload {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from PikePayload".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::pike_payload::PikePayload> for PikePayload {}
+impl IntoNative<PikePayload> for protos::pike_payload::PikePayload {}
+
+#[derive(Debug)]
+pub enum PikePayloadBuildError {
+    MissingField(String),
+}
+
+impl StdError for PikePayloadBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            PikePayloadBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn StdError> {
+        match *self {
+            PikePayloadBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for PikePayloadBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            PikePayloadBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a PikePayload
+#[derive(Default, Clone)]
+pub struct PikePayloadBuilder {
+    pub action: Option<Action>,
+    pub create_agent: Option<CreateAgentAction>,
+    pub update_agent: Option<UpdateAgentAction>,
+    pub create_organization: Option<CreateOrganizationAction>,
+    pub update_organization: Option<UpdateOrganizationAction>,
+    pub delete_agent: Option<DeleteAgentAction>,
+    pub delete_organization: Option<DeleteOrganizationAction>,
+    pub create_role: Option<CreateRoleAction>,
+    pub update_role: Option<UpdateRoleAction>,
+    pub delete_role: Option<DeleteRoleAction>,
+    pub create_delegation: Option<CreateDelegationAction>,
+    pub delete_delegation: Option<DeleteDelegationAction>,
+    pub rotate_agent_key: Option<RotateAgentKeyAction>,
+}
+
+impl PikePayloadBuilder {
+    pub fn new() -> Self {
+        PikePayloadBuilder::default()
+    }
+
+    pub fn with_action(mut self, action: Action) -> PikePayloadBuilder {
+        self.action = Some(action);
+        self
+    }
+
+    pub fn with_create_agent(mut self, create_agent: CreateAgentAction) -> PikePayloadBuilder {
+        self.create_agent = Some(create_agent);
+        self
+    }
+
+    pub fn with_update_agent(mut self, update_agent: UpdateAgentAction) -> PikePayloadBuilder {
+        self.update_agent = Some(update_agent);
+        self
+    }
+
+    pub fn with_create_organization(
+        mut self,
+        create_organization: CreateOrganizationAction,
+    ) -> PikePayloadBuilder {
+        self.create_organization = Some(create_organization);
+        self
+    }
+
+    pub fn with_update_organization(
+        mut self,
+        update_organization: UpdateOrganizationAction,
+    ) -> PikePayloadBuilder {
+        self.update_organization = Some(update_organization);
+        self
+    }
+
+    pub fn with_delete_agent(mut self, delete_agent: DeleteAgentAction) -> PikePayloadBuilder {
+        self.delete_agent = Some(delete_agent);
+        self
+    }
+
+    pub fn with_delete_organization(
+        mut self,
+        delete_organization: DeleteOrganizationAction,
+    ) -> PikePayloadBuilder {
+        self.delete_organization = Some(delete_organization);
+        self
+    }
+
+    pub fn with_create_role(mut self, create_role: CreateRoleAction) -> PikePayloadBuilder {
+        self.create_role = Some(create_role);
+        self
+    }
+
+    pub fn with_update_role(mut self, update_role: UpdateRoleAction) -> PikePayloadBuilder {
+        self.update_role = Some(update_role);
+        self
+    }
+
+    pub fn with_delete_role(mut self, delete_role: DeleteRoleAction) -> PikePayloadBuilder {
+        self.delete_role = Some(delete_role);
+        self
+    }
+
+    pub fn with_create_delegation(
+        mut self,
+        create_delegation: CreateDelegationAction,
+    ) -> PikePayloadBuilder {
+        self.create_delegation = Some(create_delegation);
+        self
+    }
+
+    pub fn with_delete_delegation(
+        mut self,
+        delete_delegation: DeleteDelegationAction,
+    ) -> PikePayloadBuilder {
+        self.delete_delegation = Some(delete_delegation);
+        self
+    }
+
+    pub fn with_rotate_agent_key(
+        mut self,
+        rotate_agent_key: RotateAgentKeyAction,
+    ) -> PikePayloadBuilder {
+        self.rotate_agent_key = Some(rotate_agent_key);
+        self
+    }
+
+    pub fn build(self) -> Result<PikePayload, PikePayloadBuildError> {
+        let action = self.action.ok_or_else(|| {
+            PikePayloadBuildError::MissingField("'action' field is required".to_string())
+        })?;
+
+        let create_agent = {
+            if action == Action::CreateAgent {
+                self.create_agent.ok_or_else(|| {
+                    PikePayloadBuildError::MissingField(
+                        "'create_agent' field is required".to_string(),
+                    )
+                })?
+            } else {
+                CreateAgentAction::default()
+            }
+        };
+
+        let update_agent = {
+            if action == Action::UpdateAgent {
+                self.update_agent.ok_or_else(|| {
+                    PikePayloadBuildError::MissingField(
+                        "'update_agent' field is required".to_string(),
+                    )
+                })?
+            } else {
+                UpdateAgentAction::default()
+            }
+        };
+
+        let create_organization = {
+            if action == Action::CreateOrganization {
+                self.create_organization.ok_or_else(|| {
+                    PikePayloadBuildError::MissingField(
+                        "'create_organization' field is required".to_string(),
+                    )
+                })?
+            } else {
+                CreateOrganizationAction::default()
+            }
+        };
+
+        let update_organization = {
+            if action == Action::UpdateOrganization {
+                self.update_organization.ok_or_else(|| {
+                    PikePayloadBuildError::MissingField(
+                        "'update_organization' field is required".to_string(),
+                    )
+                })?
+            } else {
+                UpdateOrganizationAction::default()
+            }
+        };
+
+        let delete_agent = {
+            if action == Action::DeleteAgent {
+                self.delete_agent.ok_or_else(|| {
+                    PikePayloadBuildError::MissingField(
+                        "'delete_agent' field is required".to_string(),
+                    )
+                })?
+            } else {
+                DeleteAgentAction::default()
+            }
+        };
+
+        let delete_organization = {
+            if action == Action::DeleteOrganization {
+                self.delete_organization.ok_or_else(|| {
+                    PikePayloadBuildError::MissingField(
+                        "'delete_organization' field is required".to_string(),
+                    )
+                })?
+            } else {
+                DeleteOrganizationAction::default()
+            }
+        };
+
+        let create_role = {
+            if action == Action::CreateRole {
+                self.create_role.ok_or_else(|| {
+                    PikePayloadBuildError::MissingField(
+                        "'create_role' field is required".to_string(),
+                    )
+                })?
+            } else {
+                CreateRoleAction::default()
+            }
+        };
+
+        let update_role = {
+            if action == Action::UpdateRole {
+                self.update_role.ok_or_else(|| {
+                    PikePayloadBuildError::MissingField(
+                        "'update_role' field is required".to_string(),
+                    )
+                })?
+            } else {
+                UpdateRoleAction::default()
+            }
+        };
+
+        let delete_role = {
+            if action == Action::DeleteRole {
+                self.delete_role.ok_or_else(|| {
+                    PikePayloadBuildError::MissingField(
+                        "'delete_role' field is required".to_string(),
+                    )
+                })?
+            } else {
+                DeleteRoleAction::default()
+            }
+        };
+
+        let create_delegation = {
+            if action == Action::CreateDelegation {
+                self.create_delegation.ok_or_else(|| {
+                    PikePayloadBuildError::MissingField(
+                        "'create_delegation' field is required".to_string(),
+                    )
+                })?
+            } else {
+                CreateDelegationAction::default()
+            }
+        };
+
+        let delete_delegation = {
+            if action == Action::DeleteDelegation {
+                self.delete_delegation.ok_or_else(|| {
+                    PikePayloadBuildError::MissingField(
+                        "'delete_delegation' field is required".to_string(),
+                    )
+                })?
+            } else {
+                DeleteDelegationAction::default()
+            }
+        };
+
+        let rotate_agent_key = {
+            if action == Action::RotateAgentKey {
+                self.rotate_agent_key.ok_or_else(|| {
+                    PikePayloadBuildError::MissingField(
+                        "'rotate_agent_key' field is required".to_string(),
+                    )
+                })?
+            } else {
+                RotateAgentKeyAction::default()
+            }
+        };
+
+        Ok(PikePayload {
+            action,
+            create_agent,
+            update_agent,
+            create_organization,
+            update_organization,
+            delete_agent,
+            delete_organization,
+            create_role,
+            update_role,
+            delete_role,
+            create_delegation,
+            delete_delegation,
+            rotate_agent_key,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::protocol::pike::state::KeyValueEntryBuilder;
+
+    #[test]
     // check that a create_agent action is built correctly
     fn check_create_agent_action() {
         let builder = KeyValueEntryBuilder::new();
@@ -1232,12 +2853,57 @@ mod tests {
             .with_org_id("organization".to_string())
             .with_name("name".to_string())
             .with_address("address".to_string())
+            .with_clear_alternate_ids(true)
+            .with_agents(vec!["public_key".to_string()])
             .build()
             .unwrap();
 
         let bytes = original.clone().into_bytes().unwrap();
         let org = UpdateOrganizationAction::from_bytes(&bytes).unwrap();
         assert_eq!(org, original);
+        assert!(org.clear_alternate_ids());
+        assert_eq!(org.agents(), ["public_key".to_string()]);
+    }
+
+    #[test]
+    // check that a delete_agent action is built correctly
+    fn check_delete_agent_action() {
+        let builder = DeleteAgentActionBuilder::new();
+        let delete_agent = builder
+            .with_org_id("organization".to_string())
+            .with_public_key("public_key".to_string())
+            .build()
+            .unwrap();
+
+        assert_eq!(delete_agent.org_id(), "organization");
+        assert_eq!(delete_agent.public_key(), "public_key");
+    }
+
+    #[test]
+    // check that a delete_agent can be converted to bytes and back
+    fn check_delete_agent_bytes() {
+        let builder = DeleteAgentActionBuilder::new();
+        let original = builder
+            .with_org_id("organization".to_string())
+            .with_public_key("public_key".to_string())
+            .build()
+            .unwrap();
+
+        let bytes = original.clone().into_bytes().unwrap();
+        let delete_agent = DeleteAgentAction::from_bytes(&bytes).unwrap();
+        assert_eq!(delete_agent, original);
+    }
+
+    #[test]
+    // check that a delete_organization action requires an org_id
+    fn check_delete_organization_builder() {
+        let delete_organization = DeleteOrganizationActionBuilder::new()
+            .with_org_id("organization".to_string())
+            .build()
+            .unwrap();
+
+        assert_eq!(delete_organization.org_id(), "organization");
+        assert!(DeleteOrganizationActionBuilder::new().build().is_err());
     }
 
     #[test]
@@ -1376,6 +3042,29 @@ mod tests {
         assert_eq!(payload.update_organization, action);
     }
 
+    #[test]
+    // check that a pike payload with delete_organization is built correctly
+    fn check_pike_delete_organization_payload() {
+        let action = DeleteOrganizationActionBuilder::new()
+            .with_org_id("organization".to_string())
+            .build()
+            .unwrap();
+
+        let builder = PikePayloadBuilder::new();
+        let payload = builder
+            .with_action(Action::DeleteOrganization)
+            .with_delete_organization(action.clone())
+            .build()
+            .unwrap();
+
+        assert_eq!(payload.action, Action::DeleteOrganization);
+        assert_eq!(payload.delete_agent, DeleteAgentAction::default());
+        assert_eq!(payload.delete_organization, action);
+
+        let bytes = payload.clone().into_bytes().unwrap();
+        assert_eq!(PikePayload::from_bytes(&bytes).unwrap(), payload);
+    }
+
     #[test]
     // check that a pike payload can be converted to bytes and back
     fn check_pike_payload_bytes() {
@@ -1398,4 +3087,117 @@ mod tests {
         let payload = PikePayload::from_bytes(&bytes).unwrap();
         assert_eq!(payload, original);
     }
+
+    #[test]
+    // check that pike payloads with role actions are built correctly
+    fn check_pike_role_payloads() {
+        let create_role = CreateRoleActionBuilder::new()
+            .with_org_id("organization".to_string())
+            .with_name("product_manager".to_string())
+            .with_description("Manages products".to_string())
+            .with_permissions(vec!["can_create_product".to_string()])
+            .with_inherit_from(vec!["product_viewer".to_string()])
+            .build()
+            .unwrap();
+
+        let payload = PikePayloadBuilder::new()
+            .with_action(Action::CreateRole)
+            .with_create_role(create_role.clone())
+            .build()
+            .unwrap();
+
+        assert_eq!(payload.create_role, create_role);
+        assert_eq!(payload.update_role, UpdateRoleAction::default());
+        let bytes = payload.clone().into_bytes().unwrap();
+        assert_eq!(PikePayload::from_bytes(&bytes).unwrap(), payload);
+
+        let delete_role = DeleteRoleActionBuilder::new()
+            .with_org_id("organization".to_string())
+            .with_name("product_manager".to_string())
+            .build()
+            .unwrap();
+
+        let payload = PikePayloadBuilder::new()
+            .with_action(Action::DeleteRole)
+            .with_delete_role(delete_role.clone())
+            .build()
+            .unwrap();
+        let bytes = payload.clone().into_bytes().unwrap();
+        assert_eq!(
+            PikePayload::from_bytes(&bytes).unwrap().delete_role,
+            delete_role
+        );
+
+        assert!(PikePayloadBuilder::new()
+            .with_action(Action::UpdateRole)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    // check that pike payloads with delegation actions are built correctly
+    fn check_pike_delegation_payloads() {
+        let create_delegation = CreateDelegationActionBuilder::new()
+            .with_org_id("organization".to_string())
+            .with_delegate_org_id("manufacturer".to_string())
+            .with_permissions(vec!["can_update_product".to_string()])
+            .with_expiry(1_600_000_000)
+            .build()
+            .unwrap();
+
+        let payload = PikePayloadBuilder::new()
+            .with_action(Action::CreateDelegation)
+            .with_create_delegation(create_delegation.clone())
+            .build()
+            .unwrap();
+        let bytes = payload.clone().into_bytes().unwrap();
+        let payload = PikePayload::from_bytes(&bytes).unwrap();
+        assert_eq!(payload.create_delegation(), &create_delegation);
+        assert_eq!(payload.create_delegation().expiry(), 1_600_000_000);
+
+        let delete_delegation = DeleteDelegationActionBuilder::new()
+            .with_org_id("organization".to_string())
+            .with_delegate_org_id("manufacturer".to_string())
+            .build()
+            .unwrap();
+
+        let payload = PikePayloadBuilder::new()
+            .with_action(Action::DeleteDelegation)
+            .with_delete_delegation(delete_delegation.clone())
+            .build()
+            .unwrap();
+        assert_eq!(payload.delete_delegation(), &delete_delegation);
+
+        assert!(PikePayloadBuilder::new()
+            .with_action(Action::CreateDelegation)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    // check that a pike payload rotating an agent's key survives a round trip to bytes
+    fn check_pike_rotate_agent_key_payload() {
+        let rotate_agent_key = RotateAgentKeyActionBuilder::new()
+            .with_org_id("organization".to_string())
+            .with_public_key("old_public_key".to_string())
+            .with_new_public_key("new_public_key".to_string())
+            .build()
+            .unwrap();
+
+        let payload = PikePayloadBuilder::new()
+            .with_action(Action::RotateAgentKey)
+            .with_rotate_agent_key(rotate_agent_key.clone())
+            .build()
+            .unwrap();
+        let bytes = payload.clone().into_bytes().unwrap();
+        let payload = PikePayload::from_bytes(&bytes).unwrap();
+        assert_eq!(payload.action(), &Action::RotateAgentKey);
+        assert_eq!(payload.rotate_agent_key(), &rotate_agent_key);
+
+        assert!(RotateAgentKeyActionBuilder::new()
+            .with_org_id("organization".to_string())
+            .with_public_key("old_public_key".to_string())
+            .build()
+            .is_err());
+    }
 }
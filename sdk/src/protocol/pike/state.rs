@@ -158,6 +158,7 @@ pub struct Agent {
     active: bool,
     roles: Vec<String>,
     metadata: Vec<KeyValueEntry>,
+    predecessor: String,
 }
 
 impl Agent {
@@ -180,6 +181,12 @@ impl Agent {
     pub fn metadata(&self) -> &[KeyValueEntry] {
         &self.metadata
     }
+
+    /// The public key the agent held before its key was rotated, or an empty
+    /// string if the key has never been rotated
+    pub fn predecessor(&self) -> &str {
+        &self.predecessor
+    }
 }
 
 impl FromProto<protos::pike_state::Agent> for Agent {
@@ -195,6 +202,7 @@ impl FromProto<protos::pike_state::Agent> for Agent {
                 .into_iter()
                 .map(KeyValueEntry::from_proto)
                 .collect::<Result<Vec<KeyValueEntry>, ProtoConversionError>>()?,
+            predecessor: agent.get_predecessor().to_string(),
         })
     }
 }
@@ -217,6 +225,7 @@ impl FromNative<Agent> for protos::pike_state::Agent {
                 .collect::<Result<Vec<protos::pike_state::KeyValueEntry>, ProtoConversionError>>(
                 )?,
         ));
+        agent_proto.set_predecessor(agent.predecessor().to_string());
 
         Ok(agent_proto)
     }
@@ -279,6 +288,7 @@ pub struct AgentBuilder {
     pub active: Option<bool>,
     pub roles: Vec<String>,
     pub metadata: Vec<KeyValueEntry>,
+    pub predecessor: Option<String>,
 }
 
 impl AgentBuilder {
@@ -311,6 +321,11 @@ impl AgentBuilder {
         self
     }
 
+    pub fn with_predecessor(mut self, predecessor: String) -> AgentBuilder {
+        self.predecessor = Some(predecessor);
+        self
+    }
+
     pub fn build(self) -> Result<Agent, AgentBuildError> {
         let org_id = self.org_id.ok_or_else(|| {
             AgentBuildError::MissingField("'org_id' field is required".to_string())
@@ -323,6 +338,7 @@ impl AgentBuilder {
         let active = self.active.unwrap_or_default();
         let roles = self.roles;
         let metadata = self.metadata;
+        let predecessor = self.predecessor.unwrap_or_default();
 
         Ok(Agent {
             org_id,
@@ -330,6 +346,7 @@ impl AgentBuilder {
             active,
             roles,
             metadata,
+            predecessor,
         })
     }
 }
@@ -461,6 +478,339 @@ impl AgentListBuilder {
     }
 }
 
+/// Native implementation for AlternateId
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AlternateId {
+    id_type: String,
+    id: String,
+}
+
+impl AlternateId {
+    pub fn id_type(&self) -> &str {
+        &self.id_type
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl FromProto<protos::pike_state::AlternateId> for AlternateId {
+    fn from_proto(
+        alternate_id: protos::pike_state::AlternateId,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(AlternateId {
+            id_type: alternate_id.get_id_type().to_string(),
+            id: alternate_id.get_id().to_string(),
+        })
+    }
+}
+
+impl FromNative<AlternateId> for protos::pike_state::AlternateId {
+    fn from_native(alternate_id: AlternateId) -> Result<Self, ProtoConversionError> {
+        let mut alternate_id_proto = protos::pike_state::AlternateId::new();
+
+        alternate_id_proto.set_id_type(alternate_id.id_type().to_string());
+        alternate_id_proto.set_id(alternate_id.id().to_string());
+
+        Ok(alternate_id_proto)
+    }
+}
+
+impl FromBytes<AlternateId> for AlternateId {
+    fn from_bytes(bytes: &[u8]) -> Result<AlternateId, ProtoConversionError> {
+        let proto: protos::pike_state::AlternateId =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get AlternateId from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for AlternateId {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from AlternateId".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::pike_state::AlternateId> for AlternateId {}
+impl IntoNative<AlternateId> for protos::pike_state::AlternateId {}
+
+#[derive(Debug)]
+pub enum AlternateIdBuildError {
+    MissingField(String),
+}
+
+impl StdError for AlternateIdBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            AlternateIdBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn StdError> {
+        match *self {
+            AlternateIdBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for AlternateIdBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            AlternateIdBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a AlternateId
+#[derive(Default, Clone)]
+pub struct AlternateIdBuilder {
+    pub id_type: Option<String>,
+    pub id: Option<String>,
+}
+
+impl AlternateIdBuilder {
+    pub fn new() -> Self {
+        AlternateIdBuilder::default()
+    }
+
+    pub fn with_id_type(mut self, id_type: String) -> AlternateIdBuilder {
+        self.id_type = Some(id_type);
+        self
+    }
+
+    pub fn with_id(mut self, id: String) -> AlternateIdBuilder {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn build(self) -> Result<AlternateId, AlternateIdBuildError> {
+        let id_type = self.id_type.ok_or_else(|| {
+            AlternateIdBuildError::MissingField("'id_type' field is required".to_string())
+        })?;
+
+        let id = self.id.ok_or_else(|| {
+            AlternateIdBuildError::MissingField("'id' field is required".to_string())
+        })?;
+
+        Ok(AlternateId { id_type, id })
+    }
+}
+
+/// Native implementation for Location
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Location {
+    name: String,
+    street_address: String,
+    locality: String,
+    region: String,
+    postal_code: String,
+    country: String,
+    gln: String,
+}
+
+impl Location {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn street_address(&self) -> &str {
+        &self.street_address
+    }
+
+    pub fn locality(&self) -> &str {
+        &self.locality
+    }
+
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+
+    pub fn postal_code(&self) -> &str {
+        &self.postal_code
+    }
+
+    pub fn country(&self) -> &str {
+        &self.country
+    }
+
+    pub fn gln(&self) -> &str {
+        &self.gln
+    }
+}
+
+impl FromProto<protos::pike_state::Location> for Location {
+    fn from_proto(location: protos::pike_state::Location) -> Result<Self, ProtoConversionError> {
+        Ok(Location {
+            name: location.get_name().to_string(),
+            street_address: location.get_street_address().to_string(),
+            locality: location.get_locality().to_string(),
+            region: location.get_region().to_string(),
+            postal_code: location.get_postal_code().to_string(),
+            country: location.get_country().to_string(),
+            gln: location.get_gln().to_string(),
+        })
+    }
+}
+
+impl FromNative<Location> for protos::pike_state::Location {
+    fn from_native(location: Location) -> Result<Self, ProtoConversionError> {
+        let mut location_proto = protos::pike_state::Location::new();
+
+        location_proto.set_name(location.name().to_string());
+        location_proto.set_street_address(location.street_address().to_string());
+        location_proto.set_locality(location.locality().to_string());
+        location_proto.set_region(location.region().to_string());
+        location_proto.set_postal_code(location.postal_code().to_string());
+        location_proto.set_country(location.country().to_string());
+        location_proto.set_gln(location.gln().to_string());
+
+        Ok(location_proto)
+    }
+}
+
+impl FromBytes<Location> for Location {
+    fn from_bytes(bytes: &[u8]) -> Result<Location, ProtoConversionError> {
+        let proto: protos::pike_state::Location =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get Location from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for Location {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from Location".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::pike_state::Location> for Location {}
+impl IntoNative<Location> for protos::pike_state::Location {}
+
+#[derive(Debug)]
+pub enum LocationBuildError {
+    MissingField(String),
+}
+
+impl StdError for LocationBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            LocationBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn StdError> {
+        match *self {
+            LocationBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for LocationBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            LocationBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a Location
+#[derive(Default, Clone)]
+pub struct LocationBuilder {
+    pub name: Option<String>,
+    pub street_address: Option<String>,
+    pub locality: Option<String>,
+    pub region: Option<String>,
+    pub postal_code: Option<String>,
+    pub country: Option<String>,
+    pub gln: Option<String>,
+}
+
+impl LocationBuilder {
+    pub fn new() -> Self {
+        LocationBuilder::default()
+    }
+
+    pub fn with_name(mut self, name: String) -> LocationBuilder {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn with_street_address(mut self, street_address: String) -> LocationBuilder {
+        self.street_address = Some(street_address);
+        self
+    }
+
+    pub fn with_locality(mut self, locality: String) -> LocationBuilder {
+        self.locality = Some(locality);
+        self
+    }
+
+    pub fn with_region(mut self, region: String) -> LocationBuilder {
+        self.region = Some(region);
+        self
+    }
+
+    pub fn with_postal_code(mut self, postal_code: String) -> LocationBuilder {
+        self.postal_code = Some(postal_code);
+        self
+    }
+
+    pub fn with_country(mut self, country: String) -> LocationBuilder {
+        self.country = Some(country);
+        self
+    }
+
+    pub fn with_gln(mut self, gln: String) -> LocationBuilder {
+        self.gln = Some(gln);
+        self
+    }
+
+    pub fn build(self) -> Result<Location, LocationBuildError> {
+        let name = self.name.ok_or_else(|| {
+            LocationBuildError::MissingField("'name' field is required".to_string())
+        })?;
+
+        let country = self.country.ok_or_else(|| {
+            LocationBuildError::MissingField("'country' field is required".to_string())
+        })?;
+
+        let street_address = self.street_address.unwrap_or_default();
+        let locality = self.locality.unwrap_or_default();
+        let region = self.region.unwrap_or_default();
+        let postal_code = self.postal_code.unwrap_or_default();
+        let gln = self.gln.unwrap_or_default();
+
+        Ok(Location {
+            name,
+            street_address,
+            locality,
+            region,
+            postal_code,
+            country,
+            gln,
+        })
+    }
+}
+
 /// Native implementation for Organization
 #[derive(Debug, Clone, PartialEq)]
 pub struct Organization {
@@ -468,6 +818,14 @@ pub struct Organization {
     name: String,
     address: String,
     metadata: Vec<KeyValueEntry>,
+    agents: Vec<String>,
+    alternate_ids: Vec<AlternateId>,
+    locations: Vec<Location>,
+    roles: Vec<String>,
+    delegate_org_ids: Vec<String>,
+    delegator_org_ids: Vec<String>,
+    agents_tracked: bool,
+    retired_agents: Vec<String>,
 }
 
 impl Organization {
@@ -486,6 +844,38 @@ impl Organization {
     pub fn metadata(&self) -> &[KeyValueEntry] {
         &self.metadata
     }
+
+    pub fn agents(&self) -> &[String] {
+        &self.agents
+    }
+
+    pub fn alternate_ids(&self) -> &[AlternateId] {
+        &self.alternate_ids
+    }
+
+    pub fn locations(&self) -> &[Location] {
+        &self.locations
+    }
+
+    pub fn roles(&self) -> &[String] {
+        &self.roles
+    }
+
+    pub fn delegate_org_ids(&self) -> &[String] {
+        &self.delegate_org_ids
+    }
+
+    pub fn delegator_org_ids(&self) -> &[String] {
+        &self.delegator_org_ids
+    }
+
+    pub fn agents_tracked(&self) -> bool {
+        self.agents_tracked
+    }
+
+    pub fn retired_agents(&self) -> &[String] {
+        &self.retired_agents
+    }
 }
 
 impl FromProto<protos::pike_state::Organization> for Organization {
@@ -500,6 +890,24 @@ impl FromProto<protos::pike_state::Organization> for Organization {
                 .into_iter()
                 .map(KeyValueEntry::from_proto)
                 .collect::<Result<Vec<KeyValueEntry>, ProtoConversionError>>()?,
+            agents: org.get_agents().to_vec(),
+            alternate_ids: org
+                .get_alternate_ids()
+                .to_vec()
+                .into_iter()
+                .map(AlternateId::from_proto)
+                .collect::<Result<Vec<AlternateId>, ProtoConversionError>>()?,
+            locations: org
+                .get_locations()
+                .to_vec()
+                .into_iter()
+                .map(Location::from_proto)
+                .collect::<Result<Vec<Location>, ProtoConversionError>>()?,
+            roles: org.get_roles().to_vec(),
+            delegate_org_ids: org.get_delegate_org_ids().to_vec(),
+            delegator_org_ids: org.get_delegator_org_ids().to_vec(),
+            agents_tracked: org.get_agents_tracked(),
+            retired_agents: org.get_retired_agents().to_vec(),
         })
     }
 }
@@ -519,6 +927,26 @@ impl FromNative<Organization> for protos::pike_state::Organization {
                 .collect::<Result<Vec<protos::pike_state::KeyValueEntry>, ProtoConversionError>>(
                 )?,
         ));
+        org_proto.set_agents(RepeatedField::from_vec(org.agents().to_vec()));
+        org_proto.set_alternate_ids(RepeatedField::from_vec(
+            org.alternate_ids()
+                .to_vec()
+                .into_iter()
+                .map(AlternateId::into_proto)
+                .collect::<Result<Vec<protos::pike_state::AlternateId>, ProtoConversionError>>()?,
+        ));
+        org_proto.set_locations(RepeatedField::from_vec(
+            org.locations()
+                .to_vec()
+                .into_iter()
+                .map(Location::into_proto)
+                .collect::<Result<Vec<protos::pike_state::Location>, ProtoConversionError>>()?,
+        ));
+        org_proto.set_roles(RepeatedField::from_vec(org.roles().to_vec()));
+        org_proto.set_delegate_org_ids(RepeatedField::from_vec(org.delegate_org_ids().to_vec()));
+        org_proto.set_delegator_org_ids(RepeatedField::from_vec(org.delegator_org_ids().to_vec()));
+        org_proto.set_agents_tracked(org.agents_tracked());
+        org_proto.set_retired_agents(RepeatedField::from_vec(org.retired_agents().to_vec()));
 
         Ok(org_proto)
     }
@@ -585,6 +1013,14 @@ pub struct OrganizationBuilder {
     pub name: Option<String>,
     pub address: Option<String>,
     pub metadata: Vec<KeyValueEntry>,
+    pub agents: Vec<String>,
+    pub alternate_ids: Vec<AlternateId>,
+    pub locations: Vec<Location>,
+    pub roles: Vec<String>,
+    pub delegate_org_ids: Vec<String>,
+    pub delegator_org_ids: Vec<String>,
+    pub agents_tracked: bool,
+    pub retired_agents: Vec<String>,
 }
 
 impl OrganizationBuilder {
@@ -612,6 +1048,46 @@ impl OrganizationBuilder {
         self
     }
 
+    pub fn with_agents(mut self, agents: Vec<String>) -> OrganizationBuilder {
+        self.agents = agents;
+        self
+    }
+
+    pub fn with_alternate_ids(mut self, alternate_ids: Vec<AlternateId>) -> OrganizationBuilder {
+        self.alternate_ids = alternate_ids;
+        self
+    }
+
+    pub fn with_locations(mut self, locations: Vec<Location>) -> OrganizationBuilder {
+        self.locations = locations;
+        self
+    }
+
+    pub fn with_roles(mut self, roles: Vec<String>) -> OrganizationBuilder {
+        self.roles = roles;
+        self
+    }
+
+    pub fn with_delegate_org_ids(mut self, delegate_org_ids: Vec<String>) -> OrganizationBuilder {
+        self.delegate_org_ids = delegate_org_ids;
+        self
+    }
+
+    pub fn with_delegator_org_ids(mut self, delegator_org_ids: Vec<String>) -> OrganizationBuilder {
+        self.delegator_org_ids = delegator_org_ids;
+        self
+    }
+
+    pub fn with_agents_tracked(mut self, agents_tracked: bool) -> OrganizationBuilder {
+        self.agents_tracked = agents_tracked;
+        self
+    }
+
+    pub fn with_retired_agents(mut self, retired_agents: Vec<String>) -> OrganizationBuilder {
+        self.retired_agents = retired_agents;
+        self
+    }
+
     pub fn build(self) -> Result<Organization, OrganizationBuildError> {
         let org_id = self.org_id.ok_or_else(|| {
             OrganizationBuildError::MissingField("'org_id' field is required".to_string())
@@ -626,12 +1102,28 @@ impl OrganizationBuilder {
         })?;
 
         let metadata = self.metadata;
+        let agents = self.agents;
+        let alternate_ids = self.alternate_ids;
+        let locations = self.locations;
+        let roles = self.roles;
+        let delegate_org_ids = self.delegate_org_ids;
+        let delegator_org_ids = self.delegator_org_ids;
+        let agents_tracked = self.agents_tracked;
+        let retired_agents = self.retired_agents;
 
         Ok(Organization {
             org_id,
             name,
             address,
             metadata,
+            agents,
+            alternate_ids,
+            locations,
+            roles,
+            delegate_org_ids,
+            delegator_org_ids,
+            agents_tracked,
+            retired_agents,
         })
     }
 }
@@ -768,9 +1260,607 @@ impl OrganizationListBuilder {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Native implementation for Role
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Role {
+    org_id: String,
+    name: String,
+    description: String,
+    permissions: Vec<String>,
+    inherit_from: Vec<String>,
+}
+
+impl Role {
+    pub fn org_id(&self) -> &str {
+        &self.org_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn permissions(&self) -> &[String] {
+        &self.permissions
+    }
+
+    pub fn inherit_from(&self) -> &[String] {
+        &self.inherit_from
+    }
+}
+
+impl FromProto<protos::pike_state::Role> for Role {
+    fn from_proto(role: protos::pike_state::Role) -> Result<Self, ProtoConversionError> {
+        Ok(Role {
+            org_id: role.get_org_id().to_string(),
+            name: role.get_name().to_string(),
+            description: role.get_description().to_string(),
+            permissions: role.get_permissions().to_vec(),
+            inherit_from: role.get_inherit_from().to_vec(),
+        })
+    }
+}
+
+impl FromNative<Role> for protos::pike_state::Role {
+    fn from_native(role: Role) -> Result<Self, ProtoConversionError> {
+        let mut role_proto = protos::pike_state::Role::new();
+
+        role_proto.set_org_id(role.org_id().to_string());
+        role_proto.set_name(role.name().to_string());
+        role_proto.set_description(role.description().to_string());
+        role_proto.set_permissions(RepeatedField::from_vec(role.permissions().to_vec()));
+        role_proto.set_inherit_from(RepeatedField::from_vec(role.inherit_from().to_vec()));
+
+        Ok(role_proto)
+    }
+}
+
+impl FromBytes<Role> for Role {
+    fn from_bytes(bytes: &[u8]) -> Result<Role, ProtoConversionError> {
+        let proto: protos::pike_state::Role = protobuf::parse_from_bytes(bytes).map_err(|_| {
+            ProtoConversionError::SerializationError("Unable to get Role from bytes".to_string())
+        })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for Role {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError("Unable to get bytes from Role".to_string())
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::pike_state::Role> for Role {}
+impl IntoNative<Role> for protos::pike_state::Role {}
+
+#[derive(Debug)]
+pub enum RoleBuildError {
+    MissingField(String),
+}
+
+impl StdError for RoleBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            RoleBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn StdError> {
+        match *self {
+            RoleBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for RoleBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            RoleBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a Role
+#[derive(Default, Clone)]
+pub struct RoleBuilder {
+    pub org_id: Option<String>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub permissions: Vec<String>,
+    pub inherit_from: Vec<String>,
+}
+
+impl RoleBuilder {
+    pub fn new() -> Self {
+        RoleBuilder::default()
+    }
+
+    pub fn with_org_id(mut self, org_id: String) -> RoleBuilder {
+        self.org_id = Some(org_id);
+        self
+    }
+
+    pub fn with_name(mut self, name: String) -> RoleBuilder {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn with_description(mut self, description: String) -> RoleBuilder {
+        self.description = Some(description);
+        self
+    }
+
+    pub fn with_permissions(mut self, permissions: Vec<String>) -> RoleBuilder {
+        self.permissions = permissions;
+        self
+    }
+
+    pub fn with_inherit_from(mut self, inherit_from: Vec<String>) -> RoleBuilder {
+        self.inherit_from = inherit_from;
+        self
+    }
+
+    pub fn build(self) -> Result<Role, RoleBuildError> {
+        let org_id = self.org_id.ok_or_else(|| {
+            RoleBuildError::MissingField("'org_id' field is required".to_string())
+        })?;
+
+        let name = self
+            .name
+            .ok_or_else(|| RoleBuildError::MissingField("'name' field is required".to_string()))?;
+
+        let description = self.description.unwrap_or_default();
+        let permissions = self.permissions;
+        let inherit_from = self.inherit_from;
+
+        Ok(Role {
+            org_id,
+            name,
+            description,
+            permissions,
+            inherit_from,
+        })
+    }
+}
+
+/// Native implementation of RoleList
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoleList {
+    roles: Vec<Role>,
+}
+
+impl RoleList {
+    pub fn roles(&self) -> &[Role] {
+        &self.roles
+    }
+}
+
+impl FromProto<protos::pike_state::RoleList> for RoleList {
+    fn from_proto(role_list: protos::pike_state::RoleList) -> Result<Self, ProtoConversionError> {
+        Ok(RoleList {
+            roles: role_list
+                .get_roles()
+                .to_vec()
+                .into_iter()
+                .map(Role::from_proto)
+                .collect::<Result<Vec<Role>, ProtoConversionError>>()?,
+        })
+    }
+}
+
+impl FromNative<RoleList> for protos::pike_state::RoleList {
+    fn from_native(role_list: RoleList) -> Result<Self, ProtoConversionError> {
+        let mut role_list_proto = protos::pike_state::RoleList::new();
+
+        role_list_proto.set_roles(RepeatedField::from_vec(
+            role_list
+                .roles()
+                .to_vec()
+                .into_iter()
+                .map(Role::into_proto)
+                .collect::<Result<Vec<protos::pike_state::Role>, ProtoConversionError>>()?,
+        ));
+
+        Ok(role_list_proto)
+    }
+}
+
+impl FromBytes<RoleList> for RoleList {
+    fn from_bytes(bytes: &[u8]) -> Result<RoleList, ProtoConversionError> {
+        let proto: protos::pike_state::RoleList =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get RoleList from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for RoleList {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from RoleList".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::pike_state::RoleList> for RoleList {}
+impl IntoNative<RoleList> for protos::pike_state::RoleList {}
+
+#[derive(Debug)]
+pub enum RoleListBuildError {
+    MissingField(String),
+}
+
+impl StdError for RoleListBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            RoleListBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn StdError> {
+        match *self {
+            RoleListBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for RoleListBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            RoleListBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a RoleList
+#[derive(Default, Clone)]
+pub struct RoleListBuilder {
+    pub roles: Vec<Role>,
+}
+
+impl RoleListBuilder {
+    pub fn new() -> Self {
+        RoleListBuilder::default()
+    }
+
+    pub fn with_roles(mut self, roles: Vec<Role>) -> RoleListBuilder {
+        self.roles = roles;
+        self
+    }
+
+    pub fn build(self) -> Result<RoleList, RoleListBuildError> {
+        let roles = {
+            if self.roles.is_empty() {
+                return Err(RoleListBuildError::MissingField(
+                    "'roles' cannot be empty".to_string(),
+                ));
+            } else {
+                self.roles
+            }
+        };
+
+        Ok(RoleList { roles })
+    }
+}
+
+/// Native implementation for Delegation
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Delegation {
+    org_id: String,
+    delegate_org_id: String,
+    permissions: Vec<String>,
+    expiry: u64,
+}
+
+impl Delegation {
+    pub fn org_id(&self) -> &str {
+        &self.org_id
+    }
+
+    pub fn delegate_org_id(&self) -> &str {
+        &self.delegate_org_id
+    }
+
+    pub fn permissions(&self) -> &[String] {
+        &self.permissions
+    }
+
+    pub fn expiry(&self) -> u64 {
+        self.expiry
+    }
+}
+
+impl FromProto<protos::pike_state::Delegation> for Delegation {
+    fn from_proto(
+        delegation: protos::pike_state::Delegation,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(Delegation {
+            org_id: delegation.get_org_id().to_string(),
+            delegate_org_id: delegation.get_delegate_org_id().to_string(),
+            permissions: delegation.get_permissions().to_vec(),
+            expiry: delegation.get_expiry(),
+        })
+    }
+}
+
+impl FromNative<Delegation> for protos::pike_state::Delegation {
+    fn from_native(delegation: Delegation) -> Result<Self, ProtoConversionError> {
+        let mut delegation_proto = protos::pike_state::Delegation::new();
+
+        delegation_proto.set_org_id(delegation.org_id().to_string());
+        delegation_proto.set_delegate_org_id(delegation.delegate_org_id().to_string());
+        delegation_proto
+            .set_permissions(RepeatedField::from_vec(delegation.permissions().to_vec()));
+        delegation_proto.set_expiry(delegation.expiry());
+
+        Ok(delegation_proto)
+    }
+}
+
+impl FromBytes<Delegation> for Delegation {
+    fn from_bytes(bytes: &[u8]) -> Result<Delegation, ProtoConversionError> {
+        let proto: protos::pike_state::Delegation =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get Delegation from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for Delegation {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from Delegation".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::pike_state::Delegation> for Delegation {}
+impl IntoNative<Delegation> for protos::pike_state::Delegation {}
+
+#[derive(Debug)]
+pub enum DelegationBuildError {
+    MissingField(String),
+}
+
+impl StdError for DelegationBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            DelegationBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn StdError> {
+        match *self {
+            DelegationBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for DelegationBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            DelegationBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a Delegation
+#[derive(Default, Clone)]
+pub struct DelegationBuilder {
+    pub org_id: Option<String>,
+    pub delegate_org_id: Option<String>,
+    pub permissions: Vec<String>,
+    pub expiry: Option<u64>,
+}
+
+impl DelegationBuilder {
+    pub fn new() -> Self {
+        DelegationBuilder::default()
+    }
+
+    pub fn with_org_id(mut self, org_id: String) -> DelegationBuilder {
+        self.org_id = Some(org_id);
+        self
+    }
+
+    pub fn with_delegate_org_id(mut self, delegate_org_id: String) -> DelegationBuilder {
+        self.delegate_org_id = Some(delegate_org_id);
+        self
+    }
+
+    pub fn with_permissions(mut self, permissions: Vec<String>) -> DelegationBuilder {
+        self.permissions = permissions;
+        self
+    }
+
+    pub fn with_expiry(mut self, expiry: u64) -> DelegationBuilder {
+        self.expiry = Some(expiry);
+        self
+    }
+
+    pub fn build(self) -> Result<Delegation, DelegationBuildError> {
+        let org_id = self.org_id.ok_or_else(|| {
+            DelegationBuildError::MissingField("'org_id' field is required".to_string())
+        })?;
+
+        let delegate_org_id = self.delegate_org_id.ok_or_else(|| {
+            DelegationBuildError::MissingField("'delegate_org_id' field is required".to_string())
+        })?;
+
+        let permissions = {
+            if self.permissions.is_empty() {
+                return Err(DelegationBuildError::MissingField(
+                    "'permissions' cannot be empty".to_string(),
+                ));
+            } else {
+                self.permissions
+            }
+        };
+
+        let expiry = self.expiry.unwrap_or(0);
+
+        Ok(Delegation {
+            org_id,
+            delegate_org_id,
+            permissions,
+            expiry,
+        })
+    }
+}
+
+/// Native implementation of DelegationList
+#[derive(Debug, Clone, PartialEq)]
+pub struct DelegationList {
+    delegations: Vec<Delegation>,
+}
+
+impl DelegationList {
+    pub fn delegations(&self) -> &[Delegation] {
+        &self.delegations
+    }
+}
+
+impl FromProto<protos::pike_state::DelegationList> for DelegationList {
+    fn from_proto(
+        delegation_list: protos::pike_state::DelegationList,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(DelegationList {
+            delegations: delegation_list
+                .get_delegations()
+                .to_vec()
+                .into_iter()
+                .map(Delegation::from_proto)
+                .collect::<Result<Vec<Delegation>, ProtoConversionError>>()?,
+        })
+    }
+}
+
+impl FromNative<DelegationList> for protos::pike_state::DelegationList {
+    fn from_native(delegation_list: DelegationList) -> Result<Self, ProtoConversionError> {
+        let mut delegation_list_proto = protos::pike_state::DelegationList::new();
+
+        delegation_list_proto.set_delegations(RepeatedField::from_vec(
+            delegation_list
+                .delegations()
+                .to_vec()
+                .into_iter()
+                .map(Delegation::into_proto)
+                .collect::<Result<Vec<protos::pike_state::Delegation>, ProtoConversionError>>()?,
+        ));
+
+        Ok(delegation_list_proto)
+    }
+}
+
+impl FromBytes<DelegationList> for DelegationList {
+    fn from_bytes(bytes: &[u8]) -> Result<DelegationList, ProtoConversionError> {
+        let proto: protos::pike_state::DelegationList =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get DelegationList from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for DelegationList {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from DelegationList".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::pike_state::DelegationList> for DelegationList {}
+impl IntoNative<DelegationList> for protos::pike_state::DelegationList {}
+
+#[derive(Debug)]
+pub enum DelegationListBuildError {
+    MissingField(String),
+}
+
+impl StdError for DelegationListBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            DelegationListBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn StdError> {
+        match *self {
+            DelegationListBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for DelegationListBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            DelegationListBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a DelegationList
+#[derive(Default, Clone)]
+pub struct DelegationListBuilder {
+    pub delegations: Vec<Delegation>,
+}
+
+impl DelegationListBuilder {
+    pub fn new() -> Self {
+        DelegationListBuilder::default()
+    }
+
+    pub fn with_delegations(mut self, delegations: Vec<Delegation>) -> DelegationListBuilder {
+        self.delegations = delegations;
+        self
+    }
+
+    pub fn build(self) -> Result<DelegationList, DelegationListBuildError> {
+        let delegations = {
+            if self.delegations.is_empty() {
+                return Err(DelegationListBuildError::MissingField(
+                    "'delegations' cannot be empty".to_string(),
+                ));
+            } else {
+                self.delegations
+            }
+        };
+
+        Ok(DelegationList { delegations })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
     #[test]
     // check that a KeyValueEntry is built correctly
@@ -923,6 +2013,12 @@ mod tests {
             .with_name("name".to_string())
             .with_address("address".to_string())
             .with_metadata(vec![key_value.clone()])
+            .with_agents(vec!["public_key".to_string()])
+            .with_roles(vec!["admin".to_string()])
+            .with_delegate_org_ids(vec!["delegate_org".to_string()])
+            .with_delegator_org_ids(vec!["delegator_org".to_string()])
+            .with_agents_tracked(true)
+            .with_retired_agents(vec!["old_public_key".to_string()])
             .build()
             .unwrap();
 
@@ -930,6 +2026,54 @@ mod tests {
         assert_eq!(organization.name(), "name");
         assert_eq!(organization.address(), "address");
         assert_eq!(organization.metadata(), [key_value]);
+        assert_eq!(organization.agents(), ["public_key".to_string()]);
+        assert_eq!(organization.roles(), ["admin".to_string()]);
+        assert_eq!(
+            organization.delegate_org_ids(),
+            ["delegate_org".to_string()]
+        );
+        assert_eq!(
+            organization.delegator_org_ids(),
+            ["delegator_org".to_string()]
+        );
+        assert!(organization.agents_tracked());
+        assert_eq!(
+            organization.retired_agents(),
+            ["old_public_key".to_string()]
+        );
+    }
+
+    #[test]
+    // check that an Organization keeps its alternate IDs and locations through
+    // a round trip to bytes
+    fn check_organization_alternate_ids_and_locations() {
+        let alternate_id = AlternateIdBuilder::new()
+            .with_id_type("gln".to_string())
+            .with_id("1234567890128".to_string())
+            .build()
+            .unwrap();
+        let location = LocationBuilder::new()
+            .with_name("HQ".to_string())
+            .with_street_address("1 Main St".to_string())
+            .with_country("US".to_string())
+            .build()
+            .unwrap();
+
+        let organization = OrganizationBuilder::new()
+            .with_org_id("organization".to_string())
+            .with_name("name".to_string())
+            .with_address("address".to_string())
+            .with_alternate_ids(vec![alternate_id.clone()])
+            .with_locations(vec![location.clone()])
+            .build()
+            .unwrap();
+
+        let bytes = organization.clone().into_bytes().unwrap();
+        let org = Organization::from_bytes(&bytes).unwrap();
+        assert_eq!(org, organization);
+        assert_eq!(org.alternate_ids(), [alternate_id]);
+        assert_eq!(org.locations(), [location]);
+        assert_eq!(org.locations()[0].gln(), "");
     }
 
     #[test]
@@ -1013,4 +2157,68 @@ mod tests {
         let org_list = OrganizationList::from_bytes(&bytes).unwrap();
         assert_eq!(org_list, original);
     }
+
+    #[test]
+    // check that a Role is built correctly and can be converted to bytes and back
+    fn check_role_builder() {
+        let builder = RoleBuilder::new();
+        let role = builder
+            .with_org_id("organization".to_string())
+            .with_name("product_manager".to_string())
+            .with_permissions(vec!["can_create_product".to_string()])
+            .with_inherit_from(vec!["product_viewer".to_string()])
+            .build()
+            .unwrap();
+
+        assert_eq!(role.org_id(), "organization");
+        assert_eq!(role.name(), "product_manager");
+        assert_eq!(role.description(), "");
+        assert_eq!(role.permissions(), &["can_create_product".to_string()]);
+        assert_eq!(role.inherit_from(), &["product_viewer".to_string()]);
+
+        let role_list = RoleListBuilder::new()
+            .with_roles(vec![role.clone()])
+            .build()
+            .unwrap();
+        let bytes = role_list.clone().into_bytes().unwrap();
+        assert_eq!(RoleList::from_bytes(&bytes).unwrap(), role_list);
+
+        assert!(RoleBuilder::new()
+            .with_org_id("organization".to_string())
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    // check that a Delegation is built correctly and never expires by default
+    fn check_delegation_builder() {
+        let delegation = DelegationBuilder::new()
+            .with_org_id("organization".to_string())
+            .with_delegate_org_id("manufacturer".to_string())
+            .with_permissions(vec!["can_update_product".to_string()])
+            .build()
+            .unwrap();
+
+        assert_eq!(delegation.org_id(), "organization");
+        assert_eq!(delegation.delegate_org_id(), "manufacturer");
+        assert_eq!(
+            delegation.permissions(),
+            &["can_update_product".to_string()]
+        );
+        assert_eq!(delegation.expiry(), 0);
+
+        let delegation_list = DelegationListBuilder::new()
+            .with_delegations(vec![delegation])
+            .build()
+            .unwrap();
+        let bytes = delegation_list.clone().into_bytes().unwrap();
+        assert_eq!(DelegationList::from_bytes(&bytes).unwrap(), delegation_list);
+
+        // a delegation must grant at least one permission
+        assert!(DelegationBuilder::new()
+            .with_org_id("organization".to_string())
+            .with_delegate_org_id("manufacturer".to_string())
+            .build()
+            .is_err());
+    }
 }
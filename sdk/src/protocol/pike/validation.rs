@@ -0,0 +1,280 @@
+// Copyright 2020 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::error::Error as StdError;
+
+use crate::protocol::gs1::has_valid_check_digit;
+use crate::protocol::pike::state::{AlternateId, Location};
+
+#[derive(Debug, PartialEq)]
+pub enum OrganizationValidationError {
+    /// Returned when an alternate ID type is empty or not a lowercase identifier.
+    InvalidAlternateIdType(String),
+    /// Returned when an alternate ID is not well formed for its type.
+    InvalidAlternateId(String),
+    /// Returned when the same alternate ID is listed more than once.
+    DuplicateAlternateId(String),
+    /// Returned when a location is missing its name or has a malformed field.
+    InvalidLocation(String),
+    /// Returned when two locations share a name.
+    DuplicateLocation(String),
+}
+
+impl StdError for OrganizationValidationError {
+    fn description(&self) -> &str {
+        match *self {
+            OrganizationValidationError::InvalidAlternateIdType(ref msg) => msg,
+            OrganizationValidationError::InvalidAlternateId(ref msg) => msg,
+            OrganizationValidationError::DuplicateAlternateId(ref msg) => msg,
+            OrganizationValidationError::InvalidLocation(ref msg) => msg,
+            OrganizationValidationError::DuplicateLocation(ref msg) => msg,
+        }
+    }
+}
+
+impl std::fmt::Display for OrganizationValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            OrganizationValidationError::InvalidAlternateIdType(ref id_type) => {
+                write!(f, "Invalid alternate ID type: {}", id_type)
+            }
+            OrganizationValidationError::InvalidAlternateId(ref msg) => {
+                write!(f, "Invalid alternate ID: {}", msg)
+            }
+            OrganizationValidationError::DuplicateAlternateId(ref id) => {
+                write!(f, "Alternate ID {} is listed more than once", id)
+            }
+            OrganizationValidationError::InvalidLocation(ref msg) => {
+                write!(f, "Invalid location: {}", msg)
+            }
+            OrganizationValidationError::DuplicateLocation(ref name) => {
+                write!(f, "Location {} is listed more than once", name)
+            }
+        }
+    }
+}
+
+/// Checks the alternate IDs of an organization.
+///
+/// ID types must be non-empty and made of lowercase letters, digits, `_` and
+/// `-`. IDs must be non-empty; `gln` IDs must be 13 digits with a valid GS1
+/// check digit and `duns` IDs must be 9 digits. No ID may be listed twice.
+///
+/// # Arguments
+///
+/// * `alternate_ids` - The alternate IDs to validate.
+///
+pub fn validate_alternate_ids(
+    alternate_ids: &[AlternateId],
+) -> Result<(), OrganizationValidationError> {
+    let mut seen = HashSet::new();
+
+    for alternate_id in alternate_ids {
+        let id_type = alternate_id.id_type();
+        let id = alternate_id.id();
+
+        if id_type.is_empty()
+            || !id_type
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-')
+        {
+            return Err(OrganizationValidationError::InvalidAlternateIdType(
+                id_type.to_string(),
+            ));
+        }
+
+        if id.is_empty() {
+            return Err(OrganizationValidationError::InvalidAlternateId(format!(
+                "{} ID is empty",
+                id_type
+            )));
+        }
+
+        match id_type {
+            "gln" if !is_valid_gln(id) => {
+                return Err(OrganizationValidationError::InvalidAlternateId(format!(
+                    "{} is not a valid GLN",
+                    id
+                )));
+            }
+            "duns" if !(id.len() == 9 && id.chars().all(|c| c.is_ascii_digit())) => {
+                return Err(OrganizationValidationError::InvalidAlternateId(format!(
+                    "{} is not a valid DUNS number",
+                    id
+                )));
+            }
+            _ => (),
+        }
+
+        if !seen.insert((id_type, id)) {
+            return Err(OrganizationValidationError::DuplicateAlternateId(format!(
+                "{}:{}",
+                id_type, id
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks the locations of an organization.
+///
+/// Every location needs a name that no other location of the organization
+/// uses and a country given as an ISO 3166-1 alpha-2 code. A location's GLN,
+/// if set, must be valid.
+///
+/// # Arguments
+///
+/// * `locations` - The locations to validate.
+///
+pub fn validate_locations(locations: &[Location]) -> Result<(), OrganizationValidationError> {
+    let mut names = HashSet::new();
+
+    for location in locations {
+        if location.name().is_empty() {
+            return Err(OrganizationValidationError::InvalidLocation(
+                "location name is empty".to_string(),
+            ));
+        }
+
+        let country = location.country();
+        if !(country.len() == 2 && country.chars().all(|c| c.is_ascii_uppercase())) {
+            return Err(OrganizationValidationError::InvalidLocation(format!(
+                "{} is not an ISO 3166-1 alpha-2 country code",
+                country
+            )));
+        }
+
+        if !location.gln().is_empty() && !is_valid_gln(location.gln()) {
+            return Err(OrganizationValidationError::InvalidLocation(format!(
+                "{} is not a valid GLN",
+                location.gln()
+            )));
+        }
+
+        if !names.insert(location.name()) {
+            return Err(OrganizationValidationError::DuplicateLocation(
+                location.name().to_string(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns true if `gln` is 13 digits ending in the GS1 check digit of the
+/// first 12.
+pub fn is_valid_gln(gln: &str) -> bool {
+    gln.len() == 13 && has_valid_check_digit(gln)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::protocol::pike::state::{AlternateIdBuilder, LocationBuilder};
+
+    fn alternate_id(id_type: &str, id: &str) -> AlternateId {
+        AlternateIdBuilder::new()
+            .with_id_type(id_type.to_string())
+            .with_id(id.to_string())
+            .build()
+            .unwrap()
+    }
+
+    fn location(name: &str, country: &str, gln: &str) -> Location {
+        LocationBuilder::new()
+            .with_name(name.to_string())
+            .with_country(country.to_string())
+            .with_gln(gln.to_string())
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    // check that GLNs are accepted only with a correct check digit
+    fn check_is_valid_gln() {
+        assert!(is_valid_gln("0614141000005"));
+        assert!(is_valid_gln("1234567890128"));
+        assert!(!is_valid_gln("1234567890123"));
+        assert!(!is_valid_gln("123456789012"));
+        assert!(!is_valid_gln("12345678901a8"));
+    }
+
+    #[test]
+    // check that well formed alternate IDs are accepted
+    fn check_valid_alternate_ids() {
+        let ids = vec![
+            alternate_id("gln", "1234567890128"),
+            alternate_id("duns", "123456789"),
+            alternate_id("tax_id", "US-12-3456789"),
+        ];
+        assert!(validate_alternate_ids(&ids).is_ok());
+    }
+
+    #[test]
+    // check that malformed types and IDs are rejected
+    fn check_invalid_alternate_ids() {
+        assert_eq!(
+            validate_alternate_ids(&[alternate_id("GLN", "1234567890128")]),
+            Err(OrganizationValidationError::InvalidAlternateIdType(
+                "GLN".to_string()
+            ))
+        );
+        assert_eq!(
+            validate_alternate_ids(&[alternate_id("gln:x", "1234567890128")]),
+            Err(OrganizationValidationError::InvalidAlternateIdType(
+                "gln:x".to_string()
+            ))
+        );
+        assert!(validate_alternate_ids(&[alternate_id("tax_id", "")]).is_err());
+        assert!(validate_alternate_ids(&[alternate_id("gln", "1234567890123")]).is_err());
+        assert!(validate_alternate_ids(&[alternate_id("duns", "12345678")]).is_err());
+    }
+
+    #[test]
+    // check that an alternate ID cannot be listed twice
+    fn check_duplicate_alternate_ids() {
+        let ids = vec![
+            alternate_id("duns", "123456789"),
+            alternate_id("duns", "123456789"),
+        ];
+        assert_eq!(
+            validate_alternate_ids(&ids),
+            Err(OrganizationValidationError::DuplicateAlternateId(
+                "duns:123456789".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    // check that locations need a unique name, a country code and a valid GLN
+    fn check_locations() {
+        assert!(validate_locations(&[
+            location("HQ", "US", "1234567890128"),
+            location("Warehouse", "CA", ""),
+        ])
+        .is_ok());
+        assert!(validate_locations(&[location("", "US", "")]).is_err());
+        assert!(validate_locations(&[location("HQ", "USA", "")]).is_err());
+        assert!(validate_locations(&[location("HQ", "US", "1234567890123")]).is_err());
+        assert_eq!(
+            validate_locations(&[location("HQ", "US", ""), location("HQ", "CA", "")]),
+            Err(OrganizationValidationError::DuplicateLocation(
+                "HQ".to_string()
+            ))
+        );
+    }
+}
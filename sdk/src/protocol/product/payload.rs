@@ -32,6 +32,7 @@ pub enum Action {
     ProductCreate(ProductCreateAction),
     ProductUpdate(ProductUpdateAction),
     ProductDelete(ProductDeleteAction),
+    ProductTransfer(ProductTransferAction),
 }
 
 // Rust native implementation for ProductPayload
@@ -64,6 +65,9 @@ impl FromProto<protos::product_payload::ProductPayload> for ProductPayload {
             ProductPayload_Action::PRODUCT_DELETE => Action::ProductDelete(
                 ProductDeleteAction::from_proto(payload.get_product_delete().clone())?,
             ),
+            ProductPayload_Action::PRODUCT_TRANSFER => Action::ProductTransfer(
+                ProductTransferAction::from_proto(payload.get_product_transfer().clone())?,
+            ),
             ProductPayload_Action::UNSET_ACTION => {
                 return Err(ProtoConversionError::InvalidTypeError(
                     "Cannot convert ProductPayload_Action with type unset".to_string(),
@@ -96,6 +100,10 @@ impl FromNative<ProductPayload> for protos::product_payload::ProductPayload {
                 proto.set_action(ProductPayload_Action::PRODUCT_DELETE);
                 proto.set_product_delete(payload.clone().into_proto()?);
             }
+            Action::ProductTransfer(payload) => {
+                proto.set_action(ProductPayload_Action::PRODUCT_TRANSFER);
+                proto.set_product_transfer(payload.clone().into_proto()?);
+            }
         }
 
         Ok(proto)
@@ -193,6 +201,7 @@ pub struct ProductCreateAction {
     product_id: String,
     owner: String,
     properties: Vec<PropertyValue>,
+    schema: String,
 }
 
 impl ProductCreateAction {
@@ -211,6 +220,10 @@ impl ProductCreateAction {
     pub fn properties(&self) -> &[PropertyValue] {
         &self.properties
     }
+
+    pub fn schema(&self) -> &str {
+        &self.schema
+    }
 }
 
 impl FromProto<product_payload::ProductCreateAction> for ProductCreateAction {
@@ -227,6 +240,7 @@ impl FromProto<product_payload::ProductCreateAction> for ProductCreateAction {
                 .into_iter()
                 .map(PropertyValue::from_proto)
                 .collect::<Result<Vec<PropertyValue>, ProtoConversionError>>()?,
+            schema: proto.get_schema().to_string(),
         })
     }
 }
@@ -246,6 +260,7 @@ impl FromNative<ProductCreateAction> for product_payload::ProductCreateAction {
                 .collect::<Result<Vec<protos::schema_state::PropertyValue>, ProtoConversionError>>(
                 )?,
         ));
+        proto.set_schema(native.schema().to_string());
         Ok(proto)
     }
 }
@@ -283,6 +298,7 @@ pub struct ProductCreateActionBuilder {
     product_id: Option<String>,
     owner: Option<String>,
     properties: Option<Vec<PropertyValue>>,
+    schema: Option<String>,
 }
 
 impl ProductCreateActionBuilder {
@@ -305,6 +321,10 @@ impl ProductCreateActionBuilder {
         self.properties = Some(value);
         self
     }
+    pub fn with_schema(mut self, value: String) -> Self {
+        self.schema = Some(value);
+        self
+    }
     pub fn build(self) -> Result<ProductCreateAction, BuilderError> {
         let product_type = self.product_type.ok_or_else(|| {
             BuilderError::MissingField("'product_type' field is required".to_string())
@@ -318,11 +338,13 @@ impl ProductCreateActionBuilder {
         let properties = self
             .properties
             .ok_or_else(|| BuilderError::MissingField("'properties' field is required".into()))?;
+        let schema = self.schema.unwrap_or_default();
         Ok(ProductCreateAction {
             product_type,
             product_id,
             owner,
             properties,
+            schema,
         })
     }
 }
@@ -332,6 +354,8 @@ pub struct ProductUpdateAction {
     product_type: ProductType,
     product_id: String,
     properties: Vec<PropertyValue>,
+    set_properties: Vec<PropertyValue>,
+    remove_properties: Vec<String>,
 }
 
 /// Native implementation for ProductUpdateAction
@@ -347,6 +371,17 @@ impl ProductUpdateAction {
     pub fn properties(&self) -> &[PropertyValue] {
         &self.properties
     }
+
+    /// Properties to add, or to replace the property of the same name, when
+    /// patching the product rather than replacing all of its properties
+    pub fn set_properties(&self) -> &[PropertyValue] {
+        &self.set_properties
+    }
+
+    /// Names of the properties to remove when patching the product
+    pub fn remove_properties(&self) -> &[String] {
+        &self.remove_properties
+    }
 }
 
 impl FromProto<protos::product_payload::ProductUpdateAction> for ProductUpdateAction {
@@ -362,6 +397,13 @@ impl FromProto<protos::product_payload::ProductUpdateAction> for ProductUpdateAc
                 .into_iter()
                 .map(PropertyValue::from_proto)
                 .collect::<Result<Vec<PropertyValue>, ProtoConversionError>>()?,
+            set_properties: proto
+                .get_set_properties()
+                .to_vec()
+                .into_iter()
+                .map(PropertyValue::from_proto)
+                .collect::<Result<Vec<PropertyValue>, ProtoConversionError>>()?,
+            remove_properties: proto.get_remove_properties().to_vec(),
         })
     }
 }
@@ -380,6 +422,16 @@ impl FromNative<ProductUpdateAction> for protos::product_payload::ProductUpdateA
                 .collect::<Result<Vec<protos::schema_state::PropertyValue>, ProtoConversionError>>(
                 )?,
         ));
+        proto.set_set_properties(RepeatedField::from_vec(
+            native
+                .set_properties()
+                .to_vec()
+                .into_iter()
+                .map(PropertyValue::into_proto)
+                .collect::<Result<Vec<protos::schema_state::PropertyValue>, ProtoConversionError>>(
+                )?,
+        ));
+        proto.set_remove_properties(RepeatedField::from_vec(native.remove_properties().to_vec()));
 
         Ok(proto)
     }
@@ -418,6 +470,8 @@ pub struct ProductUpdateActionBuilder {
     product_type: Option<ProductType>,
     product_id: Option<String>,
     properties: Vec<PropertyValue>,
+    set_properties: Vec<PropertyValue>,
+    remove_properties: Vec<String>,
 }
 
 impl ProductUpdateActionBuilder {
@@ -440,6 +494,16 @@ impl ProductUpdateActionBuilder {
         self
     }
 
+    pub fn with_set_properties(mut self, set_properties: Vec<PropertyValue>) -> Self {
+        self.set_properties = set_properties;
+        self
+    }
+
+    pub fn with_remove_properties(mut self, remove_properties: Vec<String>) -> Self {
+        self.remove_properties = remove_properties;
+        self
+    }
+
     pub fn build(self) -> Result<ProductUpdateAction, BuilderError> {
         let product_type = self.product_type.ok_or_else(|| {
             BuilderError::MissingField("'product_type' field is required".to_string())
@@ -449,9 +513,19 @@ impl ProductUpdateActionBuilder {
             BuilderError::MissingField("'product_id' field is required".to_string())
         })?;
 
+        let is_patch = !self.set_properties.is_empty() || !self.remove_properties.is_empty();
+
+        // A product is either given a full set of properties or patched
         let properties = {
-            if !self.properties.is_empty() {
+            if !self.properties.is_empty() && !is_patch {
                 self.properties
+            } else if self.properties.is_empty() && is_patch {
+                vec![]
+            } else if is_patch {
+                return Err(BuilderError::InvalidField(
+                    "'properties' cannot be combined with 'set_properties' or 'remove_properties'"
+                        .to_string(),
+                ));
             } else {
                 return Err(BuilderError::MissingField(
                     "'properties' field is required".to_string(),
@@ -463,6 +537,8 @@ impl ProductUpdateActionBuilder {
             product_type,
             product_id,
             properties,
+            set_properties: self.set_properties,
+            remove_properties: self.remove_properties,
         })
     }
 }
@@ -569,6 +645,126 @@ impl ProductDeleteActionBuilder {
     }
 }
 
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ProductTransferAction {
+    product_type: ProductType,
+    product_id: String,
+    new_owner: String,
+}
+
+/// Native implementation for ProductTransferAction
+impl ProductTransferAction {
+    pub fn product_type(&self) -> &ProductType {
+        &self.product_type
+    }
+
+    pub fn product_id(&self) -> &str {
+        &self.product_id
+    }
+
+    pub fn new_owner(&self) -> &str {
+        &self.new_owner
+    }
+}
+
+impl FromProto<protos::product_payload::ProductTransferAction> for ProductTransferAction {
+    fn from_proto(
+        proto: protos::product_payload::ProductTransferAction,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(ProductTransferAction {
+            product_type: ProductType::from_proto(proto.get_product_type())?,
+            product_id: proto.get_product_id().to_string(),
+            new_owner: proto.get_new_owner().to_string(),
+        })
+    }
+}
+
+impl FromNative<ProductTransferAction> for protos::product_payload::ProductTransferAction {
+    fn from_native(native: ProductTransferAction) -> Result<Self, ProtoConversionError> {
+        let mut proto = protos::product_payload::ProductTransferAction::new();
+        proto.set_product_type(native.product_type().clone().into_proto()?);
+        proto.set_product_id(native.product_id().to_string());
+        proto.set_new_owner(native.new_owner().to_string());
+        Ok(proto)
+    }
+}
+
+impl FromBytes<ProductTransferAction> for ProductTransferAction {
+    fn from_bytes(bytes: &[u8]) -> Result<ProductTransferAction, ProtoConversionError> {
+        let proto: protos::product_payload::ProductTransferAction =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get ProductTransferAction from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for ProductTransferAction {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from ProductTransferAction".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::product_payload::ProductTransferAction> for ProductTransferAction {}
+impl IntoNative<ProductTransferAction> for protos::product_payload::ProductTransferAction {}
+
+/// Builder used to create a ProductTransferAction
+#[derive(Default, Clone)]
+pub struct ProductTransferActionBuilder {
+    product_type: Option<ProductType>,
+    product_id: Option<String>,
+    new_owner: Option<String>,
+}
+
+impl ProductTransferActionBuilder {
+    pub fn new() -> Self {
+        ProductTransferActionBuilder::default()
+    }
+
+    pub fn with_product_type(mut self, product_type: ProductType) -> Self {
+        self.product_type = Some(product_type);
+        self
+    }
+
+    pub fn with_product_id(mut self, product_id: String) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    pub fn with_new_owner(mut self, new_owner: String) -> Self {
+        self.new_owner = Some(new_owner);
+        self
+    }
+
+    pub fn build(self) -> Result<ProductTransferAction, BuilderError> {
+        let product_type = self.product_type.ok_or_else(|| {
+            BuilderError::MissingField("'product_type' field is required".to_string())
+        })?;
+
+        let product_id = self.product_id.ok_or_else(|| {
+            BuilderError::MissingField("'product_id' field is required".to_string())
+        })?;
+
+        let new_owner = self.new_owner.ok_or_else(|| {
+            BuilderError::MissingField("'new_owner' field is required".to_string())
+        })?;
+
+        Ok(ProductTransferAction {
+            product_type,
+            product_id,
+            new_owner,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -650,6 +846,40 @@ mod tests {
         test_from_bytes(action, ProductUpdateAction::from_bytes);
     }
 
+    #[test]
+    // Test that a product update action can patch properties and be converted to bytes and back
+    fn test_product_update_patch_into_bytes() {
+        let action = ProductUpdateActionBuilder::new()
+            .with_product_id("688955434684".into()) // GTIN-12
+            .with_product_type(ProductType::GS1)
+            .with_set_properties(make_properties())
+            .with_remove_properties(vec!["color".into()])
+            .build()
+            .unwrap();
+
+        assert!(action.properties().is_empty());
+        assert_eq!(action.set_properties(), &make_properties()[..]);
+        assert_eq!(action.remove_properties(), &["color".to_string()]);
+
+        test_from_bytes(action, ProductUpdateAction::from_bytes);
+    }
+
+    #[test]
+    // Test that a product update action cannot both replace and patch properties
+    fn test_product_update_builder_replace_and_patch() {
+        let result = ProductUpdateActionBuilder::new()
+            .with_product_id("688955434684".into()) // GTIN-12
+            .with_product_type(ProductType::GS1)
+            .with_properties(make_properties())
+            .with_remove_properties(vec!["color".into()])
+            .build();
+
+        match result {
+            Err(BuilderError::InvalidField(_)) => (),
+            res => panic!("Expected InvalidField error, got {:?}", res),
+        }
+    }
+
     #[test]
     // Test that a product delete action can be built correctly
     fn test_product_delete_builder() {
@@ -675,6 +905,34 @@ mod tests {
         test_from_bytes(action, ProductDeleteAction::from_bytes);
     }
 
+    #[test]
+    // Test that a product transfer action can be built correctly
+    fn test_product_transfer_builder() {
+        let action = ProductTransferActionBuilder::new()
+            .with_product_id("688955434684".into()) // GTIN-12
+            .with_product_type(ProductType::GS1)
+            .with_new_owner("Cargill".into())
+            .build()
+            .unwrap();
+
+        assert_eq!(action.product_id(), "688955434684");
+        assert_eq!(*action.product_type(), ProductType::GS1);
+        assert_eq!(action.new_owner(), "Cargill");
+    }
+
+    #[test]
+    // Test that a product transfer action can be converted to bytes and back
+    fn test_product_transfer_into_bytes() {
+        let action = ProductTransferActionBuilder::new()
+            .with_product_id("688955434684".into()) // GTIN-12
+            .with_product_type(ProductType::GS1)
+            .with_new_owner("Cargill".into())
+            .build()
+            .unwrap();
+
+        test_from_bytes(action, ProductTransferAction::from_bytes);
+    }
+
     #[test]
     // Test that a product payload can be built correctly
     fn test_product_payload_builder() {
@@ -29,6 +29,8 @@ use crate::protocol::schema::state::PropertyValue;
 #[derive(Debug, Clone, PartialEq)]
 pub enum ProductType {
     GS1,
+    GLN,
+    SSCC,
 }
 
 impl Default for ProductType {
@@ -43,6 +45,8 @@ impl FromProto<protos::product_state::Product_ProductType> for ProductType {
     ) -> Result<Self, ProtoConversionError> {
         match product_type {
             protos::product_state::Product_ProductType::GS1 => Ok(ProductType::GS1),
+            protos::product_state::Product_ProductType::GLN => Ok(ProductType::GLN),
+            protos::product_state::Product_ProductType::SSCC => Ok(ProductType::SSCC),
             protos::product_state::Product_ProductType::UNSET_TYPE => {
                 Err(ProtoConversionError::InvalidTypeError(
                     "Cannot convert Product_ProductType with type UNSET_TYPE".to_string(),
@@ -56,6 +60,8 @@ impl FromNative<ProductType> for protos::product_state::Product_ProductType {
     fn from_native(product_type: ProductType) -> Result<Self, ProtoConversionError> {
         match product_type {
             ProductType::GS1 => Ok(protos::product_state::Product_ProductType::GS1),
+            ProductType::GLN => Ok(protos::product_state::Product_ProductType::GLN),
+            ProductType::SSCC => Ok(protos::product_state::Product_ProductType::SSCC),
         }
     }
 }
@@ -70,6 +76,9 @@ pub struct Product {
     product_type: ProductType,
     owner: String,
     properties: Vec<PropertyValue>,
+    schema: String,
+    pending_owner: String,
+    schema_version: u32,
 }
 
 impl Product {
@@ -89,12 +98,33 @@ impl Product {
         &self.properties
     }
 
+    /// The name of the schema the product's properties conform to; empty if
+    /// the product is not bound to a schema
+    pub fn schema(&self) -> &str {
+        &self.schema
+    }
+
+    /// The organization the owner has offered the product to; empty if no
+    /// transfer is pending
+    pub fn pending_owner(&self) -> &str {
+        &self.pending_owner
+    }
+
+    /// The version of the schema the properties were last validated against;
+    /// 0 if the product is not bound to a schema
+    pub fn schema_version(&self) -> &u32 {
+        &self.schema_version
+    }
+
     pub fn into_builder(self) -> ProductBuilder {
         ProductBuilder::new()
             .with_product_id(self.product_id)
             .with_product_type(self.product_type)
             .with_owner(self.owner)
             .with_properties(self.properties)
+            .with_schema(self.schema)
+            .with_pending_owner(self.pending_owner)
+            .with_schema_version(self.schema_version)
     }
 }
 
@@ -110,6 +140,9 @@ impl FromProto<protos::product_state::Product> for Product {
                 .into_iter()
                 .map(PropertyValue::from_proto)
                 .collect::<Result<Vec<PropertyValue>, ProtoConversionError>>()?,
+            schema: product.get_schema().to_string(),
+            pending_owner: product.get_pending_owner().to_string(),
+            schema_version: product.get_schema_version(),
         })
     }
 }
@@ -128,6 +161,9 @@ impl FromNative<Product> for protos::product_state::Product {
                 .map(PropertyValue::into_proto)
                 .collect::<Result<Vec<schema_state::PropertyValue>, ProtoConversionError>>()?,
         ));
+        proto.set_schema(product.schema().to_string());
+        proto.set_pending_owner(product.pending_owner().to_string());
+        proto.set_schema_version(*product.schema_version());
         Ok(proto)
     }
 }
@@ -195,6 +231,9 @@ pub struct ProductBuilder {
     pub product_type: Option<ProductType>,
     pub owner: Option<String>,
     pub properties: Option<Vec<PropertyValue>>,
+    pub schema: Option<String>,
+    pub pending_owner: Option<String>,
+    pub schema_version: Option<u32>,
 }
 
 impl ProductBuilder {
@@ -222,6 +261,21 @@ impl ProductBuilder {
         self
     }
 
+    pub fn with_schema(mut self, schema: String) -> Self {
+        self.schema = Some(schema);
+        self
+    }
+
+    pub fn with_pending_owner(mut self, pending_owner: String) -> Self {
+        self.pending_owner = Some(pending_owner);
+        self
+    }
+
+    pub fn with_schema_version(mut self, schema_version: u32) -> Self {
+        self.schema_version = Some(schema_version);
+        self
+    }
+
     pub fn build(self) -> Result<Product, ProductBuildError> {
         let product_id = self.product_id.ok_or_else(|| {
             ProductBuildError::MissingField("'product_id' field is required".to_string())
@@ -240,11 +294,18 @@ impl ProductBuilder {
             ProductBuildError::MissingField("'properties' field is required".to_string())
         })?;
 
+        let schema = self.schema.unwrap_or_default();
+        let pending_owner = self.pending_owner.unwrap_or_default();
+        let schema_version = self.schema_version.unwrap_or_default();
+
         Ok(Product {
             product_id,
             product_type,
             owner,
             properties,
+            schema,
+            pending_owner,
+            schema_version,
         })
     }
 }
@@ -423,6 +484,9 @@ mod tests {
         assert_eq!(builder.product_type, Some(ProductType::GS1));
         assert_eq!(builder.owner, Some("Target".to_string()));
         assert_eq!(builder.properties, Some(make_properties()));
+        assert_eq!(builder.schema, Some(String::new()));
+        assert_eq!(builder.pending_owner, Some(String::new()));
+        assert_eq!(builder.schema_version, Some(0));
     }
 
     #[test]
@@ -434,6 +498,9 @@ mod tests {
             .with_product_type(ProductType::GS1)
             .with_owner("Target".into())
             .with_properties(make_properties())
+            .with_schema("product_schema".into())
+            .with_pending_owner("Cargill".into())
+            .with_schema_version(2)
             .build()
             .unwrap();
 
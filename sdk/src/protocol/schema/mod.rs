@@ -14,3 +14,4 @@
 
 pub mod payload;
 pub mod state;
+pub mod validation;
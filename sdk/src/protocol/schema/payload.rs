@@ -28,6 +28,9 @@ use crate::protos::{
 pub enum Action {
     SchemaCreate(SchemaCreateAction),
     SchemaUpdate(SchemaUpdateAction),
+    SchemaAddEditor(SchemaAddEditorAction),
+    SchemaRemoveEditor(SchemaRemoveEditorAction),
+    SchemaTransfer(SchemaTransferAction),
 }
 
 /// Native implementation for SchemaPayload
@@ -53,6 +56,21 @@ impl FromProto<protos::schema_payload::SchemaPayload> for SchemaPayload {
             protos::schema_payload::SchemaPayload_Action::SCHEMA_UPDATE => Action::SchemaUpdate(
                 SchemaUpdateAction::from_proto(payload.get_schema_update().clone())?,
             ),
+            protos::schema_payload::SchemaPayload_Action::SCHEMA_ADD_EDITOR => {
+                Action::SchemaAddEditor(SchemaAddEditorAction::from_proto(
+                    payload.get_schema_add_editor().clone(),
+                )?)
+            }
+            protos::schema_payload::SchemaPayload_Action::SCHEMA_REMOVE_EDITOR => {
+                Action::SchemaRemoveEditor(SchemaRemoveEditorAction::from_proto(
+                    payload.get_schema_remove_editor().clone(),
+                )?)
+            }
+            protos::schema_payload::SchemaPayload_Action::SCHEMA_TRANSFER => {
+                Action::SchemaTransfer(SchemaTransferAction::from_proto(
+                    payload.get_schema_transfer().clone(),
+                )?)
+            }
             protos::schema_payload::SchemaPayload_Action::UNSET_ACTION => {
                 return Err(ProtoConversionError::InvalidTypeError(
                     "Cannot convert SchemaPayload_Action with type unset.".to_string(),
@@ -77,6 +95,21 @@ impl FromNative<SchemaPayload> for protos::schema_payload::SchemaPayload {
                     .set_action(protos::schema_payload::SchemaPayload_Action::SCHEMA_UPDATE);
                 proto_payload.set_schema_update(payload.clone().into_proto()?);
             }
+            Action::SchemaAddEditor(payload) => {
+                proto_payload
+                    .set_action(protos::schema_payload::SchemaPayload_Action::SCHEMA_ADD_EDITOR);
+                proto_payload.set_schema_add_editor(payload.clone().into_proto()?);
+            }
+            Action::SchemaRemoveEditor(payload) => {
+                proto_payload
+                    .set_action(protos::schema_payload::SchemaPayload_Action::SCHEMA_REMOVE_EDITOR);
+                proto_payload.set_schema_remove_editor(payload.clone().into_proto()?);
+            }
+            Action::SchemaTransfer(payload) => {
+                proto_payload
+                    .set_action(protos::schema_payload::SchemaPayload_Action::SCHEMA_TRANSFER);
+                proto_payload.set_schema_transfer(payload.clone().into_proto()?);
+            }
         }
         Ok(proto_payload)
     }
@@ -326,6 +359,9 @@ impl SchemaCreateBuilder {
 pub struct SchemaUpdateAction {
     schema_name: String,
     properties: Vec<PropertyDefinition>,
+    deprecate_properties: Vec<String>,
+    optional_properties: Vec<String>,
+    add_enum_options: Vec<EnumOptionsUpdate>,
 }
 
 /// Native implementation for SchemaUpdateAction
@@ -337,6 +373,18 @@ impl SchemaUpdateAction {
     pub fn properties(&self) -> &[PropertyDefinition] {
         &self.properties
     }
+
+    pub fn deprecate_properties(&self) -> &[String] {
+        &self.deprecate_properties
+    }
+
+    pub fn optional_properties(&self) -> &[String] {
+        &self.optional_properties
+    }
+
+    pub fn add_enum_options(&self) -> &[EnumOptionsUpdate] {
+        &self.add_enum_options
+    }
 }
 
 impl FromProto<protos::schema_payload::SchemaUpdateAction> for SchemaUpdateAction {
@@ -351,6 +399,14 @@ impl FromProto<protos::schema_payload::SchemaUpdateAction> for SchemaUpdateActio
                 .into_iter()
                 .map(PropertyDefinition::from_proto)
                 .collect::<Result<Vec<PropertyDefinition>, ProtoConversionError>>()?,
+            deprecate_properties: schema_update.get_deprecate_properties().to_vec(),
+            optional_properties: schema_update.get_optional_properties().to_vec(),
+            add_enum_options: schema_update
+                .get_add_enum_options()
+                .to_vec()
+                .into_iter()
+                .map(EnumOptionsUpdate::from_proto)
+                .collect::<Result<Vec<EnumOptionsUpdate>, ProtoConversionError>>()?,
         })
     }
 }
@@ -365,6 +421,20 @@ impl FromNative<SchemaUpdateAction> for protos::schema_payload::SchemaUpdateActi
             schema_update.properties().to_vec().into_iter()
             .map(PropertyDefinition::into_proto)
             .collect::<Result<Vec<protos::schema_state::PropertyDefinition>, ProtoConversionError>>()?,));
+        proto_schema_update.set_deprecate_properties(RepeatedField::from_vec(
+            schema_update.deprecate_properties().to_vec(),
+        ));
+        proto_schema_update.set_optional_properties(RepeatedField::from_vec(
+            schema_update.optional_properties().to_vec(),
+        ));
+        proto_schema_update.set_add_enum_options(RepeatedField::from_vec(
+            schema_update
+                .add_enum_options()
+                .to_vec()
+                .into_iter()
+                .map(EnumOptionsUpdate::into_proto)
+                .collect::<Result<Vec<protos::schema_payload::EnumOptionsUpdate>, ProtoConversionError>>()?,
+        ));
 
         Ok(proto_schema_update)
     }
@@ -430,6 +500,9 @@ pub struct SchemaUpdateBuilder {
     schema_name: Option<String>,
     description: Option<String>,
     properties: Vec<PropertyDefinition>,
+    deprecate_properties: Vec<String>,
+    optional_properties: Vec<String>,
+    add_enum_options: Vec<EnumOptionsUpdate>,
 }
 
 impl SchemaUpdateBuilder {
@@ -447,24 +520,565 @@ impl SchemaUpdateBuilder {
         self
     }
 
+    pub fn with_deprecate_properties(
+        mut self,
+        deprecate_properties: Vec<String>,
+    ) -> SchemaUpdateBuilder {
+        self.deprecate_properties = deprecate_properties;
+        self
+    }
+
+    pub fn with_optional_properties(
+        mut self,
+        optional_properties: Vec<String>,
+    ) -> SchemaUpdateBuilder {
+        self.optional_properties = optional_properties;
+        self
+    }
+
+    pub fn with_add_enum_options(
+        mut self,
+        add_enum_options: Vec<EnumOptionsUpdate>,
+    ) -> SchemaUpdateBuilder {
+        self.add_enum_options = add_enum_options;
+        self
+    }
+
     pub fn build(self) -> Result<SchemaUpdateAction, SchemaUpdateBuildError> {
         let schema_name = self.schema_name.ok_or_else(|| {
             SchemaUpdateBuildError::MissingField("'schema field is required".to_string())
         })?;
 
-        let properties = {
-            if !self.properties.is_empty() {
-                self.properties
-            } else {
-                return Err(SchemaUpdateBuildError::MissingField(
-                    "'properties' field is required".to_string(),
-                ));
-            }
-        };
+        if self.properties.is_empty()
+            && self.deprecate_properties.is_empty()
+            && self.optional_properties.is_empty()
+            && self.add_enum_options.is_empty()
+        {
+            return Err(SchemaUpdateBuildError::MissingField(
+                "one of 'properties', 'deprecate_properties', 'optional_properties' or \
+                 'add_enum_options' is required"
+                    .to_string(),
+            ));
+        }
 
         Ok(SchemaUpdateAction {
             schema_name,
-            properties,
+            properties: self.properties,
+            deprecate_properties: self.deprecate_properties,
+            optional_properties: self.optional_properties,
+            add_enum_options: self.add_enum_options,
+        })
+    }
+}
+
+/// Native implementation for SchemaAddEditorAction
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SchemaAddEditorAction {
+    schema_name: String,
+    editor: String,
+}
+
+impl SchemaAddEditorAction {
+    pub fn schema_name(&self) -> &str {
+        &self.schema_name
+    }
+
+    pub fn editor(&self) -> &str {
+        &self.editor
+    }
+}
+
+impl FromProto<protos::schema_payload::SchemaAddEditorAction> for SchemaAddEditorAction {
+    fn from_proto(
+        schema_add_editor: protos::schema_payload::SchemaAddEditorAction,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(SchemaAddEditorAction {
+            schema_name: schema_add_editor.get_schema_name().to_string(),
+            editor: schema_add_editor.get_editor().to_string(),
+        })
+    }
+}
+
+impl FromNative<SchemaAddEditorAction> for protos::schema_payload::SchemaAddEditorAction {
+    fn from_native(schema_add_editor: SchemaAddEditorAction) -> Result<Self, ProtoConversionError> {
+        let mut proto_schema_add_editor = protos::schema_payload::SchemaAddEditorAction::new();
+
+        proto_schema_add_editor.set_schema_name(schema_add_editor.schema_name().to_string());
+        proto_schema_add_editor.set_editor(schema_add_editor.editor().to_string());
+
+        Ok(proto_schema_add_editor)
+    }
+}
+
+impl FromBytes<SchemaAddEditorAction> for SchemaAddEditorAction {
+    fn from_bytes(bytes: &[u8]) -> Result<SchemaAddEditorAction, ProtoConversionError> {
+        let proto: protos::schema_payload::SchemaAddEditorAction =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get SchemaAddEditorAction from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for SchemaAddEditorAction {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from SchemaAddEditorAction".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::schema_payload::SchemaAddEditorAction> for SchemaAddEditorAction {}
+impl IntoNative<SchemaAddEditorAction> for protos::schema_payload::SchemaAddEditorAction {}
+
+#[derive(Debug)]
+pub enum SchemaAddEditorBuildError {
+    MissingField(String),
+}
+
+impl StdError for SchemaAddEditorBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            SchemaAddEditorBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn StdError> {
+        match *self {
+            SchemaAddEditorBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for SchemaAddEditorBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            SchemaAddEditorBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a SchemaAddEditorAction
+#[derive(Default, Clone)]
+pub struct SchemaAddEditorBuilder {
+    schema_name: Option<String>,
+    editor: Option<String>,
+}
+
+impl SchemaAddEditorBuilder {
+    pub fn new() -> Self {
+        SchemaAddEditorBuilder::default()
+    }
+
+    pub fn with_schema_name(mut self, schema_name: String) -> SchemaAddEditorBuilder {
+        self.schema_name = Some(schema_name);
+        self
+    }
+
+    pub fn with_editor(mut self, editor: String) -> SchemaAddEditorBuilder {
+        self.editor = Some(editor);
+        self
+    }
+
+    pub fn build(self) -> Result<SchemaAddEditorAction, SchemaAddEditorBuildError> {
+        let schema_name = self.schema_name.ok_or_else(|| {
+            SchemaAddEditorBuildError::MissingField("'schema_name' field is required".to_string())
+        })?;
+
+        let editor = self.editor.ok_or_else(|| {
+            SchemaAddEditorBuildError::MissingField("'editor' field is required".to_string())
+        })?;
+
+        Ok(SchemaAddEditorAction {
+            schema_name,
+            editor,
+        })
+    }
+}
+
+/// Native implementation for SchemaRemoveEditorAction
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SchemaRemoveEditorAction {
+    schema_name: String,
+    editor: String,
+}
+
+impl SchemaRemoveEditorAction {
+    pub fn schema_name(&self) -> &str {
+        &self.schema_name
+    }
+
+    pub fn editor(&self) -> &str {
+        &self.editor
+    }
+}
+
+impl FromProto<protos::schema_payload::SchemaRemoveEditorAction> for SchemaRemoveEditorAction {
+    fn from_proto(
+        schema_remove_editor: protos::schema_payload::SchemaRemoveEditorAction,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(SchemaRemoveEditorAction {
+            schema_name: schema_remove_editor.get_schema_name().to_string(),
+            editor: schema_remove_editor.get_editor().to_string(),
+        })
+    }
+}
+
+impl FromNative<SchemaRemoveEditorAction> for protos::schema_payload::SchemaRemoveEditorAction {
+    fn from_native(
+        schema_remove_editor: SchemaRemoveEditorAction,
+    ) -> Result<Self, ProtoConversionError> {
+        let mut proto_schema_remove_editor =
+            protos::schema_payload::SchemaRemoveEditorAction::new();
+
+        proto_schema_remove_editor.set_schema_name(schema_remove_editor.schema_name().to_string());
+        proto_schema_remove_editor.set_editor(schema_remove_editor.editor().to_string());
+
+        Ok(proto_schema_remove_editor)
+    }
+}
+
+impl FromBytes<SchemaRemoveEditorAction> for SchemaRemoveEditorAction {
+    fn from_bytes(bytes: &[u8]) -> Result<SchemaRemoveEditorAction, ProtoConversionError> {
+        let proto: protos::schema_payload::SchemaRemoveEditorAction =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get SchemaRemoveEditorAction from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for SchemaRemoveEditorAction {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from SchemaRemoveEditorAction".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::schema_payload::SchemaRemoveEditorAction> for SchemaRemoveEditorAction {}
+impl IntoNative<SchemaRemoveEditorAction> for protos::schema_payload::SchemaRemoveEditorAction {}
+
+#[derive(Debug)]
+pub enum SchemaRemoveEditorBuildError {
+    MissingField(String),
+}
+
+impl StdError for SchemaRemoveEditorBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            SchemaRemoveEditorBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn StdError> {
+        match *self {
+            SchemaRemoveEditorBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for SchemaRemoveEditorBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            SchemaRemoveEditorBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a SchemaRemoveEditorAction
+#[derive(Default, Clone)]
+pub struct SchemaRemoveEditorBuilder {
+    schema_name: Option<String>,
+    editor: Option<String>,
+}
+
+impl SchemaRemoveEditorBuilder {
+    pub fn new() -> Self {
+        SchemaRemoveEditorBuilder::default()
+    }
+
+    pub fn with_schema_name(mut self, schema_name: String) -> SchemaRemoveEditorBuilder {
+        self.schema_name = Some(schema_name);
+        self
+    }
+
+    pub fn with_editor(mut self, editor: String) -> SchemaRemoveEditorBuilder {
+        self.editor = Some(editor);
+        self
+    }
+
+    pub fn build(self) -> Result<SchemaRemoveEditorAction, SchemaRemoveEditorBuildError> {
+        let schema_name = self.schema_name.ok_or_else(|| {
+            SchemaRemoveEditorBuildError::MissingField(
+                "'schema_name' field is required".to_string(),
+            )
+        })?;
+
+        let editor = self.editor.ok_or_else(|| {
+            SchemaRemoveEditorBuildError::MissingField("'editor' field is required".to_string())
+        })?;
+
+        Ok(SchemaRemoveEditorAction {
+            schema_name,
+            editor,
+        })
+    }
+}
+
+/// Native implementation for SchemaTransferAction
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SchemaTransferAction {
+    schema_name: String,
+    new_owner: String,
+}
+
+impl SchemaTransferAction {
+    pub fn schema_name(&self) -> &str {
+        &self.schema_name
+    }
+
+    pub fn new_owner(&self) -> &str {
+        &self.new_owner
+    }
+}
+
+impl FromProto<protos::schema_payload::SchemaTransferAction> for SchemaTransferAction {
+    fn from_proto(
+        schema_transfer: protos::schema_payload::SchemaTransferAction,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(SchemaTransferAction {
+            schema_name: schema_transfer.get_schema_name().to_string(),
+            new_owner: schema_transfer.get_new_owner().to_string(),
+        })
+    }
+}
+
+impl FromNative<SchemaTransferAction> for protos::schema_payload::SchemaTransferAction {
+    fn from_native(schema_transfer: SchemaTransferAction) -> Result<Self, ProtoConversionError> {
+        let mut proto_schema_transfer = protos::schema_payload::SchemaTransferAction::new();
+
+        proto_schema_transfer.set_schema_name(schema_transfer.schema_name().to_string());
+        proto_schema_transfer.set_new_owner(schema_transfer.new_owner().to_string());
+
+        Ok(proto_schema_transfer)
+    }
+}
+
+impl FromBytes<SchemaTransferAction> for SchemaTransferAction {
+    fn from_bytes(bytes: &[u8]) -> Result<SchemaTransferAction, ProtoConversionError> {
+        let proto: protos::schema_payload::SchemaTransferAction = protobuf::parse_from_bytes(bytes)
+            .map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get SchemaTransferAction from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for SchemaTransferAction {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from SchemaTransferAction".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::schema_payload::SchemaTransferAction> for SchemaTransferAction {}
+impl IntoNative<SchemaTransferAction> for protos::schema_payload::SchemaTransferAction {}
+
+#[derive(Debug)]
+pub enum SchemaTransferBuildError {
+    MissingField(String),
+}
+
+impl StdError for SchemaTransferBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            SchemaTransferBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn StdError> {
+        match *self {
+            SchemaTransferBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for SchemaTransferBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            SchemaTransferBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a SchemaTransferAction
+#[derive(Default, Clone)]
+pub struct SchemaTransferBuilder {
+    schema_name: Option<String>,
+    new_owner: Option<String>,
+}
+
+impl SchemaTransferBuilder {
+    pub fn new() -> Self {
+        SchemaTransferBuilder::default()
+    }
+
+    pub fn with_schema_name(mut self, schema_name: String) -> SchemaTransferBuilder {
+        self.schema_name = Some(schema_name);
+        self
+    }
+
+    pub fn with_new_owner(mut self, new_owner: String) -> SchemaTransferBuilder {
+        self.new_owner = Some(new_owner);
+        self
+    }
+
+    pub fn build(self) -> Result<SchemaTransferAction, SchemaTransferBuildError> {
+        let schema_name = self.schema_name.ok_or_else(|| {
+            SchemaTransferBuildError::MissingField("'schema_name' field is required".to_string())
+        })?;
+
+        let new_owner = self.new_owner.ok_or_else(|| {
+            SchemaTransferBuildError::MissingField("'new_owner' field is required".to_string())
+        })?;
+
+        Ok(SchemaTransferAction {
+            schema_name,
+            new_owner,
+        })
+    }
+}
+
+/// Native implementation for EnumOptionsUpdate
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct EnumOptionsUpdate {
+    property_name: String,
+    enum_options: Vec<String>,
+}
+
+impl EnumOptionsUpdate {
+    pub fn property_name(&self) -> &str {
+        &self.property_name
+    }
+
+    pub fn enum_options(&self) -> &[String] {
+        &self.enum_options
+    }
+}
+
+impl FromProto<protos::schema_payload::EnumOptionsUpdate> for EnumOptionsUpdate {
+    fn from_proto(
+        enum_options_update: protos::schema_payload::EnumOptionsUpdate,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(EnumOptionsUpdate {
+            property_name: enum_options_update.get_property_name().to_string(),
+            enum_options: enum_options_update.get_enum_options().to_vec(),
+        })
+    }
+}
+
+impl FromNative<EnumOptionsUpdate> for protos::schema_payload::EnumOptionsUpdate {
+    fn from_native(enum_options_update: EnumOptionsUpdate) -> Result<Self, ProtoConversionError> {
+        let mut proto_enum_options_update = protos::schema_payload::EnumOptionsUpdate::new();
+
+        proto_enum_options_update
+            .set_property_name(enum_options_update.property_name().to_string());
+        proto_enum_options_update.set_enum_options(RepeatedField::from_vec(
+            enum_options_update.enum_options().to_vec(),
+        ));
+
+        Ok(proto_enum_options_update)
+    }
+}
+
+impl IntoProto<protos::schema_payload::EnumOptionsUpdate> for EnumOptionsUpdate {}
+impl IntoNative<EnumOptionsUpdate> for protos::schema_payload::EnumOptionsUpdate {}
+
+#[derive(Debug)]
+pub enum EnumOptionsUpdateBuildError {
+    MissingField(String),
+}
+
+impl StdError for EnumOptionsUpdateBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            EnumOptionsUpdateBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn StdError> {
+        match *self {
+            EnumOptionsUpdateBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for EnumOptionsUpdateBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            EnumOptionsUpdateBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create an EnumOptionsUpdate
+#[derive(Default, Clone)]
+pub struct EnumOptionsUpdateBuilder {
+    property_name: Option<String>,
+    enum_options: Vec<String>,
+}
+
+impl EnumOptionsUpdateBuilder {
+    pub fn new() -> Self {
+        EnumOptionsUpdateBuilder::default()
+    }
+
+    pub fn with_property_name(mut self, property_name: String) -> EnumOptionsUpdateBuilder {
+        self.property_name = Some(property_name);
+        self
+    }
+
+    pub fn with_enum_options(mut self, enum_options: Vec<String>) -> EnumOptionsUpdateBuilder {
+        self.enum_options = enum_options;
+        self
+    }
+
+    pub fn build(self) -> Result<EnumOptionsUpdate, EnumOptionsUpdateBuildError> {
+        let property_name = self.property_name.ok_or_else(|| {
+            EnumOptionsUpdateBuildError::MissingField(
+                "'property_name' field is required".to_string(),
+            )
+        })?;
+
+        if self.enum_options.is_empty() {
+            return Err(EnumOptionsUpdateBuildError::MissingField(
+                "'enum_options' field is required".to_string(),
+            ));
+        }
+
+        Ok(EnumOptionsUpdate {
+            property_name,
+            enum_options: self.enum_options,
         })
     }
 }
@@ -569,6 +1183,95 @@ mod tests {
         assert_eq!(update, original);
     }
 
+    #[test]
+    // check that a schema update action which only changes existing properties is built
+    // correctly and survives conversion to bytes and back
+    fn check_schema_update_changes_existing_properties() {
+        let enum_options_update = EnumOptionsUpdateBuilder::new()
+            .with_property_name("COLOR".to_string())
+            .with_enum_options(vec!["Green".to_string()])
+            .build()
+            .unwrap();
+
+        let original = SchemaUpdateBuilder::new()
+            .with_schema_name("TestSchema".to_string())
+            .with_deprecate_properties(vec!["OLD".to_string()])
+            .with_optional_properties(vec!["TEST".to_string()])
+            .with_add_enum_options(vec![enum_options_update.clone()])
+            .build()
+            .unwrap();
+
+        assert!(original.properties.is_empty());
+        assert_eq!(original.deprecate_properties, vec!["OLD".to_string()]);
+        assert_eq!(original.optional_properties, vec!["TEST".to_string()]);
+        assert_eq!(original.add_enum_options, vec![enum_options_update]);
+
+        let bytes = original.clone().into_bytes().unwrap();
+        let update = SchemaUpdateAction::from_bytes(&bytes).unwrap();
+        assert_eq!(update, original);
+    }
+
+    #[test]
+    // check that a schema update action must change something
+    fn check_schema_update_action_empty() {
+        assert!(SchemaUpdateBuilder::new()
+            .with_schema_name("TestSchema".to_string())
+            .build()
+            .is_err());
+
+        assert!(EnumOptionsUpdateBuilder::new()
+            .with_property_name("COLOR".to_string())
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    // check that the schema editor and transfer actions are built correctly and survive
+    // conversion to bytes and back
+    fn check_schema_editor_and_transfer_actions() {
+        let add_editor = SchemaAddEditorBuilder::new()
+            .with_schema_name("TestSchema".to_string())
+            .with_editor("editor_org".to_string())
+            .build()
+            .unwrap();
+        assert_eq!(add_editor.schema_name, "TestSchema");
+        assert_eq!(add_editor.editor, "editor_org");
+        let bytes = add_editor.clone().into_bytes().unwrap();
+        assert_eq!(
+            SchemaAddEditorAction::from_bytes(&bytes).unwrap(),
+            add_editor
+        );
+
+        let remove_editor = SchemaRemoveEditorBuilder::new()
+            .with_schema_name("TestSchema".to_string())
+            .with_editor("editor_org".to_string())
+            .build()
+            .unwrap();
+        let bytes = remove_editor.clone().into_bytes().unwrap();
+        assert_eq!(
+            SchemaRemoveEditorAction::from_bytes(&bytes).unwrap(),
+            remove_editor
+        );
+
+        let transfer = SchemaTransferBuilder::new()
+            .with_schema_name("TestSchema".to_string())
+            .with_new_owner("new_org".to_string())
+            .build()
+            .unwrap();
+        assert_eq!(transfer.new_owner, "new_org");
+        let payload = SchemaPayloadBuilder::new()
+            .with_action(Action::SchemaTransfer(transfer))
+            .build()
+            .unwrap();
+        let bytes = payload.clone().into_bytes().unwrap();
+        assert_eq!(SchemaPayload::from_bytes(&bytes).unwrap(), payload);
+
+        assert!(SchemaTransferBuilder::new()
+            .with_schema_name("TestSchema".to_string())
+            .build()
+            .is_err());
+    }
+
     #[test]
     // check that a schema payload with create action is built correctly
     fn check_schema_create_action_payload() {
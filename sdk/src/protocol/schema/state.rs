@@ -178,6 +178,7 @@ pub struct PropertyDefinition {
     data_type: DataType,
     required: bool,
     description: String,
+    deprecated: bool,
     number_exponent: i32,
     enum_options: Vec<String>,
     struct_properties: Vec<PropertyDefinition>,
@@ -200,6 +201,10 @@ impl PropertyDefinition {
         &self.description
     }
 
+    pub fn deprecated(&self) -> &bool {
+        &self.deprecated
+    }
+
     pub fn number_exponent(&self) -> &i32 {
         &self.number_exponent
     }
@@ -211,6 +216,18 @@ impl PropertyDefinition {
     pub fn struct_properties(&self) -> &[PropertyDefinition] {
         &self.struct_properties
     }
+
+    pub fn into_builder(self) -> PropertyDefinitionBuilder {
+        PropertyDefinitionBuilder::new()
+            .with_name(self.name)
+            .with_data_type(self.data_type)
+            .with_required(self.required)
+            .with_description(self.description)
+            .with_deprecated(self.deprecated)
+            .with_number_exponent(self.number_exponent)
+            .with_enum_options(self.enum_options)
+            .with_struct_properties(self.struct_properties)
+    }
 }
 
 impl FromProto<protos::schema_state::PropertyDefinition> for PropertyDefinition {
@@ -222,6 +239,7 @@ impl FromProto<protos::schema_state::PropertyDefinition> for PropertyDefinition
             data_type: DataType::from_proto(property_definition.get_data_type())?,
             required: property_definition.get_required(),
             description: property_definition.get_description().to_string(),
+            deprecated: property_definition.get_deprecated(),
             number_exponent: property_definition.get_number_exponent(),
             enum_options: property_definition.get_enum_options().to_vec(),
             struct_properties: property_definition
@@ -242,6 +260,7 @@ impl FromNative<PropertyDefinition> for protos::schema_state::PropertyDefinition
             .set_data_type(property_definition.data_type().clone().into_proto()?);
         proto_property_definition.set_required(property_definition.required().clone());
         proto_property_definition.set_description(property_definition.description().to_string());
+        proto_property_definition.set_deprecated(*property_definition.deprecated());
         proto_property_definition
             .set_number_exponent(property_definition.number_exponent().clone());
         proto_property_definition.set_enum_options(RepeatedField::from_vec(
@@ -321,6 +340,7 @@ pub struct PropertyDefinitionBuilder {
     pub data_type: Option<DataType>,
     pub required: Option<bool>,
     pub description: Option<String>,
+    pub deprecated: Option<bool>,
     pub number_exponent: Option<i32>,
     pub enum_options: Vec<String>,
     pub struct_properties: Vec<PropertyDefinition>,
@@ -351,6 +371,11 @@ impl PropertyDefinitionBuilder {
         self
     }
 
+    pub fn with_deprecated(mut self, deprecated: bool) -> PropertyDefinitionBuilder {
+        self.deprecated = Some(deprecated);
+        self
+    }
+
     pub fn with_number_exponent(mut self, number_exponent: i32) -> PropertyDefinitionBuilder {
         self.number_exponent = Some(number_exponent);
         self
@@ -380,6 +405,7 @@ impl PropertyDefinitionBuilder {
 
         let required = self.required.unwrap_or_else(|| false);
         let description = self.description.unwrap_or_default();
+        let deprecated = self.deprecated.unwrap_or_default();
 
         let number_exponent = {
             if data_type == DataType::Number {
@@ -426,6 +452,7 @@ impl PropertyDefinitionBuilder {
             data_type,
             required,
             description,
+            deprecated,
             number_exponent,
             enum_options,
             struct_properties,
@@ -439,6 +466,8 @@ pub struct Schema {
     name: String,
     description: String,
     owner: String,
+    version: u32,
+    editors: Vec<String>,
     properties: Vec<PropertyDefinition>,
 }
 
@@ -455,9 +484,27 @@ impl Schema {
         &self.owner
     }
 
+    pub fn version(&self) -> &u32 {
+        &self.version
+    }
+
+    pub fn editors(&self) -> &[String] {
+        &self.editors
+    }
+
     pub fn properties(&self) -> &[PropertyDefinition] {
         &self.properties
     }
+
+    pub fn into_builder(self) -> SchemaBuilder {
+        SchemaBuilder::new()
+            .with_name(self.name)
+            .with_description(self.description)
+            .with_owner(self.owner)
+            .with_version(self.version)
+            .with_editors(self.editors)
+            .with_properties(self.properties)
+    }
 }
 
 impl FromProto<protos::schema_state::Schema> for Schema {
@@ -466,6 +513,8 @@ impl FromProto<protos::schema_state::Schema> for Schema {
             name: schema.get_name().to_string(),
             description: schema.get_description().to_string(),
             owner: schema.get_owner().to_string(),
+            version: schema.get_version(),
+            editors: schema.get_editors().to_vec(),
             properties: schema
                 .get_properties()
                 .to_vec()
@@ -482,6 +531,8 @@ impl FromNative<Schema> for protos::schema_state::Schema {
         proto_schema.set_name(schema.name().to_string());
         proto_schema.set_description(schema.description().to_string());
         proto_schema.set_owner(schema.owner().to_string());
+        proto_schema.set_version(*schema.version());
+        proto_schema.set_editors(RepeatedField::from_vec(schema.editors().to_vec()));
         proto_schema.set_properties(RepeatedField::from_vec(
             schema
                 .properties()
@@ -552,6 +603,8 @@ pub struct SchemaBuilder {
     pub name: Option<String>,
     pub description: Option<String>,
     pub owner: Option<String>,
+    pub version: Option<u32>,
+    pub editors: Vec<String>,
     pub properties: Vec<PropertyDefinition>,
 }
 
@@ -575,6 +628,16 @@ impl SchemaBuilder {
         self
     }
 
+    pub fn with_version(mut self, version: u32) -> SchemaBuilder {
+        self.version = Some(version);
+        self
+    }
+
+    pub fn with_editors(mut self, editors: Vec<String>) -> SchemaBuilder {
+        self.editors = editors;
+        self
+    }
+
     pub fn with_properties(mut self, properties: Vec<PropertyDefinition>) -> SchemaBuilder {
         self.properties = properties;
         self
@@ -590,6 +653,7 @@ impl SchemaBuilder {
         })?;
 
         let description = self.description.unwrap_or_else(|| "".to_string());
+        let version = self.version.unwrap_or(1);
         let properties = {
             if !self.properties.is_empty() {
                 self.properties
@@ -604,6 +668,8 @@ impl SchemaBuilder {
             name,
             description,
             owner,
+            version,
+            editors: self.editors,
             properties,
         })
     }
@@ -1189,9 +1255,42 @@ mod tests {
         assert_eq!(schema.name, "TestSchema");
         assert_eq!(schema.description, "Test Schema");
         assert_eq!(schema.owner, "owner");
+        assert_eq!(schema.version, 1);
         assert_eq!(schema.properties, vec![property_definition]);
     }
 
+    #[test]
+    // check that a schema rebuilt from into_builder keeps its version and a deprecated
+    // property definition keeps its flag
+    fn check_schema_into_builder() {
+        let property_definition = PropertyDefinitionBuilder::new()
+            .with_name("TEST".to_string())
+            .with_data_type(DataType::String)
+            .with_deprecated(true)
+            .build()
+            .unwrap();
+
+        assert!(property_definition.deprecated);
+        assert_eq!(
+            property_definition.clone().into_builder().build().unwrap(),
+            property_definition
+        );
+
+        let schema = SchemaBuilder::new()
+            .with_name("TestSchema".to_string())
+            .with_owner("owner".to_string())
+            .with_version(3)
+            .with_editors(vec!["editor".to_string()])
+            .with_properties(vec![property_definition])
+            .build()
+            .unwrap();
+
+        let rebuilt = schema.clone().into_builder().build().unwrap();
+        assert_eq!(rebuilt.version, 3);
+        assert_eq!(rebuilt.editors, vec!["editor".to_string()]);
+        assert_eq!(rebuilt, schema);
+    }
+
     #[test]
     // check that a schema can be converted to bytes and back
     fn check_schema_bytes() {
@@ -0,0 +1,274 @@
+// Copyright 2020 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::error::Error as StdError;
+
+use crate::protocol::schema::state::{DataType, PropertyDefinition, PropertyValue};
+
+#[derive(Debug, PartialEq)]
+pub enum PropertyValidationError {
+    /// Returned when a property marked as required has no value.
+    MissingRequiredProperty(String),
+    /// Returned when a value is provided for a property the schema does not define.
+    UnknownProperty(String),
+    /// Returned when a value does not have the data type of its definition.
+    WrongDataType(String),
+    /// Returned when an enum value is not one of the definition's options.
+    InvalidEnumValue(String),
+}
+
+impl StdError for PropertyValidationError {
+    fn description(&self) -> &str {
+        match *self {
+            PropertyValidationError::MissingRequiredProperty(ref msg) => msg,
+            PropertyValidationError::UnknownProperty(ref msg) => msg,
+            PropertyValidationError::WrongDataType(ref msg) => msg,
+            PropertyValidationError::InvalidEnumValue(ref msg) => msg,
+        }
+    }
+}
+
+impl std::fmt::Display for PropertyValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            PropertyValidationError::MissingRequiredProperty(ref name) => {
+                write!(f, "Required property {} not provided", name)
+            }
+            PropertyValidationError::UnknownProperty(ref name) => {
+                write!(f, "Provided property {} is not in schema", name)
+            }
+            PropertyValidationError::WrongDataType(ref name) => {
+                write!(f, "Value provided for {} is the wrong type", name)
+            }
+            PropertyValidationError::InvalidEnumValue(ref name) => {
+                write!(f, "Value provided for {} is not a valid enum option", name)
+            }
+        }
+    }
+}
+
+/// Checks a set of property values against the property definitions of a schema.
+///
+/// Every required definition that is not deprecated must have a value, every
+/// value must be defined and of the defined data type, enum values must index
+/// one of the enum options, and struct values are checked recursively against
+/// the struct's own property definitions. Nested properties are reported as
+/// `<struct name>.<property name>`.
+///
+/// # Arguments
+///
+/// * `definitions` - The property definitions of the schema.
+/// * `values` - The property values to validate.
+///
+pub fn validate_properties(
+    definitions: &[PropertyDefinition],
+    values: &[PropertyValue],
+) -> Result<(), PropertyValidationError> {
+    validate_properties_at(definitions, values, "")
+}
+
+fn validate_properties_at(
+    definitions: &[PropertyDefinition],
+    values: &[PropertyValue],
+    prefix: &str,
+) -> Result<(), PropertyValidationError> {
+    let provided: HashMap<&str, &PropertyValue> =
+        values.iter().map(|value| (value.name(), value)).collect();
+
+    for definition in definitions {
+        if *definition.required()
+            && !*definition.deprecated()
+            && !provided.contains_key(definition.name())
+        {
+            return Err(PropertyValidationError::MissingRequiredProperty(
+                prefix.to_string() + definition.name(),
+            ));
+        }
+    }
+
+    for value in values {
+        let name = prefix.to_string() + value.name();
+        let definition = definitions
+            .iter()
+            .find(|definition| definition.name() == value.name())
+            .ok_or_else(|| PropertyValidationError::UnknownProperty(name.clone()))?;
+
+        if value.data_type() != definition.data_type() {
+            return Err(PropertyValidationError::WrongDataType(name));
+        }
+
+        match value.data_type() {
+            DataType::Enum => {
+                if *value.enum_value() as usize >= definition.enum_options().len() {
+                    return Err(PropertyValidationError::InvalidEnumValue(name));
+                }
+            }
+            DataType::Struct => validate_properties_at(
+                definition.struct_properties(),
+                value.struct_values(),
+                &format!("{}.", name),
+            )?,
+            _ => (),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::protocol::schema::state::{PropertyDefinitionBuilder, PropertyValueBuilder};
+
+    fn definitions() -> Vec<PropertyDefinition> {
+        let color = PropertyDefinitionBuilder::new()
+            .with_name("color".to_string())
+            .with_data_type(DataType::Enum)
+            .with_enum_options(vec!["red".to_string(), "blue".to_string()])
+            .build()
+            .unwrap();
+        let width = PropertyDefinitionBuilder::new()
+            .with_name("width".to_string())
+            .with_data_type(DataType::Number)
+            .with_number_exponent(0)
+            .with_required(true)
+            .build()
+            .unwrap();
+        let size = PropertyDefinitionBuilder::new()
+            .with_name("size".to_string())
+            .with_data_type(DataType::Struct)
+            .with_struct_properties(vec![width.clone()])
+            .build()
+            .unwrap();
+
+        vec![color, width, size]
+    }
+
+    fn width(value: i64) -> PropertyValue {
+        PropertyValueBuilder::new()
+            .with_name("width".to_string())
+            .with_data_type(DataType::Number)
+            .with_number_value(value)
+            .build()
+            .unwrap()
+    }
+
+    fn color(enum_value: u32) -> PropertyValue {
+        PropertyValueBuilder::new()
+            .with_name("color".to_string())
+            .with_data_type(DataType::Enum)
+            .with_enum_value(enum_value)
+            .build()
+            .unwrap()
+    }
+
+    fn size(struct_values: Vec<PropertyValue>) -> PropertyValue {
+        PropertyValueBuilder::new()
+            .with_name("size".to_string())
+            .with_data_type(DataType::Struct)
+            .with_struct_values(struct_values)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    // check that values matching their definitions are accepted
+    fn check_valid_properties() {
+        let values = vec![width(3), color(1), size(vec![width(4)])];
+        assert_eq!(validate_properties(&definitions(), &values), Ok(()));
+    }
+
+    #[test]
+    // check that a missing required property is rejected
+    fn check_missing_required_property() {
+        assert_eq!(
+            validate_properties(&definitions(), &[color(0)]),
+            Err(PropertyValidationError::MissingRequiredProperty(
+                "width".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    // check that a required property stops being required once it is deprecated, while
+    // values for it are still accepted
+    fn check_deprecated_required_property() {
+        let definitions = definitions()
+            .into_iter()
+            .map(|definition| definition.into_builder().with_deprecated(true).build())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(validate_properties(&definitions, &[color(0)]), Ok(()));
+        assert_eq!(validate_properties(&definitions, &[width(3)]), Ok(()));
+    }
+
+    #[test]
+    // check that a value for an undefined property is rejected
+    fn check_unknown_property() {
+        let height = PropertyValueBuilder::new()
+            .with_name("height".to_string())
+            .with_data_type(DataType::Number)
+            .with_number_value(1)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            validate_properties(&definitions(), &[width(1), height]),
+            Err(PropertyValidationError::UnknownProperty(
+                "height".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    // check that a value of the wrong data type is rejected
+    fn check_wrong_data_type() {
+        let wrong_width = PropertyValueBuilder::new()
+            .with_name("width".to_string())
+            .with_data_type(DataType::String)
+            .with_string_value("wide".to_string())
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            validate_properties(&definitions(), &[wrong_width]),
+            Err(PropertyValidationError::WrongDataType("width".to_string()))
+        );
+    }
+
+    #[test]
+    // check that an enum value outside of the enum options is rejected
+    fn check_invalid_enum_value() {
+        assert_eq!(
+            validate_properties(&definitions(), &[width(1), color(2)]),
+            Err(PropertyValidationError::InvalidEnumValue(
+                "color".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    // check that struct values are validated against the struct's definitions
+    fn check_invalid_struct_value() {
+        assert_eq!(
+            validate_properties(&definitions(), &[width(1), size(vec![color(0)])]),
+            Err(PropertyValidationError::MissingRequiredProperty(
+                "size.width".to_string()
+            ))
+        );
+    }
+}
@@ -27,7 +27,7 @@ use crate::protos::{
     FromBytes, FromNative, FromProto, IntoBytes, IntoNative, IntoProto, ProtoConversionError,
 };
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct CreateRecordAction {
     record_id: String,
     schema: String,
@@ -223,7 +223,7 @@ impl IntoBytes for FinalizeRecordAction {
 impl IntoProto<track_and_trace_payload::FinalizeRecordAction> for FinalizeRecordAction {}
 impl IntoNative<FinalizeRecordAction> for track_and_trace_payload::FinalizeRecordAction {}
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct UpdatePropertiesAction {
     record_id: String,
     properties: Vec<PropertyValue>,
@@ -1197,6 +1197,7 @@ impl IntoNative<AssociatedAgent> for track_and_trace_state::Record_AssociatedAge
 pub struct Record {
     record_id: String,
     schema: String,
+    schema_version: u32,
     owners: Vec<AssociatedAgent>,
     custodians: Vec<AssociatedAgent>,
     field_final: bool,
@@ -1209,6 +1210,9 @@ impl Record {
     pub fn schema(&self) -> &str {
         &self.schema
     }
+    pub fn schema_version(&self) -> &u32 {
+        &self.schema_version
+    }
     pub fn owners(&self) -> &[AssociatedAgent] {
         &self.owners
     }
@@ -1222,6 +1226,7 @@ impl Record {
         RecordBuilder::new()
             .with_record_id(self.record_id)
             .with_schema(self.schema)
+            .with_schema_version(self.schema_version)
             .with_owners(self.owners)
             .with_custodians(self.custodians)
             .with_field_final(self.field_final)
@@ -1232,6 +1237,7 @@ impl Record {
 pub struct RecordBuilder {
     record_id: Option<String>,
     schema: Option<String>,
+    schema_version: Option<u32>,
     owners: Option<Vec<AssociatedAgent>>,
     custodians: Option<Vec<AssociatedAgent>>,
     field_final: Option<bool>,
@@ -1249,6 +1255,10 @@ impl RecordBuilder {
         self.schema = Some(value);
         self
     }
+    pub fn with_schema_version(mut self, value: u32) -> Self {
+        self.schema_version = Some(value);
+        self
+    }
     pub fn with_owners(mut self, value: Vec<AssociatedAgent>) -> Self {
         self.owners = Some(value);
         self
@@ -1268,6 +1278,7 @@ impl RecordBuilder {
         let schema = self
             .schema
             .ok_or_else(|| BuilderError::MissingField("schema".into()))?;
+        let schema_version = self.schema_version.unwrap_or_default();
         let owners = self
             .owners
             .ok_or_else(|| BuilderError::MissingField("owners".into()))?;
@@ -1280,6 +1291,7 @@ impl RecordBuilder {
         Ok(Record {
             record_id,
             schema,
+            schema_version,
             owners,
             custodians,
             field_final,
@@ -1292,6 +1304,7 @@ impl FromProto<track_and_trace_state::Record> for Record {
         Ok(Record {
             record_id: proto.get_record_id().to_string(),
             schema: proto.get_schema().to_string(),
+            schema_version: proto.get_schema_version(),
             owners: proto
                 .get_owners()
                 .to_vec()
@@ -1314,6 +1327,7 @@ impl FromNative<Record> for track_and_trace_state::Record {
         let mut proto = track_and_trace_state::Record::new();
         proto.set_record_id(native.record_id().to_string());
         proto.set_schema(native.schema().to_string());
+        proto.set_schema_version(*native.schema_version());
         proto.set_owners(
             RepeatedField::from_vec(
                 native
@@ -2057,6 +2071,7 @@ mod tests {
         let record = RecordBuilder::new()
             .with_record_id("egg1234".into())
             .with_schema("egg".into())
+            .with_schema_version(2)
             .with_owners(vec![associated_agent.clone()])
             .with_custodians(vec![associated_agent.clone()])
             .with_field_final(false)
@@ -2065,6 +2080,7 @@ mod tests {
 
         assert_eq!(record.record_id(), "egg1234");
         assert_eq!(record.schema(), "egg");
+        assert_eq!(*record.schema_version(), 2);
         assert!(record.owners().iter().any(|x| *x == associated_agent));
         assert!(record.custodians().iter().any(|x| *x == associated_agent));
         assert_eq!(*record.field_final(), false);
@@ -2091,6 +2107,7 @@ mod tests {
 
         assert_eq!(builder.record_id, Some("egg1234".to_string()));
         assert_eq!(builder.schema, Some("egg".to_string()));
+        assert_eq!(builder.schema_version, Some(0));
         assert_eq!(builder.owners, Some(vec![associated_agent.clone()]));
         assert_eq!(builder.custodians, Some(vec![associated_agent.clone()]));
         assert_eq!(builder.field_final, Some(false));